@@ -77,9 +77,12 @@ pub struct GetUserInput {
 #[derive(Serialize, SeamType)]
 pub struct GetUserOutput {
 	pub login: String,
+	#[seam(nullable)]
 	pub name: Option<String>,
 	pub avatar_url: String,
+	#[seam(nullable)]
 	pub bio: Option<String>,
+	#[seam(nullable)]
 	pub location: Option<String>,
 	pub public_repos: u32,
 	pub followers: u32,
@@ -127,7 +130,9 @@ pub struct GetUserReposInput {
 pub struct RepoItem {
 	pub id: u32,
 	pub name: String,
+	#[seam(nullable)]
 	pub description: Option<String>,
+	#[seam(nullable)]
 	pub language: Option<String>,
 	pub stargazers_count: u32,
 	pub forks_count: u32,
@@ -38,7 +38,7 @@ pub async fn get_content(_input: ContentInput) -> Result<ContentOutput, SeamErro
 async fn main() -> Result<(), Box<dyn std::error::Error>> {
 	if env::args().any(|a| a == "--manifest") {
 		let procs = vec![get_content_procedure()];
-		let manifest = build_manifest(&procs, &[], &[], &[], BTreeMap::new(), &BTreeMap::new());
+		let manifest = build_manifest(&procs, &[], &[], &[], &[], BTreeMap::new(), &BTreeMap::new());
 		println!("{}", serde_json::to_string(&manifest)?);
 		return Ok(());
 	}
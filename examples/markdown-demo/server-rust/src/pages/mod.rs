@@ -17,7 +17,10 @@ pub fn article_page() -> PageDef {
 		page_loader_keys: vec!["article".to_string()],
 		i18n_keys: Vec::new(),
 		projections: None,
+		pagination: None,
 		prerender: false,
 		static_dir: None,
+		template_mtime: None,
+		compiled: None,
 	}
 }
@@ -22,7 +22,10 @@ pub fn user_page() -> PageDef {
 		page_loader_keys: vec!["user".to_string()],
 		i18n_keys: Vec::new(),
 		projections: None,
+		pagination: None,
 		prerender: false,
 		static_dir: None,
+		template_mtime: None,
+		compiled: None,
 	}
 }
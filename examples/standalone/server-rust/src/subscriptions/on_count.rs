@@ -21,6 +21,7 @@ pub fn on_count_subscription() -> SubscriptionDef {
 		error_schema: None,
 		context_keys: vec![],
 		suppress: None,
+		buffer: None,
 		handler: std::sync::Arc::new(|params: seam_server::SubscriptionParams| {
 			Box::pin(async move {
 				let input: CountInput =
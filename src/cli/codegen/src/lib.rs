@@ -8,7 +8,8 @@ pub mod rpc_hash;
 
 pub use manifest::{
 	CacheHint, ChannelSchema, ContextSchema, IncomingSchema, InvalidateTarget, Manifest,
-	MappingValue, ProcedureSchema, ProcedureType, TransportConfig, TransportPreference,
+	ManifestDiff, MappingValue, ProcedureSchema, ProcedureType, SchemaChange, TransportConfig,
+	TransportPreference,
 };
 pub use rpc_hash::{RpcHashMap, generate_random_salt, generate_rpc_hash_map};
 pub use typescript::{generate_hooks_module, generate_type_declarations, generate_typescript};
@@ -0,0 +1,200 @@
+/* src/cli/codegen/src/manifest/diff.rs */
+
+// Manifest comparison for API compatibility gating: which procedures came and went,
+// and which schema edits would break a client generated from the old manifest.
+
+use std::collections::BTreeMap;
+
+use serde_json::Value;
+
+use super::{Manifest, ProcedureSchema};
+
+/// Differences between an old and a new manifest.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ManifestDiff {
+	/// Procedures only in the new manifest
+	pub added: Vec<String>,
+	/// Procedures only in the old manifest; always breaking
+	pub removed: Vec<String>,
+	/// Schema edits to procedures present in both, in procedure order
+	pub changes: Vec<SchemaChange>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SchemaChange {
+	pub procedure: String,
+	/// Dotted location rooted at `kind`, `input`, or `output` (e.g. `input.user.email`)
+	pub path: String,
+	pub description: String,
+	pub breaking: bool,
+}
+
+impl ManifestDiff {
+	pub fn is_empty(&self) -> bool {
+		self.added.is_empty() && self.removed.is_empty() && self.changes.is_empty()
+	}
+
+	pub fn is_breaking(&self) -> bool {
+		!self.removed.is_empty() || self.changes.iter().any(|c| c.breaking)
+	}
+}
+
+impl Manifest {
+	/// Compare `self` (old) against `other` (new).
+	pub fn diff(&self, other: &Manifest) -> ManifestDiff {
+		let removed =
+			self.procedures.keys().filter(|name| !other.procedures.contains_key(*name)).cloned();
+		let mut diff = ManifestDiff { removed: removed.collect(), ..ManifestDiff::default() };
+		for (name, new) in &other.procedures {
+			match self.procedures.get(name) {
+				Some(old) => {
+					let mut changes = Changes { procedure: name, out: &mut diff.changes };
+					diff_procedure(&mut changes, old, new);
+				}
+				None => diff.added.push(name.clone()),
+			}
+		}
+		diff
+	}
+}
+
+/// Who reads a schema decides which edits break: the server reads inputs, so it must keep
+/// accepting what old clients send; clients read outputs, so they must keep getting what
+/// they expect.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Side {
+	Input,
+	Output,
+}
+
+struct Changes<'a> {
+	procedure: &'a str,
+	out: &'a mut Vec<SchemaChange>,
+}
+
+impl Changes<'_> {
+	fn push(&mut self, path: &str, description: &str, breaking: bool) {
+		self.out.push(SchemaChange {
+			procedure: self.procedure.to_string(),
+			path: path.to_string(),
+			description: description.to_string(),
+			breaking,
+		});
+	}
+}
+
+fn diff_procedure(changes: &mut Changes, old: &ProcedureSchema, new: &ProcedureSchema) {
+	if old.proc_type != new.proc_type {
+		changes.push("kind", &format!("{} -> {}", old.proc_type, new.proc_type), true);
+	}
+	diff_schema(changes, Side::Input, "input", &old.input, &new.input);
+	match (old.effective_output(), new.effective_output()) {
+		(Some(old), Some(new)) => diff_schema(changes, Side::Output, "output", old, new),
+		(Some(_), None) => changes.push("output", "output schema removed", true),
+		(None, Some(_)) => changes.push("output", "output schema added", false),
+		(None, None) => {}
+	}
+}
+
+fn diff_schema(changes: &mut Changes, side: Side, path: &str, old: &Value, new: &Value) {
+	let (old, new) = (without_metadata(old), without_metadata(new));
+	if old == new {
+		return;
+	}
+	match (is_nullable(&old), is_nullable(&new)) {
+		(false, true) => changes.push(path, "became nullable", side == Side::Output),
+		(true, false) => changes.push(path, "no longer nullable", side == Side::Input),
+		_ => {}
+	}
+	if is_object_form(&old) && is_object_form(&new) {
+		diff_fields(changes, side, path, &old, &new);
+		return;
+	}
+	for (key, suffix) in [("elements", "[]"), ("values", ".*")] {
+		if let (Some(old), Some(new)) = (old.get(key), new.get(key)) {
+			diff_schema(changes, side, &format!("{path}{suffix}"), old, new);
+			return;
+		}
+	}
+	if let (Some(old), Some(new)) = (enum_values(&old), enum_values(&new)) {
+		if new.iter().any(|v| !old.contains(v)) {
+			changes.push(path, "enum values added", side == Side::Output);
+		}
+		if old.iter().any(|v| !new.contains(v)) {
+			changes.push(path, "enum values removed", side == Side::Input);
+		}
+		return;
+	}
+	if without_nullable(&old) != without_nullable(&new) {
+		changes.push(path, "schema changed", true);
+	}
+}
+
+fn diff_fields(changes: &mut Changes, side: Side, path: &str, old: &Value, new: &Value) {
+	let (old_fields, new_fields) = (fields(old), fields(new));
+	for (name, (old_schema, old_required)) in &old_fields {
+		let at = format!("{path}.{name}");
+		let Some((new_schema, new_required)) = new_fields.get(name) else {
+			changes.push(&at, "field removed", true);
+			continue;
+		};
+		match (old_required, new_required) {
+			(true, false) => changes.push(&at, "field became optional", side == Side::Output),
+			(false, true) => changes.push(&at, "field became required", side == Side::Input),
+			_ => {}
+		}
+		diff_schema(changes, side, &at, old_schema, new_schema);
+	}
+	for (name, (_, required)) in &new_fields {
+		if !old_fields.contains_key(name) {
+			let at = format!("{path}.{name}");
+			if *required {
+				changes.push(&at, "required field added", side == Side::Input);
+			} else {
+				changes.push(&at, "optional field added", false);
+			}
+		}
+	}
+}
+
+/// Field name to (schema, required) across `properties` and `optionalProperties`.
+fn fields(schema: &Value) -> BTreeMap<&str, (&Value, bool)> {
+	let mut fields = BTreeMap::new();
+	for (key, required) in [("properties", true), ("optionalProperties", false)] {
+		if let Some(props) = schema.get(key).and_then(Value::as_object) {
+			for (name, field) in props {
+				fields.insert(name.as_str(), (field, required));
+			}
+		}
+	}
+	fields
+}
+
+fn is_object_form(schema: &Value) -> bool {
+	schema.get("properties").is_some() || schema.get("optionalProperties").is_some()
+}
+
+fn is_nullable(schema: &Value) -> bool {
+	schema.get("nullable").and_then(Value::as_bool).unwrap_or(false)
+}
+
+fn enum_values(schema: &Value) -> Option<&Vec<Value>> {
+	schema.get("enum").and_then(Value::as_array)
+}
+
+/// Metadata (descriptions, codegen hints) never changes what goes over the wire.
+fn without_metadata(schema: &Value) -> Value {
+	without_key(schema, "metadata")
+}
+
+fn without_nullable(schema: &Value) -> Value {
+	without_key(schema, "nullable")
+}
+
+fn without_key(schema: &Value, key: &str) -> Value {
+	let mut schema = schema.clone();
+	if let Some(map) = schema.as_object_mut() {
+		map.remove(key);
+	}
+	schema
+}
@@ -5,6 +5,10 @@ use std::collections::BTreeMap;
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 
+mod diff;
+
+pub use diff::{ManifestDiff, SchemaChange};
+
 #[cfg(test)]
 mod tests;
 
@@ -16,6 +20,8 @@ pub enum ProcedureType {
 	Subscription,
 	Stream,
 	Upload,
+	/// Non-JSON response (file download); has an input schema but no output schema
+	Raw,
 }
 
 impl std::fmt::Display for ProcedureType {
@@ -26,6 +32,7 @@ impl std::fmt::Display for ProcedureType {
 			Self::Subscription => write!(f, "subscription"),
 			Self::Stream => write!(f, "stream"),
 			Self::Upload => write!(f, "upload"),
+			Self::Raw => write!(f, "raw"),
 		}
 	}
 }
@@ -66,6 +73,9 @@ pub struct ContextSchema {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Manifest {
 	pub version: u32,
+	/// Schema hash computed by the server; compared to spot drift between local and served manifests.
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub hash: Option<String>,
 	#[serde(default)]
 	pub context: BTreeMap<String, ContextSchema>,
 	pub procedures: BTreeMap<String, ProcedureSchema>,
@@ -109,6 +109,7 @@ fn deserialize_upload_manifest() {
 fn serialize_outputs_kind() {
 	let m = Manifest {
 		version: 2,
+		hash: None,
 		context: BTreeMap::new(),
 		procedures: BTreeMap::from([(
 			"test".to_string(),
@@ -207,6 +208,7 @@ fn deserialize_manifest_with_context() {
 fn validate_context_refs_pass() {
 	let m = Manifest {
 		version: 2,
+		hash: None,
 		context: BTreeMap::from([(
 			"auth".to_string(),
 			ContextSchema { extract: "extractAuth".to_string(), schema: json!({}) },
@@ -236,6 +238,7 @@ fn validate_context_refs_pass() {
 fn validate_context_refs_fail() {
 	let m = Manifest {
 		version: 2,
+		hash: None,
 		context: BTreeMap::new(),
 		procedures: BTreeMap::from([(
 			"getPost".to_string(),
@@ -418,3 +421,87 @@ fn cache_hint_in_manifest() {
 	assert_eq!(m.procedures["getUser"].cache, Some(CacheHint::Config { ttl: 60 }));
 	assert_eq!(m.procedures["listPosts"].cache, Some(CacheHint::Disabled(false)));
 }
+
+// -- Manifest diff --
+
+fn manifest_of(procedures: serde_json::Value) -> Manifest {
+	let mut manifest = json!({ "version": 2 });
+	manifest["procedures"] = procedures;
+	serde_json::from_value(manifest).unwrap()
+}
+
+fn user_query(input: serde_json::Value, output: serde_json::Value) -> serde_json::Value {
+	let mut query = json!({ "kind": "query" });
+	query["input"] = input;
+	query["output"] = output;
+	let mut procedures = json!({});
+	procedures["getUser"] = query;
+	procedures
+}
+
+#[test]
+fn diff_reports_added_procedure_as_non_breaking() {
+	let old = manifest_of(user_query(json!({}), json!({})));
+	let mut procedures = user_query(json!({}), json!({}));
+	procedures["listUsers"] = json!({ "kind": "query", "input": {}, "output": {} });
+	let diff = old.diff(&manifest_of(procedures));
+	assert_eq!(diff.added, ["listUsers"]);
+	assert!(diff.removed.is_empty() && diff.changes.is_empty());
+	assert!(!diff.is_breaking());
+}
+
+#[test]
+fn diff_removed_output_field_is_breaking() {
+	let old = manifest_of(user_query(
+		json!({}),
+		json!({ "properties": { "id": { "type": "string" }, "email": { "type": "string" } } }),
+	));
+	let new =
+		manifest_of(user_query(json!({}), json!({ "properties": { "id": { "type": "string" } } })));
+	let diff = old.diff(&new);
+	assert_eq!(diff.changes.len(), 1);
+	assert_eq!(diff.changes[0].procedure, "getUser");
+	assert_eq!(diff.changes[0].path, "output.email");
+	assert!(diff.changes[0].breaking);
+	assert!(diff.is_breaking());
+}
+
+#[test]
+fn diff_added_optional_input_field_is_non_breaking() {
+	let old =
+		manifest_of(user_query(json!({ "properties": { "id": { "type": "string" } } }), json!({})));
+	let new = manifest_of(user_query(
+		json!({
+			"properties": { "id": { "type": "string" } },
+			"optionalProperties": { "fields": { "elements": { "type": "string" } } }
+		}),
+		json!({}),
+	));
+	let diff = old.diff(&new);
+	assert_eq!(diff.changes.len(), 1);
+	assert_eq!(diff.changes[0].path, "input.fields");
+	assert_eq!(diff.changes[0].description, "optional field added");
+	assert!(!diff.is_breaking());
+}
+
+#[test]
+fn diff_breaking_direction_follows_reader() {
+	// A new required field breaks old clients' inputs but not their outputs
+	let old = manifest_of(user_query(json!({ "properties": {} }), json!({ "properties": {} })));
+	let field = json!({ "properties": { "role": { "enum": ["admin"] } } });
+	let diff = old.diff(&manifest_of(user_query(field.clone(), field)));
+	let breaking: Vec<(&str, bool)> =
+		diff.changes.iter().map(|c| (c.path.as_str(), c.breaking)).collect();
+	assert_eq!(breaking, [("input.role", true), ("output.role", false)]);
+}
+
+#[test]
+fn diff_ignores_metadata_and_reports_removed_procedure() {
+	let old = manifest_of(user_query(json!({ "type": "string" }), json!({})));
+	let tagged =
+		manifest_of(user_query(json!({ "type": "string", "metadata": { "doc": "id" } }), json!({})));
+	assert!(old.diff(&tagged).is_empty());
+	let diff = old.diff(&manifest_of(json!({})));
+	assert_eq!(diff.removed, ["getUser"]);
+	assert!(diff.is_breaking());
+}
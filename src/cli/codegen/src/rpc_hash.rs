@@ -35,7 +35,8 @@ fn hash_name(name: &str, salt: &str, hash_length: usize, prefix: &str) -> String
 /// Build an RPC hash map from procedure names and a salt.
 /// When `type_hint` is true, hashes use `rpc-` prefix.
 /// `hash_length` controls the number of hex chars in the hash portion.
-/// Detects collisions and retries with modified salt (up to 100 attempts).
+/// Detects collisions and retries with modified salt (up to 100 attempts); if every attempt
+/// collides, the error names the two procedures that last shared a hash.
 pub fn generate_rpc_hash_map(
 	names: &[&str],
 	salt: &str,
@@ -43,13 +44,14 @@ pub fn generate_rpc_hash_map(
 	type_hint: bool,
 ) -> Result<RpcHashMap> {
 	let prefix = if type_hint { "rpc-" } else { "" };
+	let mut last_collision = None;
 
 	for attempt in 0..100u32 {
 		let effective_salt = if attempt == 0 { salt.to_string() } else { format!("{salt}{attempt}") };
 
 		let mut procedures = BTreeMap::new();
 		let mut seen = BTreeMap::new();
-		let mut collision = false;
+		let mut collision = None;
 
 		// Hash _batch first
 		let batch_hash = hash_name("_batch", &effective_salt, hash_length, prefix);
@@ -60,19 +62,26 @@ pub fn generate_rpc_hash_map(
 			if let Some(existing) = seen.get(&hash)
 				&& existing != name
 			{
-				collision = true;
+				collision = Some((existing.clone(), name.to_string(), hash));
 				break;
 			}
 			seen.insert(hash.clone(), name.to_string());
 			procedures.insert(name.to_string(), hash);
 		}
 
-		if !collision {
+		if collision.is_none() {
 			return Ok(RpcHashMap { salt: effective_salt, batch: batch_hash, procedures });
 		}
+		last_collision = collision;
 	}
 
-	bail!("failed to generate collision-free RPC hash map after 100 attempts")
+	match last_collision {
+		Some((a, b, hash)) => bail!(
+			"RPC hash collision: procedures \"{a}\" and \"{b}\" both hash to \"{hash}\" \
+			 under every salt tried; increase build.hash_length (currently {hash_length})"
+		),
+		None => bail!("failed to generate collision-free RPC hash map after 100 attempts"),
+	}
 }
 
 #[cfg(test)]
@@ -100,6 +109,27 @@ mod tests {
 		assert_ne!(map1.procedures["getUser"], map2.procedures["getUser"]);
 	}
 
+	#[test]
+	fn pinned_salt_is_stable_across_builds() {
+		// Fixed expectations: a pinned salt must keep client bundles valid across versions
+		let map = generate_rpc_hash_map(&["getUser", "listPosts"], "pinned-salt", 12, true).unwrap();
+		assert_eq!(map.procedures["getUser"], "rpc-3acf8d54107f");
+		assert_eq!(map.batch, "rpc-97da4e25caa9");
+		assert_eq!(map.salt, "pinned-salt");
+		// Input order does not matter
+		let reordered =
+			generate_rpc_hash_map(&["listPosts", "getUser"], "pinned-salt", 12, true).unwrap();
+		assert_eq!(reordered.procedures, map.procedures);
+	}
+
+	#[test]
+	fn distinct_pinned_salts_differ() {
+		let a = generate_rpc_hash_map(&["getUser"], "salt-a", 12, true).unwrap();
+		let b = generate_rpc_hash_map(&["getUser"], "salt-b", 12, true).unwrap();
+		assert_ne!(a.procedures["getUser"], b.procedures["getUser"]);
+		assert_ne!(a.batch, b.batch);
+	}
+
 	#[test]
 	fn no_collision_on_typical_set() {
 		let names: Vec<&str> = vec![
@@ -186,6 +216,16 @@ mod tests {
 		assert!(salt.chars().all(|c| c.is_ascii_hexdigit()));
 	}
 
+	#[test]
+	fn unavoidable_collision_names_procedures() {
+		// One hex char has 16 values, so 20 names collide under any salt
+		let names: Vec<String> = (0..20).map(|i| format!("proc{i}")).collect();
+		let refs: Vec<&str> = names.iter().map(String::as_str).collect();
+		let err = generate_rpc_hash_map(&refs, "salt", 1, false).unwrap_err().to_string();
+		assert!(err.starts_with("RPC hash collision: procedures \""), "{err}");
+		assert!(err.contains("increase build.hash_length (currently 1)"), "{err}");
+	}
+
 	#[test]
 	fn empty_procedures() {
 		let salt = generate_random_salt();
@@ -10,7 +10,7 @@ use anyhow::Result;
 use crate::manifest::{CacheHint, InvalidateTarget, Manifest, ProcedureSchema, ProcedureType};
 use crate::rpc_hash::RpcHashMap;
 
-use super::render::{render_top_level, to_pascal_case};
+use super::render::{SEAM_PAGE_DECL, render_top_level, to_pascal_case, uses_page};
 
 use channel::{channel_owned_procedures, generate_channel_factory, generate_channel_types};
 use transport::{generate_transport_hint, resolve_channel_transport};
@@ -20,10 +20,15 @@ fn quote_key(name: &str) -> String {
 	if name.contains('.') { format!("\"{name}\"") } else { name.to_string() }
 }
 
+/// Procedures the JSON client can call; raw procedures answer with bytes and are fetched directly.
+fn client_procedures(manifest: &Manifest) -> impl Iterator<Item = (&String, &ProcedureSchema)> {
+	manifest.procedures.iter().filter(|(_, s)| s.proc_type != ProcedureType::Raw)
+}
+
 /// Generate `seamProcedureConfig` runtime constant with kind, cache hints, and invalidates.
 fn generate_procedure_config(manifest: &Manifest) -> String {
 	let mut out = String::from("export const seamProcedureConfig = {\n");
-	for (name, schema) in &manifest.procedures {
+	for (name, schema) in client_procedures(manifest) {
 		let key = quote_key(name);
 		let kind = schema.proc_type.to_string();
 
@@ -86,7 +91,7 @@ fn generate_procedure_meta(manifest: &Manifest) -> String {
 		manifest.channels.keys().map(|ch| format!("{ch}.events")).collect();
 
 	let mut out = String::from("export interface SeamProcedureMeta {\n");
-	for (name, schema) in &manifest.procedures {
+	for (name, schema) in client_procedures(manifest) {
 		let pascal = to_pascal_case(name);
 		let key = quote_key(name);
 		let kind = match schema.proc_type {
@@ -95,6 +100,7 @@ fn generate_procedure_meta(manifest: &Manifest) -> String {
 			ProcedureType::Subscription => "subscription",
 			ProcedureType::Stream => "stream",
 			ProcedureType::Upload => "upload",
+			ProcedureType::Raw => "raw",
 		};
 		let (input_name, output_name) = if channel_event_names.contains(name) {
 			// Channel event subscription: types follow channel naming convention
@@ -207,6 +213,11 @@ fn generate_procedure_declarations(
 		out.push_str(&render_top_level(&input_name, &schema.input)?);
 		out.push('\n');
 
+		// Raw responses are bytes: no output type and no client method, only the input shape
+		if schema.proc_type == ProcedureType::Raw {
+			continue;
+		}
+
 		if let Some(output_schema) = schema.effective_output() {
 			out.push_str(&render_top_level(&output_name, output_schema)?);
 			out.push('\n');
@@ -335,6 +346,9 @@ pub fn generate_typescript(
 
 	out.push_str(&generate_imports(has_stream));
 	out.push_str(&format!("export const DATA_ID = \"{data_id}\";\n\n"));
+	if serde_json::to_value(manifest).is_ok_and(|m| uses_page(&m)) {
+		out.push_str(SEAM_PAGE_DECL);
+	}
 
 	let channel_owned = channel_owned_procedures(manifest);
 	let (type_decls, iface_lines, factory_lines) =
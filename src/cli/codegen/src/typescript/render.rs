@@ -9,7 +9,7 @@ use serde_json::Value;
 /// Properties form -> `export interface`, other forms -> `export type`.
 /// Empty properties form -> `Record<string, never>` to avoid lint-unfriendly empty interfaces.
 pub(super) fn render_top_level(name: &str, schema: &Value) -> Result<String> {
-	if is_properties_form(schema) {
+	if is_properties_form(schema) && page_item_schema(schema).is_none() {
 		let has_props =
 			schema.get("properties").and_then(Value::as_object).is_some_and(|o| !o.is_empty());
 		let has_opt =
@@ -111,13 +111,20 @@ fn render_type_inner(schema: &Value) -> Result<String> {
 		let parts: Vec<String> = sorted
 			.iter()
 			.map(|(key, variant)| {
-				let variant_ts = render_type(variant)?;
-				Ok(format!("({{ {tag}: \"{key}\" }} & {variant_ts})"))
+				// Flatten the tag into each variant so TS narrows on `tag` directly
+				let mut fields = vec![format!("{tag}: \"{key}\"")];
+				fields.extend(object_fields(variant)?);
+				Ok(format!("{{ {} }}", fields.join("; ")))
 			})
 			.collect::<Result<Vec<_>>>()?;
 		return Ok(parts.join(" | "));
 	}
 
+	// `seam_server::Page<T>` keeps its shared generic name
+	if let Some(item) = page_item_schema(schema) {
+		return Ok(format!("SeamPage<{}>", render_type(item)?));
+	}
+
 	// Properties form
 	if is_properties_form(schema) {
 		return render_inline_object(schema);
@@ -132,6 +139,11 @@ fn render_type_inner(schema: &Value) -> Result<String> {
 }
 
 fn render_inline_object(schema: &Value) -> Result<String> {
+	Ok(format!("{{ {} }}", object_fields(schema)?.join("; ")))
+}
+
+/// `key: T` / `key?: T` entries of a properties-form schema, required keys first.
+fn object_fields(schema: &Value) -> Result<Vec<String>> {
 	let mut fields = Vec::new();
 
 	if let Some(props) = schema.get("properties").and_then(Value::as_object) {
@@ -150,7 +162,28 @@ fn render_inline_object(schema: &Value) -> Result<String> {
 		}
 	}
 
-	Ok(format!("{{ {} }}", fields.join("; ")))
+	Ok(fields)
+}
+
+/// Declaration backing `SeamPage<T>`, emitted once when any schema uses it.
+pub(super) const SEAM_PAGE_DECL: &str =
+	"export interface SeamPage<T> {\n  items: Array<T>;\n  nextCursor: string | null;\n}\n\n";
+
+/// Item schema of a `seam_server::Page<T>` schema, recognized by its JTD `metadata` tag.
+fn page_item_schema(schema: &Value) -> Option<&Value> {
+	if schema.pointer("/metadata/seamType").and_then(Value::as_str) != Some("Page") {
+		return None;
+	}
+	schema.pointer("/properties/items/elements")
+}
+
+/// Whether `value` (a schema, or any JSON holding schemas) uses `SeamPage` anywhere.
+pub(super) fn uses_page(value: &Value) -> bool {
+	match value {
+		Value::Object(obj) => page_item_schema(value).is_some() || obj.values().any(uses_page),
+		Value::Array(items) => items.iter().any(uses_page),
+		_ => false,
+	}
 }
 
 fn is_properties_form(schema: &Value) -> bool {
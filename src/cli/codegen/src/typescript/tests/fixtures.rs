@@ -24,6 +24,7 @@ pub(super) fn make_procedure(proc_type: ProcedureType) -> ProcedureSchema {
 pub(super) fn make_manifest_with(procedures: BTreeMap<String, ProcedureSchema>) -> Manifest {
 	Manifest {
 		version: 2,
+		hash: None,
 		context: BTreeMap::new(),
 		procedures,
 		channels: BTreeMap::new(),
@@ -285,6 +285,54 @@ fn upload_codegen() {
 	));
 }
 
+#[test]
+fn page_output_declares_seam_page_once() {
+	let page = json!({
+		"properties": {
+			"items": { "elements": { "type": "string" } },
+			"nextCursor": { "type": "string", "nullable": true },
+		},
+		"metadata": { "seamType": "Page" },
+	});
+	let manifest = make_manifest_with(BTreeMap::from([
+		(
+			"listTags".into(),
+			ProcedureSchema { output: Some(page.clone()), ..make_procedure(ProcedureType::Query) },
+		),
+		(
+			"listUsers".into(),
+			ProcedureSchema { output: Some(page), ..make_procedure(ProcedureType::Query) },
+		),
+	]));
+
+	let code = generate_typescript(&manifest, None, "__data").unwrap();
+	assert_eq!(code.matches("export interface SeamPage<T> {").count(), 1);
+	assert!(code.contains("export type ListTagsOutput = SeamPage<string>;"));
+	assert!(code.contains("export type ListUsersOutput = SeamPage<string>;"));
+
+	let plain =
+		make_manifest_with(BTreeMap::from([("ping".into(), make_procedure(ProcedureType::Query))]));
+	assert!(!generate_typescript(&plain, None, "__data").unwrap().contains("SeamPage"));
+}
+
+#[test]
+fn raw_codegen_emits_input_only() {
+	let manifest = make_manifest_with(BTreeMap::from([(
+		"exportCsv".into(),
+		ProcedureSchema {
+			input: json!({ "properties": { "month": { "type": "string" } } }),
+			output: None,
+			..make_procedure(ProcedureType::Raw)
+		},
+	)]));
+
+	let code = generate_typescript(&manifest, None, "__data").unwrap();
+	assert!(code.contains("export interface ExportCsvInput {"));
+	assert!(!code.contains("ExportCsvOutput"));
+	assert!(!code.contains("exportCsv(input"));
+	assert!(!code.contains("exportCsv: {"));
+}
+
 #[test]
 fn invalidates_codegen() {
 	use crate::manifest::InvalidateTarget;
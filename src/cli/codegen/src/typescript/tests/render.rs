@@ -45,6 +45,23 @@ fn properties_required_and_optional() {
 	assert_eq!(ts, "{ age: number; name: string; email?: string }");
 }
 
+#[test]
+fn optional_field_differs_from_nullable_field() {
+	let schema = json!({
+			"properties": {
+					"bio": { "type": "string", "nullable": true }
+			},
+			"optionalProperties": {
+					"nickname": { "type": "string" },
+					"avatar": { "type": "string", "nullable": true }
+			}
+	});
+	let ts = render_top_level("Profile", &schema).unwrap();
+	assert!(ts.contains("  bio: string | null;\n"));
+	assert!(ts.contains("  nickname?: string;\n"));
+	assert!(ts.contains("  avatar?: string | null;\n"));
+}
+
 #[test]
 fn elements() {
 	let schema = json!({ "elements": { "type": "string" } });
@@ -77,9 +94,33 @@ fn discriminator_form() {
 			}
 	});
 	let ts = render_type(&schema).unwrap();
+	assert_eq!(ts, "{ type: \"email\"; address: string } | { type: \"sms\"; phone: string }");
+}
+
+#[test]
+fn discriminator_variants_render_nested_schemas() {
+	let schema = json!({
+			"discriminator": "kind",
+			"mapping": {
+					"click": {
+							"properties": { "x": { "type": "int32" }, "y": { "type": "int32" } },
+							"optionalProperties": { "target": { "type": "string", "nullable": true } }
+					},
+					"key": {
+							"properties": {
+									"value": { "type": "string" },
+									"mods": { "elements": { "enum": ["alt", "ctrl"] } }
+							}
+					},
+					"idle": { "properties": {} }
+			}
+	});
+	let ts = render_top_level("UiEvent", &schema).unwrap();
 	assert_eq!(
 		ts,
-		"({ type: \"email\" } & { address: string }) | ({ type: \"sms\" } & { phone: string })"
+		"export type UiEvent = { kind: \"click\"; x: number; y: number; target?: string | null } \
+		 | { kind: \"idle\" } \
+		 | { kind: \"key\"; mods: Array<\"alt\" | \"ctrl\">; value: string };\n"
 	);
 }
 
@@ -172,3 +213,19 @@ fn to_pascal_case_dotted() {
 fn to_pascal_case_multi_dot() {
 	assert_eq!(to_pascal_case("a.b.c"), "ABC");
 }
+
+#[test]
+fn page_schema_renders_shared_generic() {
+	let schema = json!({
+		"properties": {
+			"items": { "elements": { "properties": { "id": { "type": "string" } } } },
+			"nextCursor": { "type": "string", "nullable": true },
+		},
+		"metadata": { "seamType": "Page" },
+	});
+	assert_eq!(render_type(&schema).unwrap(), "SeamPage<{ id: string }>");
+	assert_eq!(
+		render_top_level("ListPostsOutput", &schema).unwrap(),
+		"export type ListPostsOutput = SeamPage<{ id: string }>;\n"
+	);
+}
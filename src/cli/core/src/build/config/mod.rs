@@ -1,7 +1,7 @@
 /* src/cli/core/src/build/config/mod.rs */
 
 use std::hash::{Hash, Hasher};
-use std::path::Path;
+use std::path::{Path, PathBuf};
 
 use anyhow::{Result, bail};
 
@@ -23,13 +23,19 @@ pub struct BuildConfig {
 	pub obfuscate: bool,
 	pub sourcemap: bool,
 	pub type_hint: bool,
+	pub modulepreload: bool,
+	pub gzip_templates: bool,
 	pub hash_length: usize,
 	pub rpc_salt: Option<String>,
 	pub root_id: String,
 	pub data_id: String,
+	pub lang: Option<String>,
 	pub pages_dir: Option<String>,
+	pub partials_dir: Option<String>,
 	pub i18n: Option<I18nSection>,
 	pub config_path: Option<String>,
+	/// Route to dump CTR debug artifacts for, from `seam build --ctr-debug`
+	pub ctr_debug: Option<String>,
 }
 
 impl BuildConfig {
@@ -71,13 +77,19 @@ impl BuildConfig {
 		let obfuscate = build.obfuscate.unwrap_or(true);
 		let sourcemap = build.sourcemap.unwrap_or(false);
 		let type_hint = build.type_hint.unwrap_or(true);
+		let modulepreload = build.modulepreload.unwrap_or(false);
+		let gzip_templates = build.gzip_templates.unwrap_or(false);
 		let hash_length = build.hash_length.unwrap_or(12) as usize;
 		if !(4..=64).contains(&hash_length) {
 			bail!("hash_length must be between 4 and 64 (got {hash_length})");
 		}
 
+		let rpc_salt = build.salt.clone();
+		check_rpc_salt(rpc_salt.as_deref())?;
+
 		let root_id = config.frontend.root_id.clone();
 		let data_id = config.frontend.data_id.clone();
+		let lang = config.frontend.lang.clone();
 		let i18n = config.i18n.clone();
 		let config_path = config.config_file_path.clone();
 
@@ -95,13 +107,18 @@ impl BuildConfig {
 			obfuscate,
 			sourcemap,
 			type_hint,
+			modulepreload,
+			gzip_templates,
 			hash_length,
-			rpc_salt: None,
+			rpc_salt,
 			root_id,
 			data_id,
+			lang,
 			pages_dir,
+			partials_dir: build.partials_dir.clone(),
 			i18n,
 			config_path,
+			ctr_debug: None,
 		})
 	}
 
@@ -114,6 +131,11 @@ impl BuildConfig {
 		format!("{}/.vite/manifest.json", self.dist_dir())
 	}
 
+	/// `build.partials_dir` resolved against the project root
+	pub fn partials_path(&self, base_dir: &Path) -> Option<PathBuf> {
+		self.partials_dir.as_ref().map(|dir| base_dir.join(dir))
+	}
+
 	pub fn warn_stale_vite_config(base_dir: &Path) {
 		for name in ["vite.config.ts", "vite.config.js", "vite.config.mjs"] {
 			if base_dir.join(name).exists() {
@@ -122,6 +144,14 @@ impl BuildConfig {
 		}
 	}
 
+	/// Apply `seam build --salt` over `build.salt`; the merged salt must not be empty.
+	pub fn override_rpc_salt(&mut self, salt: Option<&str>) -> Result<()> {
+		if let Some(salt) = salt {
+			self.rpc_salt = Some(salt.to_string());
+		}
+		check_rpc_salt(self.rpc_salt.as_deref())
+	}
+
 	pub fn from_seam_config_dev(config: &SeamConfig) -> Result<Self> {
 		let mut bc = Self::from_seam_config(config)?;
 		bc.obfuscate = config.dev.obfuscate.unwrap_or(false);
@@ -143,10 +173,14 @@ impl BuildConfig {
 		self.obfuscate.hash(&mut h);
 		self.sourcemap.hash(&mut h);
 		self.type_hint.hash(&mut h);
+		self.modulepreload.hash(&mut h);
+		self.gzip_templates.hash(&mut h);
 		self.hash_length.hash(&mut h);
 		self.root_id.hash(&mut h);
 		self.data_id.hash(&mut h);
+		self.lang.hash(&mut h);
 		self.pages_dir.hash(&mut h);
+		self.partials_dir.hash(&mut h);
 		self.is_fullstack.hash(&mut h);
 		if let Some(ref i18n) = self.i18n {
 			i18n.locales.hash(&mut h);
@@ -159,5 +193,12 @@ impl BuildConfig {
 	}
 }
 
+fn check_rpc_salt(salt: Option<&str>) -> Result<()> {
+	if salt == Some("") {
+		bail!("salt must not be empty (omit build.salt and --salt for a random salt per build)");
+	}
+	Ok(())
+}
+
 #[cfg(test)]
 mod tests;
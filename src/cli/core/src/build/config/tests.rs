@@ -94,6 +94,31 @@ routes = "./src/routes.ts"
 	assert!(msg.contains("frontend.entry"));
 }
 
+#[test]
+fn root_id_defaults_to_skeleton_root() {
+	let bc = BuildConfig::from_seam_config(&parse_fullstack("", "")).unwrap();
+	assert_eq!(bc.root_id, seam_skeleton::DEFAULT_ROOT_ID);
+}
+
+#[test]
+fn root_id_from_frontend_config() {
+	let config = parse_config(
+		r#"
+[project]
+name = "test"
+
+[frontend]
+entry = "src/client/main.tsx"
+root_id = "app"
+
+[build]
+routes = "./src/routes.ts"
+"#,
+	);
+	let bc = BuildConfig::from_seam_config(&config).unwrap();
+	assert_eq!(bc.root_id, "app");
+}
+
 #[test]
 fn build_config_obfuscate_defaults() {
 	let config = parse_fullstack("", "");
@@ -351,6 +376,34 @@ fn config_hash_changes_with_obfuscate() {
 	assert_ne!(h1, h2);
 }
 
+#[test]
+fn build_salt_pins_rpc_salt() {
+	let config = parse_fullstack("salt = \"release-1\"", "");
+	let bc = BuildConfig::from_seam_config(&config).unwrap();
+	assert_eq!(bc.rpc_salt.as_deref(), Some("release-1"));
+	// Dev builds always use a fresh salt
+	assert!(BuildConfig::from_seam_config_dev(&config).unwrap().rpc_salt.is_none());
+}
+
+#[test]
+fn empty_build_salt_rejected() {
+	let config = parse_fullstack("salt = \"\"", "");
+	let err = BuildConfig::from_seam_config(&config).err().unwrap();
+	assert!(err.to_string().contains("salt must not be empty"), "{err}");
+}
+
+#[test]
+fn salt_override_replaces_and_is_checked() {
+	let config = parse_fullstack("salt = \"release-1\"", "");
+	let mut bc = BuildConfig::from_seam_config(&config).unwrap();
+	bc.override_rpc_salt(None).unwrap();
+	assert_eq!(bc.rpc_salt.as_deref(), Some("release-1"));
+	bc.override_rpc_salt(Some("release-2")).unwrap();
+	assert_eq!(bc.rpc_salt.as_deref(), Some("release-2"));
+	let err = bc.override_rpc_salt(Some("")).unwrap_err();
+	assert!(err.to_string().contains("salt must not be empty"), "{err}");
+}
+
 #[test]
 fn config_hash_ignores_rpc_salt() {
 	let config = parse_fullstack("", "");
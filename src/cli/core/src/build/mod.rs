@@ -1,6 +1,7 @@
 /* src/cli/core/src/build/mod.rs */
 
 pub mod config;
+pub mod report;
 pub mod route;
 pub mod run;
 pub mod types;
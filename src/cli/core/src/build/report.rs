@@ -0,0 +1,163 @@
+/* src/cli/core/src/build/report.rs */
+
+// Library entry for builds: runs `seam build` in-process and reports what it produced.
+
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result, bail};
+
+use super::config::BuildConfig;
+use crate::config::{SeamConfig, load_seam_config};
+use crate::ui::Warnings;
+
+/// Options for [`build_project`]; the defaults match a plain `seam build`.
+#[derive(Debug, Clone, Default)]
+pub struct BuildOptions {
+	/// Route whose CTR mismatch should dump debug artifacts (`--ctr-debug`)
+	pub ctr_debug: Option<String>,
+	/// RPC hash salt (`--salt`); overrides `build.salt`
+	pub salt: Option<String>,
+}
+
+/// Outcome of a finished build. A failed build returns its error instead.
+#[derive(Debug)]
+pub struct BuildReport {
+	/// Route patterns in the written route manifest, sorted
+	pub routes: Vec<String>,
+	/// Warnings raised during the build, in the order they were printed
+	pub warnings: Vec<String>,
+	pub out_dir: PathBuf,
+}
+
+/// Load the config at `config_path` and build that project.
+pub fn build_project(config_path: &Path, options: &BuildOptions) -> Result<BuildReport> {
+	let config = load_seam_config(config_path)?;
+	let base_dir = config_path.parent().unwrap_or_else(|| Path::new("."));
+	build_with_config(&config, base_dir, options)
+}
+
+/// Build an already-loaded config rooted at `base_dir`. Progress still streams to stdout;
+/// warnings are collected into the report as well. Workspace configs are rejected, since
+/// every member has its own output.
+pub fn build_with_config(
+	config: &SeamConfig,
+	base_dir: &Path,
+	options: &BuildOptions,
+) -> Result<BuildReport> {
+	if config.is_workspace() {
+		bail!("workspace configs are built per member; pass a member's config instead");
+	}
+	let out_dir = base_dir.join(BuildConfig::from_seam_config(config)?.out_dir);
+	let warnings = Warnings::default();
+	super::run::run_build(config, base_dir, options, &warnings)?;
+	Ok(BuildReport { routes: manifest_routes(&out_dir)?, warnings: warnings.into_vec(), out_dir })
+}
+
+fn manifest_routes(out_dir: &Path) -> Result<Vec<String>> {
+	let path = out_dir.join("route-manifest.json");
+	let content =
+		std::fs::read_to_string(&path).with_context(|| format!("failed to read {}", path.display()))?;
+	let manifest: serde_json::Value =
+		serde_json::from_str(&content).context("failed to parse route-manifest.json")?;
+	let mut routes: Vec<String> =
+		manifest["routes"].as_object().map(|m| m.keys().cloned().collect()).unwrap_or_default();
+	routes.sort();
+	Ok(routes)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn fixture(name: &str, config: &str) -> PathBuf {
+		let dir = std::env::temp_dir().join(name);
+		let _ = std::fs::remove_dir_all(&dir);
+		std::fs::create_dir_all(&dir).unwrap();
+		std::fs::write(dir.join("seam.toml"), config).unwrap();
+		dir
+	}
+
+	#[test]
+	fn invalid_project_returns_error_instead_of_exiting() {
+		let dir = fixture("seam-test-library-build-invalid", "[project]\nname = \"lib-build\"\n");
+		let err = build_project(&dir.join("seam.toml"), &BuildOptions::default()).unwrap_err();
+		let _ = std::fs::remove_dir_all(&dir);
+		assert!(format!("{err:#}").contains("is required"), "{err:#}");
+	}
+
+	#[test]
+	fn workspace_project_is_rejected() {
+		let dir = fixture(
+			"seam-test-library-build-workspace",
+			"[project]\nname = \"ws\"\n\n[workspace]\nmembers = [\"a\"]\n",
+		);
+		let err = build_project(&dir.join("seam.toml"), &BuildOptions::default()).unwrap_err();
+		let _ = std::fs::remove_dir_all(&dir);
+		assert!(err.to_string().contains("per member"), "{err}");
+	}
+
+	/// Stand-ins for the `@canmi/seam-cli` bundler and `@canmi/seam-react` skeleton
+	/// scripts, so a whole frontend build runs on plain node.
+	const STUB_BUNDLER: &str = r#"import { mkdirSync, writeFileSync } from 'node:fs'
+const out = process.argv[3]
+mkdirSync(`${out}/.vite`, { recursive: true })
+mkdirSync(`${out}/assets`, { recursive: true })
+writeFileSync(`${out}/assets/main.js`, 'console.log("seam")\n')
+writeFileSync(`${out}/.vite/manifest.json`, JSON.stringify({ js: ['assets/main.js'], css: [] }))
+"#;
+	const STUB_SKELETONS: &str = r#"const route = (path, html, prerender) => ({
+  path, loaders: {}, axes: [], variants: [{ variant: {}, html }], mockHtml: html, mock: {},
+  pageSchema: null, prerender,
+})
+console.log(JSON.stringify({
+  routes: [route('/', '<p>home</p>'), route('/about', '<p>about</p>', true)],
+  warnings: ['stub renderer warning'],
+}))
+"#;
+
+	#[test]
+	fn fixture_project_builds_and_reports_warnings() {
+		if !crate::shell::which_exists("node") && !crate::shell::which_exists("bun") {
+			return;
+		}
+		let dir = fixture(
+			"seam-test-library-build-fixture",
+			"output = \"server\"\n\n[project]\nname = \"fixture\"\n\n[frontend]\nentry = \"src/main.tsx\"\n\n[build]\nroutes = \"src/routes.ts\"\n",
+		);
+		for (pkg, script, body) in [
+			("seam-cli", "build-frontend.mjs", STUB_BUNDLER),
+			("seam-react", "build-skeletons.mjs", STUB_SKELETONS),
+		] {
+			let scripts = dir.join("node_modules/@canmi").join(pkg).join("scripts");
+			std::fs::create_dir_all(&scripts).unwrap();
+			std::fs::write(scripts.join(script), body).unwrap();
+		}
+
+		let report = build_project(&dir.join("seam.toml"), &BuildOptions::default());
+		let template = std::fs::read_to_string(dir.join(".seam/output/templates/about.html"));
+		let _ = std::fs::remove_dir_all(&dir);
+		let report = report.unwrap();
+		assert_eq!(report.routes, ["/", "/about"]);
+		assert_eq!(
+			report.warnings,
+			[
+				"stub renderer warning",
+				"/about: prerender=true ignored in server mode (all pages are CTR)"
+			]
+		);
+		assert!(template.unwrap().contains("<p>about</p>"));
+	}
+
+	#[test]
+	fn routes_come_from_written_manifest() {
+		let dir = fixture("seam-test-library-build-routes", "");
+		std::fs::write(
+			dir.join("route-manifest.json"),
+			r#"{ "routes": { "/blog/:slug": {}, "/": {}, "/about": {} } }"#,
+		)
+		.unwrap();
+		let routes = manifest_routes(&dir).unwrap();
+		let _ = std::fs::remove_dir_all(&dir);
+		assert_eq!(routes, ["/", "/about", "/blog/:slug"]);
+	}
+}
@@ -1,6 +1,6 @@
 /* src/cli/core/src/build/route/helpers.rs */
 
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, HashSet};
 use std::path::Path;
 
 use anyhow::{Context, Result};
@@ -98,14 +98,122 @@ pub(crate) fn export_i18n_paged(
 	Ok(())
 }
 
-/// Convert route path to filename: `/user/:id` -> `user-id.html`, `/` -> `index.html`
+/// Replace layout outlet elements with outlet markers: `<seam-outlet></seam-outlet>` ->
+/// `<!--seam:outlet-->`, `<seam-outlet name="sidebar"></seam-outlet>` -> `<!--seam:outlet:sidebar-->`.
+pub(super) fn outlet_markers(html: &str) -> String {
+	const OPEN: &str = "<seam-outlet";
+	const CLOSE: &str = "</seam-outlet>";
+	let mut out = String::with_capacity(html.len());
+	let mut rest = html;
+	while let Some(start) = rest.find(OPEN) {
+		let after = &rest[start + OPEN.len()..];
+		let element = after.find('>').and_then(|gt| {
+			let attrs = &after[..gt];
+			let body = after[gt + 1..].strip_prefix(CLOSE)?;
+			(attrs.is_empty() || attrs.starts_with(char::is_whitespace)).then_some((attrs, body))
+		});
+		let Some((attrs, body)) = element else {
+			// Not an empty outlet element: keep it verbatim
+			out.push_str(&rest[..start + OPEN.len()]);
+			rest = after;
+			continue;
+		};
+		out.push_str(&rest[..start]);
+		match outlet_name(attrs) {
+			Some(name) => out.push_str(&format!("<!--seam:outlet:{name}-->")),
+			None => out.push_str("<!--seam:outlet-->"),
+		}
+		rest = body;
+	}
+	out.push_str(rest);
+	out
+}
+
+/// Value of the `name` attribute, if present and non-empty. Attributes are walked one by
+/// one, so `data-name="x"` or a `name=` inside another value is not the name.
+fn outlet_name(attrs: &str) -> Option<&str> {
+	let mut rest = attrs;
+	loop {
+		rest = rest.trim_start();
+		let key_end = rest.find(|c: char| c == '=' || c.is_whitespace()).unwrap_or(rest.len());
+		if key_end == 0 {
+			return None;
+		}
+		let key = &rest[..key_end];
+		rest = rest[key_end..].trim_start();
+		let mut value = "";
+		if let Some(after) = rest.strip_prefix('=') {
+			let after = after.trim_start();
+			let (v, tail) = match after.chars().next() {
+				Some(q @ ('"' | '\'')) => {
+					let end = after[1..].find(q)? + 1;
+					(&after[1..end], &after[end + 1..])
+				}
+				_ => after.split_at(after.find(char::is_whitespace).unwrap_or(after.len())),
+			};
+			value = v;
+			rest = tail;
+		}
+		if key.eq_ignore_ascii_case("name") {
+			return (!value.is_empty()).then_some(value);
+		}
+	}
+}
+
+/// Convert route path to filename: `/user/:id` -> `user-id.html`, `/` -> `index.html`.
+/// Param syntaxes (`:id`, `{id}`, `*rest`, optional `?`) reduce to their name, a bare
+/// `*` becomes `wildcard`, and anything outside letters, digits, `.`, `_`, `-` turns
+/// into `-`, so the result is a legal filename on every platform.
 pub(super) fn path_to_filename(path: &str) -> String {
-	let trimmed = path.trim_matches('/');
-	if trimmed.is_empty() {
+	let segments: Vec<String> = path
+		.split('/')
+		.map(|segment| {
+			let name = segment.trim_start_matches([':', '*']).trim_end_matches('?');
+			// `{id}`, `{*rest}`, `{id:[0-9]+}`: the name, without sigils or constraint
+			let name = match name.strip_prefix('{').and_then(|n| n.strip_suffix('}')) {
+				Some(inner) => inner.trim_start_matches('*').split(':').next().unwrap_or_default(),
+				None => name,
+			};
+			if name.is_empty() && segment.starts_with('*') {
+				"wildcard".to_string()
+			} else {
+				slugify(name)
+			}
+		})
+		.filter(|slug| !slug.is_empty())
+		.collect();
+	if segments.is_empty() {
 		return "index.html".to_string();
 	}
-	let slug = trimmed.replace('/', "-").replace(':', "");
-	format!("{slug}.html")
+	format!("{}.html", segments.join("-"))
+}
+
+/// Keep letters, digits, `.` and `_`; collapse every other run of characters to one `-`.
+fn slugify(segment: &str) -> String {
+	let mut slug = String::with_capacity(segment.len());
+	for ch in segment.chars() {
+		if ch.is_alphanumeric() || ch == '.' || ch == '_' {
+			slug.push(ch);
+		} else if !slug.is_empty() && !slug.ends_with('-') {
+			slug.push('-');
+		}
+	}
+	// Leading dots would make hidden files; trailing `-`/`.` are dropped by Windows anyway
+	slug.trim_start_matches('.').trim_end_matches(['-', '.']).to_string()
+}
+
+/// `path_to_filename`, disambiguated against filenames already in `taken` with a
+/// numeric suffix (`user-id-2.html`). The chosen name is added to `taken`.
+pub(super) fn unique_filename(path: &str, taken: &mut HashSet<String>) -> String {
+	let filename = path_to_filename(path);
+	if taken.insert(filename.clone()) {
+		return filename;
+	}
+	let stem = filename.trim_end_matches(".html");
+	let unique = (2..).map(|n| format!("{stem}-{n}.html")).find(|name| !taken.contains(name));
+	let unique = unique.expect("unbounded suffix range always yields a free name");
+	taken.insert(unique.clone());
+	unique
 }
 
 /// Print each asset file with its size from disk
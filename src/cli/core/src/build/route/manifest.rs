@@ -7,7 +7,7 @@ use anyhow::{Context, Result, bail};
 
 use crate::config::{CommandConfig, SeamConfig};
 use crate::shell::{run_command, which_exists};
-use crate::ui::{self, DIM, GREEN, RESET, col};
+use crate::ui::{self, DIM, GREEN, RESET, Warnings, col};
 use seam_codegen::{Manifest, ProcedureType};
 
 pub(super) fn levenshtein(a: &str, b: &str) -> usize {
@@ -49,14 +49,14 @@ pub(super) fn extract_jtd_fields(schema: &serde_json::Value) -> std::collections
 /// Validate invalidates declarations in commands.
 /// Errors: referenced procedure missing, referenced procedure not a query.
 /// Warnings: mapping key not in target query input, mapping.from not in command input.
-pub(crate) fn validate_invalidates(manifest: &Manifest) -> Result<()> {
+pub(crate) fn validate_invalidates(manifest: &Manifest, warnings: &Warnings) -> Result<()> {
 	let available: Vec<&str> = manifest.procedures.keys().map(String::as_str).collect();
 	let mut errors = Vec::new();
 
 	for (cmd_name, cmd) in &manifest.procedures {
 		let Some(targets) = &cmd.invalidates else { continue };
 		if cmd.proc_type != ProcedureType::Command {
-			ui::warn(&format!(
+			warnings.warn(&format!(
 				"Procedure \"{cmd_name}\" is a {} but declares invalidates. \
 				 invalidates only takes effect on command procedures.",
 				cmd.proc_type
@@ -90,13 +90,13 @@ pub(crate) fn validate_invalidates(manifest: &Manifest) -> Result<()> {
 				let cmd_fields = extract_jtd_fields(&cmd.input);
 				for (key, val) in mapping {
 					if !target_fields.is_empty() && !target_fields.contains(key.as_str()) {
-						ui::warn(&format!(
+						warnings.warn(&format!(
 							"Command \"{cmd_name}\": invalidates mapping key \"{key}\" not found in \"{}\".input",
 							target.query
 						));
 					}
 					if !cmd_fields.is_empty() && !cmd_fields.contains(val.from.as_str()) {
-						ui::warn(&format!(
+						warnings.warn(&format!(
 							"Command \"{cmd_name}\": invalidates mapping from \"{}\".input field \"{}\" not found",
 							cmd_name, val.from
 						));
@@ -121,6 +121,7 @@ pub(crate) fn print_procedure_breakdown(manifest: &Manifest) {
 	let mut subscriptions = 0u32;
 	let mut streams = 0u32;
 	let mut uploads = 0u32;
+	let mut raws = 0u32;
 	for proc in manifest.procedures.values() {
 		match proc.proc_type {
 			ProcedureType::Query => queries += 1,
@@ -128,6 +129,7 @@ pub(crate) fn print_procedure_breakdown(manifest: &Manifest) {
 			ProcedureType::Subscription => subscriptions += 1,
 			ProcedureType::Stream => streams += 1,
 			ProcedureType::Upload => uploads += 1,
+			ProcedureType::Raw => raws += 1,
 		}
 	}
 	let mut parts = Vec::new();
@@ -149,6 +151,9 @@ pub(crate) fn print_procedure_breakdown(manifest: &Manifest) {
 	if uploads > 0 {
 		parts.push(format!("{uploads} {}", if uploads == 1 { "upload" } else { "uploads" }));
 	}
+	if raws > 0 {
+		parts.push(format!("{raws} raw"));
+	}
 	let breakdown =
 		if parts.is_empty() { String::new() } else { format!(" \u{2014} {}", parts.join(", ")) };
 	ui::detail_ok(&format!("{total} procedures{breakdown}"));
@@ -309,7 +314,7 @@ fn fill_builtin_transport_defaults(
 }
 
 /// Check if the project has `@canmi/seam-query-react` in dependencies or devDependencies.
-pub(crate) fn has_query_react_dep(base_dir: &Path) -> bool {
+pub fn has_query_react_dep(base_dir: &Path) -> bool {
 	let pkg_path = base_dir.join("package.json");
 	let Ok(content) = std::fs::read_to_string(&pkg_path) else { return false };
 	let Ok(pkg) = serde_json::from_str::<serde_json::Value>(&content) else { return false };
@@ -18,10 +18,11 @@ mod tests;
 // Re-export all public items for use by other modules
 pub(crate) use helpers::{print_asset_files, read_i18n_messages};
 pub(crate) use manifest::{
-	extract_manifest, extract_manifest_command, generate_types, has_query_react_dep,
-	package_public_files, package_static_assets, print_procedure_breakdown, run_typecheck,
-	validate_invalidates,
+	extract_manifest, extract_manifest_command, generate_types, package_public_files,
+	package_static_assets, print_procedure_breakdown, run_typecheck, validate_invalidates,
 };
+// Also used by `seam generate` in the binary
+pub use manifest::has_query_react_dep;
 pub(crate) use process::{
 	BundleContext, RenderContext, apply_output_mode, export_i18n, process_routes,
 	run_skeleton_renderer,
@@ -0,0 +1,135 @@
+/* src/cli/core/src/build/route/process/include.rs */
+
+// Build-time `<!--seam:include:name-->` expansion. Partials are inlined before template
+// extraction, so their slots become part of the route template like hand-written markup.
+
+use std::path::{Component, Path, PathBuf};
+
+use anyhow::{Context, Result, bail};
+
+const OPEN: &str = "<!--seam:include:";
+
+/// Replace every include marker in `html` with its partial from `partials_dir`, first
+/// expanding the partial's own includes. `render` maps each top-level expansion before
+/// it is spliced in (`str::to_string` keeps the slots; the CTR mock renders them).
+pub(super) fn expand_includes(
+	html: &str,
+	partials_dir: Option<&Path>,
+	render: &dyn Fn(&str) -> String,
+) -> Result<String> {
+	expand(html, partials_dir, &mut Vec::new(), render)
+}
+
+fn expand(
+	html: &str,
+	partials_dir: Option<&Path>,
+	stack: &mut Vec<String>,
+	render: &dyn Fn(&str) -> String,
+) -> Result<String> {
+	if !html.contains(OPEN) {
+		return Ok(html.to_string());
+	}
+	let Some(dir) = partials_dir else {
+		bail!("<!--seam:include--> requires build.partials_dir to be set");
+	};
+	let mut out = String::with_capacity(html.len());
+	let mut rest = html;
+	while let Some(start) = rest.find(OPEN) {
+		out.push_str(&rest[..start]);
+		let after = &rest[start + OPEN.len()..];
+		let end = after.find("-->").context("unterminated <!--seam:include:...--> marker")?;
+		let partial = load_partial(after[..end].trim(), dir, stack)?;
+		out.push_str(&render(&partial));
+		rest = &after[end + 3..];
+	}
+	out.push_str(rest);
+	Ok(out)
+}
+
+/// Read partial `name` with its nested includes inlined. `stack` holds the partials
+/// currently being expanded, so a partial that reaches itself again is a cycle.
+fn load_partial(name: &str, dir: &Path, stack: &mut Vec<String>) -> Result<String> {
+	if let Some(start) = stack.iter().position(|seen| seen == name) {
+		let mut cycle = stack[start..].to_vec();
+		cycle.push(name.to_string());
+		bail!("partial include cycle: {}", cycle.join(" -> "));
+	}
+	let path = partial_path(dir, name)?;
+	let content = std::fs::read_to_string(&path)
+		.with_context(|| format!("partial \"{name}\" not found at {}", path.display()))?;
+	stack.push(name.to_string());
+	let expanded = expand(&content, Some(dir), stack, &str::to_string);
+	stack.pop();
+	expanded.with_context(|| format!("in partial \"{name}\""))
+}
+
+/// `footer` -> `<dir>/footer.html`. Names stay inside the partials dir.
+fn partial_path(dir: &Path, name: &str) -> Result<PathBuf> {
+	let rel = Path::new(name);
+	if name.is_empty() || !rel.components().all(|c| matches!(c, Component::Normal(_))) {
+		bail!("invalid partial name \"{name}\" (use a path relative to build.partials_dir)");
+	}
+	let path = dir.join(rel);
+	Ok(if path.extension().is_some() { path } else { path.with_extension("html") })
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn partials(name: &str, files: &[(&str, &str)]) -> PathBuf {
+		let dir = std::env::temp_dir().join(name);
+		let _ = std::fs::remove_dir_all(&dir);
+		for (file, content) in files {
+			let path = dir.join(file);
+			std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+			std::fs::write(path, content).unwrap();
+		}
+		dir
+	}
+
+	#[test]
+	fn include_inlines_nested_partials() {
+		let dir = partials(
+			"seam-test-include-ok",
+			&[
+				("partials/footer.html", "<footer><!--seam:include:partials/links--></footer>"),
+				("partials/links.html", "<a><!--seam:year--></a>"),
+			],
+		);
+		let html = "<main>x</main><!--seam:include:partials/footer-->";
+		let out = expand_includes(html, Some(&dir), &str::to_string).unwrap();
+		let _ = std::fs::remove_dir_all(&dir);
+		assert_eq!(out, "<main>x</main><footer><a><!--seam:year--></a></footer>");
+	}
+
+	#[test]
+	fn missing_partial_names_the_path() {
+		let dir = partials("seam-test-include-missing", &[]);
+		let err = expand_includes("<!--seam:include:card-->", Some(&dir), &str::to_string)
+			.unwrap_err()
+			.to_string();
+		assert!(err.contains("partial \"card\" not found"), "{err}");
+		assert!(err.contains("card.html"), "{err}");
+	}
+
+	#[test]
+	fn include_cycle_is_rejected() {
+		let dir = partials(
+			"seam-test-include-cycle",
+			&[("a.html", "<!--seam:include:b-->"), ("b.html", "<!--seam:include:a-->")],
+		);
+		let err = expand_includes("<!--seam:include:a-->", Some(&dir), &str::to_string).unwrap_err();
+		let _ = std::fs::remove_dir_all(&dir);
+		assert!(format!("{err:#}").contains("partial include cycle: a -> b -> a"), "{err:#}");
+	}
+
+	#[test]
+	fn include_without_partials_dir_or_outside_it_fails() {
+		let err = expand_includes("<!--seam:include:a-->", None, &str::to_string).unwrap_err();
+		assert!(err.to_string().contains("build.partials_dir"), "{err}");
+		let err = expand_includes("<!--seam:include:../a-->", Some(Path::new("/tmp")), &str::to_string)
+			.unwrap_err();
+		assert!(err.to_string().contains("invalid partial name"), "{err}");
+	}
+}
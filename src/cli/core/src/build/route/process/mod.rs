@@ -2,34 +2,46 @@
 
 mod assets;
 mod i18n_export;
+mod include;
 mod skeleton;
 
 pub(crate) use i18n_export::export_i18n;
 pub(crate) use skeleton::run_skeleton_renderer;
 
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, BTreeSet, HashSet};
 use std::path::Path;
 
 use anyhow::{Context, Result, bail};
 
-use super::helpers::path_to_filename;
+use super::helpers::{outlet_markers, unique_filename};
 use super::types::{
 	I18nManifest, LayoutManifestEntry, RouteManifest, RouteManifestEntry, SkeletonLayout,
 	SkeletonRoute,
 };
 use crate::build::types::{AssetFiles, BundleManifest, ViteDevInfo};
-use crate::config::{I18nSection, OutputMode};
-use crate::ui::{self, DIM, RESET, col};
+use crate::config::{I18nSection, LocaleRouting, OutputMode};
+use crate::ui::{self, DIM, RESET, Warnings, col};
 use assets::compute_route_assets;
+use include::expand_includes;
+use seam_skeleton::{DocumentOptions, slot_warning, wrap_document};
 use seam_skeleton::{check_template_invariants, ctr_check, extract_template, sentinel_to_slots};
-use seam_skeleton::{slot_warning, wrap_document};
 
 /// Rendering parameters shared across layout and route processing.
 pub(crate) struct RenderContext<'a> {
 	pub root_id: &'a str,
 	pub data_id: &'a str,
+	/// Explicit `<html lang>` from `frontend.lang`
+	pub lang: Option<&'a str>,
 	pub dev_mode: bool,
 	pub vite: Option<&'a ViteDevInfo>,
+	/// Route whose CTR mismatch also writes debug artifacts (`seam build --ctr-debug`)
+	pub ctr_debug: Option<&'a str>,
+	/// Directory `<!--seam:include:name-->` partials resolve against (`build.partials_dir`)
+	pub partials_dir: Option<&'a Path>,
+	/// Add `<link rel="modulepreload">` hints for entry scripts (`build.modulepreload`)
+	pub modulepreload: bool,
+	/// Sink for the build's warnings (slot lint, output mode overrides)
+	pub warnings: &'a Warnings,
 }
 
 /// Optional per-page splitting context from the bundler.
@@ -45,6 +57,7 @@ struct RouteProcessCtx<'a> {
 	render: &'a RenderContext<'a>,
 	i18n: Option<&'a I18nSection>,
 	bundle: &'a BundleContext<'a>,
+	layouts: &'a [SkeletonLayout],
 }
 
 pub(crate) fn process_routes(
@@ -56,12 +69,15 @@ pub(crate) fn process_routes(
 	i18n: Option<&I18nSection>,
 	bundle: &BundleContext<'_>,
 ) -> Result<RouteManifest> {
+	check_layout_cycles(layouts)?;
+
 	let manifest_data_id =
 		if render.data_id == "__data" { None } else { Some(render.data_id.to_string()) };
 	let i18n_manifest = i18n.map(|cfg| I18nManifest {
 		locales: cfg.locales.clone(),
 		default: cfg.default.clone(),
 		mode: cfg.mode.as_str().to_string(),
+		routing: (cfg.routing != LocaleRouting::Hybrid).then(|| cfg.routing.as_str().to_string()),
 		cache: cfg.cache,
 		route_hashes: BTreeMap::new(),
 		content_hashes: BTreeMap::new(),
@@ -72,16 +88,20 @@ pub(crate) fn process_routes(
 		routes: BTreeMap::new(),
 		data_id: manifest_data_id,
 		i18n: i18n_manifest,
+		gzip_templates: false,
 	};
 
 	process_layout_templates(layouts, templates_dir, assets, render, &mut manifest)?;
 
-	let ctx = RouteProcessCtx { templates_dir, assets, render, i18n, bundle };
+	let ctx = RouteProcessCtx { templates_dir, assets, render, i18n, bundle, layouts };
+	// Layout templates share the directory, so their names are taken up front
+	let mut taken: HashSet<String> = layouts.iter().map(|l| format!("{}.html", l.id)).collect();
 	for route in routes {
+		let filename = unique_filename(&route.path, &mut taken);
 		if let Some(ref locale_variants) = route.locale_variants {
-			process_i18n_route(route, locale_variants, &ctx, &mut manifest)?;
+			process_i18n_route(route, locale_variants, &filename, &ctx, &mut manifest)?;
 		} else {
-			process_single_route(route, &ctx, &mut manifest)?;
+			process_single_route(route, &filename, &ctx, &mut manifest)?;
 		}
 	}
 	Ok(manifest)
@@ -89,6 +109,27 @@ pub(crate) fn process_routes(
 
 // -- Layout processing --
 
+/// Bail on a layout whose `parent` chain leads back to itself; every later stage walks
+/// these chains upward and would never terminate. Unknown parents end a chain.
+pub(crate) fn check_layout_cycles(layouts: &[SkeletonLayout]) -> Result<()> {
+	let parents: BTreeMap<&str, Option<&str>> =
+		layouts.iter().map(|l| (l.id.as_str(), l.parent.as_deref())).collect();
+	for layout in layouts {
+		let mut chain = vec![layout.id.as_str()];
+		let mut current = layout.parent.as_deref();
+		while let Some(id) = current {
+			if let Some(start) = chain.iter().position(|seen| *seen == id) {
+				let mut cycle = chain[start..].to_vec();
+				cycle.push(id);
+				bail!("layout parent cycle: {}", cycle.join(" -> "));
+			}
+			chain.push(id);
+			current = parents.get(id).copied().flatten();
+		}
+	}
+	Ok(())
+}
+
 fn process_layout_templates(
 	layouts: &[SkeletonLayout],
 	templates_dir: &Path,
@@ -101,20 +142,12 @@ fn process_layout_templates(
 		if let Some(ref locale_html) = layout.locale_html {
 			let mut templates = BTreeMap::new();
 			for (locale, html) in locale_html {
-				let html = html.replace("<seam-outlet></seam-outlet>", "<!--seam:outlet-->");
+				let html = expand_includes(html, render.partials_dir, &str::to_string)
+					.with_context(|| format!("layout {} [{locale}]", layout.id))?;
+				let html = outlet_markers(&html);
 				let html = sentinel_to_slots(&html);
-				let document = if is_root {
-					wrap_document(
-						&html,
-						&assets.css,
-						&assets.js,
-						render.dev_mode,
-						render.vite,
-						render.root_id,
-					)
-				} else {
-					html
-				};
+				let document =
+					if is_root { wrap_document(&html, &document_options(assets, render)) } else { html };
 				let locale_dir = templates_dir.join(locale);
 				std::fs::create_dir_all(&locale_dir)
 					.with_context(|| format!("failed to create {}", locale_dir.display()))?;
@@ -143,13 +176,12 @@ fn process_layout_templates(
 				},
 			);
 		} else if let Some(ref html) = layout.html {
-			let html = html.replace("<seam-outlet></seam-outlet>", "<!--seam:outlet-->");
+			let html = expand_includes(html, render.partials_dir, &str::to_string)
+				.with_context(|| format!("layout {}", layout.id))?;
+			let html = outlet_markers(&html);
 			let html = sentinel_to_slots(&html);
-			let document = if is_root {
-				wrap_document(&html, &assets.css, &assets.js, render.dev_mode, render.vite, render.root_id)
-			} else {
-				html
-			};
+			let document =
+				if is_root { wrap_document(&html, &document_options(assets, render)) } else { html };
 			let filename = format!("{}.html", layout.id);
 			let filepath = templates_dir.join(&filename);
 			std::fs::write(&filepath, &document)
@@ -174,6 +206,30 @@ fn process_layout_templates(
 
 // -- Route document rendering --
 
+/// Variant HTML ready for extraction: partials inlined, sentinels turned into slots.
+fn variant_templates<'a>(
+	variants: impl Iterator<Item = &'a str>,
+	render: &RenderContext<'_>,
+) -> Result<Vec<String>> {
+	variants
+		.map(|html| {
+			Ok(sentinel_to_slots(&expand_includes(html, render.partials_dir, &str::to_string)?))
+		})
+		.collect()
+}
+
+/// The React mock render still carries the include markers; inline each partial rendered
+/// with the mock data so the CTR check compares like with like.
+fn mock_with_includes(
+	mock_html: &str,
+	mock: &serde_json::Value,
+	render: &RenderContext<'_>,
+) -> Result<String> {
+	expand_includes(mock_html, render.partials_dir, &|partial| {
+		seam_injector::inject_no_script(partial, mock)
+	})
+}
+
 /// Render a route template into a final document. For routes with layouts,
 /// returns the template as-is (head_meta now comes from skeleton output).
 /// For standalone routes, wraps with full HTML document structure.
@@ -186,8 +242,93 @@ fn render_route_document(
 	if has_layout {
 		template.to_string()
 	} else {
-		wrap_document(template, &assets.css, &assets.js, render.dev_mode, render.vite, render.root_id)
+		wrap_document(template, &document_options(assets, render))
+	}
+}
+
+/// Document settings shared by root layouts and standalone routes.
+fn document_options<'a>(assets: &'a AssetFiles, render: &RenderContext<'a>) -> DocumentOptions<'a> {
+	DocumentOptions {
+		css_files: &assets.css,
+		js_files: &assets.js,
+		dev_mode: render.dev_mode,
+		vite: render.vite,
+		root_id: render.root_id,
+		lang: render.lang,
+		modulepreload: render.modulepreload,
+	}
+}
+
+/// CTR equivalence check for one route. For the `--ctr-debug` route a mismatch also
+/// writes `react.html`/`inject.html` next to the templates and prints both normalized trees.
+fn verify_route_ctr(
+	route_path: &str,
+	locale: Option<&str>,
+	react_html: &str,
+	template: &str,
+	mock: &serde_json::Value,
+	ctx: &RouteProcessCtx<'_>,
+) -> Result<()> {
+	let data_id = ctx.render.data_id;
+	let Some(mismatch) =
+		ctr_check::find_ctr_mismatch(route_path, react_html, template, mock, data_id)
+	else {
+		return Ok(());
+	};
+	if ctx.render.ctr_debug == Some(route_path) {
+		let mut dir = ctx.templates_dir.with_file_name("ctr-debug");
+		if let Some(locale) = locale {
+			dir.push(locale);
+		}
+		write_ctr_debug(&dir, react_html, &mismatch)?;
 	}
+	bail!("{}", mismatch.report);
+}
+
+fn write_ctr_debug(dir: &Path, react_html: &str, mismatch: &ctr_check::CtrMismatch) -> Result<()> {
+	std::fs::create_dir_all(dir).with_context(|| format!("failed to create {}", dir.display()))?;
+	for (name, html) in [("react.html", react_html), ("inject.html", &mismatch.injected_html)] {
+		let path = dir.join(name);
+		std::fs::write(&path, html).with_context(|| format!("failed to write {}", path.display()))?;
+		ui::detail(&format!("ctr-debug  {}", path.display()));
+	}
+	ui::arrow("normalized react tree");
+	println!("{}", mismatch.react_tree);
+	ui::arrow("normalized template tree");
+	println!("{}", mismatch.inject_tree);
+	Ok(())
+}
+
+/// Build-time slot lint: schema-typed style/class slots, loop scope, and loader keys.
+fn warn_slots(route: &SkeletonRoute, template: &str, label: &str, ctx: &RouteProcessCtx<'_>) {
+	let mut warnings = slot_warning::check_loader_keys(template, &provided_keys(route, ctx.layouts));
+	if let Some(schema) = &route.page_schema {
+		warnings.extend(slot_warning::check_slot_types(template, schema));
+		warnings.extend(slot_warning::check_each_scope(template, schema));
+	}
+	for w in warnings {
+		ctx.render.warnings.detail_warn(&format!("{label} {w}"));
+	}
+}
+
+/// Top-level data keys a route's template can read: its own loaders, every layout
+/// loader up the chain, and the root fields of its page schema.
+fn provided_keys(route: &SkeletonRoute, layouts: &[SkeletonLayout]) -> BTreeSet<String> {
+	fn object_keys(v: Option<&serde_json::Value>) -> impl Iterator<Item = String> + '_ {
+		v.and_then(serde_json::Value::as_object).into_iter().flat_map(|m| m.keys().cloned())
+	}
+	let mut keys: BTreeSet<String> = object_keys(Some(&route.loaders)).collect();
+	let mut next = route.layout.as_deref();
+	// Cycles were rejected by `check_layout_cycles` before routes are processed
+	while let Some(layout) = next.and_then(|id| layouts.iter().find(|l| l.id == id)) {
+		keys.extend(object_keys(Some(&layout.loaders)));
+		next = layout.parent.as_deref();
+	}
+	if let Some(schema) = &route.page_schema {
+		keys.extend(object_keys(schema.get("properties")));
+		keys.extend(object_keys(schema.get("optionalProperties")));
+	}
+	keys
 }
 
 fn ensure_template_invariants(
@@ -225,36 +366,29 @@ fn ensure_template_invariants(
 fn process_i18n_route(
 	route: &SkeletonRoute,
 	locale_variants: &BTreeMap<String, super::types::LocaleRouteData>,
+	filename: &str,
 	ctx: &RouteProcessCtx<'_>,
 	manifest: &mut RouteManifest,
 ) -> Result<()> {
 	let mut templates = BTreeMap::new();
 	for (locale, data) in locale_variants {
-		let processed: Vec<_> = data.variants.iter().map(|v| sentinel_to_slots(&v.html)).collect();
+		let label = format!("{} [{locale}]", route.path);
+		let processed = variant_templates(data.variants.iter().map(|v| v.html.as_str()), ctx.render)
+			.with_context(|| label.clone())?;
 		let template = extract_template(&data.axes, &processed);
 		ensure_template_invariants(&route.path, Some(locale), &data.axes, &processed, &template)?;
 
-		ctr_check::verify_ctr_equivalence(
-			&route.path,
-			&data.mock_html,
-			&template,
-			&route.mock,
-			ctx.render.data_id,
-		)?;
-
-		if let Some(schema) = &route.page_schema {
-			for w in slot_warning::check_slot_types(&template, schema) {
-				ui::detail_warn(&format!("{} [{locale}] {w}", route.path));
-			}
-		}
+		let mock_html = mock_with_includes(&data.mock_html, &route.mock, ctx.render)?;
+		verify_route_ctr(&route.path, Some(locale), &mock_html, &template, &route.mock, ctx)?;
+
+		warn_slots(route, &template, &label, ctx);
 
 		let document = render_route_document(&template, route.layout.is_some(), ctx.assets, ctx.render);
 
 		let locale_dir = ctx.templates_dir.join(locale);
 		std::fs::create_dir_all(&locale_dir)
 			.with_context(|| format!("failed to create {}", locale_dir.display()))?;
-		let filename = path_to_filename(&route.path);
-		let filepath = locale_dir.join(&filename);
+		let filepath = locale_dir.join(filename);
 		std::fs::write(&filepath, &document)
 			.with_context(|| format!("failed to write {}", filepath.display()))?;
 
@@ -317,6 +451,7 @@ fn process_i18n_route(
 
 fn process_single_route(
 	route: &SkeletonRoute,
+	filename: &str,
 	ctx: &RouteProcessCtx<'_>,
 	manifest: &mut RouteManifest,
 ) -> Result<()> {
@@ -324,28 +459,19 @@ fn process_single_route(
 	let variants = route.variants.as_ref().expect("variants required when i18n is off");
 	let mock_html = route.mock_html.as_ref().expect("mock_html required when i18n is off");
 
-	let processed: Vec<_> = variants.iter().map(|v| sentinel_to_slots(&v.html)).collect();
+	let processed = variant_templates(variants.iter().map(|v| v.html.as_str()), ctx.render)
+		.with_context(|| route.path.clone())?;
 	let template = extract_template(axes, &processed);
 	ensure_template_invariants(&route.path, None, axes, &processed, &template)?;
 
-	ctr_check::verify_ctr_equivalence(
-		&route.path,
-		mock_html,
-		&template,
-		&route.mock,
-		ctx.render.data_id,
-	)?;
+	let mock_html = mock_with_includes(mock_html, &route.mock, ctx.render)?;
+	verify_route_ctr(&route.path, None, &mock_html, &template, &route.mock, ctx)?;
 
-	if let Some(schema) = &route.page_schema {
-		for w in slot_warning::check_slot_types(&template, schema) {
-			ui::detail_warn(&format!("{} {w}", route.path));
-		}
-	}
+	warn_slots(route, &template, &route.path, ctx);
 
 	let document = render_route_document(&template, route.layout.is_some(), ctx.assets, ctx.render);
 
-	let filename = path_to_filename(&route.path);
-	let filepath = ctx.templates_dir.join(&filename);
+	let filepath = ctx.templates_dir.join(filename);
 	std::fs::write(&filepath, &document)
 		.with_context(|| format!("failed to write {}", filepath.display()))?;
 
@@ -386,13 +512,17 @@ fn process_single_route(
 /// | static  | SSG          | SSG            | warn, still SSG |
 /// | server  | CTR          | warn, still CTR| CTR             |
 /// | hybrid  | CTR          | SSG            | CTR             |
-pub(crate) fn apply_output_mode(manifest: &mut RouteManifest, output: OutputMode) {
+pub(crate) fn apply_output_mode(
+	manifest: &mut RouteManifest,
+	output: OutputMode,
+	warnings: &Warnings,
+) {
 	for (path, entry) in &mut manifest.routes {
 		let explicit = entry.prerender;
 		let effective = match output {
 			OutputMode::Static => {
 				if explicit == Some(false) {
-					ui::detail_warn(&format!(
+					warnings.detail_warn(&format!(
 						"{path}: prerender=false ignored in static mode (all pages are SSG)"
 					));
 				}
@@ -400,7 +530,7 @@ pub(crate) fn apply_output_mode(manifest: &mut RouteManifest, output: OutputMode
 			}
 			OutputMode::Server => {
 				if explicit == Some(true) {
-					ui::detail_warn(&format!(
+					warnings.detail_warn(&format!(
 						"{path}: prerender=true ignored in server mode (all pages are CTR)"
 					));
 				}
@@ -414,7 +544,12 @@ pub(crate) fn apply_output_mode(manifest: &mut RouteManifest, output: OutputMode
 
 #[cfg(test)]
 mod tests {
-	use super::ensure_template_invariants;
+	use super::{
+		BundleContext, RenderContext, RouteProcessCtx, ensure_template_invariants, provided_keys,
+		verify_route_ctr,
+	};
+	use crate::build::types::AssetFiles;
+	use crate::ui::Warnings;
 	use seam_skeleton::Axis;
 
 	fn array_axis(path: &str) -> Axis {
@@ -435,4 +570,67 @@ mod tests {
 		assert!(message.contains("array axis \"watches.items\""));
 		assert!(message.contains("missing <!--seam:each:watches.items-->"));
 	}
+
+	#[test]
+	fn provided_keys_cover_layout_chain_and_schema() {
+		let route = serde_json::from_value(serde_json::json!({
+			"path": "/",
+			"loaders": { "page": { "procedure": "getPage" } },
+			"mock": {},
+			"pageSchema": { "properties": { "page": {} }, "optionalProperties": { "banner": {} } },
+			"layout": "docs"
+		}))
+		.unwrap();
+		let layouts: Vec<super::SkeletonLayout> = serde_json::from_value(serde_json::json!([
+			{ "id": "root", "loaders": { "session": { "procedure": "getSession" } } },
+			{ "id": "docs", "parent": "root", "loaders": { "nav": { "procedure": "getNav" } } }
+		]))
+		.unwrap();
+		let keys: Vec<String> = provided_keys(&route, &layouts).into_iter().collect();
+		assert_eq!(keys, ["banner", "nav", "page", "session"]);
+	}
+
+	#[test]
+	fn ctr_debug_writes_artifacts_for_selected_route() {
+		let out = std::env::temp_dir().join("seam-test-ctr-debug");
+		let _ = std::fs::remove_dir_all(&out);
+		let templates_dir = out.join("templates");
+		let assets = AssetFiles { css: vec![], js: vec![] };
+		let bundle = BundleContext { manifest: None, source_file_map: None };
+		let warnings = Warnings::default();
+		let render_for = |ctr_debug| RenderContext {
+			root_id: seam_skeleton::DEFAULT_ROOT_ID,
+			data_id: "__data",
+			lang: None,
+			dev_mode: false,
+			vite: None,
+			ctr_debug,
+			partials_dir: None,
+			modulepreload: false,
+			warnings: &warnings,
+		};
+		let check = |render: &RenderContext<'_>| {
+			let ctx = RouteProcessCtx {
+				templates_dir: &templates_dir,
+				assets: &assets,
+				render,
+				i18n: None,
+				bundle: &bundle,
+				layouts: &[],
+			};
+			let mock = serde_json::json!({ "msg": "hello" });
+			verify_route_ctr("/page", None, "<p>world</p>", "<p><!--seam:msg--></p>", &mock, &ctx)
+		};
+
+		// Other routes fail as usual without touching disk
+		assert!(check(&render_for(Some("/other"))).is_err());
+		assert!(!out.join("ctr-debug").exists());
+
+		let err = check(&render_for(Some("/page"))).unwrap_err();
+		assert!(err.to_string().contains("CTR equivalence check failed"));
+		let read = |name: &str| std::fs::read_to_string(out.join("ctr-debug").join(name)).unwrap();
+		assert_eq!(read("react.html"), "<p>world</p>");
+		assert!(read("inject.html").starts_with("<p>hello</p>"));
+		let _ = std::fs::remove_dir_all(&out);
+	}
 }
@@ -195,6 +195,7 @@ mod tests {
 			)]),
 			data_id: None,
 			i18n: None,
+			gzip_templates: false,
 		};
 		(manifest, dir)
 	}
@@ -295,6 +296,7 @@ mod tests {
 			)]),
 			data_id: None,
 			i18n: None,
+			gzip_templates: false,
 		};
 
 		inject_route_projections(&mut manifest, dir.path()).unwrap();
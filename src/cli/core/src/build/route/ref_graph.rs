@@ -10,7 +10,7 @@ use anyhow::{Context, Result, bail};
 
 use super::manifest::did_you_mean;
 use super::types::{RouteManifest, SkeletonOutput};
-use crate::ui;
+use crate::ui::Warnings;
 use seam_codegen::{CacheHint, Manifest, ProcedureType};
 
 /// A direct reference from a loader to a procedure.
@@ -170,7 +170,7 @@ pub(crate) fn validate_procedure_references(graph: &ProcedureRefGraph) -> Result
 
 /// Warn when the same procedure appears in both handoff and non-handoff loaders
 /// within the same page (including its layout chain).
-pub(crate) fn validate_handoff_consistency(graph: &ProcedureRefGraph) {
+pub(crate) fn validate_handoff_consistency(graph: &ProcedureRefGraph, warnings: &Warnings) {
 	for (route_path, refs) in &graph.route_deps {
 		// Group by procedure name
 		let mut by_proc: BTreeMap<&str, (Vec<&str>, Vec<&str>)> = BTreeMap::new();
@@ -185,7 +185,7 @@ pub(crate) fn validate_handoff_consistency(graph: &ProcedureRefGraph) {
 
 		for (handoff_keys, non_handoff_keys) in by_proc.values() {
 			if !handoff_keys.is_empty() && !non_handoff_keys.is_empty() {
-				ui::warn(&format!(
+				warnings.warn(&format!(
 					"Route \"{route_path}\" has loaders {} (handoff) and {} sharing the same procedure. \
            These share the same data source but have different update mechanisms after hydration.",
 					handoff_keys.iter().map(|k| format!("\"{k}\"")).collect::<Vec<_>>().join(", "),
@@ -197,7 +197,11 @@ pub(crate) fn validate_handoff_consistency(graph: &ProcedureRefGraph) {
 }
 
 /// Warn when a query procedure has no loader references and is not suppressed.
-pub(crate) fn warn_unused_queries(graph: &ProcedureRefGraph, manifest: &Manifest) {
+pub(crate) fn warn_unused_queries(
+	graph: &ProcedureRefGraph,
+	manifest: &Manifest,
+	warnings: &Warnings,
+) {
 	for name in &graph.all_procedures {
 		if graph.consumers.contains_key(name) {
 			continue;
@@ -211,7 +215,7 @@ pub(crate) fn warn_unused_queries(graph: &ProcedureRefGraph, manifest: &Manifest
 		{
 			continue;
 		}
-		ui::warn(&format!(
+		warnings.warn(&format!(
 			"query \"{name}\" is not referenced by any loader. \
        If this is intentional, add `suppress: [\"unused\"]` to the procedure definition.",
 		));
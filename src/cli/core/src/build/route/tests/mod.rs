@@ -5,9 +5,9 @@ mod packaging;
 mod ref_graph;
 mod validation;
 
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, HashSet};
 
-use super::helpers::path_to_filename;
+use super::helpers::{outlet_markers, path_to_filename, unique_filename};
 use super::manifest::{did_you_mean, levenshtein};
 use super::types::{SkeletonLayout, SkeletonOutput, SkeletonRoute};
 
@@ -31,6 +31,67 @@ fn path_to_filename_nested() {
 	assert_eq!(path_to_filename("/user/:id/posts"), "user-id-posts.html");
 }
 
+#[test]
+fn path_to_filename_wildcard() {
+	assert_eq!(path_to_filename("/files/*path"), "files-path.html");
+	assert_eq!(path_to_filename("/files/*"), "files-wildcard.html");
+	assert_eq!(path_to_filename("/docs/{*rest}"), "docs-rest.html");
+}
+
+#[test]
+fn path_to_filename_braced_param() {
+	assert_eq!(path_to_filename("/user/{id}"), "user-id.html");
+	assert_eq!(path_to_filename("/post/{slug:[a-z-]+}/edit"), "post-slug-edit.html");
+	assert_eq!(path_to_filename("/user/:id?"), "user-id.html");
+}
+
+#[test]
+fn path_to_filename_strips_illegal_characters() {
+	let name = path_to_filename(r#"/a<b>/c"d|e/.hidden/ends./q?x=1"#);
+	assert_eq!(name, "a-b-c-d-e-hidden-ends-q-x-1.html");
+	assert!(!name.contains(['<', '>', ':', '"', '/', '\\', '|', '?', '*']));
+}
+
+#[test]
+fn unique_filename_disambiguates_collisions() {
+	let mut taken = HashSet::from(["app.html".to_string()]);
+	assert_eq!(unique_filename("/user/:id", &mut taken), "user-id.html");
+	assert_eq!(unique_filename("/user/{id}", &mut taken), "user-id-2.html");
+	assert_eq!(unique_filename("/user/*id", &mut taken), "user-id-3.html");
+	// Layout names seeded into `taken` are avoided too
+	assert_eq!(unique_filename("/app", &mut taken), "app-2.html");
+	assert_eq!(unique_filename("/", &mut taken), "index.html");
+	assert_eq!(unique_filename("/index", &mut taken), "index-2.html");
+}
+
+#[test]
+fn outlet_markers_default_and_named() {
+	let html = r#"<main><seam-outlet></seam-outlet></main><aside><seam-outlet name="sidebar"></seam-outlet></aside>"#;
+	assert_eq!(
+		outlet_markers(html),
+		"<main><!--seam:outlet--></main><aside><!--seam:outlet:sidebar--></aside>"
+	);
+}
+
+#[test]
+fn outlet_markers_keeps_non_outlet_elements() {
+	let html = "<seam-outlets>x</seam-outlets><seam-outlet name=''></seam-outlet>";
+	assert_eq!(outlet_markers(html), "<seam-outlets>x</seam-outlets><!--seam:outlet-->");
+}
+
+#[test]
+fn outlet_markers_read_only_the_name_attribute() {
+	let html = concat!(
+		r#"<seam-outlet data-name="x"></seam-outlet>"#,
+		r#"<seam-outlet title="name='y'" name = main></seam-outlet>"#,
+		r#"<seam-outlet hidden NAME="side"></seam-outlet>"#,
+	);
+	assert_eq!(
+		outlet_markers(html),
+		"<!--seam:outlet--><!--seam:outlet:main--><!--seam:outlet:side-->"
+	);
+}
+
 // -- Levenshtein distance tests --
 
 #[test]
@@ -90,6 +151,7 @@ pub(super) fn make_manifest(names: &[&str]) -> seam_codegen::Manifest {
 	}
 	seam_codegen::Manifest {
 		version: 1,
+		hash: None,
 		context: BTreeMap::new(),
 		procedures,
 		channels: BTreeMap::new(),
@@ -6,6 +6,7 @@ use crate::build::route::process::apply_output_mode;
 use crate::build::route::types::{RouteManifest, RouteManifestEntry};
 use crate::build::run::steps::has_prerender_routes;
 use crate::config::OutputMode;
+use crate::ui::Warnings;
 
 fn make_route_manifest(routes: Vec<(&str, Option<bool>)>) -> RouteManifest {
 	RouteManifest {
@@ -33,6 +34,7 @@ fn make_route_manifest(routes: Vec<(&str, Option<bool>)>) -> RouteManifest {
 			.collect(),
 		data_id: None,
 		i18n: None,
+		gzip_templates: false,
 	}
 }
 
@@ -40,7 +42,9 @@ fn make_route_manifest(routes: Vec<(&str, Option<bool>)>) -> RouteManifest {
 fn static_mode_forces_all_prerender() {
 	let mut manifest =
 		make_route_manifest(vec![("/", None), ("/about", Some(true)), ("/contact", Some(false))]);
-	apply_output_mode(&mut manifest, OutputMode::Static);
+	let warnings = Warnings::default();
+	apply_output_mode(&mut manifest, OutputMode::Static, &warnings);
+	assert_eq!(warnings.into_vec().len(), 1);
 
 	assert_eq!(manifest.routes["/"].prerender, Some(true));
 	assert_eq!(manifest.routes["/about"].prerender, Some(true));
@@ -51,7 +55,9 @@ fn static_mode_forces_all_prerender() {
 fn server_mode_clears_prerender() {
 	let mut manifest =
 		make_route_manifest(vec![("/", None), ("/about", Some(true)), ("/contact", Some(false))]);
-	apply_output_mode(&mut manifest, OutputMode::Server);
+	let warnings = Warnings::default();
+	apply_output_mode(&mut manifest, OutputMode::Server, &warnings);
+	assert_eq!(warnings.into_vec().len(), 1);
 
 	assert_eq!(manifest.routes["/"].prerender, None);
 	assert_eq!(manifest.routes["/about"].prerender, None);
@@ -62,7 +68,9 @@ fn server_mode_clears_prerender() {
 fn hybrid_mode_preserves_explicit() {
 	let mut manifest =
 		make_route_manifest(vec![("/", None), ("/about", Some(true)), ("/contact", Some(false))]);
-	apply_output_mode(&mut manifest, OutputMode::Hybrid);
+	let warnings = Warnings::default();
+	apply_output_mode(&mut manifest, OutputMode::Hybrid, &warnings);
+	assert_eq!(warnings.into_vec().len(), 0);
 
 	assert_eq!(manifest.routes["/"].prerender, None);
 	assert_eq!(manifest.routes["/about"].prerender, Some(true));
@@ -8,6 +8,7 @@ use super::super::ref_graph::{
 };
 use super::super::types::{RouteManifest, RouteManifestEntry};
 use super::{make_manifest, make_skeleton, make_skeleton_ext};
+use crate::ui::Warnings;
 
 // -- validate_handoff_consistency tests --
 
@@ -25,7 +26,9 @@ fn handoff_no_conflict() {
 		vec![],
 	);
 	let graph = build_reference_graph(&manifest, &skeleton);
-	validate_handoff_consistency(&graph);
+	let warnings = Warnings::default();
+	validate_handoff_consistency(&graph, &warnings);
+	assert_eq!(warnings.into_vec().len(), 0);
 }
 
 #[test]
@@ -42,7 +45,9 @@ fn handoff_conflict_warns() {
 		vec![],
 	);
 	let graph = build_reference_graph(&manifest, &skeleton);
-	validate_handoff_consistency(&graph);
+	let warnings = Warnings::default();
+	validate_handoff_consistency(&graph, &warnings);
+	assert_eq!(warnings.into_vec().len(), 1);
 }
 
 #[test]
@@ -59,7 +64,9 @@ fn handoff_different_procedures() {
 		vec![],
 	);
 	let graph = build_reference_graph(&manifest, &skeleton);
-	validate_handoff_consistency(&graph);
+	let warnings = Warnings::default();
+	validate_handoff_consistency(&graph, &warnings);
+	assert_eq!(warnings.into_vec().len(), 0);
 }
 
 // -- ref_graph tests --
@@ -171,6 +178,7 @@ fn ref_graph_route_procedures_dedup() {
 		)]),
 		data_id: None,
 		i18n: None,
+		gzip_templates: false,
 	};
 	inject_route_procedures(&mut route_manifest, &graph);
 	let procs = route_manifest.routes["/"].procedures.as_ref().unwrap();
@@ -229,6 +237,7 @@ fn make_manifest_typed(
 	}
 	seam_codegen::Manifest {
 		version: 2,
+		hash: None,
 		context: BTreeMap::new(),
 		procedures,
 		channels: BTreeMap::new(),
@@ -242,7 +251,9 @@ fn unused_query_detected() {
 	let manifest = make_manifest_typed(vec![("getArchive", ProcedureType::Query, None)]);
 	let skeleton = make_skeleton(vec![("/", serde_json::json!({}))], vec![]);
 	let graph = build_reference_graph(&manifest, &skeleton);
-	warn_unused_queries(&graph, &manifest);
+	let warnings = Warnings::default();
+	warn_unused_queries(&graph, &manifest, &warnings);
+	assert_eq!(warnings.into_vec().len(), 1);
 }
 
 #[test]
@@ -252,7 +263,9 @@ fn used_query_not_warned() {
 	let skeleton =
 		make_skeleton(vec![("/", serde_json::json!({ "page": { "procedure": "getHome" } }))], vec![]);
 	let graph = build_reference_graph(&manifest, &skeleton);
-	warn_unused_queries(&graph, &manifest);
+	let warnings = Warnings::default();
+	warn_unused_queries(&graph, &manifest, &warnings);
+	assert_eq!(warnings.into_vec().len(), 0);
 }
 
 #[test]
@@ -261,7 +274,9 @@ fn command_not_warned() {
 	let manifest = make_manifest_typed(vec![("createUser", ProcedureType::Command, None)]);
 	let skeleton = make_skeleton(vec![("/", serde_json::json!({}))], vec![]);
 	let graph = build_reference_graph(&manifest, &skeleton);
-	warn_unused_queries(&graph, &manifest);
+	let warnings = Warnings::default();
+	warn_unused_queries(&graph, &manifest, &warnings);
+	assert_eq!(warnings.into_vec().len(), 0);
 }
 
 #[test]
@@ -270,7 +285,9 @@ fn subscription_not_warned() {
 	let manifest = make_manifest_typed(vec![("onUpdate", ProcedureType::Subscription, None)]);
 	let skeleton = make_skeleton(vec![("/", serde_json::json!({}))], vec![]);
 	let graph = build_reference_graph(&manifest, &skeleton);
-	warn_unused_queries(&graph, &manifest);
+	let warnings = Warnings::default();
+	warn_unused_queries(&graph, &manifest, &warnings);
+	assert_eq!(warnings.into_vec().len(), 0);
 }
 
 #[test]
@@ -283,7 +300,9 @@ fn suppressed_query_skipped() {
 	)]);
 	let skeleton = make_skeleton(vec![("/", serde_json::json!({}))], vec![]);
 	let graph = build_reference_graph(&manifest, &skeleton);
-	warn_unused_queries(&graph, &manifest);
+	let warnings = Warnings::default();
+	warn_unused_queries(&graph, &manifest, &warnings);
+	assert_eq!(warnings.into_vec().len(), 0);
 }
 
 #[test]
@@ -291,7 +310,9 @@ fn empty_manifest_no_warn() {
 	let manifest = make_manifest_typed(vec![]);
 	let skeleton = make_skeleton(vec![("/", serde_json::json!({}))], vec![]);
 	let graph = build_reference_graph(&manifest, &skeleton);
-	warn_unused_queries(&graph, &manifest);
+	let warnings = Warnings::default();
+	warn_unused_queries(&graph, &manifest, &warnings);
+	assert_eq!(warnings.into_vec().len(), 0);
 }
 
 // -- generate_route_procedures_ts tests --
@@ -320,6 +341,7 @@ fn make_manifest_with_cache(
 	}
 	seam_codegen::Manifest {
 		version: 2,
+		hash: None,
 		context: BTreeMap::new(),
 		procedures,
 		channels: BTreeMap::new(),
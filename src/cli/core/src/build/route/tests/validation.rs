@@ -3,10 +3,12 @@
 use std::collections::BTreeMap;
 
 use super::super::manifest::{extract_manifest_command, validate_invalidates};
+use super::super::process::check_layout_cycles;
 use super::super::ref_graph::{build_reference_graph, validate_procedure_references};
 use super::super::types::RouteManifestEntry;
-use super::{make_manifest, make_skeleton};
+use super::{make_manifest, make_skeleton, make_skeleton_ext};
 use crate::config::CommandConfig;
+use crate::ui::Warnings;
 #[test]
 fn validate_all_procedures_exist() {
 	let manifest = make_manifest(&["getHomeData", "getSession"]);
@@ -45,6 +47,33 @@ fn validate_did_you_mean_suggestion() {
 	assert!(err.to_string().contains("Did you mean: getHomeData?"));
 }
 
+#[test]
+fn layout_parent_cycle_reports_path() {
+	let skeleton = make_skeleton_ext(
+		vec![],
+		vec![
+			("_layout_root", serde_json::Value::Null, None),
+			("_layout_a", serde_json::Value::Null, Some("_layout_b")),
+			("_layout_b", serde_json::Value::Null, Some("_layout_a")),
+		],
+	);
+	let err = check_layout_cycles(&skeleton.layouts).unwrap_err();
+	assert_eq!(err.to_string(), "layout parent cycle: _layout_a -> _layout_b -> _layout_a");
+}
+
+#[test]
+fn layout_chain_without_cycle_passes() {
+	let skeleton = make_skeleton_ext(
+		vec![],
+		vec![
+			("_layout_root", serde_json::Value::Null, None),
+			("_layout_a", serde_json::Value::Null, Some("_layout_root")),
+			("_layout_b", serde_json::Value::Null, Some("_layout_missing")),
+		],
+	);
+	assert!(check_layout_cycles(&skeleton.layouts).is_ok());
+}
+
 // -- head_meta serialization tests --
 
 #[test]
@@ -200,6 +229,7 @@ fn make_manifest_with_procedures(
 	}
 	seam_codegen::Manifest {
 		version: 2,
+		hash: None,
 		context: BTreeMap::new(),
 		procedures,
 		channels: BTreeMap::new(),
@@ -218,7 +248,7 @@ fn validate_invalidates_valid() {
 			Some(vec![InvalidateTarget { query: "getPost".to_string(), mapping: None }]),
 		),
 	]);
-	assert!(validate_invalidates(&manifest).is_ok());
+	assert!(validate_invalidates(&manifest, &Warnings::default()).is_ok());
 }
 
 #[test]
@@ -229,7 +259,7 @@ fn validate_invalidates_unknown_procedure() {
 		ProcedureType::Command,
 		Some(vec![InvalidateTarget { query: "nonExistent".to_string(), mapping: None }]),
 	)]);
-	let err = validate_invalidates(&manifest).unwrap_err();
+	let err = validate_invalidates(&manifest, &Warnings::default()).unwrap_err();
 	let msg = err.to_string();
 	assert!(msg.contains("\"updatePost\""), "should mention command name");
 	assert!(msg.contains("\"nonExistent\""), "should mention missing procedure");
@@ -246,7 +276,7 @@ fn validate_invalidates_wrong_kind() {
 			Some(vec![InvalidateTarget { query: "otherCommand".to_string(), mapping: None }]),
 		),
 	]);
-	let err = validate_invalidates(&manifest).unwrap_err();
+	let err = validate_invalidates(&manifest, &Warnings::default()).unwrap_err();
 	let msg = err.to_string();
 	assert!(msg.contains("command (expected query)"), "should mention wrong kind");
 }
@@ -262,7 +292,7 @@ fn validate_invalidates_did_you_mean() {
 			Some(vec![InvalidateTarget { query: "getPots".to_string(), mapping: None }]),
 		),
 	]);
-	let err = validate_invalidates(&manifest).unwrap_err();
+	let err = validate_invalidates(&manifest, &Warnings::default()).unwrap_err();
 	assert!(err.to_string().contains("Did you mean: getPost?"));
 }
 
@@ -275,7 +305,7 @@ fn validate_invalidates_warns_on_query() {
 		Some(vec![InvalidateTarget { query: "getPost".to_string(), mapping: None }]),
 	)]);
 	// Non-command with invalidates should warn but not error
-	assert!(validate_invalidates(&manifest).is_ok());
+	assert!(validate_invalidates(&manifest, &Warnings::default()).is_ok());
 }
 
 #[test]
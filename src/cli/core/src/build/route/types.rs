@@ -123,6 +123,20 @@ pub(crate) struct RouteManifest {
 	pub(super) data_id: Option<String>,
 	#[serde(skip_serializing_if = "Option::is_none", default)]
 	pub(super) i18n: Option<I18nManifest>,
+	/// Every template has a `.gz` sibling the server may load instead
+	#[serde(skip_serializing_if = "std::ops::Not::not", default)]
+	pub(crate) gzip_templates: bool,
+}
+
+impl RouteManifest {
+	/// Template paths of all layouts and routes, relative to the output directory.
+	pub(crate) fn template_paths(&self) -> impl Iterator<Item = &str> {
+		let layouts = self.layouts.values().map(|l| (&l.template, &l.templates));
+		let routes = self.routes.values().map(|r| (&r.template, &r.templates));
+		layouts.chain(routes).flat_map(|(template, templates)| {
+			template.iter().chain(templates.iter().flat_map(BTreeMap::values)).map(String::as_str)
+		})
+	}
 }
 
 #[derive(Serialize, Deserialize)]
@@ -130,6 +144,9 @@ pub(super) struct I18nManifest {
 	pub(super) locales: Vec<String>,
 	pub(super) default: String,
 	pub(super) mode: String,
+	/// Omitted for the default `hybrid`
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub(super) routing: Option<String>,
 	#[serde(skip_serializing_if = "std::ops::Not::not")]
 	pub(super) cache: bool,
 	#[serde(skip_serializing_if = "BTreeMap::is_empty")]
@@ -10,7 +10,7 @@ use super::super::route::{BundleContext, RenderContext, package_public_files, pr
 use super::super::types::read_bundle_manifest_extended;
 use super::helpers;
 use super::steps;
-use crate::ui::{self, BRIGHT_CYAN, BRIGHT_GREEN, RESET, StepTracker, col};
+use crate::ui::{self, BRIGHT_CYAN, BRIGHT_GREEN, RESET, StepTracker, Warnings, col};
 
 // -- Step registry --
 
@@ -31,7 +31,12 @@ fn frontend_steps(build_config: &BuildConfig, has_ssg: bool) -> Vec<&'static str
 
 // -- Frontend-only build --
 
-pub(super) fn run_frontend_build(build_config: &BuildConfig, base_dir: &Path) -> Result<()> {
+#[allow(clippy::too_many_lines)]
+pub(super) fn run_frontend_build(
+	build_config: &BuildConfig,
+	base_dir: &Path,
+	warnings: &Warnings,
+) -> Result<()> {
 	let started = Instant::now();
 
 	ui::banner("build", None);
@@ -62,7 +67,8 @@ pub(super) fn run_frontend_build(build_config: &BuildConfig, base_dir: &Path) ->
 
 	// -- Rendering skeletons --
 	let t = tracker.begin();
-	let skeleton_output = steps::render_skeletons(build_config, base_dir, Path::new("none"))?;
+	let skeleton_output =
+		steps::render_skeletons(build_config, base_dir, Path::new("none"), warnings)?;
 	ui::detail_ok(&format!("{} routes found", skeleton_output.routes.len()));
 	tracker.end_with(t, &format!("{} routes", skeleton_output.routes.len()));
 
@@ -78,11 +84,17 @@ pub(super) fn run_frontend_build(build_config: &BuildConfig, base_dir: &Path) ->
 		Some(bm) => &bm.template,
 		None => &assets,
 	};
+	let partials_dir = build_config.partials_path(base_dir);
 	let render = RenderContext {
 		root_id: &build_config.root_id,
 		data_id: &build_config.data_id,
+		lang: build_config.lang.as_deref(),
 		dev_mode: false,
 		vite: None,
+		ctr_debug: build_config.ctr_debug.as_deref(),
+		partials_dir: partials_dir.as_deref(),
+		modulepreload: build_config.modulepreload,
+		warnings,
 	};
 	let bundle_ctx = BundleContext {
 		manifest: bundle_manifest.as_ref(),
@@ -20,7 +20,7 @@ use super::rebuild::copy_wasm_binary;
 use super::steps;
 use crate::config::SeamConfig;
 use crate::shell::run_command;
-use crate::ui::{self, BRIGHT_CYAN, BRIGHT_GREEN, RESET, StepTracker, col};
+use crate::ui::{self, BRIGHT_CYAN, BRIGHT_GREEN, RESET, StepTracker, Warnings, col};
 
 // -- Step registry --
 
@@ -83,6 +83,7 @@ pub(super) fn run_fullstack_build(
 	config: &SeamConfig,
 	build_config: &BuildConfig,
 	base_dir: &Path,
+	warnings: &Warnings,
 ) -> Result<()> {
 	let started = Instant::now();
 	let out_dir = base_dir.join(&build_config.out_dir);
@@ -137,12 +138,12 @@ pub(super) fn run_fullstack_build(
 	// -- Rendering skeletons (before bundling — independent of bundled output) --
 	let t = tracker.begin();
 	let skeleton_output =
-		steps::render_skeletons(build_config, base_dir, &out_dir.join("seam-manifest.json"))?;
+		steps::render_skeletons(build_config, base_dir, &out_dir.join("seam-manifest.json"), warnings)?;
 	let ref_graph = build_reference_graph(&manifest, &skeleton_output);
 	validate_procedure_references(&ref_graph)?;
-	validate_invalidates(&manifest)?;
-	validate_handoff_consistency(&ref_graph);
-	warn_unused_queries(&ref_graph, &manifest);
+	validate_invalidates(&manifest, warnings)?;
+	validate_handoff_consistency(&ref_graph, warnings);
+	warn_unused_queries(&ref_graph, &manifest, warnings);
 	tracker.end_with(t, &format!("{} routes", skeleton_output.routes.len()));
 
 	// -- Generating route procedures --
@@ -181,11 +182,17 @@ pub(super) fn run_fullstack_build(
 		Some(bm) => &bm.template,
 		None => &assets,
 	};
+	let partials_dir = build_config.partials_path(base_dir);
 	let render = RenderContext {
 		root_id: &build_config.root_id,
 		data_id: &build_config.data_id,
+		lang: build_config.lang.as_deref(),
 		dev_mode: false,
 		vite: None,
+		ctr_debug: build_config.ctr_debug.as_deref(),
+		partials_dir: partials_dir.as_deref(),
+		modulepreload: build_config.modulepreload,
+		warnings,
 	};
 	let bundle_ctx = BundleContext {
 		manifest: bundle_manifest.as_ref(),
@@ -264,6 +271,8 @@ pub fn run_dev_build(
 	build_config: &BuildConfig,
 	base_dir: &Path,
 ) -> Result<()> {
+	// Dev builds only print their warnings; nothing reads them back
+	let warnings = Warnings::default();
 	let started = Instant::now();
 	let out_dir = base_dir.join(&build_config.out_dir);
 	let vite = vite_info_from_config(config, true);
@@ -305,13 +314,17 @@ pub fn run_dev_build(
 
 	// -- Rendering skeletons (before bundling — independent of bundled output) --
 	let t = tracker.begin();
-	let skeleton_output =
-		steps::render_skeletons(build_config, base_dir, &out_dir.join("seam-manifest.json"))?;
+	let skeleton_output = steps::render_skeletons(
+		build_config,
+		base_dir,
+		&out_dir.join("seam-manifest.json"),
+		&warnings,
+	)?;
 	let ref_graph = build_reference_graph(&manifest, &skeleton_output);
 	validate_procedure_references(&ref_graph)?;
-	validate_invalidates(&manifest)?;
-	validate_handoff_consistency(&ref_graph);
-	warn_unused_queries(&ref_graph, &manifest);
+	validate_invalidates(&manifest, &warnings)?;
+	validate_handoff_consistency(&ref_graph, &warnings);
+	warn_unused_queries(&ref_graph, &manifest, &warnings);
 	tracker.end_with(t, &format!("{} routes", skeleton_output.routes.len()));
 
 	// -- Generating route procedures --
@@ -338,11 +351,17 @@ pub fn run_dev_build(
 	};
 
 	// -- Processing routes + Exporting i18n --
+	let partials_dir = build_config.partials_path(base_dir);
 	let render = RenderContext {
 		root_id: &build_config.root_id,
 		data_id: &build_config.data_id,
+		lang: build_config.lang.as_deref(),
 		dev_mode: true,
 		vite: vite.as_ref(),
+		ctr_debug: build_config.ctr_debug.as_deref(),
+		partials_dir: partials_dir.as_deref(),
+		modulepreload: build_config.modulepreload,
+		warnings: &warnings,
 	};
 	let bundle_ctx = BundleContext { manifest: None, source_file_map: None };
 	steps::execute_route_steps(
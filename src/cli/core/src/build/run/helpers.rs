@@ -18,7 +18,9 @@ pub enum RebuildMode {
 	FrontendOnly,
 }
 
-/// Generate RPC hash map when obfuscation is enabled, write to out_dir
+/// Generate RPC hash map when obfuscation is enabled, write to out_dir.
+/// Raw procedures keep their names: they have no generated client method to carry a
+/// hash, and download links address them by name.
 pub(super) fn maybe_generate_rpc_hashes(
 	build_config: &BuildConfig,
 	manifest: &seam_codegen::Manifest,
@@ -27,7 +29,12 @@ pub(super) fn maybe_generate_rpc_hashes(
 	if !build_config.obfuscate {
 		return Ok(None);
 	}
-	let names: Vec<&str> = manifest.procedures.keys().map(std::string::String::as_str).collect();
+	let names: Vec<&str> = manifest
+		.procedures
+		.iter()
+		.filter(|(_, schema)| schema.proc_type != seam_codegen::ProcedureType::Raw)
+		.map(|(name, _)| name.as_str())
+		.collect();
 	let salt = build_config
 		.rpc_salt
 		.as_deref()
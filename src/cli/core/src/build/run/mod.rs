@@ -16,7 +16,9 @@ use std::path::Path;
 use anyhow::Result;
 
 use super::config::BuildConfig;
+use super::report::BuildOptions;
 use crate::config::SeamConfig;
+use crate::ui::Warnings;
 
 pub use helpers::RebuildMode;
 pub use rebuild::run_incremental_rebuild;
@@ -30,11 +32,18 @@ pub use fullstack::run_dev_build;
 
 // -- Entry point --
 
-pub fn run_build(config: &SeamConfig, base_dir: &Path) -> Result<()> {
-	let build_config = BuildConfig::from_seam_config(config)?;
+pub(crate) fn run_build(
+	config: &SeamConfig,
+	base_dir: &Path,
+	options: &BuildOptions,
+	warnings: &Warnings,
+) -> Result<()> {
+	let mut build_config = BuildConfig::from_seam_config(config)?;
+	build_config.ctr_debug.clone_from(&options.ctr_debug);
+	build_config.override_rpc_salt(options.salt.as_deref())?;
 	if build_config.is_fullstack {
-		fullstack::run_fullstack_build(config, &build_config, base_dir)
+		fullstack::run_fullstack_build(config, &build_config, base_dir, warnings)
 	} else {
-		frontend::run_frontend_build(&build_config, base_dir)
+		frontend::run_frontend_build(&build_config, base_dir, warnings)
 	}
 }
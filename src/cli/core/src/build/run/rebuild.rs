@@ -19,6 +19,7 @@ use super::helpers::{
 };
 use super::steps;
 use crate::config::SeamConfig;
+use crate::ui::Warnings;
 
 /// Incremental rebuild for dev mode — skips banner/summary to keep output compact.
 /// In Vite mode, skips bundler + manifest read + asset packaging (Vite serves assets directly).
@@ -28,6 +29,8 @@ pub fn run_incremental_rebuild(
 	base_dir: &Path,
 	mode: RebuildMode,
 ) -> Result<()> {
+	// Like dev builds, rebuilds only print their warnings
+	let warnings = Warnings::default();
 	let out_dir = base_dir.join(&build_config.out_dir);
 	let vite = vite_info_from_config(config, true);
 	let is_vite = vite.is_some();
@@ -59,8 +62,12 @@ pub fn run_incremental_rebuild(
 		steps::bundle_frontend(build_config, base_dir, &bundler_env)?
 	};
 
-	let skeleton_output =
-		steps::render_skeletons(build_config, base_dir, &out_dir.join("seam-manifest.json"))?;
+	let skeleton_output = steps::render_skeletons(
+		build_config,
+		base_dir,
+		&out_dir.join("seam-manifest.json"),
+		&warnings,
+	)?;
 
 	let manifest_json_path = out_dir.join("seam-manifest.json");
 	let manifest_str = std::fs::read_to_string(&manifest_json_path)
@@ -69,9 +76,9 @@ pub fn run_incremental_rebuild(
 		.with_context(|| format!("failed to parse {}", manifest_json_path.display()))?;
 	let ref_graph = build_reference_graph(&manifest, &skeleton_output);
 	validate_procedure_references(&ref_graph)?;
-	validate_invalidates(&manifest)?;
-	validate_handoff_consistency(&ref_graph);
-	warn_unused_queries(&ref_graph, &manifest);
+	validate_invalidates(&manifest, &warnings)?;
+	validate_handoff_consistency(&ref_graph, &warnings);
+	warn_unused_queries(&ref_graph, &manifest, &warnings);
 
 	let rp_path = base_dir.join(".seam/generated/route-procedures.ts");
 	generate_route_procedures_ts(&ref_graph, &manifest, &rp_path)?;
@@ -84,11 +91,17 @@ pub fn run_incremental_rebuild(
 		None => None,
 	};
 	// Rebuild path: no per-page splitting (dev mode)
+	let partials_dir = build_config.partials_path(base_dir);
 	let render = RenderContext {
 		root_id: &build_config.root_id,
 		data_id: &build_config.data_id,
+		lang: build_config.lang.as_deref(),
 		dev_mode: true,
 		vite: vite.as_ref(),
+		ctr_debug: build_config.ctr_debug.as_deref(),
+		partials_dir: partials_dir.as_deref(),
+		modulepreload: build_config.modulepreload,
+		warnings: &warnings,
 	};
 	let bundle_ctx = BundleContext { manifest: None, source_file_map: None };
 	let mut route_manifest = process_routes(
@@ -110,7 +123,7 @@ pub fn run_incremental_rebuild(
 	}
 
 	let meta = Some(super::super::route::build_manifest_meta(build_config));
-	steps::write_route_manifest(&out_dir, &mut route_manifest, meta)?;
+	steps::write_route_manifest(&out_dir, &mut route_manifest, meta, build_config.gzip_templates)?;
 
 	if !is_vite {
 		package_static_assets(base_dir, &out_dir, build_config.dist_dir())?;
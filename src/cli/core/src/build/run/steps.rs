@@ -18,7 +18,7 @@ use super::super::types::{AssetFiles, read_bundle_manifest};
 use super::helpers::print_cache_stats;
 use crate::config::OutputMode;
 use crate::shell::{resolve_node_module, run_builtin_bundler, which_exists};
-use crate::ui::{self, DIM, RESET, StepTracker, col};
+use crate::ui::{self, DIM, RESET, StepTracker, Warnings, col};
 
 pub(crate) type EnvPairs = Vec<(String, String)>;
 
@@ -47,6 +47,7 @@ pub(crate) fn render_skeletons(
 	build_config: &BuildConfig,
 	base_dir: &Path,
 	manifest_json_path: &Path,
+	warnings: &Warnings,
 ) -> Result<SkeletonOutput> {
 	let script_path = resolve_node_module(base_dir, "@canmi/seam-react/scripts/build-skeletons.mjs")
 		.ok_or_else(|| anyhow::anyhow!("build-skeletons.mjs not found -- install @canmi/seam-react"))?;
@@ -59,7 +60,7 @@ pub(crate) fn render_skeletons(
 		build_config.i18n.as_ref(),
 	)?;
 	for w in &output.warnings {
-		ui::detail_warn(w);
+		warnings.detail_warn(w);
 	}
 	print_cache_stats(&output.cache);
 	Ok(output)
@@ -78,12 +79,19 @@ pub(crate) fn bundle_frontend(
 }
 
 /// Write route-manifest.json to the output directory, optionally embedding `_meta`.
+/// With `gzip` (`build.gzip_templates`) the templates get their `.gz` siblings first, so
+/// the manifest never advertises files that are not written yet.
 pub(crate) fn write_route_manifest(
 	out_dir: &Path,
 	route_manifest: &mut RouteManifest,
 	meta: Option<ManifestMeta>,
+	gzip: bool,
 ) -> Result<()> {
 	route_manifest._meta = meta;
+	route_manifest.gzip_templates = gzip;
+	if gzip {
+		write_gzip_templates(out_dir, route_manifest)?;
+	}
 	let path = out_dir.join("route-manifest.json");
 	let json = serde_json::to_string_pretty(route_manifest)?;
 	std::fs::write(&path, &json).with_context(|| format!("failed to write {}", path.display()))?;
@@ -91,6 +99,24 @@ pub(crate) fn write_route_manifest(
 	Ok(())
 }
 
+/// Write `<template>.gz` next to every template the manifest lists.
+fn write_gzip_templates(out_dir: &Path, route_manifest: &RouteManifest) -> Result<()> {
+	use std::io::Write as _;
+	for rel in route_manifest.template_paths() {
+		let path = out_dir.join(rel);
+		let html =
+			std::fs::read(&path).with_context(|| format!("failed to read {}", path.display()))?;
+		let mut encoder = flate2::write::GzEncoder::new(Vec::new(), flate2::Compression::best());
+		encoder.write_all(&html)?;
+		let mut gz_path = path.into_os_string();
+		gz_path.push(".gz");
+		let gz_path = std::path::PathBuf::from(gz_path);
+		std::fs::write(&gz_path, encoder.finish()?)
+			.with_context(|| format!("failed to write {}", gz_path.display()))?;
+	}
+	Ok(())
+}
+
 /// Inputs for the shared route processing + i18n export pipeline.
 pub(crate) struct RouteStepInput<'a> {
 	pub skeleton: &'a SkeletonOutput,
@@ -125,7 +151,7 @@ pub(crate) fn execute_route_steps(
 	)?;
 
 	// Apply output mode behavior matrix (static/server/hybrid → effective prerender)
-	apply_output_mode(&mut route_manifest, input.build_config.output);
+	apply_output_mode(&mut route_manifest, input.build_config.output, input.render.warnings);
 
 	if let Some(graph) = input.ref_graph {
 		inject_route_procedures(&mut route_manifest, graph);
@@ -136,7 +162,12 @@ pub(crate) fn execute_route_steps(
 
 	if input.build_config.i18n.is_none() {
 		let meta = Some(build_manifest_meta(input.build_config));
-		write_route_manifest(input.out_dir, &mut route_manifest, meta)?;
+		write_route_manifest(
+			input.out_dir,
+			&mut route_manifest,
+			meta,
+			input.build_config.gzip_templates,
+		)?;
 	}
 
 	let route_count = input.skeleton.routes.len();
@@ -153,7 +184,12 @@ pub(crate) fn execute_route_steps(
 		let t = tracker.begin();
 		export_i18n(input.out_dir, &i18n_messages, &mut route_manifest, cfg)?;
 		let meta = Some(build_manifest_meta(input.build_config));
-		write_route_manifest(input.out_dir, &mut route_manifest, meta)?;
+		write_route_manifest(
+			input.out_dir,
+			&mut route_manifest,
+			meta,
+			input.build_config.gzip_templates,
+		)?;
 		tracker.end(t);
 	}
 
@@ -88,3 +88,29 @@ fn read_seam_manifest_not_confused_with_vite() {
 	assert_eq!(assets.css, vec!["assets/app.css"]);
 	std::fs::remove_dir_all(&dir).ok();
 }
+
+#[test]
+fn gzip_templates_are_written_and_loaded() {
+	use super::super::route::RouteManifest;
+
+	let dir = std::env::temp_dir().join("seam-test-gzip-templates");
+	let _ = std::fs::remove_dir_all(&dir);
+	std::fs::create_dir_all(dir.join("templates")).unwrap();
+	let html = "<html><body><p><!--seam:title--></p></body></html>";
+	std::fs::write(dir.join("templates/index.html"), html).unwrap();
+	let mut manifest: RouteManifest = serde_json::from_value(serde_json::json!({
+		"routes": { "/": { "template": "templates/index.html", "loaders": {} } }
+	}))
+	.unwrap();
+
+	super::steps::write_route_manifest(&dir, &mut manifest, None, true).unwrap();
+	let gz = std::fs::File::open(dir.join("templates/index.html.gz")).unwrap();
+	let mut unzipped = String::new();
+	std::io::Read::read_to_string(&mut flate2::read::GzDecoder::new(gz), &mut unzipped).unwrap();
+	assert_eq!(unzipped, html);
+	let written = std::fs::read_to_string(dir.join("route-manifest.json")).unwrap();
+	assert!(written.contains(r#""gzip_templates": true"#), "{written}");
+	let pages = seam_server::load_build_output(dir.to_str().unwrap()).unwrap();
+	assert_eq!(pages[0].template, html);
+	std::fs::remove_dir_all(&dir).ok();
+}
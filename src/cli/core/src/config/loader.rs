@@ -1,5 +1,6 @@
 /* src/cli/core/src/config/loader.rs */
 
+use std::collections::BTreeMap;
 use std::path::{Path, PathBuf};
 use std::process::Command;
 
@@ -97,14 +98,75 @@ fn resolve_project_name(config: &mut SeamConfig, config_dir: &Path) {
 fn load_toml_config(path: &Path) -> Result<SeamConfig> {
 	let content =
 		std::fs::read_to_string(path).with_context(|| format!("failed to read {}", path.display()))?;
-	let config: SeamConfig =
+	let raw: toml::Value =
 		toml::from_str(&content).with_context(|| format!("failed to parse {}", path.display()))?;
+	let raw = interpolate_env(raw, &|name| std::env::var(name).ok())
+		.with_context(|| format!("failed to interpolate {}", path.display()))?;
+	let config: SeamConfig =
+		raw.try_into().with_context(|| format!("failed to parse {}", path.display()))?;
 	if let Some(ref i18n) = config.i18n {
 		i18n.validate()?;
 	}
 	Ok(config)
 }
 
+/// Substitute `${VAR}` / `${VAR:-default}` in every string value of a parsed TOML
+/// config. Only strings are touched, so numeric fields like `port` stay literal.
+pub(super) fn interpolate_env(
+	value: toml::Value,
+	lookup: &dyn Fn(&str) -> Option<String>,
+) -> Result<toml::Value> {
+	Ok(match value {
+		toml::Value::String(s) => toml::Value::String(interpolate_str(&s, lookup)?),
+		toml::Value::Array(items) => toml::Value::Array(
+			items.into_iter().map(|v| interpolate_env(v, lookup)).collect::<Result<_>>()?,
+		),
+		toml::Value::Table(table) => toml::Value::Table(
+			table
+				.into_iter()
+				.map(|(k, v)| interpolate_env(v, lookup).map(|v| (k, v)))
+				.collect::<Result<_>>()?,
+		),
+		other => other,
+	})
+}
+
+/// `$${` escapes a literal `${` (e.g. for shell variables in `dev_command`).
+fn interpolate_str(s: &str, lookup: &dyn Fn(&str) -> Option<String>) -> Result<String> {
+	let mut out = String::with_capacity(s.len());
+	let mut rest = s;
+	while let Some(pos) = rest.find("${") {
+		if rest[..pos].ends_with('$') {
+			out.push_str(&rest[..pos - 1]);
+			out.push_str("${");
+			rest = &rest[pos + 2..];
+			continue;
+		}
+		out.push_str(&rest[..pos]);
+		let Some(len) = rest[pos + 2..].find('}') else {
+			bail!("unterminated `${{` in {s:?}");
+		};
+		let expr = &rest[pos + 2..pos + 2 + len];
+		let (name, default) = match expr.split_once(":-") {
+			Some((name, default)) => (name, Some(default)),
+			None => (expr, None),
+		};
+		if name.is_empty() {
+			bail!("empty variable name in {s:?}");
+		}
+		match (lookup(name), default) {
+			(Some(value), _) => out.push_str(&value),
+			(None, Some(default)) => out.push_str(default),
+			(None, None) => {
+				bail!("environment variable {name} is not set (use ${{{name}:-default}} for a fallback)")
+			}
+		}
+		rest = &rest[pos + 2 + len + 1..];
+	}
+	out.push_str(rest);
+	Ok(out)
+}
+
 fn load_ts_config(path: &Path) -> Result<SeamConfig> {
 	let base_dir = path.parent().unwrap_or_else(|| Path::new("."));
 	let file_name = path.file_name().and_then(|n| n.to_str()).unwrap_or("seam.config.ts");
@@ -250,7 +312,8 @@ pub fn validate_workspace(config: &SeamConfig, base_dir: &Path) -> Result<()> {
 		bail!("workspace.members must not be empty");
 	}
 
-	let mut seen_names = std::collections::HashSet::new();
+	check_duplicate_members(members)?;
+
 	for member_path in members {
 		let dir = base_dir.join(member_path);
 		if !dir.is_dir() {
@@ -260,12 +323,6 @@ pub fn validate_workspace(config: &SeamConfig, base_dir: &Path) -> Result<()> {
 			bail!("workspace member missing config file: {}", dir.display());
 		}
 
-		// Extract basename for duplicate check
-		let name = Path::new(member_path).file_name().and_then(|n| n.to_str()).unwrap_or(member_path);
-		if !seen_names.insert(name.to_string()) {
-			bail!("duplicate workspace member name: {name}");
-		}
-
 		// Load and check manifest extraction method
 		let member_config = resolve_member_config(config, &dir)?;
 		if member_config.build.router_file.is_none() && member_config.build.manifest_command.is_none() {
@@ -277,3 +334,34 @@ pub fn validate_workspace(config: &SeamConfig, base_dir: &Path) -> Result<()> {
 
 	Ok(())
 }
+
+/// Members are keyed by directory basename (output dirs, CLI `--member`), so two paths
+/// sharing one is as ambiguous as listing a path twice. Reports every collision at once.
+fn check_duplicate_members(members: &[String]) -> Result<()> {
+	let mut by_path: BTreeMap<&str, usize> = BTreeMap::new();
+	let mut by_name: BTreeMap<&str, Vec<&str>> = BTreeMap::new();
+	for member in members {
+		let path = member.trim_start_matches("./").trim_end_matches('/');
+		*by_path.entry(path).or_default() += 1;
+	}
+	for &path in by_path.keys() {
+		let name = Path::new(path).file_name().and_then(|n| n.to_str()).unwrap_or(path);
+		by_name.entry(name).or_default().push(path);
+	}
+
+	let mut problems: Vec<String> = by_path
+		.iter()
+		.filter(|(_, count)| **count > 1)
+		.map(|(path, count)| format!("path \"{path}\" is listed {count} times"))
+		.collect();
+	problems.extend(
+		by_name
+			.iter()
+			.filter(|(_, paths)| paths.len() > 1)
+			.map(|(name, paths)| format!("name \"{name}\" is shared by {}", paths.join(", "))),
+	);
+	if !problems.is_empty() {
+		bail!("duplicate workspace members:\n  {}", problems.join("\n  "));
+	}
+	Ok(())
+}
@@ -8,6 +8,6 @@ mod tests;
 
 pub use loader::{find_seam_config, load_seam_config, resolve_member_config, validate_workspace};
 pub use types::{
-	CommandConfig, I18nMode, I18nSection, OutputMode, SeamConfig, TransportConfig,
-	TransportPreference, TransportSection,
+	CommandConfig, I18nMode, I18nSection, LocaleRouting, OutputMode, RobotsSection, SeamConfig,
+	TransportConfig, TransportPreference, TransportSection,
 };
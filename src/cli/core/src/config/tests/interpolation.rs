@@ -0,0 +1,82 @@
+/* src/cli/core/src/config/tests/interpolation.rs */
+
+use super::*;
+use loader::interpolate_env;
+
+fn env(name: &str) -> Option<String> {
+	match name {
+		"OUT_DIR" => Some("/srv/dist".into()),
+		"HOST" => Some("api.internal".into()),
+		_ => None,
+	}
+}
+
+fn interpolate(toml_str: &str) -> anyhow::Result<SeamConfig> {
+	let raw: toml::Value = toml::from_str(toml_str).unwrap();
+	Ok(interpolate_env(raw, &env)?.try_into()?)
+}
+
+#[test]
+fn substitutes_env_vars_in_strings() {
+	let config = interpolate(
+		r#"
+[build]
+out_dir = "${OUT_DIR}/app"
+
+[backend]
+dev_command = "serve --host ${HOST} --dir ${OUT_DIR}"
+"#,
+	)
+	.unwrap();
+	assert_eq!(config.build.out_dir.as_deref(), Some("/srv/dist/app"));
+	assert_eq!(
+		config.backend.dev_command.as_ref().map(CommandConfig::command),
+		Some("serve --host api.internal --dir /srv/dist")
+	);
+}
+
+#[test]
+fn default_applies_only_when_unset() {
+	let config = interpolate(
+		r#"
+[project]
+name = "${APP_NAME:-fallback-app}"
+
+[build]
+out_dir = "${OUT_DIR:-dist}"
+"#,
+	)
+	.unwrap();
+	assert_eq!(config.project_name(), "fallback-app");
+	assert_eq!(config.build.out_dir.as_deref(), Some("/srv/dist"));
+}
+
+#[test]
+fn undefined_var_without_default_errors() {
+	let err = interpolate("[build]\nout_dir = \"${MISSING_DIR}\"\n").unwrap_err();
+	assert!(err.to_string().contains("environment variable MISSING_DIR is not set"), "{err}");
+}
+
+#[test]
+fn escaped_and_unterminated_placeholders() {
+	let config = interpolate("[backend]\ndev_command = \"echo $${PORT}\"\n").unwrap();
+	assert_eq!(config.backend.dev_command.as_ref().map(CommandConfig::command), Some("echo ${PORT}"));
+	assert!(interpolate("[build]\nout_dir = \"${OUT_DIR\"\n").is_err());
+}
+
+#[test]
+fn load_seam_config_interpolates_toml() {
+	let tmp = std::env::temp_dir().join("seam-test-env-interp");
+	let _ = std::fs::remove_dir_all(&tmp);
+	std::fs::create_dir_all(&tmp).unwrap();
+
+	std::fs::write(
+		tmp.join("seam.toml"),
+		"[build]\nout_dir = \"${SEAM_TEST_UNSET_OUT_DIR:-from-default}\"\n",
+	)
+	.unwrap();
+	let config = loader::load_seam_config(&tmp.join("seam.toml")).unwrap();
+	assert_eq!(config.build.out_dir.as_deref(), Some("from-default"));
+
+	let _ = std::fs::remove_dir_all(&tmp);
+}
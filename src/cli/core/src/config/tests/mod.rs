@@ -2,6 +2,7 @@
 
 use super::*;
 
+mod interpolation;
 mod parsing;
 mod ts_config;
 mod validation;
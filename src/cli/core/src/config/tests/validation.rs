@@ -35,6 +35,21 @@ locales = ["origin", "zh"]
 	assert_eq!(i18n.locales, vec!["origin", "zh"]);
 	assert_eq!(i18n.default, "origin");
 	assert_eq!(i18n.messages_dir, "locales");
+	assert_eq!(i18n.routing, LocaleRouting::Hybrid);
+}
+
+#[test]
+fn parse_i18n_routing() {
+	let toml_str = r#"
+[i18n]
+locales = ["en", "fr"]
+default = "en"
+routing = "hidden"
+"#;
+	let config: SeamConfig = toml::from_str(toml_str).unwrap();
+	assert_eq!(config.i18n.unwrap().routing, LocaleRouting::Hidden);
+	let bad = "[i18n]\nlocales = [\"en\"]\nrouting = \"subdomain\"\n";
+	assert!(toml::from_str::<SeamConfig>(bad).is_err());
 }
 
 #[test]
@@ -207,3 +207,21 @@ members = ["member"]
 
 	let _ = std::fs::remove_dir_all(&tmp);
 }
+
+#[test]
+fn workspace_validation_reports_all_duplicates() {
+	let config: SeamConfig = toml::from_str(
+		r#"
+[workspace]
+members = ["a/hono", "b/hono", "rust", "./rust/", "c/bun"]
+"#,
+	)
+	.unwrap();
+
+	// Duplicates are reported before any member directory is inspected
+	let err = validate_workspace(&config, std::path::Path::new("/nonexistent")).unwrap_err();
+	assert_eq!(
+		err.to_string(),
+		"duplicate workspace members:\n  path \"rust\" is listed 2 times\n  name \"hono\" is shared by a/hono, b/hono"
+	);
+}
@@ -69,6 +69,8 @@ pub struct SeamConfig {
 	#[serde(default)]
 	pub clean: CleanSection,
 	#[serde(default)]
+	pub robots: RobotsSection,
+	#[serde(default)]
 	pub transport: Option<TransportSection>,
 	#[serde(default)]
 	#[allow(dead_code)] // deserialized for passthrough to bundler scripts via SEAM_CONFIG_PATH
@@ -92,6 +94,15 @@ pub struct CleanSection {
 	pub commands: Vec<String>,
 }
 
+/// `seam robots` input. Unconfigured, robots.txt allows every path.
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct RobotsSection {
+	#[serde(default)]
+	pub disallow: Vec<String>,
+	/// Absolute sitemap URL for the `Sitemap:` line
+	pub sitemap: Option<String>,
+}
+
 #[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Deserialize)]
 #[serde(rename_all = "lowercase")]
 pub enum I18nMode {
@@ -109,6 +120,27 @@ impl I18nMode {
 	}
 }
 
+/// How the page URL carries the locale. `hybrid` serves both `/fr/page` and `/page`,
+/// resolving the latter from the cookie, then `Accept-Language`.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum LocaleRouting {
+	Prefix,
+	Hidden,
+	#[default]
+	Hybrid,
+}
+
+impl LocaleRouting {
+	pub fn as_str(self) -> &'static str {
+		match self {
+			Self::Prefix => "prefix",
+			Self::Hidden => "hidden",
+			Self::Hybrid => "hybrid",
+		}
+	}
+}
+
 #[derive(Debug, Clone, Deserialize)]
 pub struct I18nSection {
 	pub locales: Vec<String>,
@@ -119,6 +151,8 @@ pub struct I18nSection {
 	#[serde(default)]
 	pub mode: I18nMode,
 	#[serde(default)]
+	pub routing: LocaleRouting,
+	#[serde(default)]
 	pub cache: bool,
 }
 
@@ -175,6 +209,8 @@ pub struct FrontendConfig {
 	pub root_id: String,
 	#[serde(default = "default_data_id")]
 	pub data_id: String,
+	/// `<html lang>` for every page; the resolved locale wins when i18n is on
+	pub lang: Option<String>,
 }
 
 impl Default for FrontendConfig {
@@ -187,12 +223,13 @@ impl Default for FrontendConfig {
 			out_dir: None,
 			root_id: default_root_id(),
 			data_id: default_data_id(),
+			lang: None,
 		}
 	}
 }
 
 fn default_root_id() -> String {
-	"__seam".to_string()
+	seam_skeleton::DEFAULT_ROOT_ID.to_string()
 }
 
 fn default_data_id() -> String {
@@ -221,7 +258,17 @@ pub struct BuildSection {
 	pub type_hint: Option<bool>,
 	#[serde(default)]
 	pub hash_length: Option<u32>,
+	/// Pins the RPC hash salt so obfuscated names survive rebuilds; random per build when unset
+	pub salt: Option<String>,
 	pub pages_dir: Option<String>,
+	/// Directory `<!--seam:include:name-->` markers resolve against
+	pub partials_dir: Option<String>,
+	/// Emit `<link rel="modulepreload">` hints for the entry scripts (default: false)
+	#[serde(default)]
+	pub modulepreload: Option<bool>,
+	/// Also write `<template>.html.gz` for the server to load instead (default: false)
+	#[serde(default)]
+	pub gzip_templates: Option<bool>,
 }
 
 #[derive(Debug, Clone, Default, Deserialize)]
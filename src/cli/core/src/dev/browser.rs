@@ -0,0 +1,107 @@
+/* src/cli/core/src/dev/browser.rs */
+
+// `seam dev --open`: launch the default browser once the dev URL is reachable.
+// Headless environments (CI, no opener on PATH) skip it instead of failing dev.
+
+use std::ffi::OsStr;
+use std::process::Stdio;
+use std::time::Duration;
+
+use super::network::wait_for_port;
+use crate::ui;
+
+pub(super) fn browser_url(port: u16) -> String {
+	format!("http://localhost:{port}")
+}
+
+/// The frontend is served on `dev_port` by the embedded dev server or the frontend
+/// command; only a backend-only setup has nothing there but the backend's own port.
+pub(super) fn open_port(frontend_served: bool, dev_port: u16, backend_port: u16) -> u16 {
+	if frontend_served { dev_port } else { backend_port }
+}
+
+/// Wait in the background for `port` to accept connections, then open it.
+pub(super) fn open_when_ready(port: u16) {
+	let ci = is_ci(std::env::var_os("CI").as_deref());
+	let Some(opener) = resolve_opener(ci, system_opener()) else {
+		return;
+	};
+	tokio::spawn(async move {
+		if wait_for_port(port, Duration::from_secs(30)).await.is_err() {
+			ui::detail_warn(&format!("--open skipped: nothing listening on :{port}"));
+			return;
+		}
+		let url = browser_url(port);
+		let status = tokio::process::Command::new(opener)
+			.arg(&url)
+			.stdout(Stdio::null())
+			.stderr(Stdio::null())
+			.status()
+			.await;
+		// explorer exits non-zero even after opening the URL, so only a spawn error counts
+		let failed = match status {
+			Ok(status) => !status.success() && opener != "explorer",
+			Err(_) => true,
+		};
+		if failed {
+			ui::detail_warn(&format!("--open failed to launch {opener} {url}"));
+		}
+	});
+}
+
+/// `CI` set to anything but empty, `0` or `false` (any case) marks a CI run.
+fn is_ci(value: Option<&OsStr>) -> bool {
+	value.is_some_and(|v| {
+		let v = v.to_string_lossy();
+		let v = v.trim();
+		!(v.is_empty() || v == "0" || v.eq_ignore_ascii_case("false"))
+	})
+}
+
+/// CI never opens a browser, even when an opener happens to be installed.
+fn resolve_opener(ci: bool, opener: Option<&'static str>) -> Option<&'static str> {
+	if ci { None } else { opener }
+}
+
+fn system_opener() -> Option<&'static str> {
+	if cfg!(windows) {
+		return Some("explorer");
+	}
+	let cmd = if cfg!(target_os = "macos") { "open" } else { "xdg-open" };
+	crate::shell::which_exists(cmd).then_some(cmd)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn url_targets_localhost_port() {
+		assert_eq!(browser_url(5173), "http://localhost:5173");
+		assert_eq!(browser_url(80), "http://localhost:80");
+	}
+
+	#[test]
+	fn open_port_prefers_frontend() {
+		assert_eq!(open_port(true, 5173, 3000), 5173);
+		assert_eq!(open_port(false, 5173, 3000), 3000);
+	}
+
+	#[test]
+	fn ci_false_or_zero_is_not_ci() {
+		assert!(is_ci(Some(OsStr::new("true"))));
+		assert!(is_ci(Some(OsStr::new("1"))));
+		assert!(!is_ci(Some(OsStr::new("false"))));
+		assert!(!is_ci(Some(OsStr::new("FALSE"))));
+		assert!(!is_ci(Some(OsStr::new("0"))));
+		assert!(!is_ci(Some(OsStr::new(""))));
+		assert!(!is_ci(None));
+	}
+
+	#[test]
+	fn ci_or_missing_opener_skips() {
+		assert_eq!(resolve_opener(false, Some("xdg-open")), Some("xdg-open"));
+		assert_eq!(resolve_opener(true, Some("xdg-open")), None);
+		assert_eq!(resolve_opener(false, None), None);
+	}
+}
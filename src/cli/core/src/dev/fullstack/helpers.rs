@@ -285,6 +285,10 @@ pub(super) fn setup_watched_dirs(
 	if let Some(pages_dir) = &build_config.pages_dir {
 		watch_dir(watcher, &base_dir.join(pages_dir), &format!("{pages_dir}/"), &mut watched_dirs)?;
 	}
+	if let Some(partials_dir) = &build_config.partials_dir {
+		let label = format!("{partials_dir}/");
+		watch_dir(watcher, &base_dir.join(partials_dir), &label, &mut watched_dirs)?;
+	}
 	if let Some(public_dir) = public_dir {
 		watch_dir(watcher, public_dir, "public/", &mut watched_dirs)?;
 	}
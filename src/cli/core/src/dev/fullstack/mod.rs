@@ -28,13 +28,18 @@ pub async fn run_dev_workspace(
 	root: &SeamConfig,
 	base_dir: &Path,
 	member_name: &str,
+	open: bool,
 ) -> Result<()> {
 	let members = crate::workspace::resolve_members(root, base_dir, Some(member_name))?;
 	let member = &members[0];
-	run_dev_fullstack(&member.merged_config, base_dir).await
+	run_dev_fullstack(&member.merged_config, base_dir, open).await
 }
 
-pub(super) async fn run_dev_fullstack(config: &SeamConfig, base_dir: &Path) -> Result<()> {
+pub(super) async fn run_dev_fullstack(
+	config: &SeamConfig,
+	base_dir: &Path,
+	open: bool,
+) -> Result<()> {
 	let public_port = find_available_port(config.dev.port)?;
 	let backend_port = find_available_port_excluding(config.backend.port, &[public_port])?;
 	let vite_port = find_available_port_excluding(
@@ -63,6 +68,9 @@ pub(super) async fn run_dev_fullstack(config: &SeamConfig, base_dir: &Path) -> R
 		vite_port,
 	)?;
 	print_fullstack_banner(&effective_config, public_port, &watched_dirs, Some(vite_port));
+	if open {
+		super::browser::open_when_ready(public_port);
+	}
 	let mut children = spawn_fullstack_children(&effective_config, base_dir, &spawn_opts).await?;
 	let mut dev_server = std::pin::pin!(crate::dev_server::start_fullstack_dev_server(
 		spawn_opts.public_port,
@@ -24,13 +24,18 @@ fn test_build_config() -> BuildConfig {
 		obfuscate: false,
 		sourcemap: true,
 		type_hint: true,
+		modulepreload: false,
+		gzip_templates: false,
 		hash_length: 12,
 		rpc_salt: None,
-		root_id: "__SEAM_ROOT__".to_string(),
+		root_id: seam_skeleton::DEFAULT_ROOT_ID.to_string(),
 		data_id: "__data".to_string(),
+		lang: None,
 		pages_dir: None,
+		partials_dir: None,
 		i18n: None,
 		config_path: None,
+		ctr_debug: None,
 	}
 }
 
@@ -1,5 +1,6 @@
 /* src/cli/core/src/dev/mod.rs */
 
+mod browser;
 mod fullstack;
 mod network;
 mod process;
@@ -22,10 +23,10 @@ use ui::{build_frontend, print_dev_banner};
 
 pub use fullstack::run_dev_workspace;
 
-pub async fn run_dev(config: &SeamConfig, base_dir: &Path) -> Result<()> {
+pub async fn run_dev(config: &SeamConfig, base_dir: &Path, open: bool) -> Result<()> {
 	let build_config = BuildConfig::from_seam_config(config);
 	if build_config.as_ref().is_ok_and(|bc| bc.is_fullstack) {
-		return run_dev_fullstack(config, base_dir).await;
+		return run_dev_fullstack(config, base_dir, open).await;
 	}
 
 	let backend_cmd = config.backend.dev_command.as_ref();
@@ -70,9 +71,14 @@ pub async fn run_dev(config: &SeamConfig, base_dir: &Path) -> Result<()> {
 		children.push(proc);
 	}
 
+	let dev_port = config.frontend.dev_port.unwrap_or(5173);
+	if open {
+		let frontend_served = use_embedded || frontend_cmd.is_some();
+		browser::open_when_ready(browser::open_port(frontend_served, dev_port, config.backend.port));
+	}
+
 	// Wait for Ctrl+C, child exit, or dev server error
 	if use_embedded {
-		let dev_port = config.frontend.dev_port.unwrap_or(5173);
 		let (manifest_path, static_dir) = match &build_config {
 			Ok(bc) => (base_dir.join(bc.bundler_manifest()), base_dir.join(bc.dist_dir())),
 			Err(_) => (base_dir.join(".seam/dist/.vite/manifest.json"), base_dir.join(".seam/dist")),
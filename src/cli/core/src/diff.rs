@@ -0,0 +1,83 @@
+/* src/cli/core/src/diff.rs */
+
+// `seam diff` command: compares two procedure manifests and fails on breaking changes,
+// so CI can gate API compatibility.
+
+use std::path::Path;
+
+use anyhow::{Context, Result, bail};
+use seam_codegen::{Manifest, ManifestDiff};
+
+use crate::ui::{self, DIM, RESET, col};
+
+/// Print the differences from `old` to `new`; errors (non-zero exit) when any break clients.
+pub fn run_diff(old: &Path, new: &Path) -> Result<()> {
+	let diff = read_manifest(old)?.diff(&read_manifest(new)?);
+	ui::banner("diff", None);
+	print_diff(&diff);
+	let breaking = breaking_count(&diff);
+	if breaking > 0 {
+		bail!("{breaking} breaking change(s) between {} and {}", old.display(), new.display());
+	}
+	if diff.is_empty() {
+		ui::ok("manifests are identical");
+	} else {
+		ui::ok("no breaking changes");
+	}
+	Ok(())
+}
+
+fn read_manifest(path: &Path) -> Result<Manifest> {
+	let content =
+		std::fs::read_to_string(path).with_context(|| format!("failed to read {}", path.display()))?;
+	serde_json::from_str(&content).with_context(|| format!("failed to parse {}", path.display()))
+}
+
+fn breaking_count(diff: &ManifestDiff) -> usize {
+	diff.removed.len() + diff.changes.iter().filter(|c| c.breaking).count()
+}
+
+fn print_diff(diff: &ManifestDiff) {
+	let (d, r) = (col(DIM), col(RESET));
+	for name in &diff.added {
+		ui::detail_ok(&format!("{name}  {d}added{r}"));
+	}
+	for name in &diff.removed {
+		ui::detail_warn(&format!("{name}  removed (breaking)"));
+	}
+	for change in &diff.changes {
+		let line = format!("{} {}  {}", change.procedure, change.path, change.description);
+		if change.breaking {
+			ui::detail_warn(&format!("{line} (breaking)"));
+		} else {
+			ui::detail(&line);
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn write(dir: &Path, name: &str, procedures: &serde_json::Value) -> std::path::PathBuf {
+		let path = dir.join(name);
+		let manifest = serde_json::json!({ "version": 2, "procedures": procedures });
+		std::fs::write(&path, manifest.to_string()).unwrap();
+		path
+	}
+
+	#[test]
+	fn breaking_diff_returns_error() {
+		let dir = std::env::temp_dir().join("seam-test-diff-breaking");
+		let _ = std::fs::remove_dir_all(&dir);
+		std::fs::create_dir_all(&dir).unwrap();
+		let query = serde_json::json!({ "getUser": { "kind": "query", "input": {}, "output": {} } });
+		let old = write(&dir, "old.json", &query);
+		let new = write(&dir, "new.json", &serde_json::json!({}));
+
+		let err = run_diff(&old, &new).unwrap_err();
+		assert!(err.to_string().contains("1 breaking change"), "{err}");
+		assert!(run_diff(&new, &old).is_ok());
+		let _ = std::fs::remove_dir_all(&dir);
+	}
+}
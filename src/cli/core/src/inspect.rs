@@ -0,0 +1,203 @@
+/* src/cli/core/src/inspect.rs */
+
+// `seam inspect` command: shows what a route resolved to in the built route manifest.
+
+use std::collections::BTreeMap;
+use std::path::Path;
+
+use anyhow::{Context, Result, bail};
+use serde::{Deserialize, Serialize};
+
+use crate::ui::{self, DIM, RESET, col};
+
+#[derive(Deserialize)]
+struct InspectManifest {
+	#[serde(default)]
+	layouts: BTreeMap<String, InspectLayout>,
+	#[serde(default)]
+	routes: BTreeMap<String, InspectRoute>,
+}
+
+#[derive(Deserialize)]
+struct InspectLayout {
+	#[serde(default)]
+	loaders: serde_json::Value,
+	#[serde(default)]
+	parent: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct InspectRoute {
+	#[serde(default)]
+	template: Option<String>,
+	#[serde(default)]
+	templates: Option<BTreeMap<String, String>>,
+	#[serde(default)]
+	layout: Option<String>,
+	#[serde(default)]
+	loaders: serde_json::Value,
+	#[serde(default)]
+	head_meta: Option<String>,
+}
+
+#[derive(Serialize)]
+struct RouteReport {
+	route: String,
+	template: Option<String>,
+	/// Contents of `template`, read from the output dir
+	#[serde(skip_serializing_if = "Option::is_none")]
+	template_html: Option<String>,
+	#[serde(skip_serializing_if = "BTreeMap::is_empty")]
+	locale_templates: BTreeMap<String, String>,
+	/// Innermost layout first
+	layouts: Vec<String>,
+	loaders: Vec<LoaderReport>,
+	head_meta: Option<String>,
+}
+
+#[derive(Serialize)]
+struct LoaderReport {
+	key: String,
+	procedure: String,
+	/// `page`, or the id of the layout that declares the loader
+	source: String,
+}
+
+/// Print the resolved template, loaders, and head metadata for `route`.
+pub fn run_inspect(out_dir: &Path, route: &str, json: bool) -> Result<()> {
+	let manifest_path = out_dir.join("route-manifest.json");
+	let content = std::fs::read_to_string(&manifest_path).with_context(|| {
+		format!("failed to read {} (run `seam build` first)", manifest_path.display())
+	})?;
+	let manifest: InspectManifest =
+		serde_json::from_str(&content).context("failed to parse route-manifest.json")?;
+	let mut report = inspect_route(&manifest, route)?;
+	report.template_html =
+		report.template.as_ref().and_then(|t| std::fs::read_to_string(out_dir.join(t)).ok());
+
+	if json {
+		println!("{}", serde_json::to_string_pretty(&report)?);
+	} else {
+		print_report(&report);
+	}
+	Ok(())
+}
+
+fn inspect_route(manifest: &InspectManifest, route: &str) -> Result<RouteReport> {
+	let Some(entry) = manifest.routes.get(route) else {
+		let known: Vec<&str> = manifest.routes.keys().map(String::as_str).collect();
+		bail!("route \"{route}\" is not in the route manifest\navailable routes: {}", known.join(", "));
+	};
+
+	let mut loaders = loader_reports(&entry.loaders, "page");
+	let mut layouts = Vec::new();
+	let mut next = entry.layout.clone();
+	while let Some(id) = next {
+		// A cyclic chain is rejected at build time; stop rather than loop on a stale manifest
+		if layouts.contains(&id) {
+			break;
+		}
+		let Some(layout) = manifest.layouts.get(&id) else {
+			break;
+		};
+		loaders.extend(loader_reports(&layout.loaders, &id));
+		next = layout.parent.clone();
+		layouts.push(id);
+	}
+
+	Ok(RouteReport {
+		route: route.to_string(),
+		template: entry.template.clone(),
+		template_html: None,
+		locale_templates: entry.templates.clone().unwrap_or_default(),
+		layouts,
+		loaders,
+		head_meta: entry.head_meta.clone(),
+	})
+}
+
+fn loader_reports(loaders: &serde_json::Value, source: &str) -> Vec<LoaderReport> {
+	let Some(map) = loaders.as_object() else {
+		return Vec::new();
+	};
+	map
+		.iter()
+		.map(|(key, def)| LoaderReport {
+			key: key.clone(),
+			procedure: def.get("procedure").and_then(|p| p.as_str()).unwrap_or("?").to_string(),
+			source: source.to_string(),
+		})
+		.collect()
+}
+
+fn print_report(report: &RouteReport) {
+	let (d, r) = (col(DIM), col(RESET));
+	ui::banner("inspect", Some(&report.route));
+	match report.template {
+		Some(ref t) => ui::arrow(&format!("template  {t}")),
+		None => ui::arrow(&format!("template  {d}(per locale){r}")),
+	}
+	for (locale, path) in &report.locale_templates {
+		ui::detail(&format!("{locale}  {d}{path}{r}"));
+	}
+	if !report.layouts.is_empty() {
+		ui::arrow(&format!("layouts   {}", report.layouts.join(" -> ")));
+	}
+	ui::arrow(&format!("loaders   {}", report.loaders.len()));
+	for l in &report.loaders {
+		ui::detail(&format!("{} -> {}  {d}({}){r}", l.key, l.procedure, l.source));
+	}
+	if let Some(ref meta) = report.head_meta {
+		ui::arrow("head_meta");
+		ui::detail(meta);
+	}
+	if let Some(ref html) = report.template_html {
+		ui::blank();
+		println!("{html}");
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn manifest() -> InspectManifest {
+		serde_json::from_value(serde_json::json!({
+			"layouts": {
+				"root": { "template": "templates/root.html", "loaders": { "session": { "procedure": "getSession" } } },
+				"blog": { "template": "templates/blog.html", "parent": "root" }
+			},
+			"routes": {
+				"/blog/:slug": {
+					"template": "templates/blog-slug.html",
+					"layout": "blog",
+					"loaders": { "post": { "procedure": "getPost", "params": { "slug": "route" } } },
+					"head_meta": "<title><!--seam:post.title--></title>"
+				}
+			}
+		}))
+		.unwrap()
+	}
+
+	#[test]
+	fn inspect_surfaces_loader_procedures() {
+		let report = inspect_route(&manifest(), "/blog/:slug").unwrap();
+		let loaders: Vec<(&str, &str, &str)> = report
+			.loaders
+			.iter()
+			.map(|l| (l.key.as_str(), l.procedure.as_str(), l.source.as_str()))
+			.collect();
+		assert_eq!(loaders, [("post", "getPost", "page"), ("session", "getSession", "root")]);
+		assert_eq!(report.layouts, ["blog", "root"]);
+		assert_eq!(report.template.as_deref(), Some("templates/blog-slug.html"));
+		assert!(report.head_meta.unwrap().contains("post.title"));
+	}
+
+	#[test]
+	fn inspect_unknown_route_lists_known_ones() {
+		let Err(err) = inspect_route(&manifest(), "/nope") else {
+			panic!("expected an error");
+		};
+		assert!(err.to_string().contains("available routes: /blog/:slug"), "{err}");
+	}
+}
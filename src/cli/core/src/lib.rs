@@ -0,0 +1,25 @@
+/* src/cli/core/src/lib.rs */
+#![cfg_attr(test, allow(clippy::unwrap_used))]
+#![allow(clippy::print_stdout, clippy::print_stderr)]
+
+//! Library side of the `seam` CLI. [`build_project`] runs a build from other Rust tooling
+//! without shelling out; [`run_cli`] is the whole `seam` binary.
+
+mod build;
+mod clean;
+mod cli;
+mod config;
+mod dev;
+mod dev_server;
+mod diff;
+mod inspect;
+mod pull;
+mod robots;
+mod shell;
+mod sitemap;
+mod ui;
+mod workspace;
+
+pub use build::report::{BuildOptions, BuildReport, build_project, build_with_config};
+pub use cli::run_cli;
+pub use config::{SeamConfig, load_seam_config};
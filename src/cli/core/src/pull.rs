@@ -45,6 +45,7 @@ pub async fn pull_manifest(base_url: &str, out: &Path) -> Result<()> {
 	let mut subscriptions = 0u32;
 	let mut streams = 0u32;
 	let mut uploads = 0u32;
+	let mut raws = 0u32;
 	for proc in manifest.procedures.values() {
 		match proc.proc_type {
 			ProcedureType::Query => queries += 1,
@@ -52,6 +53,7 @@ pub async fn pull_manifest(base_url: &str, out: &Path) -> Result<()> {
 			ProcedureType::Subscription => subscriptions += 1,
 			ProcedureType::Stream => streams += 1,
 			ProcedureType::Upload => uploads += 1,
+			ProcedureType::Raw => raws += 1,
 		}
 	}
 
@@ -74,6 +76,9 @@ pub async fn pull_manifest(base_url: &str, out: &Path) -> Result<()> {
 	if uploads > 0 {
 		parts.push(format!("{uploads} {}", if uploads == 1 { "upload" } else { "uploads" }));
 	}
+	if raws > 0 {
+		parts.push(format!("{raws} raw"));
+	}
 
 	let breakdown =
 		if parts.is_empty() { String::new() } else { format!(" \u{2014} {}", parts.join(", ")) };
@@ -100,9 +105,47 @@ pub async fn pull_manifest(base_url: &str, out: &Path) -> Result<()> {
 	Ok(())
 }
 
+/// Hashes of a local and a served manifest when both carry one and they differ.
+fn hash_drift<'a>(local: &'a Manifest, served: &'a Manifest) -> Option<(&'a str, &'a str)> {
+	match (local.hash.as_deref(), served.hash.as_deref()) {
+		(Some(l), Some(s)) if l != s => Some((l, s)),
+		_ => None,
+	}
+}
+
+/// Warn when the manifest at `local_path` was pulled from a different server schema than
+/// `served`. A missing or unreadable local file is not an error: there is nothing to compare.
+pub fn warn_on_manifest_drift(local_path: &Path, served: &Manifest) {
+	let Ok(content) = std::fs::read_to_string(local_path) else { return };
+	let Ok(local) = serde_json::from_str::<Manifest>(&content) else { return };
+	if let Some((local_hash, served_hash)) = hash_drift(&local, served) {
+		ui::warn(&format!(
+			"{} is out of date (hash {local_hash}, server {served_hash}); run `seam pull` to refresh",
+			local_path.display()
+		));
+	}
+}
+
 #[cfg(test)]
 mod tests {
-	use super::manifest_url;
+	use super::{Manifest, hash_drift, manifest_url};
+
+	fn manifest_with_hash(hash: Option<&str>) -> Manifest {
+		let mut value = serde_json::json!({"version": 2, "procedures": {}});
+		if let Some(h) = hash {
+			value["hash"] = serde_json::json!(h);
+		}
+		serde_json::from_value(value).unwrap()
+	}
+
+	#[test]
+	fn hash_drift_reports_mismatch_only() {
+		let a = manifest_with_hash(Some("aaaa"));
+		let b = manifest_with_hash(Some("bbbb"));
+		assert_eq!(hash_drift(&a, &b), Some(("aaaa", "bbbb")));
+		assert_eq!(hash_drift(&a, &a), None);
+		assert_eq!(hash_drift(&manifest_with_hash(None), &b), None);
+	}
 
 	#[test]
 	fn manifest_url_accepts_base_url() {
@@ -0,0 +1,100 @@
+/* src/cli/core/src/robots.rs */
+
+// `seam robots` command: writes robots.txt into the public output served at the site root.
+
+use std::path::Path;
+
+use anyhow::{Context, Result};
+
+use crate::config::RobotsSection;
+use crate::ui;
+
+/// First line of every generated file; an existing robots.txt without it is the user's.
+const GENERATED_HEADER: &str = "# Generated by `seam robots`\n";
+
+/// Write `<out_dir>/public-root/robots.txt` from the `[robots]` config section. A
+/// robots.txt the build copied from `public/` is left alone.
+pub fn run_robots(robots: &RobotsSection, out_dir: &Path) -> Result<()> {
+	ui::banner("robots", None);
+	let dir = out_dir.join("public-root");
+	std::fs::create_dir_all(&dir).with_context(|| format!("failed to create {}", dir.display()))?;
+	let path = dir.join("robots.txt");
+	if is_user_file(&path) {
+		ui::warn(&format!(
+			"{} was not generated by seam robots (copied from public/?); leaving it unchanged",
+			path.display()
+		));
+		return Ok(());
+	}
+	std::fs::write(&path, render_robots(robots))
+		.with_context(|| format!("failed to write {}", path.display()))?;
+	ui::ok(&format!("{}  {} disallowed", path.display(), robots.disallow.len()));
+	Ok(())
+}
+
+fn is_user_file(path: &Path) -> bool {
+	std::fs::read_to_string(path).is_ok_and(|content| !content.starts_with(GENERATED_HEADER))
+}
+
+fn render_robots(robots: &RobotsSection) -> String {
+	let mut out = format!("{GENERATED_HEADER}User-agent: *\n");
+	if robots.disallow.is_empty() {
+		// An empty Disallow allows everything
+		out.push_str("Disallow:\n");
+	}
+	for path in &robots.disallow {
+		out.push_str(&format!("Disallow: {path}\n"));
+	}
+	if let Some(ref sitemap) = robots.sitemap {
+		out.push_str(&format!("\nSitemap: {sitemap}\n"));
+	}
+	out
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn disallow_list_and_sitemap() {
+		let config: crate::config::SeamConfig = toml::from_str(
+			r#"
+[robots]
+disallow = ["/admin", "/_seam/"]
+sitemap = "https://example.com/sitemap.xml"
+"#,
+		)
+		.unwrap();
+		assert_eq!(
+			render_robots(&config.robots),
+			"# Generated by `seam robots`\nUser-agent: *\nDisallow: /admin\nDisallow: /_seam/\n\n\
+			 Sitemap: https://example.com/sitemap.xml\n"
+		);
+	}
+
+	#[test]
+	fn unconfigured_allows_everything() {
+		assert_eq!(
+			render_robots(&RobotsSection::default()),
+			"# Generated by `seam robots`\nUser-agent: *\nDisallow:\n"
+		);
+	}
+
+	#[test]
+	fn user_robots_txt_is_kept_and_generated_one_replaced() {
+		let out_dir = std::env::temp_dir().join("seam-test-robots-user-file");
+		let path = out_dir.join("public-root/robots.txt");
+		let _ = std::fs::remove_dir_all(&out_dir);
+		std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+		std::fs::write(&path, "User-agent: *\nDisallow: /private\n").unwrap();
+		let robots = RobotsSection { disallow: vec!["/admin".into()], sitemap: None };
+
+		run_robots(&robots, &out_dir).unwrap();
+		assert_eq!(std::fs::read_to_string(&path).unwrap(), "User-agent: *\nDisallow: /private\n");
+
+		std::fs::write(&path, render_robots(&RobotsSection::default())).unwrap();
+		run_robots(&robots, &out_dir).unwrap();
+		assert_eq!(std::fs::read_to_string(&path).unwrap(), render_robots(&robots));
+		let _ = std::fs::remove_dir_all(&out_dir);
+	}
+}
@@ -0,0 +1,184 @@
+/* src/cli/core/src/sitemap.rs */
+
+// `seam sitemap` command: writes sitemap.xml from the built route manifest.
+
+use std::collections::BTreeMap;
+use std::fmt::Write as _;
+use std::path::Path;
+
+use anyhow::{Context, Result};
+use serde::Deserialize;
+
+use crate::ui;
+
+/// The slice of route-manifest.json a sitemap needs.
+#[derive(Deserialize)]
+struct SitemapManifest {
+	#[serde(default)]
+	routes: BTreeMap<String, serde_json::Value>,
+	#[serde(default)]
+	i18n: Option<SitemapI18n>,
+}
+
+#[derive(Deserialize)]
+struct SitemapI18n {
+	locales: Vec<String>,
+	default: String,
+	/// Absent means `hybrid`
+	#[serde(default)]
+	routing: Option<String>,
+}
+
+/// Read `<out_dir>/route-manifest.json` and write the sitemap to `out`.
+pub fn run_sitemap(out_dir: &Path, base_url: &str, out: &Path) -> Result<()> {
+	ui::banner("sitemap", None);
+	let manifest_path = out_dir.join("route-manifest.json");
+	let content = std::fs::read_to_string(&manifest_path).with_context(|| {
+		format!("failed to read {} (run `seam build` first)", manifest_path.display())
+	})?;
+	let manifest: SitemapManifest =
+		serde_json::from_str(&content).context("failed to parse route-manifest.json")?;
+
+	let (xml, count) = render_sitemap(&manifest, base_url);
+	std::fs::write(out, xml).with_context(|| format!("failed to write {}", out.display()))?;
+	let skipped = manifest.routes.len() - manifest.routes.keys().filter(|r| is_static(r)).count();
+	if skipped > 0 {
+		ui::detail(&format!("skipped {skipped} parameterized routes"));
+	}
+	ui::ok(&format!("{}  {count} urls", out.display()));
+	Ok(())
+}
+
+/// Routes with `:param`, `*rest`, or `{param}` segments (including partial ones such as
+/// `post-{id}`) need data to enumerate, so they are skipped.
+fn is_static(route: &str) -> bool {
+	route.split('/').all(|s| !s.starts_with(':') && !s.starts_with('*') && !s.contains('{'))
+}
+
+/// Sitemap XML and its `<url>` count. i18n builds with locale prefixes get one entry per
+/// locale, each listing every locale as an `hreflang` alternate.
+fn render_sitemap(manifest: &SitemapManifest, base_url: &str) -> (String, usize) {
+	let base = base_url.trim_end_matches('/');
+	let i18n = manifest.i18n.as_ref().filter(|i| i.routing.as_deref() != Some("hidden"));
+
+	let mut xml = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+	xml.push_str("<urlset xmlns=\"http://www.sitemaps.org/schemas/sitemap/0.9\"");
+	if i18n.is_some() {
+		xml.push_str(" xmlns:xhtml=\"http://www.w3.org/1999/xhtml\"");
+	}
+	xml.push_str(">\n");
+
+	let mut count = 0;
+	for route in manifest.routes.keys().filter(|r| is_static(r)) {
+		let Some(i18n) = i18n else {
+			push_url(&mut xml, &page_url(base, None, route), &[]);
+			count += 1;
+			continue;
+		};
+		let mut alternates: Vec<(&str, String)> =
+			i18n.locales.iter().map(|l| (l.as_str(), page_url(base, Some(l), route))).collect();
+		// Hybrid also serves the unprefixed path, resolved per visitor
+		let fallback = if i18n.routing.is_none() { None } else { Some(i18n.default.as_str()) };
+		alternates.push(("x-default", page_url(base, fallback, route)));
+		for locale in &i18n.locales {
+			push_url(&mut xml, &page_url(base, Some(locale), route), &alternates);
+			count += 1;
+		}
+	}
+	xml.push_str("</urlset>\n");
+	(xml, count)
+}
+
+fn page_url(base: &str, locale: Option<&str>, route: &str) -> String {
+	let route = if route == "/" { "" } else { route };
+	match locale {
+		Some(loc) => format!("{base}/{loc}{route}"),
+		None if route.is_empty() => format!("{base}/"),
+		None => format!("{base}{route}"),
+	}
+}
+
+fn push_url(xml: &mut String, loc: &str, alternates: &[(&str, String)]) {
+	let _ = writeln!(xml, "  <url>\n    <loc>{}</loc>", escape_xml(loc));
+	for (lang, href) in alternates {
+		let _ = writeln!(
+			xml,
+			"    <xhtml:link rel=\"alternate\" hreflang=\"{lang}\" href=\"{}\"/>",
+			escape_xml(href)
+		);
+	}
+	xml.push_str("  </url>\n");
+}
+
+fn escape_xml(s: &str) -> String {
+	s.replace('&', "&amp;")
+		.replace('<', "&lt;")
+		.replace('>', "&gt;")
+		.replace('"', "&quot;")
+		.replace('\'', "&apos;")
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn manifest(json: &str) -> SitemapManifest {
+		serde_json::from_str(json).unwrap()
+	}
+
+	#[test]
+	fn static_routes_only() {
+		let m =
+			manifest(r#"{ "routes": { "/": {}, "/about": {}, "/blog/:slug": {}, "/docs/*rest": {} } }"#);
+		let (xml, count) = render_sitemap(&m, "https://example.com/");
+		assert_eq!(count, 2);
+		assert!(xml.contains("<url>\n    <loc>https://example.com/</loc>\n  </url>"), "{xml}");
+		assert!(xml.contains("<loc>https://example.com/about</loc>"));
+		assert!(!xml.contains("slug") && !xml.contains("docs"));
+		assert!(!xml.contains("xmlns:xhtml"));
+	}
+
+	#[test]
+	fn brace_params_are_dynamic() {
+		assert!(!is_static("/blog/{slug}"));
+		assert!(!is_static("/docs/{...rest}"));
+		assert!(!is_static("/posts/post-{id}"));
+		assert!(is_static("/blog/archive"));
+	}
+
+	#[test]
+	fn i18n_route_lists_hreflang_alternates() {
+		let m = manifest(
+			r#"{ "routes": { "/about": {} }, "i18n": { "locales": ["en", "fr"], "default": "en" } }"#,
+		);
+		let (xml, count) = render_sitemap(&m, "https://example.com");
+		assert_eq!(count, 2);
+		assert!(xml.contains("xmlns:xhtml=\"http://www.w3.org/1999/xhtml\""));
+		let alternates = concat!(
+			"    <xhtml:link rel=\"alternate\" hreflang=\"en\" href=\"https://example.com/en/about\"/>\n",
+			"    <xhtml:link rel=\"alternate\" hreflang=\"fr\" href=\"https://example.com/fr/about\"/>\n",
+			"    <xhtml:link rel=\"alternate\" hreflang=\"x-default\" href=\"https://example.com/about\"/>\n",
+		);
+		for loc in ["https://example.com/en/about", "https://example.com/fr/about"] {
+			let entry = format!("  <url>\n    <loc>{loc}</loc>\n{alternates}  </url>");
+			assert!(xml.contains(&entry), "{xml}");
+		}
+	}
+
+	#[test]
+	fn hidden_routing_has_one_url_per_route() {
+		let m = manifest(
+			r#"{ "routes": { "/about": {} },
+				"i18n": { "locales": ["en", "fr"], "default": "en", "routing": "hidden" } }"#,
+		);
+		let (xml, count) = render_sitemap(&m, "https://example.com");
+		assert_eq!(count, 1);
+		assert!(!xml.contains("hreflang"));
+	}
+
+	#[test]
+	fn escapes_loc() {
+		assert_eq!(page_url("https://a.io", None, "/q&a"), "https://a.io/q&a");
+		assert_eq!(escape_xml("https://a.io/q&a"), "https://a.io/q&amp;a");
+	}
+}
@@ -1,8 +1,8 @@
 /* src/cli/core/src/ui.rs */
 
 use std::io::{IsTerminal, Write};
-use std::sync::OnceLock;
 use std::sync::atomic::{AtomicU32, Ordering};
+use std::sync::{Mutex, OnceLock, PoisonError};
 use std::time::Instant;
 
 use indicatif::{ProgressBar, ProgressStyle};
@@ -73,6 +73,33 @@ fn reset_detail_lines() -> u32 {
 	DETAIL_LINES.swap(0, Ordering::Relaxed)
 }
 
+// -- Warning sink --
+
+/// Warnings of one build: printed as they are raised and kept for its `BuildReport`.
+/// Each build owns its sink, so concurrent builds in one process never mix them.
+#[derive(Debug, Default)]
+pub(crate) struct Warnings(Mutex<Vec<String>>);
+
+impl Warnings {
+	pub(crate) fn warn(&self, msg: &str) {
+		warn(msg);
+		self.record(msg);
+	}
+
+	pub(crate) fn detail_warn(&self, msg: &str) {
+		detail_warn(msg);
+		self.record(msg);
+	}
+
+	fn record(&self, msg: &str) {
+		self.0.lock().unwrap_or_else(PoisonError::into_inner).push(msg.to_string());
+	}
+
+	pub(crate) fn into_vec(self) -> Vec<String> {
+		self.0.into_inner().unwrap_or_else(PoisonError::into_inner)
+	}
+}
+
 // -- Constants --
 
 const VERSION: &str = env!("CARGO_PKG_VERSION");
@@ -18,7 +18,7 @@ use crate::build::route::{
 use crate::build::run::steps;
 use crate::config::{SeamConfig, resolve_member_config, validate_workspace};
 use crate::shell::run_command;
-use crate::ui::{self, DIM, GREEN, RESET, col};
+use crate::ui::{self, DIM, GREEN, RESET, Warnings, col};
 use seam_codegen::Manifest;
 
 #[derive(Debug)]
@@ -149,6 +149,7 @@ fn build_reference_member(
 	base_dir: &Path,
 	shared_out_dir: &Path,
 ) -> Result<ReferenceOutput> {
+	let warnings = Warnings::default();
 	// [1.1] Compile backend
 	ui::detail(&format!("{}[{}/backend]{} compiling...", col(DIM), first.name, col(RESET)));
 	if let Some(cmd) = &first.build_config.backend_build_command {
@@ -194,21 +195,28 @@ fn build_reference_member(
 		&first.build_config,
 		base_dir,
 		&shared_out_dir.join("seam-manifest.json"),
+		&warnings,
 	)?;
 	let ref_graph = build_reference_graph(&manifest, &skeleton_output);
 	validate_procedure_references(&ref_graph)?;
-	validate_invalidates(&manifest)?;
-	validate_handoff_consistency(&ref_graph);
-	warn_unused_queries(&ref_graph, &manifest);
+	validate_invalidates(&manifest, &warnings)?;
+	validate_handoff_consistency(&ref_graph, &warnings);
+	warn_unused_queries(&ref_graph, &manifest, &warnings);
 
 	let templates_dir = shared_out_dir.join("templates");
 	std::fs::create_dir_all(&templates_dir)
 		.with_context(|| format!("failed to create {}", templates_dir.display()))?;
+	let partials_dir = first.build_config.partials_path(base_dir);
 	let render = RenderContext {
 		root_id: &first.build_config.root_id,
 		data_id: &first.build_config.data_id,
+		lang: first.build_config.lang.as_deref(),
 		dev_mode: false,
 		vite: None,
+		ctr_debug: None,
+		partials_dir: partials_dir.as_deref(),
+		modulepreload: first.build_config.modulepreload,
+		warnings: &warnings,
 	};
 	let bundle_ctx = BundleContext { manifest: None, source_file_map: None };
 	let mut route_manifest = process_routes(
@@ -223,7 +231,12 @@ fn build_reference_member(
 	inject_route_procedures(&mut route_manifest, &ref_graph);
 
 	let meta = Some(crate::build::route::build_manifest_meta(&first.build_config));
-	steps::write_route_manifest(shared_out_dir, &mut route_manifest, meta)?;
+	steps::write_route_manifest(
+		shared_out_dir,
+		&mut route_manifest,
+		meta,
+		first.build_config.gzip_templates,
+	)?;
 
 	// [1.7] Package output
 	let first_member_out = shared_out_dir.join(&first.name);
@@ -328,6 +341,7 @@ mod tests {
 				"subscription" => ProcedureType::Subscription,
 				"stream" => ProcedureType::Stream,
 				"upload" => ProcedureType::Upload,
+				"raw" => ProcedureType::Raw,
 				_ => ProcedureType::Query,
 			};
 			procedures.insert(
@@ -348,6 +362,7 @@ mod tests {
 		}
 		Manifest {
 			version: 1,
+			hash: None,
 			context: BTreeMap::new(),
 			procedures,
 			channels: BTreeMap::new(),
@@ -41,10 +41,18 @@ export interface BuildSection {
 	obfuscate?: boolean
 	sourcemap?: boolean
 	typeHint?: boolean
+	/** Emit `<link rel="modulepreload">` hints for entry scripts in `<head>` (default: false). */
+	modulepreload?: boolean
+	/** Also write gzipped templates, which Rust servers decompress once at startup (default: false). */
+	gzipTemplates?: boolean
 	/** Route hash length. Must be between 4 and 64 (default: 12). */
 	hashLength?: number
+	/** Pins the RPC hash salt so obfuscated names stay stable across builds (default: random per build). */
+	salt?: string
 	/** Filesystem-based routing directory. Mutually exclusive with `routes`. */
 	pagesDir?: string
+	/** Directory `<!--seam:include:name-->` partials resolve against (`name` -> `<partialsDir>/name.html`). */
+	partialsDir?: string
 }
 
 export interface GenerateSection {
@@ -15,6 +15,17 @@ mod report;
 use anyhow::{Result, bail};
 use serde_json::Value;
 
+/// A failed CTR check with everything needed to inspect it (`seam build --ctr-debug`).
+pub struct CtrMismatch {
+	/// The report `verify_ctr_equivalence` fails with
+	pub report: String,
+	/// Raw injector output, data script included
+	pub injected_html: String,
+	/// Normalized trees as compared, one node per line
+	pub react_tree: String,
+	pub inject_tree: String,
+}
+
 /// Verify that template injection with mock data produces semantically
 /// equivalent HTML to React's renderToString with the same data.
 pub fn verify_ctr_equivalence(
@@ -24,20 +35,39 @@ pub fn verify_ctr_equivalence(
 	mock_data: &Value,
 	data_id: &str,
 ) -> Result<()> {
-	let injected_raw = seam_injector::inject(template, mock_data, data_id);
+	match find_ctr_mismatch(route_path, react_html, template, mock_data, data_id) {
+		Some(mismatch) => bail!("{}", mismatch.report),
+		None => Ok(()),
+	}
+}
+
+/// `verify_ctr_equivalence` returning the mismatch details instead of an error.
+pub fn find_ctr_mismatch(
+	route_path: &str,
+	react_html: &str,
+	template: &str,
+	mock_data: &Value,
+	data_id: &str,
+) -> Option<CtrMismatch> {
+	let injected_html = seam_injector::inject_with_id(template, mock_data, data_id);
 
 	let mut react_tree = parse::parse_ctr_tree(react_html, data_id);
-	let mut inject_tree = parse::parse_ctr_tree(&injected_raw, data_id);
+	let mut inject_tree = parse::parse_ctr_tree(&injected_html, data_id);
 
 	normalize::normalize_tree(&mut react_tree);
 	normalize::normalize_tree(&mut inject_tree);
 
 	let result = diff::diff_trees(&react_tree, &inject_tree, "");
 	if result.diffs.is_empty() {
-		return Ok(());
+		return None;
 	}
 
-	bail!("{}", report::format_ctr_report(route_path, &result.diffs, result.total_count));
+	Some(CtrMismatch {
+		report: report::format_ctr_report(route_path, &result.diffs, result.total_count),
+		injected_html,
+		react_tree: report::format_tree(&react_tree),
+		inject_tree: report::format_tree(&inject_tree),
+	})
 }
 
 #[cfg(test)]
@@ -173,7 +173,7 @@ fn parse_element(bytes: &[u8], pos: &mut usize, data_id: &str) -> Option<CtrNode
 		}
 		if let Some(rel) = attrs.get("rel") {
 			let rel_lower = rel.to_lowercase();
-			if rel_lower == "preload" || rel_lower == "dns-prefetch" || rel_lower == "preconnect" {
+			if matches!(rel_lower.as_str(), "preload" | "modulepreload" | "dns-prefetch" | "preconnect") {
 				return None;
 			}
 		}
@@ -3,7 +3,10 @@
 // Format CTR diff results into developer-friendly error messages.
 // Labels use "react" vs "template" (not expected/actual) for clarity.
 
+use std::fmt::Write as _;
+
 use super::diff::CtrDiff;
+use super::parse::CtrNode;
 
 /// Format a CTR equivalence check failure report.
 pub(super) fn format_ctr_report(route_path: &str, diffs: &[CtrDiff], total_count: usize) -> String {
@@ -34,6 +37,31 @@ pub(super) fn format_ctr_report(route_path: &str, diffs: &[CtrDiff], total_count
 	out
 }
 
+/// Indented outline of a normalized tree: one element or text node per line.
+pub(super) fn format_tree(nodes: &[CtrNode]) -> String {
+	fn walk(out: &mut String, nodes: &[CtrNode], depth: usize) {
+		for node in nodes {
+			let indent = "  ".repeat(depth);
+			match node {
+				CtrNode::Element { tag, attrs, children } => {
+					let _ = write!(out, "{indent}<{tag}");
+					for (name, value) in attrs {
+						let _ = write!(out, " {name}=\"{value}\"");
+					}
+					out.push_str(">\n");
+					walk(out, children, depth + 1);
+				}
+				CtrNode::Text(text) => {
+					let _ = writeln!(out, "{indent}{text:?}");
+				}
+			}
+		}
+	}
+	let mut out = String::new();
+	walk(&mut out, nodes, 0);
+	out
+}
+
 fn format_diff(out: &mut String, index: usize, diff: &CtrDiff) {
 	match diff {
 		CtrDiff::TagMismatch { path, expected, actual } => {
@@ -27,6 +27,26 @@ fn mismatched_style_fails() {
 	assert!(err.contains("span"), "error should contain element path: {err}");
 }
 
+#[test]
+fn mismatch_carries_normalized_trees() {
+	let data = json!({"msg": "hello"});
+	let mismatch = find_ctr_mismatch(
+		"/page",
+		r#"<p class="a">world</p>"#,
+		r#"<p class="a"><!--seam:msg--></p>"#,
+		&data,
+		"__data",
+	)
+	.unwrap();
+	assert!(mismatch.report.contains("CTR equivalence check failed"));
+	assert_eq!(mismatch.react_tree, "<p class=\"a\">\n  \"world\"\n");
+	assert_eq!(mismatch.inject_tree, "<p class=\"a\">\n  \"hello\"\n");
+	assert!(mismatch.injected_html.starts_with(r#"<p class="a">hello</p>"#));
+	assert!(
+		find_ctr_mismatch("/page", "<p>hello</p>", "<p><!--seam:msg--></p>", &data, "__data").is_none()
+	);
+}
+
 #[test]
 fn mismatched_text_content_fails() {
 	let template = "<p><!--seam:msg--></p>";
@@ -63,6 +83,22 @@ fn resource_hints_stripped_from_inject_output() {
 	assert!(result.is_ok(), "resource hints should be stripped: {result:?}");
 }
 
+#[test]
+fn modulepreload_hints_pass_ctr_equivalence() {
+	let js = ["main.js".to_string()];
+	let wrap = |body: &str, modulepreload| {
+		crate::wrap_document(
+			body,
+			&crate::DocumentOptions { js_files: &js, modulepreload, ..Default::default() },
+		)
+	};
+	let react_html = wrap("<div>Alice</div>", false);
+	let template = wrap("<div><!--seam:name--></div>", true);
+	let result =
+		verify_ctr_equivalence("/test", &react_html, &template, &json!({"name": "Alice"}), "__data");
+	assert!(result.is_ok(), "modulepreload hints should be stripped: {result:?}");
+}
+
 #[test]
 fn user_authored_links_preserved() {
 	let nodes =
@@ -2,21 +2,67 @@
 
 use crate::ViteDevInfo;
 
+const CHARSET_META: &str = r#"<meta charset="utf-8">"#;
+const VIEWPORT_META: &str =
+	r#"<meta name="viewport" content="width=device-width, initial-scale=1">"#;
+
+/// Root element id when `frontend.root_id` is unset; client hydration looks up the same id.
+pub const DEFAULT_ROOT_ID: &str = "__seam";
+
 const LIVE_RELOAD_SCRIPT: &str = r#"<script>new EventSource("/_seam/dev/reload").onmessage=function(){location.reload()}</script>"#;
 
+/// Asset references and document settings for [`wrap_document`].
+#[derive(Debug, Clone, Copy)]
+pub struct DocumentOptions<'a> {
+	pub css_files: &'a [String],
+	pub js_files: &'a [String],
+	/// Inject a live reload SSE script before `</body>`.
+	pub dev_mode: bool,
+	/// Replace static CSS/JS refs with Vite dev server scripts.
+	pub vite: Option<&'a ViteDevInfo>,
+	pub root_id: &'a str,
+	/// `<html lang>`; a locale resolved at request time overrides it.
+	pub lang: Option<&'a str>,
+	/// Add a `<link rel="modulepreload">` hint in `<head>` for each JS file, so the browser
+	/// fetches the module graph in parallel with the HTML body.
+	pub modulepreload: bool,
+}
+
+impl Default for DocumentOptions<'_> {
+	fn default() -> Self {
+		Self {
+			css_files: &[],
+			js_files: &[],
+			dev_mode: false,
+			vite: None,
+			root_id: DEFAULT_ROOT_ID,
+			lang: None,
+			modulepreload: false,
+		}
+	}
+}
+
 /// Wrap a skeleton HTML fragment in a compact HTML5 document with asset references.
 /// Produces minimal single-line output for production templates.
-/// When `dev_mode` is true, injects a live reload SSE script before `</body>`.
-/// When `vite` is Some, replaces static CSS/JS refs with Vite dev server scripts.
-pub fn wrap_document(
-	skeleton: &str,
-	css_files: &[String],
-	js_files: &[String],
-	dev_mode: bool,
-	vite: Option<&ViteDevInfo>,
-	root_id: &str,
-) -> String {
-	let mut doc = String::from("<!DOCTYPE html><html><head><meta charset=\"utf-8\">");
+/// Default charset/viewport metas are skipped when the skeleton already defines them,
+/// since React hoists those into `<head>` on hydration.
+pub fn wrap_document(skeleton: &str, options: &DocumentOptions<'_>) -> String {
+	let DocumentOptions { css_files, js_files, dev_mode, vite, root_id, lang, modulepreload } =
+		*options;
+	let mut doc = match lang {
+		Some(lang) => {
+			format!("<!DOCTYPE html><html lang=\"{}\">", seam_injector::escape_attr_value(lang))
+		}
+		None => String::from("<!DOCTYPE html><html>"),
+	};
+	doc.push_str("<head>");
+	let (has_charset, has_viewport) = existing_meta(skeleton);
+	if !has_charset {
+		doc.push_str(CHARSET_META);
+	}
+	if !has_viewport {
+		doc.push_str(VIEWPORT_META);
+	}
 	if let Some(v) = vite {
 		// React Fast Refresh preamble
 		doc.push_str(&format!(
@@ -46,6 +92,11 @@ pub fn wrap_document(
 		for f in css_files {
 			doc.push_str(&format!(r#"<link rel="stylesheet" href="/_seam/static/{f}">"#));
 		}
+		if modulepreload {
+			for f in js_files {
+				doc.push_str(&format!(r#"<link rel="modulepreload" href="/_seam/static/{f}">"#));
+			}
+		}
 		// Per-page asset slots (replaced at runtime by engine when page_assets is present)
 		doc.push_str("<!--seam:page-styles-->");
 		doc.push_str("<!--seam:prefetch-->");
@@ -66,6 +117,21 @@ pub fn wrap_document(
 	doc
 }
 
+/// Whether `html` already contains a `<meta charset>` / `<meta name="viewport">` tag.
+fn existing_meta(html: &str) -> (bool, bool) {
+	let (mut charset, mut viewport) = (false, false);
+	let lower = html.to_ascii_lowercase();
+	let mut rest = lower.as_str();
+	while let Some(start) = rest.find("<meta") {
+		let tag = &rest[start..];
+		let tag = &tag[..tag.find('>').unwrap_or(tag.len())];
+		charset |= tag.contains("charset=");
+		viewport |= tag.contains("name=\"viewport\"") || tag.contains("name=viewport");
+		rest = &rest[start + tag.len()..];
+	}
+	(charset, viewport)
+}
+
 #[cfg(test)]
 mod tests {
 	use super::*;
@@ -74,16 +140,17 @@ mod tests {
 	fn wraps_with_assets() {
 		let result = wrap_document(
 			"<p>Hello</p>",
-			&["style-abc.css".into()],
-			&["main-xyz.js".into()],
-			false,
-			None,
-			"__seam",
+			&DocumentOptions {
+				css_files: &["style-abc.css".into()],
+				js_files: &["main-xyz.js".into()],
+				..Default::default()
+			},
 		);
 		assert_eq!(
 			result,
 			concat!(
 				"<!DOCTYPE html><html><head><meta charset=\"utf-8\">",
+				"<meta name=\"viewport\" content=\"width=device-width, initial-scale=1\">",
 				"<link rel=\"stylesheet\" href=\"/_seam/static/style-abc.css\">",
 				"<!--seam:page-styles--><!--seam:prefetch-->",
 				"</head><body>",
@@ -95,13 +162,39 @@ mod tests {
 		);
 	}
 
+	#[test]
+	fn custom_root_id_wraps_skeleton() {
+		let result = wrap_document(
+			"<p>Hi</p>",
+			&DocumentOptions { js_files: &["app.js".into()], root_id: "app", ..Default::default() },
+		);
+		assert!(result.contains(r#"<body><div id="app"><p>Hi</p></div><script"#), "{result}");
+		assert!(!result.contains(DEFAULT_ROOT_ID));
+	}
+
+	#[test]
+	fn modulepreload_hints_each_script_in_head() {
+		let js = ["main-xyz.js".to_string(), "vendor-abc.js".to_string()];
+		let result = wrap_document(
+			"<p>Hi</p>",
+			&DocumentOptions { js_files: &js, modulepreload: true, ..Default::default() },
+		);
+		let head = &result[..result.find("</head>").unwrap()];
+		assert!(head.contains(r#"<link rel="modulepreload" href="/_seam/static/main-xyz.js">"#));
+		assert!(head.contains(r#"<link rel="modulepreload" href="/_seam/static/vendor-abc.js">"#));
+		let without =
+			wrap_document("<p>Hi</p>", &DocumentOptions { js_files: &js, ..Default::default() });
+		assert!(!without.contains("modulepreload"));
+	}
+
 	#[test]
 	fn wraps_without_assets() {
-		let result = wrap_document("<p>Hi</p>", &[], &[], false, None, "__seam");
+		let result = wrap_document("<p>Hi</p>", &DocumentOptions::default());
 		assert_eq!(
 			result,
 			concat!(
 				"<!DOCTYPE html><html><head><meta charset=\"utf-8\">",
+				"<meta name=\"viewport\" content=\"width=device-width, initial-scale=1\">",
 				"<!--seam:page-styles--><!--seam:prefetch-->",
 				"</head><body>",
 				"<div id=\"__seam\"><p>Hi</p></div>",
@@ -115,7 +208,10 @@ mod tests {
 	fn skeleton_with_metadata_stays_in_body() {
 		// With structured head, metadata in skeleton JSX stays in body (not extracted)
 		let skeleton = "<title>My Page</title><meta name=\"desc\"><p>content</p>";
-		let result = wrap_document(skeleton, &["style.css".into()], &[], false, None, "__seam");
+		let result = wrap_document(
+			skeleton,
+			&DocumentOptions { css_files: &["style.css".into()], ..Default::default() },
+		);
 
 		let root_start = result.find("__seam").unwrap();
 		let root_section = &result[root_start..];
@@ -125,7 +221,10 @@ mod tests {
 
 	#[test]
 	fn dev_mode_injects_live_reload_script() {
-		let result = wrap_document("<p>dev</p>", &[], &["app.js".into()], true, None, "__seam");
+		let result = wrap_document(
+			"<p>dev</p>",
+			&DocumentOptions { js_files: &["app.js".into()], dev_mode: true, ..Default::default() },
+		);
 		assert!(result.contains("EventSource"), "dev_mode should inject EventSource live reload");
 		assert!(result.contains("/_seam/dev/reload"));
 		let script_pos = result.find("EventSource").unwrap();
@@ -139,7 +238,10 @@ mod tests {
 
 	#[test]
 	fn production_mode_no_reload_script() {
-		let result = wrap_document("<p>prod</p>", &[], &["app.js".into()], false, None, "__seam");
+		let result = wrap_document(
+			"<p>prod</p>",
+			&DocumentOptions { js_files: &["app.js".into()], ..Default::default() },
+		);
 		assert!(!result.contains("EventSource"), "production mode must not inject live reload");
 	}
 
@@ -151,11 +253,12 @@ mod tests {
 		};
 		let result = wrap_document(
 			"<p>vite</p>",
-			&["ignored.css".into()],
-			&["ignored.js".into()],
-			false,
-			Some(&vite),
-			"__seam",
+			&DocumentOptions {
+				css_files: &["ignored.css".into()],
+				js_files: &["ignored.js".into()],
+				vite: Some(&vite),
+				..Default::default()
+			},
 		);
 
 		// All three Vite scripts present
@@ -175,7 +278,10 @@ mod tests {
 			origin: "http://localhost:5173".to_string(),
 			entry: "src/client/main.tsx".to_string(),
 		};
-		let result = wrap_document("<p>vite-dev</p>", &[], &[], true, Some(&vite), "__seam");
+		let result = wrap_document(
+			"<p>vite-dev</p>",
+			&DocumentOptions { dev_mode: true, vite: Some(&vite), ..Default::default() },
+		);
 
 		// Vite scripts present
 		assert!(result.contains("/@vite/client"));
@@ -186,23 +292,61 @@ mod tests {
 	#[test]
 	fn vite_proxy_mode_uses_relative_dev_scripts() {
 		let vite = ViteDevInfo { origin: String::new(), entry: "src/client/main.tsx".to_string() };
-		let result = wrap_document("<p>vite-proxy</p>", &[], &[], true, Some(&vite), "__seam");
+		let result = wrap_document(
+			"<p>vite-proxy</p>",
+			&DocumentOptions { dev_mode: true, vite: Some(&vite), ..Default::default() },
+		);
 
 		assert!(result.contains("import RefreshRuntime from '/@react-refresh'"));
 		assert!(result.contains(r#"<script type="module" src="/@vite/client"></script>"#));
 		assert!(result.contains(r#"<script type="module" src="/src/client/main.tsx"></script>"#));
 	}
 
+	#[test]
+	fn configured_lang_sets_html_attribute() {
+		let result =
+			wrap_document("<p>Hi</p>", &DocumentOptions { lang: Some("en"), ..Default::default() });
+		assert!(result.starts_with(r#"<!DOCTYPE html><html lang="en"><head>"#));
+	}
+
+	#[test]
+	fn injects_charset_and_viewport_when_absent() {
+		let result = wrap_document("<p>Hi</p>", &DocumentOptions::default());
+		assert_eq!(result.matches("<meta charset").count(), 1);
+		assert_eq!(result.matches(r#"name="viewport""#).count(), 1);
+	}
+
+	#[test]
+	fn skeleton_viewport_not_duplicated() {
+		let skeleton = r#"<meta name="viewport" content="width=320"><p>Hi</p>"#;
+		let result = wrap_document(skeleton, &DocumentOptions::default());
+		assert_eq!(result.matches(r#"name="viewport""#).count(), 1);
+		assert!(result.contains(r#"content="width=320""#));
+		assert!(result.contains(r#"<head><meta charset="utf-8"><!--seam:page-styles-->"#));
+	}
+
+	#[test]
+	fn skeleton_charset_not_duplicated() {
+		let result = wrap_document(r#"<meta charSet="utf-8"><p>Hi</p>"#, &DocumentOptions::default());
+		assert_eq!(result.to_ascii_lowercase().matches("<meta charset").count(), 1);
+	}
+
 	#[test]
 	fn no_metadata_passes_through() {
-		let result = wrap_document("<div><p>Hello</p></div>", &[], &[], false, None, "__seam");
+		let result = wrap_document("<div><p>Hello</p></div>", &DocumentOptions::default());
 		assert!(result.contains("<div id=\"__seam\"><div><p>Hello</p></div></div>"));
 	}
 
 	#[test]
 	fn slot_markers_present_in_production() {
-		let result =
-			wrap_document("<p>test</p>", &["a.css".into()], &["a.js".into()], false, None, "__seam");
+		let result = wrap_document(
+			"<p>test</p>",
+			&DocumentOptions {
+				css_files: &["a.css".into()],
+				js_files: &["a.js".into()],
+				..Default::default()
+			},
+		);
 		assert!(result.contains("<!--seam:page-styles-->"));
 		assert!(result.contains("<!--seam:prefetch-->"));
 		assert!(result.contains("<!--seam:page-scripts-->"));
@@ -220,7 +364,8 @@ mod tests {
 			origin: "http://localhost:5173".to_string(),
 			entry: "src/main.tsx".to_string(),
 		};
-		let result = wrap_document("<p>test</p>", &[], &[], false, Some(&vite), "__seam");
+		let result =
+			wrap_document("<p>test</p>", &DocumentOptions { vite: Some(&vite), ..Default::default() });
 		assert!(!result.contains("<!--seam:page-styles-->"));
 		assert!(!result.contains("<!--seam:prefetch-->"));
 		assert!(!result.contains("<!--seam:page-scripts-->"));
@@ -231,7 +376,7 @@ mod tests {
 		// Conditional directives in skeleton are no longer extracted to head
 		let skeleton =
 			"<!--seam:if:x--><!--seam:d:attr:content--><meta name=\"og\"><!--seam:endif:x--><p>body</p>";
-		let result = wrap_document(skeleton, &[], &[], false, None, "__seam");
+		let result = wrap_document(skeleton, &DocumentOptions::default());
 
 		let root_start = result.find("__seam").unwrap();
 		let root_section = &result[root_start..];
@@ -27,22 +27,6 @@ pub(super) fn insert_boolean_directives(
 	let mut tree_content_idx = 0usize; // which content node we're at in tree
 	let mut tree_pos = 0usize; // raw index into tree
 
-	// Helper: advance tree_pos to copy any directive comments before the next content node
-	fn copy_leading_directives(
-		tree: &[DomNode],
-		tree_pos: &mut usize,
-		content_map: &[usize],
-		tree_content_idx: usize,
-		result: &mut Vec<DomNode>,
-	) {
-		let target =
-			if tree_content_idx < content_map.len() { content_map[tree_content_idx] } else { tree.len() };
-		while *tree_pos < target {
-			result.push(tree[*tree_pos].clone());
-			*tree_pos += 1;
-		}
-	}
-
 	let mut op_idx = 0;
 	while op_idx < ops.len() {
 		match &ops[op_idx] {
@@ -57,6 +41,10 @@ pub(super) fn insert_boolean_directives(
 				copy_leading_directives(tree, &mut tree_pos, &content_map, tree_content_idx, &mut result);
 				// Same tag, different content — try to recurse into children
 				match (&tree[tree_pos], &a_nodes[*ai], &b_nodes[*bi]) {
+					// Whitespace-only reflow between variants renders the same; keep the true branch
+					(_, a, b) if is_blank_text(a) && is_blank_text(b) => {
+						result.push(tree[tree_pos].clone());
+					}
 					(
 						DomNode::Element { tag, attrs, children: tc, self_closing },
 						DomNode::Element { attrs: aa, children: ac, .. },
@@ -85,8 +73,20 @@ pub(super) fn insert_boolean_directives(
 				tree_content_idx += 1;
 				op_idx += 1;
 			}
-			DiffOp::OnlyLeft(_ai) => {
+			DiffOp::OnlyLeft(ai) => {
 				copy_leading_directives(tree, &mut tree_pos, &content_map, tree_content_idx, &mut result);
+				// Whitespace replaced by other whitespace; whitespace on one side only is a
+				// real difference (`a b` vs `ab`) and falls through to a directive
+				if is_blank_text(&a_nodes[*ai])
+					&& let Some(DiffOp::OnlyRight(bi)) = ops.get(op_idx + 1)
+					&& is_blank_text(&b_nodes[*bi])
+				{
+					result.push(tree[tree_pos].clone());
+					tree_pos += 1;
+					tree_content_idx += 1;
+					op_idx += 2;
+					continue;
+				}
 				// Check if next op is OnlyRight — forms an if/else replacement pair
 				if op_idx + 1 < ops.len()
 					&& let DiffOp::OnlyRight(bi) = &ops[op_idx + 1]
@@ -131,6 +131,28 @@ pub(super) fn insert_boolean_directives(
 	result
 }
 
+/// Advance `tree_pos`, copying any directive comments before the next content node.
+fn copy_leading_directives(
+	tree: &[DomNode],
+	tree_pos: &mut usize,
+	content_map: &[usize],
+	tree_content_idx: usize,
+	result: &mut Vec<DomNode>,
+) {
+	let target =
+		if tree_content_idx < content_map.len() { content_map[tree_content_idx] } else { tree.len() };
+	while *tree_pos < target {
+		result.push(tree[*tree_pos].clone());
+		*tree_pos += 1;
+	}
+}
+
+/// Text made only of HTML whitespace. `&nbsp;` is kept raw (and U+00A0 is not ASCII
+/// whitespace), so non-breaking space differences still get a directive.
+fn is_blank_text(node: &DomNode) -> bool {
+	matches!(node, DomNode::Text(t) if t.bytes().all(|b| b.is_ascii_whitespace()))
+}
+
 /// Process a single boolean/nullable axis: insert if/else/endif directives.
 pub(super) fn process_boolean(
 	result: Vec<DomNode>,
@@ -2,9 +2,16 @@
 
 #[derive(Debug, Clone, PartialEq)]
 pub(super) enum DomNode {
-	Element { tag: String, attrs: String, children: Vec<DomNode>, self_closing: bool },
+	Element {
+		tag: String,
+		attrs: String,
+		children: Vec<DomNode>,
+		self_closing: bool,
+	},
 	Text(String),
 	Comment(String),
+	/// `<!DOCTYPE ...>` declaration; holds the text between `<!` and `>`.
+	Doctype(String),
 }
 
 /// Parse HTML (React renderToString output) into a list of DOM nodes.
@@ -44,6 +51,12 @@ fn parse_nodes(bytes: &[u8], pos: &mut usize, parent_tag: Option<&str>) -> Vec<D
 				continue;
 			}
 
+			// Doctype (or any other `<!...>` declaration): a leaf, never a container
+			if bytes[*pos..].starts_with(b"<!") {
+				nodes.push(parse_doctype(bytes, pos));
+				continue;
+			}
+
 			// Opening tag
 			nodes.push(parse_element(bytes, pos));
 		} else {
@@ -80,6 +93,20 @@ fn parse_comment(bytes: &[u8], pos: &mut usize) -> DomNode {
 	DomNode::Comment(content.to_string())
 }
 
+fn parse_doctype(bytes: &[u8], pos: &mut usize) -> DomNode {
+	// Skip "<!"
+	*pos += 2;
+	let start = *pos;
+	while *pos < bytes.len() && bytes[*pos] != b'>' {
+		*pos += 1;
+	}
+	let content = std::str::from_utf8(&bytes[start..*pos]).expect("valid UTF-8 from HTML source");
+	if *pos < bytes.len() {
+		*pos += 1; // skip '>'
+	}
+	DomNode::Doctype(content.to_string())
+}
+
 fn parse_element(bytes: &[u8], pos: &mut usize) -> DomNode {
 	// Skip '<'
 	*pos += 1;
@@ -175,6 +202,11 @@ fn serialize_node(node: &DomNode, out: &mut String) {
 			out.push_str(content);
 			out.push_str("-->");
 		}
+		DomNode::Doctype(content) => {
+			out.push_str("<!");
+			out.push_str(content);
+			out.push('>');
+		}
 	}
 }
 
@@ -277,6 +309,22 @@ mod tests {
 		roundtrip(r#"<div data-x="a&gt;b">content</div>"#);
 	}
 
+	#[test]
+	fn quoted_angle_bracket_does_not_close_tag() {
+		let html = r#"<div title="a > b"><span data-json='{"a":">"}'>x</span></div><p>after</p>"#;
+		roundtrip(html);
+		let nodes = parse_html(html);
+		assert_eq!(nodes.len(), 2, "expected div + p at top level, got: {nodes:?}");
+		match &nodes[0] {
+			DomNode::Element { tag, attrs, children, .. } => {
+				assert_eq!(tag, "div");
+				assert_eq!(attrs, r#" title="a > b""#);
+				assert_eq!(children.len(), 1);
+			}
+			_ => panic!("expected div Element"),
+		}
+	}
+
 	#[test]
 	fn roundtrip_realistic_react() {
 		roundtrip(
@@ -339,6 +387,15 @@ mod tests {
 		assert_eq!(nodes[0], DomNode::Comment("unterminated".to_string()));
 	}
 
+	#[test]
+	fn doctype_is_leaf_node() {
+		let html = "<!DOCTYPE html><html><body>x</body></html>";
+		roundtrip(html);
+		let nodes = parse_html(html);
+		assert_eq!(nodes.len(), 2);
+		assert_eq!(nodes[0], DomNode::Doctype("DOCTYPE html".to_string()));
+	}
+
 	#[test]
 	fn self_closing_with_attrs_roundtrip() {
 		roundtrip(r#"<input type="text"/>"#);
@@ -79,6 +79,55 @@ fn find_enum_region(base: &[DomNode], others: &[Vec<DomNode>]) -> Option<EnumReg
 	Some(EnumRegion { parent_path: Vec::new(), prefix: common_prefix, suffix: common_suffix })
 }
 
+/// Sibling axes computed their own effects from the same variants. When one of them
+/// changes the region's shared prefix/suffix or any ancestor level, slicing arm bodies
+/// at the enum's offsets would cut through (or past) that effect. Widen the region
+/// until every arm variant agrees on everything outside it, and let the recursive
+/// sub-extraction of each arm body re-derive the overlapping sibling effects.
+fn widen_region_over_siblings(
+	base: &[DomNode],
+	arm_trees: &[Vec<DomNode>],
+	region: EnumRegion,
+) -> EnumRegion {
+	if arm_trees
+		.iter()
+		.all(|tree| region_fits(base, tree, &region.parent_path, region.prefix, region.suffix))
+	{
+		return region;
+	}
+	if arm_trees.iter().all(|tree| region_fits(base, tree, &region.parent_path, 0, 0)) {
+		return EnumRegion { parent_path: region.parent_path, prefix: 0, suffix: 0 };
+	}
+	EnumRegion { parent_path: Vec::new(), prefix: 0, suffix: 0 }
+}
+
+/// True when `tree` matches `base` outside the region: identical siblings and
+/// same-tag elements along `path`, and an identical `prefix`/`suffix` at its level.
+fn region_fits(
+	base: &[DomNode],
+	tree: &[DomNode],
+	path: &[usize],
+	prefix: usize,
+	suffix: usize,
+) -> bool {
+	let Some((&step, rest)) = path.split_first() else {
+		return tree.len() >= prefix + suffix
+			&& base.len() >= prefix + suffix
+			&& (0..prefix).all(|i| base[i] == tree[i])
+			&& (1..=suffix).all(|i| base[base.len() - i] == tree[tree.len() - i]);
+	};
+	if base.len() != tree.len() || (0..base.len()).any(|i| i != step && base[i] != tree[i]) {
+		return false;
+	}
+	match (&base[step], &tree[step]) {
+		(
+			DomNode::Element { tag: bt, attrs: ba, children: bc, .. },
+			DomNode::Element { tag: tt, attrs: ta, children: tc, .. },
+		) if bt == tt && ba == ta => region_fits(bc, tc, rest, prefix, suffix),
+		_ => false,
+	}
+}
+
 /// Process a single enum axis: insert match/when/endmatch directives.
 /// Returns `(result, consumed_siblings)` — when `consumed_siblings` is true,
 /// all sibling axes were recursively processed inside each arm body and must
@@ -113,6 +162,15 @@ pub(super) fn process_enum(
 	} else {
 		Vec::new()
 	};
+	let region = if has_siblings {
+		let arm_trees: Vec<Vec<DomNode>> = all_groups
+			.iter()
+			.flat_map(|(_, indices)| indices.iter().map(|&i| parse_html(&variants[i])))
+			.collect();
+		widen_region_over_siblings(base_tree, &arm_trees, region)
+	} else {
+		region
+	};
 
 	// Build match/when branches
 	let mut branches = Vec::new();
@@ -107,8 +107,10 @@ fn extract_template_inner(axes: &[Axis], variants: &[String]) -> String {
 		}
 		let axis = &axes[idx];
 		result = match axis.kind.as_str() {
-			"boolean" | "nullable" => process_boolean(result, axes, variants, idx),
-			"enum" => {
+			"boolean" => process_boolean(result, axes, variants, idx),
+			"nullable" if axis.values.len() <= 2 => process_boolean(result, axes, variants, idx),
+			// Three-state nullable (value / "" / null) is a match; `if` treats "" and null alike
+			"enum" | "nullable" => {
 				let (new_result, consumed_siblings) = process_enum(result, axes, variants, idx);
 				if consumed_siblings {
 					// All sibling axes were recursively processed inside each enum arm,
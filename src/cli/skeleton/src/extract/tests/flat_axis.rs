@@ -90,6 +90,63 @@ fn extract_boolean_if_else() {
 	assert!(result.contains("<!--seam:endif:isLoggedIn-->"), "missing endif in: {result}");
 }
 
+#[test]
+fn extract_boolean_whitespace_only_diff_has_no_directive() {
+	let axes = vec![make_axis("compact", "boolean", vec![json!(true), json!(false)])];
+	let variants = vec![
+		"<div><b>Hi</b> <i>there</i></div>".to_string(),
+		"<div><b>Hi</b>\n  <i>there</i></div>".to_string(),
+	];
+	let result = extract_template(&axes, &variants);
+	assert!(!result.contains("seam:if"), "unexpected directive in: {result}");
+	assert!(result.contains("<b>Hi</b> <i>there</i>"), "true branch lost in: {result}");
+}
+
+#[test]
+fn extract_boolean_one_sided_whitespace_keeps_directive() {
+	let axes = vec![make_axis("compact", "boolean", vec![json!(true), json!(false)])];
+	let (spaced, tight) = ("<div><b>Hi</b> <i>there</i></div>", "<div><b>Hi</b><i>there</i></div>");
+	let result = extract_template(&axes, &[spaced.to_string(), tight.to_string()]);
+	assert!(result.contains("<!--seam:if:compact--> <!--seam:endif:compact-->"), "got: {result}");
+	let result = extract_template(&axes, &[tight.to_string(), spaced.to_string()]);
+	assert!(
+		result.contains("<!--seam:if:compact--><!--seam:else--> <!--seam:endif:compact-->"),
+		"got: {result}"
+	);
+}
+
+#[test]
+fn extract_boolean_nbsp_diff_keeps_directive() {
+	let axes = vec![make_axis("compact", "boolean", vec![json!(true), json!(false)])];
+	let variants = vec![
+		"<div><b>Hi</b>&nbsp;<i>there</i></div>".to_string(),
+		"<div><b>Hi</b> <i>there</i></div>".to_string(),
+	];
+	let result = extract_template(&axes, &variants);
+	assert!(result.contains("<!--seam:if:compact-->&nbsp;<!--seam:else--> "), "got: {result}");
+}
+
+#[test]
+fn extract_three_state_nullable_match() {
+	let axes = vec![make_axis("bio", "nullable", vec![json!("present"), json!(""), json!(null)])];
+	let variants = vec![
+		"<div><p><!--seam:bio--></p></div>".to_string(),
+		"<div><i>Empty bio</i></div>".to_string(),
+		"<div><b>No bio</b></div>".to_string(),
+	];
+	let result = extract_template(&axes, &variants);
+	assert_eq!(
+		result,
+		concat!(
+			"<!--seam:match:bio-->",
+			"<!--seam:when:--><div><i>Empty bio</i></div>",
+			"<!--seam:when:null--><div><b>No bio</b></div>",
+			"<!--seam:when:_--><div><p><!--seam:bio--></p></div>",
+			"<!--seam:endmatch-->",
+		)
+	);
+}
+
 #[test]
 fn extract_enum_match() {
 	let axes = vec![make_axis("role", "enum", vec![json!("admin"), json!("member"), json!("guest")])];
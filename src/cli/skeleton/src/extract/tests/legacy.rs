@@ -9,6 +9,8 @@ fn detect_conditional(full_html: &str, nulled_html: &str, field: &str) -> Option
 		return None;
 	}
 	let prefix_len = full_html.bytes().zip(nulled_html.bytes()).take_while(|(a, b)| a == b).count();
+	// Equal leading bytes can stop mid-codepoint (`é` vs `è`); back off to a boundary
+	let prefix_len = full_html.floor_char_boundary(prefix_len);
 	let full_remaining = &full_html[prefix_len..];
 	let nulled_remaining = &nulled_html[prefix_len..];
 	let suffix_len = full_remaining
@@ -18,7 +20,7 @@ fn detect_conditional(full_html: &str, nulled_html: &str, field: &str) -> Option
 		.take_while(|(a, b)| a == b)
 		.count();
 	let mut block_start = prefix_len;
-	let mut block_end = full_html.len() - suffix_len;
+	let mut block_end = full_html.ceil_char_boundary(full_html.len() - suffix_len);
 	if block_start > 0 && full_html.as_bytes()[block_start - 1] == b'<' {
 		block_start -= 1;
 	}
@@ -55,6 +57,7 @@ fn detect_array_block(full_html: &str, emptied_html: &str, field: &str) -> Optio
 		return None;
 	}
 	let prefix_len = full_html.bytes().zip(emptied_html.bytes()).take_while(|(a, b)| a == b).count();
+	let prefix_len = full_html.floor_char_boundary(prefix_len);
 	let full_remaining = &full_html[prefix_len..];
 	let emptied_remaining = &emptied_html[prefix_len..];
 	let suffix_len = full_remaining
@@ -64,7 +67,7 @@ fn detect_array_block(full_html: &str, emptied_html: &str, field: &str) -> Optio
 		.take_while(|(a, b)| a == b)
 		.count();
 	let mut block_start = prefix_len;
-	let mut block_end = full_html.len() - suffix_len;
+	let mut block_end = full_html.ceil_char_boundary(full_html.len() - suffix_len);
 	if block_start > 0 && full_html.as_bytes()[block_start - 1] == b'<' {
 		block_start -= 1;
 	}
@@ -108,6 +111,16 @@ fn simple_conditional() {
 	assert_eq!(&full[block.start..block.end], "<span>Avatar</span>");
 }
 
+#[test]
+fn conditional_between_multibyte_text() {
+	// `é`/`è` share their leading byte, as do many CJK and emoji codepoints
+	let full = "<p>café🎉</p><p>東京</p>";
+	let nulled = "<p>cafè🎉</p><p>東京</p>";
+	let block = detect_conditional(full, nulled, "x").unwrap();
+	assert!(full.is_char_boundary(block.start) && full.is_char_boundary(block.end));
+	assert_eq!(&full[block.start..block.end], "é");
+}
+
 #[test]
 fn identical_html_no_conditional() {
 	let html = "<div>Same</div>";
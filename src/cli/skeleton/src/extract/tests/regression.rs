@@ -330,3 +330,79 @@ fn extract_sibling_booleans() {
 		"missing isLoggedIn conditional in:\n{result}"
 	);
 }
+
+// -- Full documents: doctype must survive the diff verbatim --
+
+#[test]
+fn extract_full_document_keeps_doctype() {
+	let axes = vec![make_axis("user", "nullable", vec![json!("present"), json!(null)])];
+	let variants = vec![
+		"<!DOCTYPE html><html><body><p><!--seam:user.name--></p></body></html>".to_string(),
+		"<!DOCTYPE html><html><body></body></html>".to_string(),
+	];
+	let result = extract_template(&axes, &variants);
+	assert!(result.starts_with("<!DOCTYPE html><html><body>"), "doctype lost in:\n{result}");
+	assert!(!result.contains("</!DOCTYPE>"), "doctype treated as element in:\n{result}");
+	assert!(result.contains("<!--seam:if:user--><p><!--seam:user.name--></p><!--seam:endif:user-->"));
+}
+
+// -- Enum region overlapping a sibling axis effect --
+
+#[test]
+fn extract_enum_region_overlapping_boolean_is_merged() {
+	// `flag` removes nodes that sit in the enum's shared prefix, so the enum
+	// offsets do not apply to flag=false variants (used to panic slicing arm bodies)
+	let axes = vec![
+		make_axis("flag", "boolean", vec![json!(true), json!(false)]),
+		make_axis("kind", "enum", vec![json!("a"), json!("b")]),
+	];
+	let variants = vec![
+		"<h1>T</h1><b>F</b><i>A</i>".into(), // true, a
+		"<h1>T</h1><b>F</b><i>B</i>".into(), // true, b
+		"<h1>T</h1>".into(),                 // false, a
+		"<h1>T</h1>".into(),                 // false, b
+	];
+	let result = extract_template(&axes, &variants);
+	let arm = |body: &str| {
+		format!(
+			"<h1>T</h1><!--seam:if:flag--><b>F</b><!--seam:endif:flag-->\
+			 <!--seam:if:flag--><i>{body}</i><!--seam:endif:flag-->"
+		)
+	};
+	assert_eq!(
+		result,
+		format!(
+			"<!--seam:match:kind--><!--seam:when:a-->{}<!--seam:when:b-->{}<!--seam:endmatch-->",
+			arm("A"),
+			arm("B")
+		)
+	);
+}
+
+// -- Multibyte text around conditionals --
+
+#[test]
+fn extract_conditional_surrounded_by_emoji_and_cjk() {
+	let axes = vec![make_axis("vip", "boolean", vec![json!(true), json!(false)])];
+	let variants = vec![
+		"<p>🎉 東京</p><span>⭐ 会員</span><p>😀 终</p>".into(),
+		"<p>🎉 東京</p><p>😀 终</p>".into(),
+	];
+	let result = extract_template(&axes, &variants);
+	assert_eq!(
+		result,
+		"<p>🎉 東京</p><!--seam:if:vip--><span>⭐ 会員</span><!--seam:endif:vip--><p>😀 终</p>"
+	);
+}
+
+#[test]
+fn extract_if_else_text_sharing_leading_bytes() {
+	// 東 (E6 9D B1) and 束 (E6 9D 9F) differ only in their last byte
+	let axes = vec![make_axis("east", "boolean", vec![json!(true), json!(false)])];
+	let variants = vec!["<div><b>🎉</b>東</div>".into(), "<div><b>🎉</b>束</div>".into()];
+	let result = extract_template(&axes, &variants);
+	assert_eq!(
+		result,
+		"<div><b>🎉</b><!--seam:if:east-->東<!--seam:else-->束<!--seam:endif:east--></div>"
+	);
+}
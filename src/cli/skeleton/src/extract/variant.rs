@@ -47,6 +47,28 @@ pub(super) fn find_pair_for_axis(
 	None
 }
 
+/// `when:` label for each value of a match-rendered axis, in arm order. Enum values label
+/// themselves. A three-state nullable (value, `""`, `null`) labels the value state `_`
+/// and moves it last, since the injector takes the first matching arm.
+fn arm_values(axis: &Axis) -> Vec<(String, &serde_json::Value)> {
+	let mut arms: Vec<(String, &serde_json::Value)> = axis
+		.values
+		.iter()
+		.map(|value| {
+			let label = match value {
+				serde_json::Value::Null if axis.kind == "nullable" => "null".to_string(),
+				serde_json::Value::String(s) if axis.kind == "nullable" && s.is_empty() => String::new(),
+				_ if axis.kind == "nullable" => "_".to_string(),
+				serde_json::Value::String(s) => s.clone(),
+				other => other.to_string(),
+			};
+			(label, value)
+		})
+		.collect();
+	arms.sort_by_key(|(label, _)| label == "_");
+	arms
+}
+
 /// Find one representative variant index per enum value (other axes at reference).
 pub(super) fn find_enum_group_for_axis(
 	axes: &[Axis],
@@ -59,12 +81,7 @@ pub(super) fn find_enum_group_for_axis(
 
 	let reference_combo = if combos.is_empty() { return result } else { &combos[0] };
 
-	for value in &axis.values {
-		let val_str = match value {
-			serde_json::Value::String(s) => s.clone(),
-			other => other.to_string(),
-		};
-
+	for (val_str, value) in arm_values(axis) {
 		for (i, combo) in combos.iter().enumerate() {
 			if i >= variant_count {
 				break;
@@ -101,11 +118,7 @@ pub(super) fn find_enum_all_variants_for_axis(
 	let combos = generate_combos(axes);
 	let mut result = Vec::new();
 
-	for value in &axis.values {
-		let val_str = match value {
-			serde_json::Value::String(s) => s.clone(),
-			other => other.to_string(),
-		};
+	for (val_str, value) in arm_values(axis) {
 		let indices: Vec<usize> = combos
 			.iter()
 			.enumerate()
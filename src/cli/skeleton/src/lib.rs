@@ -6,7 +6,7 @@ mod extract;
 mod slot;
 mod template_invariant;
 
-pub use document::wrap_document;
+pub use document::{DEFAULT_ROOT_ID, DocumentOptions, wrap_document};
 pub use extract::extract_template;
 pub use slot::sentinel_to_slots;
 pub use template_invariant::{TemplateInvariantViolation, check_template_invariants};
@@ -68,8 +68,14 @@ mod tests {
 		assert!(template.contains("<span>Has avatar</span>"));
 
 		// Step 3: document wrapping
-		let doc =
-			wrap_document(&template, &["app.css".into()], &["app.js".into()], false, None, "__seam");
+		let doc = wrap_document(
+			&template,
+			&DocumentOptions {
+				css_files: &["app.css".into()],
+				js_files: &["app.js".into()],
+				..Default::default()
+			},
+		);
 		assert!(doc.starts_with("<!DOCTYPE html>"));
 		assert!(doc.contains("__seam"));
 		assert!(doc.contains("<!--seam:user.name-->"));
@@ -82,7 +88,7 @@ mod tests {
 	fn attribute_and_text_mixed_pipeline() {
 		let html = r#"<div><a href="%%SEAM:link.url%%">%%SEAM:link.text%%</a></div>"#;
 		let result = sentinel_to_slots(html);
-		let doc = wrap_document(&result, &[], &[], false, None, "__seam");
+		let doc = wrap_document(&result, &DocumentOptions::default());
 		assert!(doc.contains("<!--seam:link.url:attr:href-->"));
 		assert!(doc.contains("<!--seam:link.text-->"));
 		assert!(!doc.contains("%%SEAM:"));
@@ -99,8 +105,14 @@ mod tests {
 		assert!(slotted.contains("<!--seam:d:attr:content-->"));
 		assert!(slotted.contains("<!--seam:u:attr:href-->"));
 
-		let doc =
-			wrap_document(&slotted, &["style.css".into()], &["app.js".into()], false, None, "__seam");
+		let doc = wrap_document(
+			&slotted,
+			&DocumentOptions {
+				css_files: &["style.css".into()],
+				js_files: &["app.js".into()],
+				..Default::default()
+			},
+		);
 		assert!(doc.starts_with("<!DOCTYPE html>"));
 
 		// All skeleton content stays in body (no extraction to <head>)
@@ -127,7 +139,7 @@ mod tests {
 		assert!(template.contains("<!--seam:t-->"));
 		assert!(template.contains("<!--seam:body-->"));
 
-		let doc = wrap_document(&template, &[], &[], false, None, "__seam");
+		let doc = wrap_document(&template, &DocumentOptions::default());
 		// All content stays in body (head_meta provided separately)
 		let root = &doc[doc.find("__seam").unwrap()..];
 		assert!(root.contains("<!--seam:t-->"), "title slot in body");
@@ -21,7 +21,10 @@ fn text_re() -> &'static Regex {
 
 fn tag_re() -> &'static Regex {
 	static RE: OnceLock<Regex> = OnceLock::new();
-	RE.get_or_init(|| Regex::new(r"<([a-zA-Z][a-zA-Z0-9]*)\b([^>]*)>").expect("valid regex"))
+	// Quoted attribute values may contain `>`, so they are matched as whole units
+	RE.get_or_init(|| {
+		Regex::new(r#"<([a-zA-Z][a-zA-Z0-9]*)\b((?:[^>"']|"[^"]*"|'[^']*')*)>"#).expect("valid regex")
+	})
 }
 
 /// Replace text sentinels `%%SEAM:path%%` with slot markers `<!--seam:path-->`.
@@ -167,6 +170,12 @@ mod tests {
 		assert!(!result.contains("%%SEAM:"));
 	}
 
+	#[test]
+	fn attribute_sentinel_after_quoted_angle_bracket() {
+		let html = r#"<a title="a > b" href="%%SEAM:url%%">x</a>"#;
+		assert_eq!(sentinel_to_slots(html), r#"<!--seam:url:attr:href--><a title="a > b">x</a>"#);
+	}
+
 	#[test]
 	fn no_sentinels() {
 		let html = "<p>Hello world</p>";
@@ -8,39 +8,59 @@ use std::sync::OnceLock;
 
 use regex::Regex;
 
-/// Text, html, attr, and style data slots.
+/// Text, html, urlencode, attr, and style data slots.
 fn data_slot_re() -> &'static Regex {
 	static RE: OnceLock<Regex> = OnceLock::new();
 	RE.get_or_init(|| {
-		Regex::new(r"<!--seam:([^:>]+(?:\.[^:>]+)*)(?::(?:html|attr:[^>]+|style:[^>]+))?-->")
+		Regex::new(r"<!--seam:([^:>]+(?:\.[^:>]+)*)(?::(?:html|urlencode|(?:urlencode:)?attr:[^>]+|style:[^>]+))?-->")
 			.expect("valid regex")
 	})
 }
 
-/// Directive data paths: if, each, match.
+/// Directive data paths: if, each, match, and the count behind `each:range`.
 fn directive_re() -> &'static Regex {
 	static RE: OnceLock<Regex> = OnceLock::new();
 	RE.get_or_init(|| {
-		Regex::new(r"<!--seam:(?:if|each|match):([^:>]+(?:\.[^:>]+)*)-->").expect("valid regex")
+		Regex::new(r"<!--seam:(?:if|each:range|each|match):([^:>]+(?:\.[^:>]+)*)-->")
+			.expect("valid regex")
+	})
+}
+
+/// `#comment ... #endcomment` blocks; their markers never render, so they read no data.
+fn comment_block_re() -> &'static Regex {
+	static RE: OnceLock<Regex> = OnceLock::new();
+	RE.get_or_init(|| {
+		Regex::new(r"(?s)<!--seam:#comment-->.*?<!--seam:#endcomment-->").expect("valid regex")
 	})
 }
 
 /// Structural markers that pattern 1 may capture but are not data references.
-const NON_DATA_MARKERS: &[&str] =
-	&["outlet", "else", "endeach", "endmatch", "page-styles", "page-scripts", "prefetch"];
+pub(crate) const NON_DATA_MARKERS: &[&str] = &[
+	"outlet",
+	"else",
+	"endeach",
+	"endiflocale",
+	"endmatch",
+	"page-styles",
+	"page-scripts",
+	"prefetch",
+];
 
 /// Extract all data-referencing slot paths from a template.
 pub fn collect_slot_paths(template: &str) -> BTreeSet<String> {
 	let mut paths = BTreeSet::new();
+	let template = comment_block_re().replace_all(template, "");
 
-	for cap in data_slot_re().captures_iter(template) {
+	for cap in data_slot_re().captures_iter(&template) {
 		let path = &cap[1];
-		if !NON_DATA_MARKERS.contains(&path) {
+		// `@index`-style loop variables are scope bindings, not loader data
+		// `#note` markers are one-line comments
+		if !NON_DATA_MARKERS.contains(&path) && !path.starts_with(['@', '#']) {
 			paths.insert(path.to_string());
 		}
 	}
 
-	for cap in directive_re().captures_iter(template) {
+	for cap in directive_re().captures_iter(&template) {
 		paths.insert(cap[1].to_string());
 	}
 
@@ -86,6 +106,13 @@ mod tests {
 		assert_eq!(paths, BTreeSet::from(["user.name".into(), "user.email".into()]));
 	}
 
+	#[test]
+	fn comments_read_no_data() {
+		let tmpl = "<!--seam:#user.note--><!--seam:#comment--><!--seam:user.draft--><!--seam:#endcomment--><!--seam:user.name-->";
+		let paths = collect_slot_paths(tmpl);
+		assert_eq!(paths, BTreeSet::from(["user.name".into()]));
+	}
+
 	#[test]
 	fn attr_slot() {
 		let tmpl = r#"<!--seam:user.avatar:attr:src--><img>"#;
@@ -100,6 +127,13 @@ mod tests {
 		assert_eq!(paths, BTreeSet::from(["spacing.top".into()]));
 	}
 
+	#[test]
+	fn urlencode_slots() {
+		let tmpl = "<!--seam:search.q:urlencode--><!--seam:search.tag:urlencode:attr:href--><a></a>";
+		let paths = collect_slot_paths(tmpl);
+		assert_eq!(paths, BTreeSet::from(["search.q".into(), "search.tag".into()]));
+	}
+
 	#[test]
 	fn html_slot() {
 		let tmpl = "<!--seam:post.body:html-->";
@@ -123,6 +157,12 @@ mod tests {
 		assert!(!paths.contains("endmatch"));
 	}
 
+	#[test]
+	fn each_range_count_and_loop_index() {
+		let tmpl = "<!--seam:each:range:pager.count--><a><!--seam:@index1--></a><!--seam:endeach-->";
+		assert_eq!(collect_slot_paths(tmpl), BTreeSet::from(["pager.count".into()]));
+	}
+
 	#[test]
 	fn nested_dollar_paths() {
 		let tmpl = concat!(
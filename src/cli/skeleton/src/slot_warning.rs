@@ -1,15 +1,20 @@
 /* src/cli/skeleton/src/slot_warning.rs */
 
-// Detect open-string slots in style/class contexts at build time.
+// Detect suspicious slots at build time.
 // An open `{ "type": "string" }` in a style property or class attribute is
 // almost certainly a bug — the author likely meant a constrained type (enum,
-// integer, or a CSS-specific token).
+// integer, or a CSS-specific token). A loop body slot without a `$` scope
+// that names no root field usually forgot the `$.` prefix, and a root slot whose
+// first segment no loader provides is usually a typo of a loader key.
 
+use std::collections::BTreeSet;
 use std::sync::OnceLock;
 
 use regex::Regex;
 use serde_json::Value;
 
+use crate::slot_paths::NON_DATA_MARKERS;
+
 fn slot_re() -> &'static Regex {
 	static RE: OnceLock<Regex> = OnceLock::new();
 	RE.get_or_init(|| {
@@ -19,6 +24,11 @@ fn slot_re() -> &'static Regex {
 	})
 }
 
+fn marker_re() -> &'static Regex {
+	static RE: OnceLock<Regex> = OnceLock::new();
+	RE.get_or_init(|| Regex::new(r"<!--seam:(.*?)-->").expect("valid regex"))
+}
+
 /// Resolve a dot-separated path against a JTD schema.
 /// Returns the schema node at that path, or None if unresolvable.
 ///
@@ -83,6 +93,163 @@ pub fn check_slot_types(template: &str, page_schema: &Value) -> Vec<String> {
 	warnings
 }
 
+/// Closing marker of a block whose inner markers are inert: `raw` renders them literally,
+/// `#comment` drops them. An unclosed `#comment` drops only itself, so it only opens a
+/// block when `rest` holds its closer.
+fn inert_block_end(directive: &str, rest: &str) -> Option<&'static str> {
+	match directive {
+		"raw" => Some("endraw"),
+		"#comment" if rest.contains("<!--seam:#endcomment-->") => Some("#endcomment"),
+		_ => None,
+	}
+}
+
+/// Data path read by a text, attr, or style slot marker; None for block markers.
+fn slot_path(directive: &str) -> Option<&str> {
+	// `#note` is a one-line template comment
+	if directive.starts_with('#') {
+		return None;
+	}
+	if let Some(idx) = [":attr:", ":attrif:", ":style:"].iter().find_map(|m| directive.find(m)) {
+		let path = &directive[..idx];
+		return Some(path.strip_suffix(":urlencode").unwrap_or(path));
+	}
+	let path = directive.strip_suffix(":html").unwrap_or(directive);
+	let path = path.strip_suffix(":urlencode").unwrap_or(path);
+	// Block markers either carry a `:` or are a bare keyword
+	if path.contains(':') || matches!(path, "else" | "endhas" | "endiflocale" | "endmatch") {
+		None
+	} else {
+		Some(path)
+	}
+}
+
+/// Scan `each` bodies for text/attr slots that escape the loop scope: the path
+/// neither starts with `$` (item scope) or `/` (explicit root reference) nor names a
+/// root field, so it resolves to nothing. Typical cause: `name` written for `$.name`.
+pub fn check_each_scope(template: &str, page_schema: &Value) -> Vec<String> {
+	let root_field = |name: &str| {
+		["properties", "optionalProperties"]
+			.iter()
+			.any(|key| page_schema.get(key).and_then(|p| p.get(name)).is_some())
+	};
+	// An empty (JTD "any") schema gives nothing to compare against
+	if page_schema.get("properties").is_none() && page_schema.get("optionalProperties").is_none() {
+		return Vec::new();
+	}
+
+	let mut loops: Vec<&str> = Vec::new();
+	let mut inert_until = None;
+	let mut warnings = Vec::new();
+	for cap in marker_re().captures_iter(template) {
+		let directive = cap.get(1).expect("capture group exists").as_str();
+		let after = &template[cap.get(0).expect("match exists").end()..];
+		if let Some(end) = inert_until {
+			if directive == end {
+				inert_until = None;
+			}
+			continue;
+		}
+		let path = if let Some(end) = inert_block_end(directive, after) {
+			inert_until = Some(end);
+			continue;
+		} else if let Some(spec) = directive.strip_prefix("each:") {
+			let spec = spec.strip_prefix("range:").unwrap_or(spec);
+			loops.push(spec.split(':').next().unwrap_or(spec));
+			continue;
+		} else if directive == "endeach" {
+			loops.pop();
+			continue;
+		} else if let Some(path) = slot_path(directive) {
+			path
+		} else {
+			continue;
+		};
+		let Some(each_path) = loops.last() else {
+			continue;
+		};
+		let head = path.split('.').next().unwrap_or(path);
+		// `@index`/`@index1` are bound by the loop itself
+		if path.starts_with(['$', '/', '@']) || root_field(head) {
+			continue;
+		}
+		warnings.push(format!(
+			"slot \"{path}\" inside each:{each_path} matches no root field\n\
+       \x20\x20\x20\x20\x20\x20\x20\x20  hint: use \"$.{path}\" to read the current item"
+		));
+	}
+
+	warnings
+}
+
+/// Flag root-scope data references whose first segment is not in `loader_keys`,
+/// once per unknown key. Inside `each` only `/`-rooted paths are checked; the rest
+/// resolve against the item. `__`-prefixed keys (`__page`, `__pageCount`) are filled in
+/// by the server, not loaders, so they are never flagged.
+pub fn check_loader_keys(template: &str, loader_keys: &BTreeSet<String>) -> Vec<String> {
+	let mut depth = 0usize;
+	let mut inert_until = None;
+	let mut reported = BTreeSet::new();
+	let mut warnings = Vec::new();
+	for cap in marker_re().captures_iter(template) {
+		let directive = cap.get(1).expect("capture group exists").as_str();
+		let after = &template[cap.get(0).expect("match exists").end()..];
+		if let Some(end) = inert_until {
+			if directive == end {
+				inert_until = None;
+			}
+			continue;
+		}
+		// `scope` is the loop depth the path resolves in
+		let (path, scope) = if let Some(end) = inert_block_end(directive, after) {
+			inert_until = Some(end);
+			continue;
+		} else if let Some(spec) = directive.strip_prefix("each:") {
+			let spec = spec.strip_prefix("range:").unwrap_or(spec);
+			depth += 1;
+			(spec.split(':').next().unwrap_or(spec), depth - 1)
+		} else if directive == "endeach" {
+			depth = depth.saturating_sub(1);
+			continue;
+		} else if let Some(path) =
+			["if:", "has:", "match:"].iter().find_map(|d| directive.strip_prefix(d))
+		{
+			(path, depth)
+		} else if let Some(path) = slot_path(directive) {
+			(path, depth)
+		} else {
+			continue;
+		};
+
+		let path = match path.strip_prefix('/') {
+			Some(rooted) => rooted,
+			None if scope == 0 && !path.starts_with(['$', '@']) => path,
+			None => continue,
+		};
+		let head = path.split('.').next().unwrap_or(path);
+		if head.is_empty()
+			|| head.starts_with("__")
+			|| NON_DATA_MARKERS.contains(&head)
+			|| loader_keys.contains(head)
+			|| !reported.insert(head)
+		{
+			continue;
+		}
+		let known = loader_keys.iter().map(String::as_str).collect::<Vec<_>>().join(", ");
+		let hint = if known.is_empty() {
+			"no loaders are declared".to_string()
+		} else {
+			format!("loaders provide {known}")
+		};
+		warnings.push(format!(
+			"slot \"{path}\" reads \"{head}\", which no loader provides\n\
+       \x20\x20\x20\x20\x20\x20\x20\x20  hint: {hint}"
+		));
+	}
+
+	warnings
+}
+
 #[cfg(test)]
 mod tests {
 	use super::*;
@@ -189,6 +356,100 @@ mod tests {
 		assert_eq!(warnings.len(), 2);
 	}
 
+	#[test]
+	fn warns_unscoped_slot_in_each() {
+		let template = concat!(
+			"<!--seam:each:repos--><li><!--seam:language--></li>",
+			"<!--seam:stars:attr:title--><b></b><!--seam:endeach-->",
+		);
+		let warnings = check_each_scope(template, &page_schema());
+		assert_eq!(warnings.len(), 2);
+		assert!(warnings[0].contains("slot \"language\" inside each:repos"));
+		assert!(warnings[0].contains("\"$.language\""));
+		assert!(warnings[1].contains("slot \"stars\""));
+	}
+
+	#[test]
+	fn no_scope_warning_for_root_reference_in_each() {
+		let template = concat!(
+			"<!--seam:each:repos--><li><!--seam:$.language--> by <!--seam:user.name-->",
+			"<!--seam:/user.age--><!--seam:if:$.stars-->*<!--seam:else-->-<!--seam:endif:$.stars-->",
+			"<!--seam:match:$.color--><!--seam:when:red-->r<!--seam:endmatch--></li>",
+			"<!--seam:endeach--><!--seam:language-->",
+		);
+		assert!(check_each_scope(template, &page_schema()).is_empty());
+	}
+
+	#[test]
+	fn urlencode_slot_in_each_names_bare_path() {
+		let template =
+			"<!--seam:each:repos--><!--seam:slug:urlencode:attr:href--><a></a><!--seam:endeach-->";
+		let warnings = check_each_scope(template, &page_schema());
+		assert_eq!(warnings.len(), 1);
+		assert!(warnings[0].contains("slot \"slug\" inside each:repos"), "{}", warnings[0]);
+	}
+
+	#[test]
+	fn no_scope_warning_for_loop_index() {
+		let template = "<!--seam:each:range:user.age--><a><!--seam:@index1--></a><!--seam:endeach-->";
+		assert!(check_each_scope(template, &page_schema()).is_empty());
+	}
+
+	#[test]
+	fn no_scope_warning_inside_raw_block() {
+		let template =
+			"<!--seam:each:repos--><!--seam:raw--><!--seam:x--><!--seam:endraw--><!--seam:endeach-->";
+		assert!(check_each_scope(template, &page_schema()).is_empty());
+	}
+
+	#[test]
+	fn no_warning_inside_comments() {
+		let template = concat!(
+			"<!--seam:each:repos--><!--seam:#comment--><!--seam:x--><!--seam:#endcomment-->",
+			"<!--seam:#x--><!--seam:endeach--><!--seam:#comment--><!--seam:ghost--><!--seam:#endcomment-->",
+		);
+		assert!(check_each_scope(template, &page_schema()).is_empty());
+		assert!(check_loader_keys(template, &keys(&["repos"])).is_empty());
+	}
+
+	fn keys(names: &[&str]) -> BTreeSet<String> {
+		names.iter().map(|n| (*n).to_string()).collect()
+	}
+
+	#[test]
+	fn warns_slot_with_unknown_loader_key() {
+		let template = "<h1><!--seam:usr.name--></h1><p><!--seam:usr.bio--></p><!--seam:user.name-->";
+		let warnings = check_loader_keys(template, &keys(&["user"]));
+		assert_eq!(warnings.len(), 1, "{warnings:?}");
+		assert!(warnings[0].contains("slot \"usr.name\" reads \"usr\""), "{}", warnings[0]);
+		assert!(warnings[0].contains("loaders provide user"));
+	}
+
+	#[test]
+	fn no_loader_key_warning_when_all_provided() {
+		let template = concat!(
+			"<!--seam:outlet--><!--seam:if:user--><!--seam:user.name--><!--seam:endif:user-->",
+			"<!--seam:each:repos--><!--seam:$.language--><!--seam:name--><!--seam:/user.name-->",
+			"<!--seam:@index--><!--seam:endeach--><!--seam:user.role:attr:class--><div></div>",
+		);
+		assert!(check_loader_keys(template, &keys(&["user", "repos"])).is_empty());
+	}
+
+	#[test]
+	fn loader_key_check_skips_reserved_keys() {
+		let template =
+			"<!--seam:__page-->/<!--seam:__pageCount--><!--seam:if:__page--><!--seam:endif:__page-->";
+		assert!(check_loader_keys(template, &keys(&["repos"])).is_empty());
+	}
+
+	#[test]
+	fn loader_key_check_follows_root_refs_in_loops() {
+		let template = "<!--seam:each:repos--><!--seam:/usr.name--><!--seam:endeach-->";
+		let warnings = check_loader_keys(template, &keys(&["user", "repos"]));
+		assert_eq!(warnings.len(), 1);
+		assert!(warnings[0].contains("\"usr\""));
+	}
+
 	#[test]
 	fn resolve_path_basic() {
 		let schema = page_schema();
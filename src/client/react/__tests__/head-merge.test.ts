@@ -106,6 +106,13 @@ describe('mergeHeadConfigs - HeadFn', () => {
 		])
 	})
 
+	it('concatenates blocks (base first, then override)', () => {
+		const base: HeadConfig = { blocks: [{ style: 'body{margin:0}' }] }
+		const override: HeadConfig = { blocks: [{ jsonLd: '{"@type":"WebPage"}' }] }
+		const result = mergeHeadConfigs(base, override) as HeadConfig
+		expect(result.blocks).toEqual([{ style: 'body{margin:0}' }, { jsonLd: '{"@type":"WebPage"}' }])
+	})
+
 	it('HeadFn + static returns HeadFn', () => {
 		const baseFn: HeadFn = (data) => ({ title: `${data.name}` })
 		const override: HeadConfig = { link: [{ rel: 'icon', href: '/icon.png' }] }
@@ -82,6 +82,22 @@ describe('headConfigToSlotHtml', () => {
 		)
 	})
 
+	it('emits JSON-LD and inline style blocks in order after links', () => {
+		const config: HeadConfig = {
+			link: [{ rel: 'canonical', href: '<!--seam:url-->' }],
+			blocks: [
+				{ jsonLd: '{"@type":"Organization","name":"Seam"}' },
+				{ style: 'body{margin:0}' },
+			],
+		}
+		expect(headConfigToSlotHtml(config)).toBe(
+			'<link rel="canonical" href="<!--seam:url-->">' +
+				'<script type="application/ld+json" data-seam-head>' +
+				'{"@type":"Organization","name":"Seam"}</script>' +
+				'<style data-seam-head>body{margin:0}</style>',
+		)
+	})
+
 	it('end-to-end: proxy → head function → slot HTML', () => {
 		const proxy = buildHeadSlotProxy() as Record<string, unknown>
 		const post = proxy.post as Record<string, unknown>
@@ -1,9 +1,11 @@
 /* src/client/react/src/head.ts */
 
-import type { HeadConfig, HeadFn, HeadMeta } from './types.js'
+import type { HeadBlock, HeadConfig, HeadFn, HeadMeta } from './types.js'
 
 const SLOT_PREFIX = '<!--seam:'
 const SLOT_SUFFIX = '-->'
+/** Marks tags `updateHead` replaces on SPA navigation */
+const HEAD_MARKER = 'data-seam-head'
 
 /**
  * Build a deep Proxy that returns slot marker strings for property access.
@@ -50,9 +52,21 @@ export function headConfigToSlotHtml(config: HeadConfig): string {
 		}
 		html += '>'
 	}
+	for (const block of config.blocks ?? []) {
+		html += headBlockHtml(block)
+	}
 	return html
 }
 
+/**
+ * Blocks carry the head marker: unlike meta and links, they have no identity to match
+ * SSR tags by, so SPA navigation could not otherwise remove them.
+ */
+function headBlockHtml(block: HeadBlock): string {
+	if ('style' in block) return `<style ${HEAD_MARKER}>${block.style}</style>`
+	return `<script type="application/ld+json" ${HEAD_MARKER}>${block.jsonLd}</script>`
+}
+
 /** Identity key for meta dedup: name > property > httpEquiv */
 function metaKey(m: HeadMeta): string | undefined {
 	return m.name ?? m.property ?? m.httpEquiv
@@ -91,6 +105,13 @@ function mergeStaticHeadConfigs(base: HeadConfig, override: HeadConfig): HeadCon
 		result.link = [...baseLink, ...overrideLink]
 	}
 
+	// blocks: concatenate (base first, then override)
+	const baseBlocks = base.blocks ?? []
+	const overrideBlocks = override.blocks ?? []
+	if (baseBlocks.length > 0 || overrideBlocks.length > 0) {
+		result.blocks = [...baseBlocks, ...overrideBlocks]
+	}
+
 	return result
 }
 
@@ -101,7 +122,7 @@ function resolveHead(head: HeadConfig | HeadFn, data: Record<string, unknown>):
 
 /**
  * Merge two head configs (static or dynamic). Override wins for title and
- * conflicting meta; links are concatenated.
+ * conflicting meta; links and blocks are concatenated.
  */
 export function mergeHeadConfigs(
 	base: HeadConfig | HeadFn | undefined,
@@ -17,6 +17,7 @@ export type {
 	HeadConfig,
 	HeadMeta,
 	HeadLink,
+	HeadBlock,
 	HeadFn,
 } from './types.js'
 export type { LoaderError } from './use-seam-data.js'
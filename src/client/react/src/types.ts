@@ -33,10 +33,19 @@ export interface HeadLink {
 	[key: string]: string | undefined
 }
 
+/**
+ * Inline `<style>` (e.g. critical CSS) or `<script type="application/ld+json">` body.
+ * Slots inside a block render HTML-escaped, which JSON does not decode, so JSON-LD is
+ * best kept static.
+ */
+export type HeadBlock = { style: string } | { jsonLd: string }
+
 export interface HeadConfig {
 	title?: string
 	meta?: HeadMeta[]
 	link?: HeadLink[]
+	/** Emitted after meta and links, in list order */
+	blocks?: HeadBlock[]
 }
 
 export type HeadFn = (data: Record<string, unknown>) => HeadConfig
@@ -63,6 +63,21 @@ describe('updateHead', () => {
 		const managed = document.head.querySelector('meta[name="description"][data-seam-head]')
 		expect(managed?.getAttribute('content')).toBe('spa content')
 	})
+
+	it('replaces SSR blocks with the new page blocks in order', () => {
+		// Simulate SSR-injected block (carries the marker)
+		const ssrStyle = document.createElement('style')
+		ssrStyle.setAttribute('data-seam-head', '')
+		ssrStyle.textContent = '.old{}'
+		document.head.appendChild(ssrStyle)
+
+		updateHead({ blocks: [{ jsonLd: '{"@type":"WebPage"}' }, { style: '.new{}' }] })
+
+		const managed = [...document.head.querySelectorAll('[data-seam-head]')]
+		expect(managed.map((el) => el.tagName)).toEqual(['SCRIPT', 'STYLE'])
+		expect(managed[0]?.getAttribute('type')).toBe('application/ld+json')
+		expect(managed[1]?.textContent).toBe('.new{}')
+	})
 })
 
 describe('clearHead', () => {
@@ -45,6 +45,21 @@ export function updateHead(config: HeadConfig): void {
 		}
 		document.head.appendChild(el)
 	}
+
+	// SSR blocks carry the marker too, so the sweep above already removed them
+	for (const block of config.blocks ?? []) {
+		let el: HTMLElement
+		if ('style' in block) {
+			el = document.createElement('style')
+			el.textContent = block.style
+		} else {
+			el = document.createElement('script')
+			el.setAttribute('type', 'application/ld+json')
+			el.textContent = block.jsonLd
+		}
+		el.setAttribute(MARKER, '')
+		document.head.appendChild(el)
+	}
 }
 
 /** Remove all Seam-managed head tags from previous page. */
@@ -0,0 +1,34 @@
+/* src/server/adapter/actix/src/error.rs */
+
+use actix_web::http::StatusCode;
+use actix_web::{HttpResponse, ResponseError};
+use seam_server::SeamError;
+use seam_server::dispatch::error_body;
+
+/// Newtype wrapper to implement `ResponseError` for `SeamError`.
+/// Required because Rust's orphan rule prevents `impl ResponseError for SeamError`
+/// when both types are foreign to this crate.
+#[derive(Debug)]
+pub(crate) struct ActixError(pub SeamError);
+
+impl std::fmt::Display for ActixError {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		f.write_str(self.0.message())
+	}
+}
+
+impl ResponseError for ActixError {
+	fn status_code(&self) -> StatusCode {
+		StatusCode::from_u16(self.0.status()).unwrap_or(StatusCode::INTERNAL_SERVER_ERROR)
+	}
+
+	fn error_response(&self) -> HttpResponse {
+		HttpResponse::build(self.status_code()).json(error_body(&self.0))
+	}
+}
+
+impl From<SeamError> for ActixError {
+	fn from(err: SeamError) -> Self {
+		Self(err)
+	}
+}
@@ -0,0 +1,175 @@
+/* src/server/adapter/actix/src/handler/mod.rs */
+
+mod page;
+mod rpc;
+mod subscribe;
+
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
+
+use actix_web::HttpRequest;
+use actix_web::web::{self, ServiceConfig};
+use seam_server::context::{ContextConfig, RawContextMap, resolve_context};
+use seam_server::dispatch::{Dispatcher, MAX_BODY_BYTES, check_reserved_names, compile_schemas};
+use seam_server::page::PageDef;
+use seam_server::procedure::SubscriptionDef;
+use seam_server::{SeamError, SeamParts, TrailingSlash};
+
+pub(crate) struct AppState {
+	pub manifest_json: serde_json::Value,
+	pub dispatch: Dispatcher,
+	pub subscriptions: HashMap<String, Arc<SubscriptionDef>>,
+	/// Keyed by the Actix route pattern the page is registered under.
+	pub pages: HashMap<String, Arc<PageDef>>,
+	pub compiled_sub_input_schemas: HashMap<String, seam_server::CompiledSchema>,
+	pub heartbeat_interval: Duration,
+	pub sse_idle_timeout: Duration,
+}
+
+/// Route table and shared state, built once and applied to every worker's `App`.
+pub(crate) struct SeamApp {
+	state: web::Data<AppState>,
+	/// Page route patterns in registration order, with the handler each one gets.
+	page_routes: Vec<(String, PageRoute)>,
+}
+
+#[derive(Clone, Copy)]
+enum PageRoute {
+	Render,
+	RedirectTrailingSlash,
+}
+
+/// Extract raw context values from HTTP request (headers, cookies, query).
+pub(super) fn extract_raw_context_from_req(
+	config: &ContextConfig,
+	req: &HttpRequest,
+) -> RawContextMap {
+	let header_list: Vec<(String, String)> = req
+		.headers()
+		.iter()
+		.filter_map(|(k, v)| v.to_str().ok().map(|v| (k.as_str().to_string(), v.to_string())))
+		.collect();
+	let cookie_header = req.headers().get("cookie").and_then(|v| v.to_str().ok());
+	let query_string = Some(req.query_string()).filter(|q| !q.is_empty());
+	seam_server::extract_raw_context(config, &header_list, cookie_header, query_string)
+}
+
+/// Resolve context for a specific procedure given its context_keys.
+pub(super) fn resolve_ctx_for_proc(
+	state: &AppState,
+	context_keys: &[String],
+	req: &HttpRequest,
+) -> Result<serde_json::Value, SeamError> {
+	if context_keys.is_empty() {
+		return Ok(serde_json::Value::Object(serde_json::Map::new()));
+	}
+	let config = state.dispatch.procedures.context_config();
+	let raw = extract_raw_context_from_req(config, req);
+	resolve_context(config, &raw, context_keys)
+}
+
+pub(crate) fn build_app(manifest_json: serde_json::Value, mut parts: SeamParts) -> SeamApp {
+	let dispatch = Dispatcher::from_parts(&mut parts);
+	let has_url_prefix = dispatch.has_url_prefix();
+	let trailing_slash = parts.trailing_slash;
+
+	let subscriptions: HashMap<_, _> =
+		parts.subscriptions.into_iter().map(|s| (s.name.clone(), Arc::new(s))).collect();
+	check_reserved_names("subscription", subscriptions.keys());
+	let compiled_sub_input_schemas = if seam_server::should_validate(&parts.validation_mode) {
+		compile_schemas(&subscriptions, |s| &s.input_schema)
+	} else {
+		HashMap::new()
+	};
+
+	// Pages are served under /_seam/page/* prefix only.
+	let mut page_map = HashMap::new();
+	let mut page_routes = Vec::new();
+	for page in parts.pages {
+		let page_arc = Arc::new(page);
+		let route = actix_route(&page_arc.route);
+		let mut full_routes = vec![format!("/_seam/page{route}")];
+		// Register locale-prefixed routes only when url_prefix strategy is active
+		if has_url_prefix {
+			full_routes.push(format!("/_seam/page/{{_seam_locale}}{route}"));
+		}
+		for full_route in full_routes {
+			page_map.insert(full_route.clone(), page_arc.clone());
+			page_routes.push((full_route.clone(), PageRoute::Render));
+
+			if full_route.ends_with('/') || page_arc.route.contains("{*") {
+				continue;
+			}
+			let slashed = format!("{full_route}/");
+			match trailing_slash {
+				TrailingSlash::Redirect => {
+					page_routes.push((slashed, PageRoute::RedirectTrailingSlash));
+				}
+				TrailingSlash::Rewrite => {
+					page_map.insert(slashed.clone(), page_arc.clone());
+					page_routes.push((slashed, PageRoute::Render));
+				}
+				TrailingSlash::Strict => {}
+			}
+		}
+	}
+
+	let state = web::Data::new(AppState {
+		manifest_json,
+		dispatch,
+		subscriptions,
+		pages: page_map,
+		compiled_sub_input_schemas,
+		heartbeat_interval: parts.transport_config.heartbeat_interval,
+		sse_idle_timeout: parts.transport_config.sse_idle_timeout,
+	});
+
+	SeamApp { state, page_routes }
+}
+
+/// Register `/_seam/*` routes and the shared state on one worker's service config.
+pub(crate) fn configure(cfg: &mut ServiceConfig, app: &SeamApp) {
+	cfg
+		.app_data(app.state.clone())
+		.route("/_seam/manifest.json", web::get().to(rpc::handle_manifest))
+		.service(
+			web::resource("/_seam/procedure/{name}")
+				.app_data(web::PayloadConfig::new(MAX_BODY_BYTES))
+				.route(web::post().to(rpc::handle_procedure_post))
+				.route(web::get().to(subscribe::handle_subscribe)),
+		);
+	for (pattern, route) in &app.page_routes {
+		match route {
+			PageRoute::Render => cfg.route(pattern, web::get().to(page::handle_page)),
+			PageRoute::RedirectTrailingSlash => {
+				cfg.route(pattern, web::get().to(page::redirect_trailing_slash))
+			}
+		};
+	}
+	// Registered last, so it only sees page URLs no route above matched
+	if app.state.dispatch.status_pages.not_found.is_some() {
+		cfg
+			.route("/_seam/page", web::get().to(page::handle_unmatched))
+			.route("/_seam/page/{tail:.*}", web::get().to(page::handle_unmatched));
+	}
+}
+
+/// Axum-style page route (`/blog/{*rest}`) in Actix syntax (`/blog/{rest:.*}`).
+/// Plain `{param}` segments are the same in both.
+pub(crate) fn actix_route(route: &str) -> String {
+	let mut out = String::with_capacity(route.len());
+	let mut rest = route;
+	while let Some(start) = rest.find("{*") {
+		let Some(len) = rest[start..].find('}') else {
+			break;
+		};
+		out.push_str(&rest[..start]);
+		out.push('{');
+		out.push_str(&rest[start + 2..start + len]);
+		out.push_str(":.*}");
+		rest = &rest[start + len + 1..];
+	}
+	out.push_str(rest);
+	out
+}
@@ -0,0 +1,80 @@
+/* src/server/adapter/actix/src/handler/page.rs */
+
+use std::collections::HashMap;
+
+use actix_web::http::{StatusCode, header};
+use actix_web::web;
+use actix_web::{HttpRequest, HttpResponse};
+use seam_server::SeamError;
+use seam_server::dispatch::{read_prerendered, trailing_slash_location};
+
+use super::{AppState, extract_raw_context_from_req};
+use crate::error::ActixError;
+
+/// 308 from `/dashboard/` to `/dashboard`; see `trailing_slash_location`.
+pub(super) async fn redirect_trailing_slash(req: HttpRequest) -> HttpResponse {
+	let query = Some(req.query_string()).filter(|q| !q.is_empty());
+	let location = trailing_slash_location(req.path(), query);
+	HttpResponse::PermanentRedirect().insert_header((header::LOCATION, location)).finish()
+}
+
+/// `GET /_seam/page/*`. Errors become the registered status page when there is one.
+pub(super) async fn handle_page(
+	state: web::Data<AppState>,
+	req: HttpRequest,
+) -> Result<HttpResponse, ActixError> {
+	render_page(&state, &req).await.or_else(|ActixError(err)| status_page_response(&state, err, &req))
+}
+
+/// Catch-all after the page routes, registered only with a 404 page.
+pub(super) async fn handle_unmatched(
+	state: web::Data<AppState>,
+	req: HttpRequest,
+) -> Result<HttpResponse, ActixError> {
+	status_page_response(&state, SeamError::not_found("Page not found"), &req)
+}
+
+/// Swap a page error for its registered template; see `Dispatcher::status_page`.
+fn status_page_response(
+	state: &AppState,
+	err: SeamError,
+	req: &HttpRequest,
+) -> Result<HttpResponse, ActixError> {
+	let Some((status, html)) = state.dispatch.status_page(&err, req.path()) else {
+		return Err(ActixError(err));
+	};
+	let status = StatusCode::from_u16(status).unwrap_or(StatusCode::INTERNAL_SERVER_ERROR);
+	Ok(HttpResponse::build(status).content_type("text/html; charset=utf-8").body(html))
+}
+
+async fn render_page(state: &AppState, req: &HttpRequest) -> Result<HttpResponse, ActixError> {
+	let page = req
+		.match_pattern()
+		.and_then(|pattern| state.pages.get(&pattern))
+		.cloned()
+		.ok_or_else(|| SeamError::not_found("Page not found"))?;
+
+	if let Some(html) = read_prerendered(&page, req.path()).await {
+		return Ok(html_response(html));
+	}
+
+	let mut params: HashMap<String, String> =
+		req.match_info().iter().map(|(k, v)| (k.to_string(), v.to_string())).collect();
+	let path_locale = params.remove("_seam_locale");
+	let header_str = |name| req.headers().get(name).and_then(|v| v.to_str().ok());
+	let locale = state.dispatch.page_locale(
+		path_locale.as_deref(),
+		req.uri().path_and_query().map(actix_web::http::uri::PathAndQuery::as_str).unwrap_or(""),
+		header_str(header::COOKIE),
+		header_str(header::ACCEPT_LANGUAGE),
+	)?;
+
+	let raw_ctx = extract_raw_context_from_req(state.dispatch.procedures.context_config(), req);
+	let output = state.dispatch.run_loaders(&page, &params, raw_ctx).await?;
+	let html = state.dispatch.render_page(&page, output, locale.as_deref(), Some(req.query_string()));
+	Ok(html_response(html))
+}
+
+fn html_response(html: String) -> HttpResponse {
+	HttpResponse::Ok().content_type("text/html; charset=utf-8").body(html)
+}
@@ -0,0 +1,34 @@
+/* src/server/adapter/actix/src/handler/rpc.rs */
+
+use actix_web::http::StatusCode;
+use actix_web::web::{self, Bytes};
+use actix_web::{HttpRequest, HttpResponse};
+use seam_server::SeamError;
+use seam_server::dispatch::body_too_large;
+
+use super::{AppState, extract_raw_context_from_req};
+use crate::error::ActixError;
+
+pub(super) async fn handle_manifest(
+	state: web::Data<AppState>,
+) -> Result<HttpResponse, ActixError> {
+	state.dispatch.check_manifest()?;
+	Ok(HttpResponse::Ok().json(&state.manifest_json))
+}
+
+/// POST dispatcher for queries, commands, and the batch endpoint. The route's
+/// `PayloadConfig` caps the body at `MAX_BODY_BYTES`.
+pub(super) async fn handle_procedure_post(
+	state: web::Data<AppState>,
+	name: web::Path<String>,
+	req: HttpRequest,
+	body: Result<Bytes, actix_web::Error>,
+) -> Result<HttpResponse, ActixError> {
+	let body = body.map_err(|e| match e.as_response_error().status_code() {
+		StatusCode::PAYLOAD_TOO_LARGE => body_too_large(),
+		_ => SeamError::validation(format!("Failed to read request body: {e}")),
+	})?;
+	let raw_ctx = extract_raw_context_from_req(state.dispatch.procedures.context_config(), &req);
+	let data = state.dispatch.call_procedure(&name, &body, raw_ctx).await?;
+	Ok(HttpResponse::Ok().json(serde_json::json!({ "ok": true, "data": data })))
+}
@@ -0,0 +1,88 @@
+/* src/server/adapter/actix/src/handler/subscribe.rs */
+
+use actix_web::web::{self, Bytes};
+use actix_web::{HttpRequest, HttpResponse};
+use futures_util::stream::{self, StreamExt};
+use seam_server::SeamError;
+use seam_server::dispatch::{
+	SseFrame, buffer_events, check_input, error_object, with_sse_lifecycle,
+};
+
+use super::{AppState, resolve_ctx_for_proc};
+
+#[derive(serde::Deserialize)]
+pub(super) struct SubscribeQuery {
+	input: Option<String>,
+}
+
+/// SSE subscription: `data` events carry an incrementing `id`, a handler error becomes an
+/// `error` event, and a `complete` event closes the stream once the source ends or idles
+/// past the transport's SSE timeout. Heartbeat comments keep the connection open.
+pub(super) async fn handle_subscribe(
+	state: web::Data<AppState>,
+	name: web::Path<String>,
+	query: web::Query<SubscribeQuery>,
+	req: HttpRequest,
+) -> HttpResponse {
+	let setup = async {
+		// Resolve hash -> original name for subscriptions
+		let resolved = state.dispatch.resolve_name(&name)?;
+
+		let sub = state
+			.subscriptions
+			.get(&resolved)
+			.ok_or_else(|| SeamError::not_found(format!("Subscription '{resolved}' not found")))?;
+
+		let raw_input = match &query.input {
+			Some(s) => serde_json::from_str(s).map_err(|e| SeamError::validation(e.to_string()))?,
+			None => serde_json::Value::Object(serde_json::Map::new()),
+		};
+
+		check_input(
+			state.compiled_sub_input_schemas.get(&resolved),
+			"subscription",
+			&resolved,
+			&raw_input,
+		)?;
+
+		let ctx = resolve_ctx_for_proc(&state, &sub.context_keys, &req)?;
+		let last_event_id =
+			req.headers().get("last-event-id").and_then(|v| v.to_str().ok()).map(String::from);
+		let stream =
+			(sub.handler)(seam_server::SubscriptionParams { input: raw_input, ctx, last_event_id })
+				.await?;
+		Ok(buffer_events(stream, sub.buffer))
+	};
+
+	let body = match setup.await {
+		Ok(data_stream) => {
+			let mut seq: u64 = 0;
+			with_sse_lifecycle(data_stream, state.heartbeat_interval, state.sse_idle_timeout)
+				.map(move |frame| {
+					let frame = match frame {
+						SseFrame::Data(Ok(value)) => {
+							let data = serde_json::to_string(&value).unwrap_or_default();
+							let frame = format!("event: data\nid: {seq}\ndata: {data}\n\n");
+							seq += 1;
+							frame
+						}
+						SseFrame::Data(Err(e)) => error_frame(&e),
+						SseFrame::Heartbeat => ": heartbeat\n\n".to_string(),
+						SseFrame::Complete => "event: complete\ndata: {}\n\n".to_string(),
+					};
+					Ok::<_, actix_web::Error>(Bytes::from(frame))
+				})
+				.boxed_local()
+		}
+		Err(err) => stream::once(async move { Ok(Bytes::from(error_frame(&err))) }).boxed_local(),
+	};
+
+	HttpResponse::Ok()
+		.content_type("text/event-stream")
+		.insert_header(("cache-control", "no-cache"))
+		.streaming(body)
+}
+
+fn error_frame(err: &SeamError) -> String {
+	format!("event: error\ndata: {}\n\n", error_object(err))
+}
@@ -0,0 +1,56 @@
+/* src/server/adapter/actix/src/lib.rs */
+#![cfg_attr(test, allow(clippy::unwrap_used))]
+
+mod error;
+mod handler;
+
+use std::sync::Arc;
+
+use actix_web::web::ServiceConfig;
+use seam_server::SeamServer;
+use seam_server::manifest::build_manifest;
+
+/// Re-export seam-server core for convenience
+pub use seam_server;
+
+/// Extension trait that converts a `SeamServer` into an Actix Web service configuration.
+pub trait IntoActixApp {
+	/// Route configuration for `App::configure`. Cheap to clone, so the `HttpServer`
+	/// factory can apply it once per worker.
+	fn into_actix_config(self) -> impl Fn(&mut ServiceConfig) + Clone + Send + 'static;
+	fn serve(self, addr: &str) -> impl std::future::Future<Output = std::io::Result<()>>;
+}
+
+impl IntoActixApp for SeamServer {
+	fn into_actix_config(self) -> impl Fn(&mut ServiceConfig) + Clone + Send + 'static {
+		let mut parts = self.into_parts();
+		// Streams, uploads, and raw procedures are not served by this adapter, so the manifest omits them
+		let manifest_json = serde_json::to_value(build_manifest(
+			&parts.procedures,
+			&parts.subscriptions,
+			&[],
+			&[],
+			&[],
+			std::mem::take(&mut parts.channel_metas),
+			&parts.context_config,
+		))
+		.expect("manifest serialization");
+		let app = Arc::new(handler::build_app(manifest_json, parts));
+		move |cfg: &mut ServiceConfig| handler::configure(cfg, &app)
+	}
+
+	#[allow(clippy::print_stdout)]
+	async fn serve(self, addr: &str) -> std::io::Result<()> {
+		let config = self.into_actix_config();
+		let server =
+			actix_web::HttpServer::new(move || actix_web::App::new().configure(config.clone()))
+				.bind(addr)?;
+		if let Some(local_addr) = server.addrs().first() {
+			println!("Seam Rust backend running on http://localhost:{}", local_addr.port());
+		}
+		server.run().await
+	}
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,48 @@
+/* src/server/adapter/actix/src/tests/mod.rs */
+
+mod page;
+mod rpc;
+mod subscribe;
+
+use super::*;
+use actix_web::http::StatusCode;
+use actix_web::{App, test};
+use seam_server::procedure::{ProcedureDef, ProcedureType};
+
+fn test_server() -> SeamServer {
+	SeamServer::new().procedure(ProcedureDef {
+		name: "greet".into(),
+		proc_type: ProcedureType::Query,
+		input_schema: serde_json::json!({"properties": {"name": {"type": "string"}}}),
+		output_schema: serde_json::json!({"properties": {"message": {"type": "string"}}}),
+		error_schema: None,
+		context_keys: vec![],
+		suppress: None,
+		cache: None,
+		timeout: None,
+		handler: Arc::new(|input, _ctx| {
+			Box::pin(async move {
+				let name = input.get("name").and_then(|v| v.as_str()).unwrap_or("World");
+				Ok(serde_json::json!({"message": format!("Hello, {}!", name)}))
+			})
+		}),
+	})
+}
+
+/// Run one request against a fresh app built from `server`.
+async fn send(server: SeamServer, req: test::TestRequest) -> (StatusCode, String) {
+	let app = test::init_service(App::new().configure(server.into_actix_config())).await;
+	let resp = test::call_service(&app, req.to_request()).await;
+	let status = resp.status();
+	let bytes = test::read_body(resp).await;
+	(status, String::from_utf8_lossy(&bytes).to_string())
+}
+
+#[actix_web::test]
+async fn app_builds_from_empty_server() {
+	let (status, body) =
+		send(SeamServer::new(), test::TestRequest::get().uri("/_seam/manifest.json")).await;
+	assert_eq!(status, StatusCode::OK);
+	let manifest: serde_json::Value = serde_json::from_str(&body).unwrap();
+	assert!(manifest["procedures"].as_object().unwrap().is_empty());
+}
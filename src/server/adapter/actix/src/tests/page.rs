@@ -0,0 +1,98 @@
+/* src/server/adapter/actix/src/tests/page.rs */
+
+use super::*;
+use seam_server::TrailingSlash;
+use seam_server::page::{LoaderDef, PageDef};
+
+use crate::handler::actix_route;
+
+fn page_def(route: &str, template: &str, loaders: Vec<LoaderDef>) -> PageDef {
+	PageDef {
+		route: route.into(),
+		template: template.into(),
+		locale_templates: None,
+		loaders,
+		data_id: "__data".into(),
+		layout_chain: vec![],
+		page_loader_keys: vec![],
+		i18n_keys: vec![],
+		projections: None,
+		pagination: None,
+		prerender: false,
+		static_dir: None,
+		template_mtime: None,
+		compiled: None,
+	}
+}
+
+fn greeting_server() -> SeamServer {
+	let loader = LoaderDef {
+		data_key: "greeting".into(),
+		procedure: "greet".into(),
+		input_fn: Arc::new(|params| serde_json::json!({"name": params.get("name")})),
+	};
+	test_server().page(page_def(
+		"/hello/{name}",
+		"<html><body><p><!--seam:message--></p></body></html>",
+		vec![loader],
+	))
+}
+
+#[actix_web::test]
+async fn page_renders_loader_data() {
+	let (status, html) =
+		send(greeting_server(), test::TestRequest::get().uri("/_seam/page/hello/Ann")).await;
+	assert_eq!(status, StatusCode::OK);
+	assert!(html.starts_with("<html><body><p>Hello, Ann!</p>"));
+	assert!(html.contains(r#"<script id="__data" type="application/json">"#));
+	assert!(html.ends_with("</script></body></html>"));
+}
+
+#[actix_web::test]
+async fn page_trailing_slash_redirects() {
+	let req = test::TestRequest::get().uri("/_seam/page/hello/Ann/?x=1");
+	let app = test::init_service(App::new().configure(greeting_server().into_actix_config())).await;
+	let resp = test::call_service(&app, req.to_request()).await;
+	assert_eq!(resp.status(), StatusCode::PERMANENT_REDIRECT);
+	assert_eq!(resp.headers().get("location").unwrap(), "/hello/Ann?x=1");
+}
+
+#[actix_web::test]
+async fn page_trailing_slash_strict_is_not_found() {
+	let server = greeting_server().trailing_slash(TrailingSlash::Strict);
+	let (status, _) = send(server, test::TestRequest::get().uri("/_seam/page/hello/Ann/")).await;
+	assert_eq!(status, StatusCode::NOT_FOUND);
+}
+
+#[actix_web::test]
+async fn catch_all_route_uses_actix_syntax() {
+	assert_eq!(actix_route("/user/{id}"), "/user/{id}");
+	assert_eq!(actix_route("/docs/{*rest}"), "/docs/{rest:.*}");
+}
+
+/// A page whose loader fails (unregistered procedure) plus both status templates.
+fn status_page_server() -> SeamServer {
+	let loader = LoaderDef {
+		data_key: "boom".into(),
+		procedure: "missing".into(),
+		input_fn: Arc::new(|_params| serde_json::json!({})),
+	};
+	SeamServer::new()
+		.page(page_def("/broken", "<html><body></body></html>", vec![loader]))
+		.not_found_page("<h1><!--seam:status--> <!--seam:path--></h1>")
+		.error_page("<h1><!--seam:status--></h1>")
+}
+
+#[actix_web::test]
+async fn status_pages_render_for_unknown_page_and_failing_loader() {
+	let get = |uri: &str| test::TestRequest::get().uri(uri);
+	let (status, html) = send(status_page_server(), get("/_seam/page/missing")).await;
+	assert_eq!((status, html.as_str()), (StatusCode::NOT_FOUND, "<h1>404 /missing</h1>"));
+	let (status, html) = send(status_page_server(), get("/_seam/page/broken")).await;
+	assert_eq!((status, html.as_str()), (StatusCode::INTERNAL_SERVER_ERROR, "<h1>500</h1>"));
+
+	// Non-page URLs keep the bare 404
+	let (status, body) = send(status_page_server(), get("/_seam/other")).await;
+	assert_eq!(status, StatusCode::NOT_FOUND);
+	assert!(body.is_empty());
+}
@@ -0,0 +1,64 @@
+/* src/server/adapter/actix/src/tests/rpc.rs */
+
+use super::*;
+
+fn post_json(uri: &str, body: &str) -> test::TestRequest {
+	test::TestRequest::post()
+		.uri(uri)
+		.insert_header(("content-type", "application/json"))
+		.set_payload(body.to_string())
+}
+
+#[actix_web::test]
+async fn query_returns_data() {
+	let (status, body) =
+		send(test_server(), post_json("/_seam/procedure/greet", r#"{"name":"Actix"}"#)).await;
+	assert_eq!(status, StatusCode::OK);
+	let json: serde_json::Value = serde_json::from_str(&body).unwrap();
+	assert_eq!(json, serde_json::json!({"ok": true, "data": {"message": "Hello, Actix!"}}));
+}
+
+#[actix_web::test]
+async fn unknown_procedure_is_not_found() {
+	let (status, body) = send(test_server(), post_json("/_seam/procedure/missing", "{}")).await;
+	assert_eq!(status, StatusCode::NOT_FOUND);
+	let json: serde_json::Value = serde_json::from_str(&body).unwrap();
+	assert_eq!(json["error"]["code"], "NOT_FOUND");
+}
+
+#[actix_web::test]
+async fn invalid_input_fails_validation() {
+	let (status, body) =
+		send(test_server(), post_json("/_seam/procedure/greet", r#"{"name":42}"#)).await;
+	assert_eq!(status, StatusCode::BAD_REQUEST);
+	let json: serde_json::Value = serde_json::from_str(&body).unwrap();
+	assert_eq!(json["error"]["code"], "VALIDATION_ERROR");
+}
+
+#[actix_web::test]
+async fn batch_preserves_call_order() {
+	let body = r#"{"calls":[{"procedure":"greet","input":{"name":"a"}},{"procedure":"nope"}]}"#;
+	let (status, body) = send(test_server(), post_json("/_seam/procedure/_batch", body)).await;
+	assert_eq!(status, StatusCode::OK);
+	let json: serde_json::Value = serde_json::from_str(&body).unwrap();
+	let results = json["data"]["results"].as_array().unwrap();
+	assert_eq!(results[0]["data"]["message"], "Hello, a!");
+	assert_eq!(results[1]["error"]["code"], "NOT_FOUND");
+}
+
+#[actix_web::test]
+async fn rpc_accepts_body_past_actix_default_limit() {
+	// actix's own payload limit is 256 KB; seam allows up to `MAX_BODY_BYTES`
+	let body = format!(r#"{{"name":"{}"}}"#, "x".repeat(512 * 1024));
+	let (status, _) = send(test_server(), post_json("/_seam/procedure/greet", &body)).await;
+	assert_eq!(status, StatusCode::OK);
+}
+
+#[actix_web::test]
+async fn rpc_oversized_body_is_rejected() {
+	let body = format!(r#"{{"name":"{}"}}"#, "x".repeat(seam_server::dispatch::MAX_BODY_BYTES));
+	let (status, body) = send(test_server(), post_json("/_seam/procedure/greet", &body)).await;
+	assert_eq!(status, StatusCode::PAYLOAD_TOO_LARGE);
+	let json: serde_json::Value = serde_json::from_str(&body).unwrap();
+	assert_eq!(json["error"]["code"], "PAYLOAD_TOO_LARGE");
+}
@@ -0,0 +1,34 @@
+/* src/server/adapter/actix/src/tests/subscribe.rs */
+
+use super::*;
+use seam_server::SeamError;
+use seam_server::procedure::{BoxStream, SubscriptionDef};
+
+fn events_server() -> SeamServer {
+	SeamServer::new().subscription(SubscriptionDef {
+		name: "chat.events".into(),
+		input_schema: serde_json::json!({}),
+		output_schema: serde_json::json!({}),
+		error_schema: None,
+		context_keys: vec![],
+		suppress: None,
+		buffer: None,
+		handler: Arc::new(|_params| {
+			Box::pin(async move {
+				let stream: BoxStream<Result<serde_json::Value, SeamError>> =
+					Box::pin(futures_util::stream::iter(vec![Ok(serde_json::json!({"text": "hello"}))]));
+				Ok(stream)
+			})
+		}),
+	})
+}
+
+#[actix_web::test]
+async fn subscription_opens_with_heartbeat_and_ends_with_complete() {
+	let (status, body) =
+		send(events_server(), test::TestRequest::get().uri("/_seam/procedure/chat.events")).await;
+	assert_eq!(status, StatusCode::OK);
+	assert!(body.starts_with(": heartbeat\n\n"), "missing initial heartbeat in:\n{body}");
+	assert!(body.contains(r#""text":"hello""#), "missing payload in:\n{body}");
+	assert!(body.ends_with("event: complete\ndata: {}\n\n"), "missing complete in:\n{body}");
+}
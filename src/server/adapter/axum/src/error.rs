@@ -3,6 +3,7 @@
 use axum::http::StatusCode;
 use axum::response::{IntoResponse, Response};
 use seam_server::SeamError;
+use seam_server::dispatch::error_body;
 
 /// Newtype wrapper to implement `IntoResponse` for `SeamError`.
 /// Required because Rust's orphan rule prevents `impl IntoResponse for SeamError`
@@ -11,17 +12,8 @@ pub(crate) struct AxumError(pub SeamError);
 
 impl IntoResponse for AxumError {
 	fn into_response(self) -> Response {
-		let err = self.0;
-		let status = StatusCode::from_u16(err.status()).unwrap_or(StatusCode::INTERNAL_SERVER_ERROR);
-		let mut error_obj = serde_json::json!({
-			"code": err.code(),
-			"message": err.message(),
-			"transient": false,
-		});
-		if let Some(details) = err.details() {
-			error_obj["details"] = serde_json::Value::Array(details.to_vec());
-		}
-		let body = serde_json::json!({ "ok": false, "error": error_obj });
+		let status = StatusCode::from_u16(self.0.status()).unwrap_or(StatusCode::INTERNAL_SERVER_ERROR);
+		let body = error_body(&self.0);
 		(status, axum::Json(body)).into_response()
 	}
 }
@@ -1,14 +1,19 @@
 /* src/server/adapter/axum/src/handler/channel.rs */
 
 use std::sync::Arc;
+use std::time::Duration;
 
-use axum::extract::ws::{Message, WebSocket};
+use axum::extract::ws::{Message, WebSocket, WebSocketUpgrade};
+use axum::extract::{Path, Query, State};
+use axum::http::{HeaderMap, Uri};
+use axum::response::{IntoResponse, Response};
 use futures_util::SinkExt;
 use futures_util::stream::SplitSink;
 use seam_server::SeamError;
 use tokio::time::interval;
 use tokio_stream::StreamExt;
 
+use super::subscribe::SubscribeQuery;
 use super::{AppState, resolve_ctx_for_proc};
 
 // --- WebSocket channel types ---
@@ -113,27 +118,18 @@ async fn dispatch_ws_uplink(
 
 	// Resolve handler name (hash map lookup)
 	let proc_name = &uplink.procedure;
-	let resolved_proc = if let Some(ref map) = state.rpc_hash_map {
-		map.get(proc_name).cloned().unwrap_or_else(|| proc_name.clone())
-	} else {
-		proc_name.clone()
-	};
+	let resolved_proc = state.dispatch.resolve_name(proc_name).unwrap_or_else(|_| proc_name.clone());
 
-	if state.should_validate
-		&& let Some(cs) = state.compiled_input_schemas.get(&resolved_proc)
-		&& let Err((msg, details)) = seam_server::validate_compiled(cs, &merged_input)
-	{
-		let detail_json: Vec<serde_json::Value> =
-			details.iter().map(seam_server::ValidationDetail::to_json).collect();
+	if let Err(e) = state.dispatch.procedures.validate(&resolved_proc, &merged_input) {
 		let resp = WsResponse {
 			id: uplink.id,
 			ok: false,
 			data: None,
 			error: Some(WsError {
-				code: "VALIDATION_ERROR".into(),
-				message: format!("Input validation failed for procedure '{resolved_proc}': {msg}"),
+				code: e.code().to_string(),
+				message: e.message().to_string(),
 				transient: false,
-				details: Some(detail_json),
+				details: e.details().map(<[serde_json::Value]>::to_vec),
 			}),
 		};
 		let _ =
@@ -141,7 +137,7 @@ async fn dispatch_ws_uplink(
 		return;
 	}
 
-	let resp = match state.handlers.get(&resolved_proc) {
+	let resp = match state.dispatch.procedures.get(&resolved_proc) {
 		Some(proc) => match (proc.handler)(merged_input, ctx.clone()).await {
 			Ok(data) => WsResponse { id: uplink.id, ok: true, data: Some(data), error: None },
 			Err(e) => WsResponse {
@@ -173,13 +169,56 @@ async fn dispatch_ws_uplink(
 		ws_sender.send(Message::Text(serde_json::to_string(&resp).unwrap_or_default().into())).await;
 }
 
+/// `GET /_seam/channel/{name}`: WebSocket transport for a channel registered with
+/// `SeamServer::channel`. Outgoing events come from its `{name}.events` subscription and
+/// uplink messages dispatch to its incoming handlers.
+pub(super) async fn handle_channel(
+	State(state): State<Arc<AppState>>,
+	Path(name): Path<String>,
+	Query(query): Query<SubscribeQuery>,
+	headers: HeaderMap,
+	uri: Uri,
+	ws: WebSocketUpgrade,
+) -> Response {
+	let sub_name = format!("{name}.events");
+	upgrade_channel(state, sub_name, query.input.as_deref(), headers, uri, ws)
+}
+
+/// Parse the `?input=` channel input and hand the socket to `handle_channel_ws`.
+/// Malformed input is answered over plain HTTP, before the upgrade.
+pub(super) fn upgrade_channel(
+	state: Arc<AppState>,
+	sub_name: String,
+	input: Option<&str>,
+	headers: HeaderMap,
+	uri: Uri,
+	ws: WebSocketUpgrade,
+) -> Response {
+	let raw_input = match input {
+		Some(s) => match serde_json::from_str(s) {
+			Ok(v) => v,
+			Err(e) => {
+				let payload = serde_json::json!({
+					"ok": false,
+					"error": { "code": "VALIDATION_ERROR", "message": e.to_string(), "transient": false }
+				});
+				return axum::Json(payload).into_response();
+			}
+		},
+		None => serde_json::Value::Object(serde_json::Map::new()),
+	};
+
+	ws.on_upgrade(move |socket| handle_channel_ws(state, sub_name, raw_input, headers, uri, socket))
+		.into_response()
+}
+
 #[allow(clippy::too_many_lines)]
-pub(super) async fn handle_channel_ws(
+async fn handle_channel_ws(
 	state: Arc<AppState>,
 	sub_name: String,
 	channel_input: serde_json::Value,
-	headers: axum::http::HeaderMap,
-	uri: axum::http::Uri,
+	headers: HeaderMap,
+	uri: Uri,
 	socket: WebSocket,
 ) {
 	let channel_name = sub_name.strip_suffix(".events").unwrap_or(&sub_name);
@@ -253,7 +292,9 @@ pub(super) async fn handle_channel_ws(
 	};
 
 	let mut event_stream = std::pin::pin!(event_stream);
-	let mut heartbeat = interval(state.heartbeat_interval);
+	let heartbeat_enabled = state.heartbeat_interval > Duration::ZERO;
+	// `interval` rejects a zero period; the branch is disabled in that case anyway
+	let mut heartbeat = interval(state.heartbeat_interval.max(Duration::from_millis(1)));
 	let pong_timeout = state.pong_timeout;
 	let mut pong_deadline: Option<tokio::time::Instant> = None;
 
@@ -300,7 +341,7 @@ pub(super) async fn handle_channel_ws(
 					None => break,
 				}
 			}
-			_ = heartbeat.tick() => {
+			_ = heartbeat.tick(), if heartbeat_enabled => {
 				// Send JSON heartbeat for application-level keepalive
 				let hb = serde_json::json!({ "heartbeat": true });
 				if ws_sender.send(Message::Text(hb.to_string().into())).await.is_err() {
@@ -2,7 +2,8 @@
 
 mod channel;
 mod page;
-mod projection;
+mod page_stream;
+mod raw;
 mod rpc;
 mod sse_lifecycle;
 mod stream;
@@ -20,34 +21,30 @@ use axum::http::{Method, Request, StatusCode};
 use axum::middleware::{self, Next};
 use axum::response::{IntoResponse, Response};
 use axum::routing::{get, post};
-use seam_server::RpcHashMap;
-use seam_server::SeamError;
 use seam_server::context::{ContextConfig, RawContextMap, resolve_context};
+use seam_server::dispatch::{Dispatcher, check_reserved_names, compile_schemas};
 use seam_server::page::PageDef;
-use seam_server::procedure::{ProcedureDef, ProcedureType, StreamDef, SubscriptionDef, UploadDef};
-use seam_server::resolve::ResolveStrategy;
+use seam_server::procedure::{RawDef, StreamDef, SubscriptionDef, UploadDef};
+use seam_server::{SeamError, SeamParts, TrailingSlash};
 use tower::util::ServiceExt;
+use tower_http::compression::CompressionLayer;
+use tower_http::compression::predicate::{DefaultPredicate, NotForContentType, Predicate};
 use tower_http::services::ServeFile;
 
 pub(crate) struct AppState {
 	pub manifest_json: serde_json::Value,
-	pub handlers: HashMap<String, Arc<ProcedureDef>>,
+	pub dispatch: Dispatcher,
 	pub subscriptions: HashMap<String, Arc<SubscriptionDef>>,
 	pub streams: HashMap<String, Arc<StreamDef>>,
 	pub uploads: HashMap<String, Arc<UploadDef>>,
+	pub raws: HashMap<String, Arc<RawDef>>,
 	pub pages: HashMap<String, Arc<PageDef>>,
-	pub rpc_hash_map: Option<HashMap<String, String>>,
-	pub batch_hash: Option<String>,
-	pub i18n_config: Option<seam_server::I18nConfig>,
-	pub locale_set: Option<std::collections::HashSet<String>>,
-	pub strategies: Vec<Box<dyn ResolveStrategy>>,
-	pub context_config: ContextConfig,
 	pub should_validate: bool,
-	pub compiled_input_schemas: HashMap<String, seam_server::CompiledSchema>,
 	pub compiled_sub_input_schemas: HashMap<String, seam_server::CompiledSchema>,
 	pub compiled_stream_input_schemas: HashMap<String, seam_server::CompiledSchema>,
 	pub compiled_upload_input_schemas: HashMap<String, seam_server::CompiledSchema>,
-	/// Maps procedure name -> kind ("query"|"command"|"stream"|"upload")
+	pub compiled_raw_input_schemas: HashMap<String, seam_server::CompiledSchema>,
+	/// Maps procedure name -> kind ("stream"|"upload"|"raw"); queries and commands are absent
 	pub kind_map: HashMap<String, &'static str>,
 	pub heartbeat_interval: Duration,
 	pub sse_idle_timeout: Duration,
@@ -79,8 +76,9 @@ pub(super) fn resolve_ctx_for_proc(
 	if context_keys.is_empty() {
 		return Ok(serde_json::Value::Object(serde_json::Map::new()));
 	}
-	let raw = extract_raw_context_from_req(&state.context_config, headers, uri);
-	resolve_context(&state.context_config, &raw, context_keys)
+	let config = state.dispatch.procedures.context_config();
+	let raw = extract_raw_context_from_req(config, headers, uri);
+	resolve_context(config, &raw, context_keys)
 }
 
 fn safe_public_path(path: &str) -> Option<PathBuf> {
@@ -126,157 +124,55 @@ pub fn with_public_files(router: Router, public_dir: PathBuf) -> Router {
 	router.layer(middleware::from_fn_with_state(Arc::new(public_dir), public_file_middleware))
 }
 
-#[allow(clippy::too_many_arguments, clippy::too_many_lines)]
-pub(crate) fn build_router(
-	manifest_json: serde_json::Value,
-	mut handlers: HashMap<String, Arc<ProcedureDef>>,
-	subscriptions: HashMap<String, Arc<SubscriptionDef>>,
-	streams: HashMap<String, Arc<StreamDef>>,
-	uploads: HashMap<String, Arc<UploadDef>>,
-	pages: Vec<PageDef>,
-	hash_map: Option<RpcHashMap>,
-	i18n_config: Option<seam_server::I18nConfig>,
-	strategies: Vec<Box<dyn ResolveStrategy>>,
-	context_config: ContextConfig,
-	validation_mode: &seam_server::ValidationMode,
-	transport_config: &seam_server::TransportConfig,
-) -> Router {
-	let (rpc_hash_map, batch_hash) = match hash_map {
-		Some(m) => {
-			let mut rev = m.reverse_lookup();
-			// Built-in procedures bypass hash obfuscation (identity mapping)
-			rev.insert("seam.i18n.query".to_string(), "seam.i18n.query".to_string());
-			(Some(rev), Some(m.batch))
-		}
-		None => (None, None),
-	};
-
-	let locale_set = i18n_config
-		.as_ref()
-		.map(|c| c.locales.iter().cloned().collect::<std::collections::HashSet<_>>());
-
-	// Use default strategies when none provided
-	let strategies =
-		if strategies.is_empty() { seam_server::default_strategies() } else { strategies };
-
-	let has_url_prefix = strategies.iter().any(|s| s.kind() == "url_prefix");
-
-	// Validate user-defined procedure names before registering built-in ones
-	for name in handlers.keys() {
-		if name.starts_with("seam.") {
-			panic!("procedure name {name:?} uses reserved \"seam.\" namespace");
-		}
-	}
-	for name in subscriptions.keys() {
-		if name.starts_with("seam.") {
-			panic!("subscription name {name:?} uses reserved \"seam.\" namespace");
-		}
-	}
-	for name in streams.keys() {
-		if name.starts_with("seam.") {
-			panic!("stream name {name:?} uses reserved \"seam.\" namespace");
-		}
-	}
-	for name in uploads.keys() {
-		if name.starts_with("seam.") {
-			panic!("upload name {name:?} uses reserved \"seam.\" namespace");
-		}
-	}
-
-	// Register built-in seam.i18n.query procedure (route-hash-based lookup)
-	if let Some(ref i18n) = i18n_config {
-		let i18n_clone = i18n.clone();
-		let valid_locales: std::collections::HashSet<String> = i18n.locales.iter().cloned().collect();
-		handlers.insert(
-			"seam.i18n.query".to_string(),
-			Arc::new(ProcedureDef {
-				name: "seam.i18n.query".to_string(),
-				proc_type: ProcedureType::Query,
-				input_schema: serde_json::json!({}),
-				output_schema: serde_json::json!({}),
-				error_schema: None,
-				context_keys: vec![],
-				suppress: None,
-				cache: None,
-				handler: Arc::new(move |input: serde_json::Value, _ctx: serde_json::Value| {
-					let i18n = i18n_clone.clone();
-					let valid = valid_locales.clone();
-					Box::pin(async move {
-						let route_hash = input.get("route").and_then(|v| v.as_str()).unwrap_or("").to_string();
-						let raw_locale = input.get("locale").and_then(|v| v.as_str()).unwrap_or(&i18n.default);
-						let locale = if valid.contains(raw_locale) {
-							raw_locale.to_string()
-						} else {
-							i18n.default.clone()
-						};
-
-						let messages = lookup_i18n_messages(&i18n, &route_hash, &locale);
-						let hash = i18n
-							.content_hashes
-							.get(&route_hash)
-							.and_then(|m| m.get(&locale))
-							.cloned()
-							.unwrap_or_default();
-
-						Ok(serde_json::json!({ "hash": hash, "messages": messages }))
-					})
-				}),
-			}),
-		);
-	}
-
-	let should_validate = seam_server::should_validate(validation_mode);
-
-	let mut compiled_input_schemas = HashMap::new();
-	if should_validate {
-		for (name, proc) in &handlers {
-			if let Ok(cs) = seam_server::compile_schema(&proc.input_schema) {
-				compiled_input_schemas.insert(name.clone(), cs);
-			}
-		}
-	}
-
-	let mut compiled_sub_input_schemas = HashMap::new();
-	if should_validate {
-		for (name, sub) in &subscriptions {
-			if let Ok(cs) = seam_server::compile_schema(&sub.input_schema) {
-				compiled_sub_input_schemas.insert(name.clone(), cs);
-			}
-		}
-	}
-
-	let mut compiled_stream_input_schemas = HashMap::new();
-	if should_validate {
-		for (name, stream) in &streams {
-			if let Ok(cs) = seam_server::compile_schema(&stream.input_schema) {
-				compiled_stream_input_schemas.insert(name.clone(), cs);
-			}
-		}
-	}
+/// Negotiate gzip/brotli from `Accept-Encoding`. The default predicate skips
+/// `text/event-stream`, and NDJSON is skipped too, so SSE and NDJSON streams are never
+/// buffered by the encoder.
+pub(crate) fn with_compression(router: Router) -> Router {
+	let predicate = DefaultPredicate::new().and(NotForContentType::new("application/x-ndjson"));
+	router.layer(CompressionLayer::new().gzip(true).br(true).compress_when(predicate))
+}
 
-	let mut compiled_upload_input_schemas = HashMap::new();
-	if should_validate {
-		for (name, upload) in &uploads {
-			if let Ok(cs) = seam_server::compile_schema(&upload.input_schema) {
-				compiled_upload_input_schemas.insert(name.clone(), cs);
-			}
-		}
-	}
+pub(crate) fn build_router(manifest_json: serde_json::Value, mut parts: SeamParts) -> Router {
+	let dispatch = Dispatcher::from_parts(&mut parts);
+	let has_url_prefix = dispatch.has_url_prefix();
+	let trailing_slash = parts.trailing_slash;
+	let transport_config = &parts.transport_config;
+
+	let subscriptions: HashMap<_, _> =
+		parts.subscriptions.into_iter().map(|s| (s.name.clone(), Arc::new(s))).collect();
+	let streams: HashMap<_, _> =
+		parts.streams.into_iter().map(|s| (s.name.clone(), Arc::new(s))).collect();
+	let uploads: HashMap<_, _> =
+		parts.uploads.into_iter().map(|u| (u.name.clone(), Arc::new(u))).collect();
+	let raws: HashMap<_, _> = parts.raws.into_iter().map(|r| (r.name.clone(), Arc::new(r))).collect();
+	check_reserved_names("subscription", subscriptions.keys());
+	check_reserved_names("stream", streams.keys());
+	check_reserved_names("upload", uploads.keys());
+	check_reserved_names("raw procedure", raws.keys());
+
+	let should_validate = seam_server::should_validate(&parts.validation_mode);
+	let (sub_schemas, stream_schemas, upload_schemas, raw_schemas) = if should_validate {
+		(
+			compile_schemas(&subscriptions, |s| &s.input_schema),
+			compile_schemas(&streams, |s| &s.input_schema),
+			compile_schemas(&uploads, |u| &u.input_schema),
+			compile_schemas(&raws, |r| &r.input_schema),
+		)
+	} else {
+		Default::default()
+	};
 
 	// Build kind map for unified POST dispatcher
 	let mut kind_map = HashMap::new();
-	for (name, proc) in &handlers {
-		match proc.proc_type {
-			ProcedureType::Command => kind_map.insert(name.clone(), "command"),
-			ProcedureType::Query => kind_map.insert(name.clone(), "query"),
-		};
-	}
 	for name in streams.keys() {
 		kind_map.insert(name.clone(), "stream");
 	}
 	for name in uploads.keys() {
 		kind_map.insert(name.clone(), "upload");
 	}
+	for name in raws.keys() {
+		kind_map.insert(name.clone(), "raw");
+	}
 
 	let mut page_map = HashMap::new();
 	let mut router = Router::new()
@@ -285,50 +181,73 @@ pub(crate) fn build_router(
 			"/_seam/procedure/{name}",
 			post(rpc::handle_procedure_post).get(subscribe::handle_subscribe),
 		)
+		.route("/_seam/channel/{name}", get(channel::handle_channel))
 		.route("/_seam/data/{*path}", get(handle_page_data));
 
 	// Pages are served under /_seam/page/* prefix only.
-	for page in pages {
-		let full_route = format!("/_seam/page{}", page.route);
+	for page in parts.pages {
 		let page_arc = Arc::new(page);
-		page_map.insert(full_route.clone(), page_arc.clone());
-		router = router.route(&full_route, get(page::handle_page));
-
+		let mut full_routes = vec![format!("/_seam/page{}", page_arc.route)];
 		// Register locale-prefixed routes only when url_prefix strategy is active
 		if has_url_prefix {
-			let locale_route = format!("/_seam/page/{{_seam_locale}}{}", page_arc.route);
-			page_map.insert(locale_route.clone(), page_arc.clone());
-			router = router.route(&locale_route, get(page::handle_page));
+			full_routes.push(format!("/_seam/page/{{_seam_locale}}{}", page_arc.route));
+		}
+		for full_route in full_routes {
+			page_map.insert(full_route.clone(), page_arc.clone());
+			router = router.route(&full_route, get(page::handle_page));
+
+			let Some(slashed) = slashed_route(&full_route) else {
+				continue;
+			};
+			match trailing_slash {
+				TrailingSlash::Redirect => {
+					router = router.route(&slashed, get(page::redirect_trailing_slash));
+				}
+				TrailingSlash::Rewrite => {
+					page_map.insert(slashed.clone(), page_arc.clone());
+					router = router.route(&slashed, get(page::handle_page));
+				}
+				TrailingSlash::Strict => {}
+			}
 		}
 	}
 
 	let state = Arc::new(AppState {
 		manifest_json,
-		handlers,
+		dispatch,
 		subscriptions,
 		streams,
 		uploads,
+		raws,
 		pages: page_map,
-		rpc_hash_map,
-		batch_hash,
-		i18n_config,
-		locale_set,
-		strategies,
-		context_config,
 		should_validate,
-		compiled_input_schemas,
-		compiled_sub_input_schemas,
-		compiled_stream_input_schemas,
-		compiled_upload_input_schemas,
+		compiled_sub_input_schemas: sub_schemas,
+		compiled_stream_input_schemas: stream_schemas,
+		compiled_upload_input_schemas: upload_schemas,
+		compiled_raw_input_schemas: raw_schemas,
 		kind_map,
 		heartbeat_interval: transport_config.heartbeat_interval,
 		sse_idle_timeout: transport_config.sse_idle_timeout,
 		pong_timeout: transport_config.pong_timeout,
 	});
 
+	// Unmatched page URLs never reach a handler, so the 404 page needs the fallback
+	if state.dispatch.status_pages.not_found.is_some() {
+		router = router.fallback(page::handle_unmatched);
+	}
+
 	router.with_state(state)
 }
 
+/// Trailing-slash twin of a page route. None for the root page, whose path already ends
+/// in `/`, and for catch-all routes, which match the slash themselves.
+fn slashed_route(full_route: &str) -> Option<String> {
+	if full_route.ends_with('/') || full_route.contains("{*") {
+		return None;
+	}
+	Some(format!("{full_route}/"))
+}
+
 /// Serve __data.json for prerendered pages (SPA navigation).
 async fn handle_page_data(
 	axum::extract::State(state): axum::extract::State<Arc<AppState>>,
@@ -354,31 +273,3 @@ async fn handle_page_data(
 
 	Err(SeamError::not_found("Page data not found").into())
 }
-
-/// Look up pre-resolved messages by route hash + locale. Zero merge, zero filter.
-pub(super) fn lookup_i18n_messages(
-	i18n: &seam_server::I18nConfig,
-	route_hash: &str,
-	locale: &str,
-) -> serde_json::Value {
-	// Paged mode: read from disk
-	if i18n.mode == "paged" {
-		if let Some(ref dist_dir) = i18n.dist_dir {
-			let path = dist_dir.join("i18n").join(route_hash).join(format!("{locale}.json"));
-			if let Ok(content) = std::fs::read_to_string(&path)
-				&& let Ok(parsed) = serde_json::from_str::<serde_json::Value>(&content)
-			{
-				return parsed;
-			}
-		}
-		return serde_json::Value::Object(Default::default());
-	}
-
-	// Memory mode: direct lookup
-	i18n
-		.messages
-		.get(locale)
-		.and_then(|route_msgs| route_msgs.get(route_hash))
-		.cloned()
-		.unwrap_or(serde_json::Value::Object(Default::default()))
-}
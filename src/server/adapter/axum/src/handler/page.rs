@@ -3,289 +3,123 @@
 use std::collections::HashMap;
 use std::sync::Arc;
 
-use axum::extract::{MatchedPath, Path, State};
-use axum::response::Html;
-use seam_server::SeamError;
-use seam_server::context::resolve_context;
-use seam_server::page::PageDef;
-use tokio::task::JoinSet;
-
-use super::{AppState, extract_raw_context_from_req, lookup_i18n_messages};
+use axum::extract::{Extension, MatchedPath, Path, State};
+use axum::http::{Method, StatusCode, header};
+use axum::response::{Html, IntoResponse, Redirect, Response};
+use seam_server::dispatch::{
+	http_date, not_modified_since, read_prerendered, trailing_slash_location,
+};
+use seam_server::{CspNonce, SeamError};
+
+use super::{AppState, extract_raw_context_from_req};
 use crate::error::AxumError;
+use crate::trace::{self, Op};
 
-/// Resolve locale from request using the configured strategy chain.
-fn resolve_locale(
-	state: &AppState,
-	params: &mut HashMap<String, String>,
-	uri: &axum::http::Uri,
-	headers: &axum::http::HeaderMap,
-) -> Result<Option<String>, SeamError> {
-	let Some(ref locale_set) = state.locale_set else {
-		return Ok(None);
-	};
-
-	let extracted = params.remove("_seam_locale");
-	if let Some(ref loc) = extracted
-		&& !locale_set.contains(loc)
-	{
-		return Err(SeamError::not_found("Unknown locale"));
-	}
-
-	let i18n =
-		state.i18n_config.as_ref().ok_or_else(|| SeamError::internal("i18n_config missing"))?;
-	let url_str = uri.path_and_query().map(axum::http::uri::PathAndQuery::as_str).unwrap_or("");
-	let data = seam_server::ResolveData {
-		url: url_str,
-		path_locale: extracted.as_deref(),
-		cookie_header: headers.get(axum::http::header::COOKIE).and_then(|v| v.to_str().ok()),
-		accept_language: headers.get(axum::http::header::ACCEPT_LANGUAGE).and_then(|v| v.to_str().ok()),
-		locales: &i18n.locales,
-		default_locale: &i18n.default,
-	};
-	Ok(Some(seam_server::resolve_chain(&state.strategies, &data)))
+/// 308 from `/dashboard/` to `/dashboard`. The Location is the public URL: the
+/// application's fallback forwards page requests under `/_seam/page`.
+pub(super) async fn redirect_trailing_slash(uri: axum::http::Uri) -> Redirect {
+	Redirect::permanent(&trailing_slash_location(uri.path(), uri.query()))
 }
 
-struct LoaderOutput {
-	data: serde_json::Map<String, serde_json::Value>,
-	meta: serde_json::Map<String, serde_json::Value>,
-}
-
-/// Run page loaders concurrently and collect keyed results + metadata.
-/// Per-loader error boundary: each loader fails independently, producing an
-/// error marker instead of aborting the entire page.
-async fn run_loaders(
-	state: &Arc<AppState>,
-	page: &PageDef,
-	params: &HashMap<String, String>,
-	headers: &axum::http::HeaderMap,
-	uri: &axum::http::Uri,
-) -> Result<LoaderOutput, SeamError> {
-	let mut join_set = JoinSet::new();
-
-	// Extract raw context once — shared across all loaders
-	let raw_ctx = Arc::new(extract_raw_context_from_req(&state.context_config, headers, uri));
-
-	for loader in &page.loaders {
-		let input = (loader.input_fn)(params);
-		let proc_name = loader.procedure.clone();
-		let data_key = loader.data_key.clone();
-		let state = Arc::clone(state);
-		let raw_ctx = Arc::clone(&raw_ctx);
-
-		join_set.spawn(async move {
-			let result: Result<serde_json::Value, SeamError> = async {
-				let proc = state
-					.handlers
-					.get(&proc_name)
-					.ok_or_else(|| SeamError::internal(format!("Procedure '{proc_name}' not found")))?
-					.clone();
-
-				if state.should_validate
-					&& let Some(cs) = state.compiled_input_schemas.get(&proc_name)
-					&& let Err((msg, details)) = seam_server::validate_compiled(cs, &input)
-				{
-					let detail_json = details.iter().map(seam_server::ValidationDetail::to_json).collect();
-					return Err(SeamError::validation_detailed(
-						format!("Input validation failed for procedure '{proc_name}': {msg}"),
-						detail_json,
-					));
-				}
-
-				let ctx = if proc.context_keys.is_empty() {
-					serde_json::Value::Object(serde_json::Map::new())
-				} else {
-					resolve_context(&state.context_config, &raw_ctx, &proc.context_keys)?
-				};
-
-				(proc.handler)(input.clone(), ctx).await
-			}
-			.await;
-
-			(data_key, result, proc_name, input)
-		});
-	}
-
-	let mut data = serde_json::Map::new();
-	let mut meta = serde_json::Map::new();
-	while let Some(join_result) = join_set.join_next().await {
-		let (key, result, procedure, input) =
-			join_result.map_err(|e| SeamError::internal(e.to_string()))?; // JoinError (task panic) = infrastructure failure
-		match result {
-			Ok(value) => {
-				data.insert(key.clone(), value);
-				meta.insert(key, serde_json::json!({ "procedure": procedure, "input": input }));
-			}
-			Err(err) => {
-				data.insert(
-					key.clone(),
-					serde_json::json!({
-						"__error": true,
-						"code": err.code(),
-						"message": err.message(),
-					}),
-				);
-				meta.insert(
-					key,
-					serde_json::json!({ "procedure": procedure, "input": input, "error": true }),
-				);
-			}
-		}
-	}
-	Ok(LoaderOutput { data, meta })
+pub(super) async fn handle_page(
+	state: State<Arc<AppState>>,
+	matched: MatchedPath,
+	uri: axum::http::Uri,
+	headers: axum::http::HeaderMap,
+	params: Path<HashMap<String, String>>,
+	nonce: Option<Extension<CspNonce>>,
+) -> Result<Response, AxumError> {
+	let route_pattern = matched.as_str().to_string();
+	let path = uri.path().to_string();
+	let app = Arc::clone(&state.0);
+	let result = trace::traced(
+		Op::Page,
+		&route_pattern,
+		render_page(state, &route_pattern, uri, headers, params, nonce),
+	)
+	.await;
+	result.or_else(|AxumError(err)| status_page_response(&app, err, &path))
 }
 
-/// Build the client-side script data JSON, grouping layout-claimed keys under `_layouts`.
-fn build_script_data(
-	data: &serde_json::Map<String, serde_json::Value>,
-	page: &PageDef,
-) -> serde_json::Map<String, serde_json::Value> {
-	if page.layout_chain.is_empty() {
-		return data.clone();
-	}
-
-	let mut claimed_keys = std::collections::HashSet::new();
-	for entry in &page.layout_chain {
-		for key in &entry.loader_keys {
-			claimed_keys.insert(key.as_str());
-		}
-	}
-
-	// Page data = keys not claimed by any layout
-	let mut script_data = serde_json::Map::new();
-	for (k, v) in data {
-		if !claimed_keys.contains(k.as_str()) {
-			script_data.insert(k.clone(), v.clone());
-		}
-	}
-
-	// Per-layout _layouts grouping
-	let mut layouts_map = serde_json::Map::new();
-	for entry in &page.layout_chain {
-		let mut layout_data = serde_json::Map::new();
-		for key in &entry.loader_keys {
-			if let Some(v) = data.get(key) {
-				layout_data.insert(key.clone(), v.clone());
-			}
-		}
-		if !layout_data.is_empty() {
-			layouts_map.insert(entry.id.clone(), serde_json::Value::Object(layout_data));
-		}
-	}
-	if !layouts_map.is_empty() {
-		script_data.insert("_layouts".to_string(), serde_json::Value::Object(layouts_map));
+/// Router fallback, installed only with a 404 page: unknown `/_seam/page/*` URLs get
+/// the rendered template, everything else the bare 404 axum would have sent.
+pub(super) async fn handle_unmatched(
+	State(state): State<Arc<AppState>>,
+	method: Method,
+	uri: axum::http::Uri,
+) -> Response {
+	let path = uri.path();
+	let is_page = path == "/_seam/page" || path.starts_with("/_seam/page/");
+	if !is_page || (method != Method::GET && method != Method::HEAD) {
+		return StatusCode::NOT_FOUND.into_response();
 	}
-
-	script_data
+	let err = SeamError::not_found("Page not found");
+	status_page_response(&state, err, path).unwrap_or_else(IntoResponse::into_response)
 }
 
-/// Inject _i18n data into script_data for client hydration.
-fn inject_i18n_data(
-	script_data: &mut serde_json::Map<String, serde_json::Value>,
-	locale: &str,
-	i18n: &seam_server::I18nConfig,
-	route: &str,
-) {
-	let route_hash = i18n.route_hashes.get(route).cloned().unwrap_or_default();
-	let messages = lookup_i18n_messages(i18n, &route_hash, locale);
-
-	let mut i18n_data = serde_json::Map::new();
-	i18n_data.insert("locale".into(), serde_json::Value::String(locale.to_string()));
-	i18n_data.insert("messages".into(), messages);
-
-	if i18n.cache && !route_hash.is_empty() {
-		if let Some(hash) = i18n.content_hashes.get(&route_hash).and_then(|m| m.get(locale)) {
-			i18n_data.insert("hash".into(), serde_json::Value::String(hash.clone()));
-		}
-		if let Ok(router) = serde_json::to_value(&i18n.content_hashes) {
-			i18n_data.insert("router".into(), router);
-		}
-	}
-
-	script_data.insert("_i18n".into(), serde_json::Value::Object(i18n_data));
+/// Swap a page error for its registered template; see `Dispatcher::status_page`.
+fn status_page_response(
+	state: &AppState,
+	err: SeamError,
+	path: &str,
+) -> Result<Response, AxumError> {
+	let Some((status, html)) = state.dispatch.status_page(&err, path) else {
+		return Err(AxumError(err));
+	};
+	let status = StatusCode::from_u16(status).unwrap_or(StatusCode::INTERNAL_SERVER_ERROR);
+	Ok((status, Html(html)).into_response())
 }
 
-pub(super) async fn handle_page(
+async fn render_page(
 	State(state): State<Arc<AppState>>,
-	matched: MatchedPath,
+	route_pattern: &str,
 	uri: axum::http::Uri,
 	headers: axum::http::HeaderMap,
 	Path(mut params): Path<HashMap<String, String>>,
-) -> Result<Html<String>, AxumError> {
-	let route_pattern = matched.as_str().to_string();
+	nonce: Option<Extension<CspNonce>>,
+) -> Result<Response, AxumError> {
 	let page =
-		state.pages.get(&route_pattern).ok_or_else(|| SeamError::not_found("Page not found"))?;
+		state.pages.get(route_pattern).ok_or_else(|| SeamError::not_found("Page not found"))?;
 
 	// SSG short-circuit: serve pre-rendered HTML without loader execution
-	if page.prerender
-		&& let Some(ref static_dir) = page.static_dir
-	{
-		let route_path = uri.path().strip_prefix("/_seam/page").unwrap_or(uri.path());
-		let sub_path = if route_path == "/" { "" } else { route_path };
-		let html_path = static_dir.join(sub_path.trim_start_matches('/')).join("index.html");
-		if let Ok(html) = tokio::fs::read_to_string(&html_path).await {
-			return Ok(Html(html));
-		}
-		// Fall through to dynamic rendering (graceful degradation)
-	}
-
-	let locale = resolve_locale(&state, &mut params, &uri, &headers)?;
-
-	// Select locale-specific template (pre-resolved with layout chain)
-	let template = if let Some(ref loc) = locale {
-		page
-			.locale_templates
-			.as_ref()
-			.and_then(|lt| lt.get(loc))
-			.map(std::string::String::as_str)
-			.unwrap_or(&page.template)
-	} else {
-		&page.template
-	};
-
-	let loader_output = run_loaders(&state, page, &params, &headers, &uri).await?;
-	let mut data = loader_output.data;
-	let loader_meta = loader_output.meta;
-
-	// Prune to projected fields before template injection
-	super::projection::apply_projection(&mut data, &page.projections);
-
-	// Flatten keyed loader results for slot resolution: spread nested object
-	// values to the top level so slots like <!--seam:tagline--> can resolve from
-	// data like {page: {tagline: "..."}} (matching TS `flattenForSlots`).
-	let mut inject_map = data.clone();
-	for value in data.values() {
-		if let serde_json::Value::Object(nested) = value {
-			for (nk, nv) in nested {
-				inject_map.entry(nk.clone()).or_insert_with(|| nv.clone());
-			}
-		}
+	if let Some(html) = read_prerendered(page, uri.path()).await {
+		return Ok(Html(html).into_response());
 	}
-	let inject_data = serde_json::Value::Object(inject_map);
-	let mut html = seam_injector::inject_no_script(template, &inject_data);
-
-	let mut script_data = build_script_data(&data, page);
 
-	if let (Some(loc), Some(i18n)) = (&locale, &state.i18n_config) {
-		inject_i18n_data(&mut script_data, loc, i18n, &page.route);
+	let path_locale = params.remove("_seam_locale");
+	let header_str = |name: header::HeaderName| headers.get(name).and_then(|v| v.to_str().ok());
+	let locale = state.dispatch.page_locale(
+		path_locale.as_deref(),
+		uri.path_and_query().map(axum::http::uri::PathAndQuery::as_str).unwrap_or(""),
+		header_str(header::COOKIE),
+		header_str(header::ACCEPT_LANGUAGE),
+	)?;
+	trace::record_locale(locale.as_deref());
+
+	// A resolved locale or a per-request nonce would make a cached copy stale
+	let last_modified = page.last_modified().filter(|_| locale.is_none() && nonce.is_none());
+	if let Some(mtime) = last_modified
+		&& not_modified_since(
+			mtime,
+			headers.get(header::IF_MODIFIED_SINCE).and_then(|v| v.to_str().ok()),
+		) {
+		return Ok(
+			(StatusCode::NOT_MODIFIED, [(header::LAST_MODIFIED, http_date(mtime))]).into_response(),
+		);
 	}
 
-	if !loader_meta.is_empty() {
-		script_data.insert("__loaders".to_string(), serde_json::Value::Object(loader_meta));
-	}
+	let config = state.dispatch.procedures.context_config();
+	let raw_ctx = extract_raw_context_from_req(config, &headers, &uri);
+	let output = state.dispatch.run_loaders(page, &params, raw_ctx).await?;
+	let prepared = state.dispatch.prepare_page(page, output, locale.as_deref(), uri.query());
+	let script = prepared.data_script(page, nonce.as_ref().map(|Extension(n)| n.0.as_str()));
 
-	let json = serde_json::to_string(&serde_json::Value::Object(script_data)).unwrap_or_default();
-	let escaped = seam_server::ascii_escape_json(&json);
-	let script =
-		format!(r#"<script id="{}" type="application/json">{}</script>"#, page.data_id, escaped,);
-	if let Some(pos) = html.rfind("</body>") {
-		html.insert_str(pos, &script);
-	} else {
-		html.push_str(&script);
-	}
-
-	if let Some(ref loc) = locale {
-		html = html.replacen("<html", &format!("<html lang=\"{loc}\""), 1);
+	// Locale-specific template (pre-resolved with layout chain) is selected by the render task
+	let body = super::page_stream::stream_page(Arc::clone(page), locale, prepared, script);
+	let mut resp = ([(header::CONTENT_TYPE, "text/html; charset=utf-8")], body).into_response();
+	if let Some(value) = last_modified.and_then(|t| header::HeaderValue::from_str(&http_date(t)).ok())
+	{
+		resp.headers_mut().insert(header::LAST_MODIFIED, value);
 	}
-
-	Ok(Html(html))
+	Ok(resp)
 }
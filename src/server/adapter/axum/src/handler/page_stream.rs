@@ -0,0 +1,182 @@
+/* src/server/adapter/axum/src/handler/page_stream.rs */
+
+use std::io::{self, Write};
+use std::sync::Arc;
+
+use axum::body::{Body, Bytes};
+use seam_server::dispatch::PreparedPage;
+use seam_server::page::PageDef;
+use tokio::sync::mpsc;
+use tokio_stream::wrappers::ReceiverStream;
+
+/// Bytes accumulated before a chunk is sent to the response body.
+const CHUNK_SIZE: usize = 8 * 1024;
+
+/// In-flight chunks between the render task and the client.
+const CHANNEL_CAPACITY: usize = 16;
+
+/// Render a page template on a blocking task and stream the HTML as the response body.
+/// Uses the page's precompiled template when present. The locale becomes `lang` on the
+/// first `<html` tag and `script` lands before the last `</body>` of the output,
+/// matching the buffered `inject_html_lang` / `inject_data_script` placement.
+pub(super) fn stream_page(
+	page: Arc<PageDef>,
+	locale: Option<String>,
+	prepared: PreparedPage,
+	script: String,
+) -> Body {
+	let (tx, rx) = mpsc::channel::<io::Result<Bytes>>(CHANNEL_CAPACITY);
+
+	tokio::task::spawn_blocking(move || {
+		let mut writer = DocumentWriter::new(ChannelWriter::new(tx.clone()), locale.clone(), script);
+		let options = prepared.render_options(locale.as_deref());
+		let data = &prepared.inject_data;
+		let result = match page.compiled {
+			Some(ref compiled) => seam_injector::inject_precompiled_to_writer_with_options(
+				compiled.for_locale(locale.as_deref()),
+				data,
+				&options,
+				&mut writer,
+			),
+			None => {
+				let template = locale
+					.as_ref()
+					.and_then(|loc| page.locale_templates.as_ref().and_then(|lt| lt.get(loc)))
+					.unwrap_or(&page.template);
+				let compiled = seam_injector::compile(template);
+				seam_injector::inject_precompiled_to_writer_with_options(
+					&compiled,
+					data,
+					&options,
+					&mut writer,
+				)
+			}
+		};
+		if let Err(e) = result.and_then(|()| writer.finish()) {
+			// Client disconnect closes the channel; nothing left to report to
+			let _ = tx.blocking_send(Err(e));
+		}
+	});
+
+	Body::from_stream(ReceiverStream::new(rx))
+}
+
+/// Buffers writes into fixed-size chunks sent over the body channel.
+struct ChannelWriter {
+	tx: mpsc::Sender<io::Result<Bytes>>,
+	buf: Vec<u8>,
+}
+
+impl ChannelWriter {
+	fn new(tx: mpsc::Sender<io::Result<Bytes>>) -> Self {
+		Self { tx, buf: Vec::with_capacity(CHUNK_SIZE) }
+	}
+
+	fn send_buf(&mut self) -> io::Result<()> {
+		if self.buf.is_empty() {
+			return Ok(());
+		}
+		let chunk = Bytes::from(std::mem::replace(&mut self.buf, Vec::with_capacity(CHUNK_SIZE)));
+		self
+			.tx
+			.blocking_send(Ok(chunk))
+			.map_err(|_| io::Error::new(io::ErrorKind::BrokenPipe, "client disconnected"))
+	}
+}
+
+impl Write for ChannelWriter {
+	fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+		self.buf.extend_from_slice(buf);
+		if self.buf.len() >= CHUNK_SIZE {
+			self.send_buf()?;
+		}
+		Ok(buf.len())
+	}
+
+	fn flush(&mut self) -> io::Result<()> {
+		self.send_buf()
+	}
+}
+
+const HTML_OPEN: &[u8] = b"<html";
+const BODY_CLOSE: &[u8] = b"</body>";
+
+/// Applies `<html lang>` and inserts the data script before the last `</body>`
+/// without buffering the document. Output from the first `</body>` onward is held,
+/// since a later one may follow.
+struct DocumentWriter<W: Write> {
+	inner: W,
+	held: Vec<u8>,
+	/// Locale for the first `<html>` tag; cleared once applied.
+	lang: Option<String>,
+	script: String,
+}
+
+impl<W: Write> DocumentWriter<W> {
+	fn new(inner: W, lang: Option<String>, script: String) -> Self {
+		Self { inner, held: Vec::new(), lang, script }
+	}
+
+	/// Rewrite the first `<html ...>` tag in `held` once it is complete, or report
+	/// how many bytes are safe to emit while still waiting for it.
+	fn apply_lang(&mut self) -> Option<usize> {
+		let locale = self.lang.as_ref()?;
+		let Some(start) = find_bytes(&self.held, HTML_OPEN) else {
+			return Some(self.held.len().saturating_sub(HTML_OPEN.len() - 1));
+		};
+		let Some(len) = self.held[start..].iter().position(|&b| b == b'>') else {
+			return Some(start);
+		};
+		let end = start + len + 1;
+		// Tag spans `<` to `>`, so it is valid UTF-8 whenever the output is
+		if let Ok(tag) = std::str::from_utf8(&self.held[start..end]) {
+			let tag = seam_engine::inject_html_lang(tag, locale);
+			self.held.splice(start..end, tag.into_bytes());
+		}
+		self.lang = None;
+		None
+	}
+
+	fn finish(mut self) -> io::Result<()> {
+		match rfind_bytes(&self.held, BODY_CLOSE) {
+			Some(pos) => {
+				self.inner.write_all(&self.held[..pos])?;
+				self.inner.write_all(self.script.as_bytes())?;
+				self.inner.write_all(&self.held[pos..])?;
+			}
+			None => {
+				self.inner.write_all(&self.held)?;
+				self.inner.write_all(self.script.as_bytes())?;
+			}
+		}
+		self.inner.flush()
+	}
+}
+
+impl<W: Write> Write for DocumentWriter<W> {
+	fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+		self.held.extend_from_slice(buf);
+		// Keep a partial `<html` / `</body>` prefix in case it completes on the next write
+		let lang_limit = self.apply_lang().unwrap_or(usize::MAX);
+		let body_limit = find_bytes(&self.held, BODY_CLOSE)
+			.unwrap_or_else(|| self.held.len().saturating_sub(BODY_CLOSE.len() - 1));
+		let emit = lang_limit.min(body_limit);
+		if emit > 0 {
+			self.inner.write_all(&self.held[..emit])?;
+			self.held.drain(..emit);
+		}
+		Ok(buf.len())
+	}
+
+	fn flush(&mut self) -> io::Result<()> {
+		self.inner.flush()
+	}
+}
+
+fn find_bytes(haystack: &[u8], needle: &[u8]) -> Option<usize> {
+	haystack.windows(needle.len()).position(|w| w == needle)
+}
+
+fn rfind_bytes(haystack: &[u8], needle: &[u8]) -> Option<usize> {
+	haystack.windows(needle.len()).rposition(|w| w == needle)
+}
@@ -0,0 +1,50 @@
+/* src/server/adapter/axum/src/handler/raw.rs */
+
+use axum::http::{HeaderValue, header};
+use axum::response::{IntoResponse, Response};
+use seam_server::SeamError;
+
+use super::{AppState, resolve_ctx_for_proc};
+use crate::error::AxumError;
+use crate::trace::{self, Op};
+
+/// Handles a raw procedure — JSON input, response body and content type from the handler.
+pub(super) async fn handle_raw_inner(
+	state: &AppState,
+	name: &str,
+	headers: &axum::http::HeaderMap,
+	uri: &axum::http::Uri,
+	body: &[u8],
+) -> Result<Response, AxumError> {
+	trace::traced(Op::Rpc, name, async {
+		let raw = state
+			.raws
+			.get(name)
+			.ok_or_else(|| SeamError::not_found(format!("Raw procedure '{name}' not found")))?;
+
+		let input: serde_json::Value =
+			serde_json::from_slice(body).map_err(|e| SeamError::validation(e.to_string()))?;
+
+		if state.should_validate
+			&& let Some(cs) = state.compiled_raw_input_schemas.get(name)
+			&& let Err((msg, details)) = seam_server::validate_compiled(cs, &input)
+		{
+			let detail_json = details.iter().map(seam_server::ValidationDetail::to_json).collect();
+			return Err(
+				SeamError::validation_detailed(
+					format!("Input validation failed for procedure '{name}': {msg}"),
+					detail_json,
+				)
+				.into(),
+			);
+		}
+
+		let ctx = resolve_ctx_for_proc(state, &raw.context_keys, headers, uri)?;
+		let (content_type, bytes) = (raw.handler)(input, ctx).await?;
+		let content_type = HeaderValue::from_str(&content_type).map_err(|_| {
+			SeamError::internal(format!("Raw procedure '{name}' returned an invalid content type"))
+		})?;
+		Ok(([(header::CONTENT_TYPE, content_type)], bytes).into_response())
+	})
+	.await
+}
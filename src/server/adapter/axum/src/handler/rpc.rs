@@ -5,24 +5,22 @@ use std::sync::Arc;
 use axum::body::Bytes;
 use axum::extract::{Path, State};
 use axum::response::{IntoResponse, Response};
-use http_body_util::BodyExt;
+use http_body_util::{BodyExt, LengthLimitError, Limited};
 use seam_server::SeamError;
-use seam_server::context::resolve_context;
-use tokio::task::JoinSet;
+use seam_server::dispatch::{MAX_BODY_BYTES, body_too_large};
 
-use super::{AppState, extract_raw_context_from_req, resolve_ctx_for_proc};
+use super::{AppState, extract_raw_context_from_req};
 use crate::error::AxumError;
+use crate::trace::{self, Op};
 
 pub(super) async fn handle_manifest(
 	State(state): State<Arc<AppState>>,
 ) -> Result<impl IntoResponse, AxumError> {
-	if state.rpc_hash_map.is_some() {
-		return Err(SeamError::forbidden("Manifest disabled").into());
-	}
+	state.dispatch.check_manifest()?;
 	Ok(axum::Json(state.manifest_json.clone()))
 }
 
-/// Unified POST dispatcher — routes to RPC, stream, or upload based on kind_map.
+/// Unified POST dispatcher — routes to RPC, stream, upload, or raw based on kind_map.
 pub(super) async fn handle_procedure_post(
 	State(state): State<Arc<AppState>>,
 	Path(name): Path<String>,
@@ -31,24 +29,23 @@ pub(super) async fn handle_procedure_post(
 	let headers = req.headers().clone();
 	let uri = req.uri().clone();
 
-	// Collect body bytes
-	let body: Bytes =
-		req.into_body().collect().await.map_err(|e| SeamError::validation(e.to_string()))?.to_bytes();
+	// Collect body bytes; the raw `Request` extractor skips axum's `DefaultBodyLimit`
+	let body: Bytes = match Limited::new(req.into_body(), MAX_BODY_BYTES).collect().await {
+		Ok(collected) => collected.to_bytes(),
+		Err(e) if e.is::<LengthLimitError>() => return Err(body_too_large().into()),
+		Err(e) => return Err(SeamError::validation(e.to_string()).into()),
+	};
 
-	// Batch: match both original "_batch" and hashed batch endpoint
-	if name == "_batch" || state.batch_hash.as_deref() == Some(&name) {
-		return handle_batch(State(state), headers, &uri, body).await;
+	if state.dispatch.is_batch(&name) {
+		return handle_batch(&state, &headers, &uri, &name, &body).await;
 	}
-
-	// Resolve hash -> original name when obfuscation is active
-	let resolved = if let Some(ref map) = state.rpc_hash_map {
-		map.get(&name).cloned().ok_or_else(|| SeamError::not_found("Not found"))?
-	} else {
-		name.clone()
-	};
+	let resolved = state.dispatch.resolve_name(&name)?;
 
 	// Dispatch based on procedure kind
 	match state.kind_map.get(&resolved).copied() {
+		Some("stream") if super::stream::wants_ndjson(&headers) => {
+			return super::stream::handle_stream_ndjson(&state, &resolved, &headers, &uri, &body).await;
+		}
 		Some("stream") => {
 			return Ok(
 				super::stream::handle_stream_inner(&state, &resolved, &headers, &uri, &body)
@@ -59,6 +56,9 @@ pub(super) async fn handle_procedure_post(
 		Some("upload") => {
 			return super::upload::handle_upload_inner(&state, &resolved, &headers, &uri, body).await;
 		}
+		Some("raw") => {
+			return super::raw::handle_raw_inner(&state, &resolved, &headers, &uri, &body).await;
+		}
 		_ => {}
 	}
 
@@ -72,158 +72,24 @@ async fn handle_rpc_inner(
 	uri: &axum::http::Uri,
 	body: &[u8],
 ) -> Result<Response, AxumError> {
-	let proc = state
-		.handlers
-		.get(resolved)
-		.ok_or_else(|| SeamError::not_found(format!("Procedure '{resolved}' not found")))?;
-
-	let input: serde_json::Value =
-		serde_json::from_slice(body).map_err(|e| SeamError::validation(e.to_string()))?;
-
-	if state.should_validate
-		&& let Some(cs) = state.compiled_input_schemas.get(resolved)
-		&& let Err((msg, details)) = seam_server::validate_compiled(cs, &input)
-	{
-		let detail_json = details.iter().map(seam_server::ValidationDetail::to_json).collect();
-		return Err(
-			SeamError::validation_detailed(
-				format!("Input validation failed for procedure '{resolved}': {msg}"),
-				detail_json,
-			)
-			.into(),
-		);
-	}
-
-	let ctx = resolve_ctx_for_proc(state, &proc.context_keys, headers, uri)?;
-	let result = (proc.handler)(input, ctx).await?;
-	Ok(axum::Json(serde_json::json!({"ok": true, "data": result})).into_response())
-}
-
-#[derive(serde::Deserialize)]
-struct BatchRequest {
-	calls: Vec<BatchCall>,
-}
-
-#[derive(serde::Deserialize)]
-struct BatchCall {
-	procedure: String,
-	#[serde(default)]
-	input: serde_json::Value,
-}
-
-#[derive(serde::Serialize)]
-#[serde(untagged)]
-enum BatchResultItem {
-	Ok { ok: bool, data: serde_json::Value },
-	Err { ok: bool, error: BatchError },
-}
-
-#[derive(serde::Serialize)]
-struct BatchError {
-	code: String,
-	message: String,
-	transient: bool,
-	#[serde(skip_serializing_if = "Option::is_none")]
-	details: Option<Vec<serde_json::Value>>,
+	trace::traced(Op::Rpc, resolved, async {
+		let raw_ctx =
+			extract_raw_context_from_req(state.dispatch.procedures.context_config(), headers, uri);
+		let result = state.dispatch.procedures.call_json(resolved, body, &raw_ctx).await?;
+		Ok(axum::Json(serde_json::json!({"ok": true, "data": result})).into_response())
+	})
+	.await
 }
 
 async fn handle_batch(
-	State(state): State<Arc<AppState>>,
-	headers: axum::http::HeaderMap,
+	state: &AppState,
+	headers: &axum::http::HeaderMap,
 	uri: &axum::http::Uri,
-	body: Bytes,
+	name: &str,
+	body: &[u8],
 ) -> Result<Response, AxumError> {
-	let batch: BatchRequest = serde_json::from_slice(&body)
-		.map_err(|_| SeamError::validation("Batch request must have a 'calls' array"))?;
-
-	// Extract raw context once for all calls
-	let raw_ctx = Arc::new(extract_raw_context_from_req(&state.context_config, &headers, uri));
-
-	let mut join_set = JoinSet::new();
-	for (idx, call) in batch.calls.into_iter().enumerate() {
-		let state = state.clone();
-		let raw_ctx = raw_ctx.clone();
-		join_set.spawn(async move {
-			// Resolve hash -> original name
-			let proc_name = if let Some(ref map) = state.rpc_hash_map {
-				map.get(&call.procedure).cloned().unwrap_or(call.procedure)
-			} else {
-				call.procedure
-			};
-
-			let result = match state.handlers.get(&proc_name) {
-				Some(proc) => {
-					if state.should_validate
-						&& let Some(cs) = state.compiled_input_schemas.get(&proc_name)
-						&& let Err((msg, details)) = seam_server::validate_compiled(cs, &call.input)
-					{
-						let detail_json = details.iter().map(seam_server::ValidationDetail::to_json).collect();
-						return (
-							idx,
-							BatchResultItem::Err {
-								ok: false,
-								error: BatchError {
-									code: "VALIDATION_ERROR".to_string(),
-									message: format!("Input validation failed for procedure '{proc_name}': {msg}"),
-									transient: false,
-									details: Some(detail_json),
-								},
-							},
-						);
-					}
-
-					let ctx = match resolve_context(&state.context_config, &raw_ctx, &proc.context_keys) {
-						Ok(c) => c,
-						Err(e) => {
-							return (
-								idx,
-								BatchResultItem::Err {
-									ok: false,
-									error: BatchError {
-										code: e.code().to_string(),
-										message: e.message().to_string(),
-										transient: false,
-										details: e.details().map(<[serde_json::Value]>::to_vec),
-									},
-								},
-							);
-						}
-					};
-					match (proc.handler)(call.input, ctx).await {
-						Ok(data) => BatchResultItem::Ok { ok: true, data },
-						Err(e) => BatchResultItem::Err {
-							ok: false,
-							error: BatchError {
-								code: e.code().to_string(),
-								message: e.message().to_string(),
-								transient: false,
-								details: e.details().map(<[serde_json::Value]>::to_vec),
-							},
-						},
-					}
-				}
-				None => BatchResultItem::Err {
-					ok: false,
-					error: BatchError {
-						code: "NOT_FOUND".to_string(),
-						message: format!("Procedure '{proc_name}' not found"),
-						transient: false,
-						details: None,
-					},
-				},
-			};
-			(idx, result)
-		});
-	}
-
-	// Collect results preserving original order
-	let mut indexed: Vec<(usize, BatchResultItem)> = Vec::new();
-	while let Some(result) = join_set.join_next().await {
-		let (idx, item) = result.map_err(|e| SeamError::internal(e.to_string()))?;
-		indexed.push((idx, item));
-	}
-	indexed.sort_by_key(|(i, _)| *i);
-	let results: Vec<BatchResultItem> = indexed.into_iter().map(|(_, item)| item).collect();
-
-	Ok(axum::Json(serde_json::json!({ "ok": true, "data": { "results": results } })).into_response())
+	let raw_ctx =
+		extract_raw_context_from_req(state.dispatch.procedures.context_config(), headers, uri);
+	let data = state.dispatch.call_procedure(name, body, raw_ctx).await?;
+	Ok(axum::Json(serde_json::json!({ "ok": true, "data": data })).into_response())
 }
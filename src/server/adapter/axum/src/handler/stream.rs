@@ -3,47 +3,79 @@
 use std::convert::Infallible;
 use std::pin::Pin;
 
+use axum::body::Body;
+use axum::http::{HeaderMap, HeaderValue, header};
 use axum::response::sse::{Event, Sse};
+use axum::response::{IntoResponse, Response};
 use futures_core::Stream;
-use seam_server::SeamError;
+use seam_server::{BoxStream, SeamError};
 use tokio_stream::StreamExt;
 
 use super::{AppState, resolve_ctx_for_proc};
+use crate::error::AxumError;
 
-/// Handles a stream procedure — SSE with incrementing `id` on data events.
-pub(super) async fn handle_stream_inner(
+const NDJSON: &str = "application/x-ndjson";
+
+/// Whether the client asked for newline-delimited JSON instead of SSE: `Accept` lists
+/// the NDJSON media range without `q=0`. Wildcards keep the SSE default.
+pub(super) fn wants_ndjson(headers: &HeaderMap) -> bool {
+	let Some(accept) = headers.get(header::ACCEPT).and_then(|v| v.to_str().ok()) else {
+		return false;
+	};
+	accept.split(',').any(|range| {
+		let mut parts = range.split(';');
+		let media = parts.next().unwrap_or_default().trim();
+		media.eq_ignore_ascii_case(NDJSON) && !parts.any(is_q_zero)
+	})
+}
+
+/// A `q=0` parameter marks the media range as not acceptable.
+fn is_q_zero(param: &str) -> bool {
+	param.split_once('=').is_some_and(|(name, value)| {
+		name.trim().eq_ignore_ascii_case("q") && value.trim().parse::<f32>().is_ok_and(|q| q <= 0.0)
+	})
+}
+
+/// Validate input, resolve context, and start the handler's stream.
+async fn open_stream(
 	state: &AppState,
 	name: &str,
-	headers: &axum::http::HeaderMap,
+	headers: &HeaderMap,
 	uri: &axum::http::Uri,
 	body: &[u8],
-) -> Sse<Pin<Box<dyn Stream<Item = Result<Event, Infallible>> + Send>>> {
-	let setup = async {
-		let stream = state
-			.streams
-			.get(name)
-			.ok_or_else(|| SeamError::not_found(format!("Stream '{name}' not found")))?;
+) -> Result<BoxStream<Result<serde_json::Value, SeamError>>, SeamError> {
+	let stream = state
+		.streams
+		.get(name)
+		.ok_or_else(|| SeamError::not_found(format!("Stream '{name}' not found")))?;
 
-		let input: serde_json::Value =
-			serde_json::from_slice(body).map_err(|e| SeamError::validation(e.to_string()))?;
+	let input: serde_json::Value =
+		serde_json::from_slice(body).map_err(|e| SeamError::validation(e.to_string()))?;
 
-		if state.should_validate
-			&& let Some(cs) = state.compiled_stream_input_schemas.get(name)
-			&& let Err((msg, details)) = seam_server::validate_compiled(cs, &input)
-		{
-			let detail_json = details.iter().map(seam_server::ValidationDetail::to_json).collect();
-			return Err(SeamError::validation_detailed(
-				format!("Input validation failed for stream '{name}': {msg}"),
-				detail_json,
-			));
-		}
+	if state.should_validate
+		&& let Some(cs) = state.compiled_stream_input_schemas.get(name)
+		&& let Err((msg, details)) = seam_server::validate_compiled(cs, &input)
+	{
+		let detail_json = details.iter().map(seam_server::ValidationDetail::to_json).collect();
+		return Err(SeamError::validation_detailed(
+			format!("Input validation failed for stream '{name}': {msg}"),
+			detail_json,
+		));
+	}
 
-		let ctx = resolve_ctx_for_proc(state, &stream.context_keys, headers, uri)?;
-		let data_stream = (stream.handler)(seam_server::StreamParams { input, ctx }).await?;
-		Ok::<_, SeamError>(data_stream)
-	};
+	let ctx = resolve_ctx_for_proc(state, &stream.context_keys, headers, uri)?;
+	(stream.handler)(seam_server::StreamParams { input, ctx }).await
+}
 
-	match setup.await {
+/// Handles a stream procedure — SSE with incrementing `id` on data events.
+pub(super) async fn handle_stream_inner(
+	state: &AppState,
+	name: &str,
+	headers: &HeaderMap,
+	uri: &axum::http::Uri,
+	body: &[u8],
+) -> Sse<Pin<Box<dyn Stream<Item = Result<Event, Infallible>> + Send>>> {
+	match open_stream(state, name, headers, uri, body).await {
 		Ok(data_stream) => {
 			let mut seq: u64 = 0;
 			let event_stream = data_stream.map(move |item| {
@@ -83,3 +115,34 @@ pub(super) async fn handle_stream_inner(
 		}
 	}
 }
+
+/// Stream procedure as `application/x-ndjson`: one JSON value per line, for clients that
+/// want to consume large exports without buffering or parsing SSE. Setup errors use the
+/// JSON error envelope; an error mid-stream is written as a final `{"error": ...}` line.
+pub(super) async fn handle_stream_ndjson(
+	state: &AppState,
+	name: &str,
+	headers: &HeaderMap,
+	uri: &axum::http::Uri,
+	body: &[u8],
+) -> Result<Response, AxumError> {
+	let data_stream = open_stream(state, name, headers, uri, body).await?;
+	let mut failed = false;
+	let lines = data_stream.map_while(move |item| {
+		if failed {
+			return None;
+		}
+		let mut line = match item {
+			Ok(value) => serde_json::to_string(&value).unwrap_or_default(),
+			Err(e) => {
+				failed = true;
+				serde_json::json!({ "error": { "code": e.code(), "message": e.message() } }).to_string()
+			}
+		};
+		line.push('\n');
+		Some(Ok::<_, Infallible>(line))
+	});
+	let mut resp = Body::from_stream(lines).into_response();
+	resp.headers_mut().insert(header::CONTENT_TYPE, HeaderValue::from_static(NDJSON));
+	Ok(resp)
+}
@@ -10,14 +10,16 @@ use axum::response::sse::{Event, Sse};
 use axum::response::{IntoResponse, Response};
 use futures_core::Stream;
 use seam_server::SeamError;
+use seam_server::dispatch::buffer_events;
 use tokio_stream::StreamExt;
 
-use super::channel::handle_channel_ws;
+use super::channel::upgrade_channel;
 use super::{AppState, resolve_ctx_for_proc};
+use crate::trace::{self, Op};
 
 #[derive(serde::Deserialize)]
 pub(super) struct SubscribeQuery {
-	input: Option<String>,
+	pub(super) input: Option<String>,
 }
 
 pub(super) async fn handle_subscribe(
@@ -39,31 +41,9 @@ pub(super) async fn handle_subscribe(
 		let (mut parts, _body) = req.into_parts();
 		if let Ok(ws) = WebSocketUpgrade::from_request_parts(&mut parts, &state).await {
 			// Resolve hash -> original subscription name (e.g. "chat.events")
-			let sub_name = if let Some(ref map) = state.rpc_hash_map {
-				map.get(&name).cloned().unwrap_or(name.clone())
-			} else {
-				name.clone()
-			};
+			let sub_name = state.dispatch.resolve_name(&name).unwrap_or_else(|_| name.clone());
 
-			let raw_input = match &query.input {
-				Some(s) => match serde_json::from_str(s) {
-					Ok(v) => v,
-					Err(e) => {
-						let payload = serde_json::json!({
-							"ok": false,
-							"error": { "code": "VALIDATION_ERROR", "message": e.to_string(), "transient": false }
-						});
-						return axum::Json(payload).into_response();
-					}
-				},
-				None => serde_json::Value::Object(serde_json::Map::new()),
-			};
-
-			return ws
-				.on_upgrade(move |socket| {
-					handle_channel_ws(state, sub_name, raw_input, headers, uri, socket)
-				})
-				.into_response();
+			return upgrade_channel(state, sub_name, query.input.as_deref(), headers, uri, ws);
 		}
 	}
 
@@ -81,11 +61,7 @@ async fn handle_subscribe_sse(
 ) -> Sse<Pin<Box<dyn Stream<Item = Result<Event, Infallible>> + Send>>> {
 	let setup = async {
 		// Resolve hash -> original name for subscriptions
-		let resolved = if let Some(ref map) = state.rpc_hash_map {
-			map.get(&name).cloned().ok_or_else(|| SeamError::not_found("Not found"))?
-		} else {
-			name.clone()
-		};
+		let resolved = state.dispatch.resolve_name(&name)?;
 
 		let sub = state
 			.subscriptions
@@ -114,10 +90,10 @@ async fn handle_subscribe_sse(
 		let data_stream =
 			(sub.handler)(seam_server::SubscriptionParams { input: raw_input, ctx, last_event_id })
 				.await?;
-		Ok::<_, SeamError>(data_stream)
+		Ok::<_, SeamError>(buffer_events(data_stream, sub.buffer))
 	};
 
-	match setup.await {
+	match trace::traced(Op::Subscribe, &name, setup).await {
 		Ok(data_stream) => {
 			let mut seq: u64 = 0;
 			let event_stream = data_stream.map(move |item| {
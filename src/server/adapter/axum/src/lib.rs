@@ -3,8 +3,7 @@
 
 mod error;
 mod handler;
-
-use std::sync::Arc;
+mod trace;
 
 use seam_server::SeamServer;
 use seam_server::manifest::build_manifest;
@@ -24,36 +23,22 @@ pub trait IntoAxumRouter {
 
 impl IntoAxumRouter for SeamServer {
 	fn into_axum_router(self) -> axum::Router {
-		let parts = self.into_parts();
+		let mut parts = self.into_parts();
 		let public_dir = parts.public_dir.clone();
+		let compression = parts.compression;
 		let manifest_json = serde_json::to_value(build_manifest(
 			&parts.procedures,
 			&parts.subscriptions,
 			&parts.streams,
 			&parts.uploads,
-			parts.channel_metas,
+			&parts.raws,
+			std::mem::take(&mut parts.channel_metas),
 			&parts.context_config,
 		))
 		.expect("manifest serialization");
-		let handlers = parts.procedures.into_iter().map(|p| (p.name.clone(), Arc::new(p))).collect();
-		let subscriptions =
-			parts.subscriptions.into_iter().map(|s| (s.name.clone(), Arc::new(s))).collect();
-		let streams = parts.streams.into_iter().map(|s| (s.name.clone(), Arc::new(s))).collect();
-		let uploads = parts.uploads.into_iter().map(|u| (u.name.clone(), Arc::new(u))).collect();
-		let router = handler::build_router(
-			manifest_json,
-			handlers,
-			subscriptions,
-			streams,
-			uploads,
-			parts.pages,
-			parts.rpc_hash_map,
-			parts.i18n_config,
-			parts.strategies,
-			parts.context_config,
-			&parts.validation_mode,
-			&parts.transport_config,
-		);
+		let router = handler::build_router(manifest_json, parts);
+		// Applied before public files so only /_seam/* routes are compressed
+		let router = if compression { handler::with_compression(router) } else { router };
 		if let Some(public_dir) = public_dir {
 			handler::with_public_files(router, public_dir)
 		} else {
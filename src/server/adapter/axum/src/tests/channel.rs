@@ -2,7 +2,7 @@
 
 use super::*;
 use seam_server::procedure::{BoxStream, ProcedureDef, ProcedureType, SubscriptionDef};
-use seam_server::{SeamError, TransportConfig};
+use seam_server::{ChannelDef, IncomingDef, SeamError, TransportConfig};
 use std::time::Duration;
 use tokio::net::TcpListener;
 
@@ -16,6 +16,7 @@ fn channel_router() -> axum::Router {
 			error_schema: None,
 			context_keys: vec![],
 			suppress: None,
+			buffer: None,
 			handler: Arc::new(|_params| {
 				Box::pin(async move {
 					let stream: BoxStream<Result<serde_json::Value, SeamError>> =
@@ -36,6 +37,7 @@ fn channel_router() -> axum::Router {
 			context_keys: vec![],
 			suppress: None,
 			cache: None,
+			timeout: None,
 			handler: Arc::new(|_input, _ctx| {
 				Box::pin(async move { Ok(serde_json::json!({"ok": true})) })
 			}),
@@ -54,6 +56,7 @@ fn uplink_router() -> axum::Router {
 			error_schema: None,
 			context_keys: vec![],
 			suppress: None,
+			buffer: None,
 			handler: Arc::new(|_params| {
 				Box::pin(async move {
 					// Keep the stream alive long enough for uplink tests
@@ -77,6 +80,7 @@ fn uplink_router() -> axum::Router {
 			context_keys: vec![],
 			suppress: None,
 			cache: None,
+			timeout: None,
 			handler: Arc::new(|_input, _ctx| {
 				Box::pin(async move { Ok(serde_json::json!({"ok": true})) })
 			}),
@@ -90,6 +94,7 @@ fn heartbeat_router(interval: Duration) -> axum::Router {
 			heartbeat_interval: interval,
 			sse_idle_timeout: Duration::from_secs(12),
 			pong_timeout: Duration::from_secs(5),
+			procedure_timeout: Duration::from_secs(30),
 		})
 		.subscription(SubscriptionDef {
 			name: "chat.events".into(),
@@ -98,6 +103,7 @@ fn heartbeat_router(interval: Duration) -> axum::Router {
 			error_schema: None,
 			context_keys: vec![],
 			suppress: None,
+			buffer: None,
 			handler: Arc::new(|_params| {
 				Box::pin(async move {
 					// Keep the stream alive so heartbeat has time to fire
@@ -182,6 +188,34 @@ async fn sse_subscription_starts_with_heartbeat() {
 	assert!(body.starts_with(": heartbeat\n\n"), "missing initial heartbeat in:\n{body}");
 }
 
+#[tokio::test]
+async fn sse_subscription_heartbeats_while_idle() {
+	// Data arrives after 500ms; a 100ms interval must keep the idle stream alive meanwhile
+	let router = heartbeat_router(Duration::from_millis(100));
+	let req = Request::builder()
+		.method("GET")
+		.uri("/_seam/procedure/chat.events")
+		.body(Body::empty())
+		.unwrap();
+	let (_, body) = send_raw_request(router, req).await;
+	let before_data = &body[..body.find("event: data").unwrap()];
+	assert!(before_data.matches(": heartbeat\n\n").count() >= 3, "too few heartbeats in:\n{body}");
+	assert!(!body.contains("data: heartbeat"), "heartbeat leaked as data in:\n{body}");
+}
+
+#[tokio::test]
+async fn sse_subscription_zero_interval_disables_heartbeat() {
+	let router = heartbeat_router(Duration::ZERO);
+	let req = Request::builder()
+		.method("GET")
+		.uri("/_seam/procedure/chat.events")
+		.body(Body::empty())
+		.unwrap();
+	let (_, body) = send_raw_request(router, req).await;
+	assert!(!body.contains("heartbeat"), "unexpected heartbeat in:\n{body}");
+	assert!(body.contains("event: data\n"), "missing data event in:\n{body}");
+}
+
 // --- WebSocket channel tests ---
 
 #[tokio::test]
@@ -419,3 +453,96 @@ async fn ws_channel_unknown_subscription() {
 
 	handle.abort();
 }
+
+// --- /_seam/channel/{name} route tests ---
+
+/// Router with a `ChannelDef` whose `echo` message returns its text; the outgoing stream
+/// stays open so uplink responses can arrive
+fn channel_def_router() -> axum::Router {
+	let server = SeamServer::new().channel(ChannelDef {
+		name: "chat".into(),
+		input_schema: serde_json::json!({"properties": {"roomId": {"type": "string"}}}),
+		incoming: vec![(
+			"echo".into(),
+			IncomingDef {
+				input_schema: serde_json::json!({"properties": {"text": {"type": "string"}}}),
+				output_schema: serde_json::json!({"properties": {"text": {"type": "string"}}}),
+				error_schema: None,
+				handler: Arc::new(|input, _ctx| {
+					Box::pin(async move {
+						Ok(serde_json::json!({"text": input["text"], "roomId": input["roomId"]}))
+					})
+				}),
+			},
+		)],
+		outgoing: vec![("message".into(), serde_json::json!({}))],
+		subscribe_handler: Arc::new(|_params| {
+			Box::pin(async move {
+				let (tx, rx) = tokio::sync::mpsc::channel(8);
+				tokio::spawn(async move {
+					tokio::time::sleep(Duration::from_secs(10)).await;
+					drop(tx);
+				});
+				let stream: BoxStream<Result<serde_json::Value, SeamError>> =
+					Box::pin(tokio_stream::wrappers::ReceiverStream::new(rx));
+				Ok(stream)
+			})
+		}),
+	});
+	server.into_axum_router()
+}
+
+/// Send one uplink message and wait up to 5s for the response carrying its id.
+async fn uplink_roundtrip(url: &str, uplink: serde_json::Value) -> serde_json::Value {
+	use futures_util::{SinkExt, StreamExt};
+	use tokio_tungstenite::tungstenite::Message as WsMessage;
+
+	let (mut ws, _) = tokio_tungstenite::connect_async(url).await.unwrap();
+	let id = uplink["id"].clone();
+	ws.send(WsMessage::Text(uplink.to_string().into())).await.unwrap();
+	let read = async {
+		while let Some(Ok(msg)) = ws.next().await {
+			if let WsMessage::Text(text) = msg {
+				let v: serde_json::Value = serde_json::from_str(&text).unwrap();
+				if v["id"] == id {
+					return v;
+				}
+			}
+		}
+		panic!("socket closed before response {id}");
+	};
+	tokio::time::timeout(Duration::from_secs(5), read).await.expect("uplink response timed out")
+}
+
+#[tokio::test]
+async fn channel_route_requires_websocket_upgrade() {
+	let req = Request::builder().uri("/_seam/channel/chat").body(Body::empty()).unwrap();
+	let (status, _) = send_raw_request(channel_def_router(), req).await;
+	// The route exists; a plain GET is rejected by the upgrade extractor, not the router
+	assert_ne!(status, StatusCode::NOT_FOUND);
+	assert!(status.is_client_error());
+}
+
+#[tokio::test]
+async fn channel_route_echoes_incoming_message() {
+	let (port, handle) = spawn_server(channel_def_router()).await;
+	let url = format!("ws://127.0.0.1:{port}/_seam/channel/chat?input=%7B%22roomId%22%3A%22r1%22%7D");
+	let uplink = serde_json::json!({"id": "1", "procedure": "chat.echo", "input": {"text": "hi"}});
+	let resp = uplink_roundtrip(&url, uplink).await;
+	assert_eq!(
+		resp,
+		serde_json::json!({"id": "1", "ok": true, "data": {"text": "hi", "roomId": "r1"}})
+	);
+	handle.abort();
+}
+
+#[tokio::test]
+async fn channel_route_validates_incoming_input() {
+	let (port, handle) = spawn_server(channel_def_router()).await;
+	let url = format!("ws://127.0.0.1:{port}/_seam/channel/chat?input=%7B%22roomId%22%3A%22r1%22%7D");
+	let uplink = serde_json::json!({"id": "2", "procedure": "chat.echo", "input": {"text": 42}});
+	let resp = uplink_roundtrip(&url, uplink).await;
+	assert_eq!(resp["ok"], false);
+	assert_eq!(resp["error"]["code"], "VALIDATION_ERROR");
+	handle.abort();
+}
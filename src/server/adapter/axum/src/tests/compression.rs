@@ -0,0 +1,140 @@
+/* src/server/adapter/axum/src/tests/compression.rs */
+
+use std::sync::Mutex;
+use std::time::Duration;
+
+use super::*;
+use seam_server::SeamError;
+use seam_server::procedure::{BoxStream, StreamDef};
+use tokio::sync::mpsc;
+use tokio_stream::StreamExt;
+use tokio_stream::wrappers::UnboundedReceiverStream;
+
+fn noop_procedure(name: String) -> ProcedureDef {
+	ProcedureDef {
+		name,
+		proc_type: ProcedureType::Query,
+		input_schema: serde_json::json!({"properties": {"id": {"type": "string"}}}),
+		output_schema: serde_json::json!({"properties": {"value": {"type": "string"}}}),
+		error_schema: None,
+		context_keys: vec![],
+		suppress: None,
+		cache: None,
+		timeout: None,
+		handler: Arc::new(|_input, _ctx| Box::pin(async move { Ok(serde_json::json!({})) })),
+	}
+}
+
+fn large_manifest_server() -> SeamServer {
+	(0..64).fold(SeamServer::new(), |server, i| server.procedure(noop_procedure(format!("proc{i}"))))
+}
+
+fn manifest_request() -> Request<Body> {
+	Request::builder()
+		.method("GET")
+		.uri("/_seam/manifest.json")
+		.header("accept-encoding", "gzip")
+		.body(Body::empty())
+		.unwrap()
+}
+
+#[tokio::test]
+async fn compression_gzips_large_manifest() {
+	let router = large_manifest_server().compression(true).into_axum_router();
+	let resp = router.oneshot(manifest_request()).await.unwrap();
+	assert_eq!(resp.status(), StatusCode::OK);
+	assert_eq!(resp.headers().get("content-encoding").unwrap(), "gzip");
+	let bytes = resp.into_body().collect().await.unwrap().to_bytes();
+	// gzip magic number
+	assert_eq!(&bytes[..2], &[0x1f, 0x8b]);
+}
+
+#[tokio::test]
+async fn compression_disabled_by_default() {
+	let router = large_manifest_server().into_axum_router();
+	let resp = router.oneshot(manifest_request()).await.unwrap();
+	assert_eq!(resp.status(), StatusCode::OK);
+	assert!(resp.headers().get("content-encoding").is_none());
+}
+
+#[tokio::test]
+async fn compression_skips_sse_streams() {
+	let router = SeamServer::new()
+		.compression(true)
+		.stream(StreamDef {
+			name: "ticks".into(),
+			input_schema: serde_json::json!({}),
+			chunk_output_schema: serde_json::json!({"type": "int32"}),
+			error_schema: None,
+			context_keys: vec![],
+			suppress: None,
+			handler: Arc::new(|_params| {
+				Box::pin(async move {
+					let stream: BoxStream<Result<serde_json::Value, SeamError>> =
+						Box::pin(futures_util::stream::iter((0..3).map(|i| Ok(serde_json::json!(i)))));
+					Ok(stream)
+				})
+			}),
+		})
+		.into_axum_router();
+	let req = Request::builder()
+		.method("POST")
+		.uri("/_seam/procedure/ticks")
+		.header("content-type", "application/json")
+		.header("accept-encoding", "gzip")
+		.body(Body::from("{}"))
+		.unwrap();
+	let resp = router.oneshot(req).await.unwrap();
+	assert_eq!(resp.headers().get("content-type").unwrap(), "text/event-stream");
+	assert!(resp.headers().get("content-encoding").is_none());
+}
+
+#[tokio::test]
+async fn compression_skips_ndjson_streams() {
+	let (tx, rx) = mpsc::unbounded_channel::<serde_json::Value>();
+	let rx = Mutex::new(Some(rx));
+	let router = SeamServer::new()
+		.compression(true)
+		.stream(StreamDef {
+			name: "ticks".into(),
+			input_schema: serde_json::json!({}),
+			chunk_output_schema: serde_json::json!({"type": "int32"}),
+			error_schema: None,
+			context_keys: vec![],
+			suppress: None,
+			handler: Arc::new(move |_params| {
+				let rx = rx.lock().unwrap().take().expect("stream called once");
+				Box::pin(async move {
+					let stream: BoxStream<Result<serde_json::Value, SeamError>> =
+						Box::pin(UnboundedReceiverStream::new(rx).map(Ok));
+					Ok(stream)
+				})
+			}),
+		})
+		.into_axum_router();
+	let req = Request::builder()
+		.method("POST")
+		.uri("/_seam/procedure/ticks")
+		.header("content-type", "application/json")
+		.header("accept", "application/x-ndjson")
+		.header("accept-encoding", "gzip")
+		.body(Body::from("{}"))
+		.unwrap();
+	let resp = router.oneshot(req).await.unwrap();
+	assert_eq!(resp.headers().get("content-type").unwrap(), "application/x-ndjson");
+	assert!(resp.headers().get("content-encoding").is_none());
+
+	// Each line reaches the client while the stream is still open
+	let mut body = resp.into_body();
+	for i in 0..2 {
+		tx.send(serde_json::json!(i)).unwrap();
+		let frame = tokio::time::timeout(Duration::from_secs(5), body.frame())
+			.await
+			.expect("line was buffered")
+			.unwrap()
+			.unwrap();
+		assert_eq!(frame.into_data().unwrap(), format!("{i}\n"));
+	}
+	drop(tx);
+	assert!(body.collect().await.unwrap().to_bytes().is_empty());
+}
@@ -1,10 +1,17 @@
 /* src/server/adapter/axum/src/tests/mod.rs */
 
 mod channel;
+mod compression;
+mod page;
+mod raw;
 mod rpc;
 mod stream;
+#[cfg(feature = "tracing")]
+mod trace;
 mod upload;
 
+use std::sync::Arc;
+
 use super::*;
 use axum::body::Body;
 use axum::http::{Request, StatusCode};
@@ -23,6 +30,7 @@ fn test_router() -> axum::Router {
 		context_keys: vec![],
 		suppress: None,
 		cache: None,
+		timeout: None,
 		handler: Arc::new(|input, _ctx| {
 			Box::pin(async move {
 				let name = input.get("name").and_then(|v| v.as_str()).unwrap_or("World");
@@ -39,6 +47,7 @@ fn test_router() -> axum::Router {
 		context_keys: vec![],
 		suppress: None,
 		cache: None,
+		timeout: None,
 		handler: Arc::new(|_input, _ctx| Box::pin(async move { Ok(serde_json::json!({"ok": true})) })),
 	});
 	server.into_axum_router()
@@ -0,0 +1,376 @@
+/* src/server/adapter/axum/src/tests/page.rs */
+
+use super::*;
+use std::collections::HashMap;
+
+use seam_server::page::{LoaderDef, PageDef};
+
+fn page_def(route: &str, template: &str, loaders: Vec<LoaderDef>) -> PageDef {
+	PageDef {
+		route: route.into(),
+		template: template.into(),
+		locale_templates: None,
+		loaders,
+		data_id: "__data".into(),
+		layout_chain: vec![],
+		page_loader_keys: vec![],
+		i18n_keys: vec![],
+		projections: None,
+		pagination: None,
+		prerender: false,
+		static_dir: None,
+		template_mtime: None,
+		compiled: None,
+	}
+}
+
+fn items_loader() -> LoaderDef {
+	LoaderDef {
+		data_key: "list".into(),
+		procedure: "listItems".into(),
+		input_fn: Arc::new(|_params| serde_json::json!({})),
+	}
+}
+
+fn items_server(template: &str) -> SeamServer {
+	items_server_for(page_def("/items", template, vec![items_loader()]))
+}
+
+fn items_server_for(page: PageDef) -> SeamServer {
+	SeamServer::new()
+		.procedure(ProcedureDef {
+			name: "listItems".into(),
+			proc_type: ProcedureType::Query,
+			input_schema: serde_json::json!({}),
+			output_schema: serde_json::json!({}),
+			error_schema: None,
+			context_keys: vec![],
+			suppress: None,
+			cache: None,
+			timeout: None,
+			handler: Arc::new(|_input, _ctx| {
+				Box::pin(async move {
+					let items: Vec<_> = (0..500).map(|i| serde_json::json!({"n": i})).collect();
+					Ok(serde_json::json!({"items": items}))
+				})
+			}),
+		})
+		.page(page)
+}
+
+async fn get_page(router: axum::Router, path: &str) -> (StatusCode, String) {
+	let req = Request::builder().method("GET").uri(path).body(Body::empty()).unwrap();
+	send_raw_request(router, req).await
+}
+
+#[tokio::test]
+async fn page_streams_large_each_identical_to_buffered() {
+	let template = concat!(
+		"<!DOCTYPE html><html><head></head><body><ul>",
+		"<!--seam:each:items--><!--seam:$.n:attr:data-n--><li><!--seam:$.n--></li><!--seam:endeach-->",
+		"</ul></body></html>"
+	);
+	let (status, html) =
+		get_page(items_server(template).into_axum_router(), "/_seam/page/items").await;
+	assert_eq!(status, StatusCode::OK);
+
+	let items: Vec<_> = (0..500).map(|i| serde_json::json!({"n": i})).collect();
+	let expected = seam_injector::inject_no_script(template, &serde_json::json!({"items": items}));
+	let script_pos = html.find(r#"<script id="__data""#).expect("data script");
+	assert_eq!(html[..script_pos], expected[..expected.rfind("</body>").unwrap()]);
+	assert!(html.ends_with("</script></body></html>"));
+}
+
+#[tokio::test]
+async fn page_query_selects_loader_array_page() {
+	let template = concat!(
+		"<body><!--seam:each:list.items--><i><!--seam:$.n--></i><!--seam:endeach-->",
+		"<p><!--seam:__page-->/<!--seam:__pageCount--></p></body>"
+	);
+	let mut page = page_def("/items", template, vec![items_loader()]);
+	page.pagination =
+		Some(seam_server::page::PaginationDef { path: "list.items".into(), default_size: 20 });
+	let router = items_server_for(page).into_axum_router();
+	let (status, html) = get_page(router, "/_seam/page/items?page=2&size=10").await;
+	assert_eq!(status, StatusCode::OK);
+
+	let cells: String = (10..20).map(|n| format!("<i>{n}</i>")).collect();
+	assert!(html.starts_with(&format!("<body>{cells}<p>2/50</p>")), "{html}");
+	assert!(html.contains(r#""__pageCount":50"#), "{html}");
+	assert!(!html.contains(r#"{"n":20}"#), "{html}");
+}
+
+#[tokio::test]
+async fn page_without_body_appends_script() {
+	let (status, html) =
+		get_page(items_server("<p>fragment</p>").into_axum_router(), "/_seam/page/items").await;
+	assert_eq!(status, StatusCode::OK);
+	assert!(html.starts_with("<p>fragment</p><script id=\"__data\""));
+}
+
+fn i18n_router(page: PageDef) -> axum::Router {
+	SeamServer::new()
+		.page(page)
+		.i18n_config(seam_server::I18nConfig {
+			locales: vec!["en".into(), "zh".into()],
+			default: "zh".into(),
+			mode: "memory".into(),
+			cache: false,
+			route_hashes: HashMap::new(),
+			content_hashes: HashMap::new(),
+			messages: HashMap::new(),
+			dist_dir: None,
+			routing: seam_server::LocaleRouting::Hybrid,
+		})
+		.into_axum_router()
+}
+
+#[tokio::test]
+async fn page_applies_locale_template_and_lang() {
+	let mut page = page_def("/items", "<html><body>en</body></html>", vec![]);
+	page.locale_templates =
+		Some(HashMap::from([("zh".to_string(), "<html><body>zh</body></html>".to_string())]));
+	let (status, html) = get_page(i18n_router(page), "/_seam/page/items").await;
+	assert_eq!(status, StatusCode::OK);
+	assert!(html.starts_with(r#"<html lang="zh"><body>zh<script id="__data""#), "{html}");
+}
+
+#[tokio::test]
+async fn resolved_locale_overrides_configured_lang() {
+	let page = page_def("/items", r#"<!DOCTYPE html><html lang="en"><body></body></html>"#, vec![]);
+	let (_, html) = get_page(i18n_router(page), "/_seam/page/items").await;
+	assert!(html.starts_with(r#"<!DOCTYPE html><html lang="zh"><body>"#), "{html}");
+}
+
+#[tokio::test]
+async fn configured_lang_kept_without_i18n() {
+	let (_, html) = get_page(
+		items_server(r#"<html lang="en"><body></body></html>"#).into_axum_router(),
+		"/_seam/page/items",
+	)
+	.await;
+	assert!(html.starts_with(r#"<html lang="en"><body>"#));
+}
+
+#[tokio::test]
+async fn page_data_script_carries_csp_nonce() {
+	let router = items_server("<body></body>")
+		.into_axum_router()
+		.layer(axum::Extension(seam_server::CspNonce("abc123".into())));
+	let (_, html) = get_page(router, "/_seam/page/items").await;
+	assert!(
+		html.contains(r#"<script id="__data" nonce="abc123" type="application/json">"#),
+		"{html}"
+	);
+}
+
+#[tokio::test]
+async fn page_data_script_uses_configured_data_id() {
+	let mut page = page_def("/plain", "<body></body>", vec![]);
+	page.data_id = "__app_state".into();
+	let router = SeamServer::new().page(page).into_axum_router();
+	let (status, html) = get_page(router, "/_seam/page/plain").await;
+	assert_eq!(status, StatusCode::OK);
+	assert!(html.contains(r#"<script id="__app_state" type="application/json">"#), "{html}");
+}
+
+#[tokio::test]
+async fn page_data_script_without_nonce() {
+	let (_, html) =
+		get_page(items_server("<body></body>").into_axum_router(), "/_seam/page/items").await;
+	assert!(html.contains(r#"<script id="__data" type="application/json">"#));
+	assert!(!html.contains("nonce"));
+}
+
+#[tokio::test]
+async fn page_data_script_carries_integrity_when_enabled() {
+	let router = items_server("<body></body>").data_integrity(true).into_axum_router();
+	let (_, html) = get_page(router, "/_seam/page/items").await;
+	assert!(html.contains(r#"<script id="__data" data-integrity="sha256-"#), "{html}");
+}
+
+#[tokio::test]
+async fn iflocale_blocks_render_without_locale_when_enabled() {
+	let template = "<body><!--seam:iflocale:en--><p>en</p><!--seam:endiflocale--></body>";
+	let (_, html) = get_page(items_server(template).into_axum_router(), "/_seam/page/items").await;
+	assert!(!html.contains("<p>en</p>"), "{html}");
+	let router = items_server(template).iflocale_when_unset(true).into_axum_router();
+	let (_, html) = get_page(router, "/_seam/page/items").await;
+	assert!(html.contains("<p>en</p>"), "{html}");
+}
+
+fn plain_router(mode: seam_server::TrailingSlash) -> axum::Router {
+	SeamServer::new()
+		.page(page_def("/", "<body>home</body>", vec![]))
+		.page(page_def("/plain", "<body>plain</body>", vec![]))
+		.trailing_slash(mode)
+		.into_axum_router()
+}
+
+#[tokio::test]
+async fn trailing_slash_redirects_by_default() {
+	let req = Request::builder().uri("/_seam/page/plain/?tab=1").body(Body::empty()).unwrap();
+	let resp = plain_router(seam_server::TrailingSlash::default()).oneshot(req).await.unwrap();
+	assert_eq!(resp.status(), StatusCode::PERMANENT_REDIRECT);
+	assert_eq!(resp.headers()["location"], "/plain?tab=1");
+
+	let (status, html) =
+		get_page(plain_router(seam_server::TrailingSlash::default()), "/_seam/page/plain").await;
+	assert_eq!(status, StatusCode::OK);
+	assert!(html.contains("plain"));
+}
+
+#[tokio::test]
+async fn trailing_slash_rewrite_serves_both_forms() {
+	for path in ["/_seam/page/plain", "/_seam/page/plain/"] {
+		let (status, html) = get_page(plain_router(seam_server::TrailingSlash::Rewrite), path).await;
+		assert_eq!(status, StatusCode::OK, "{path}");
+		assert!(html.starts_with("<body>plain"), "{path}: {html}");
+	}
+	let (status, html) =
+		get_page(plain_router(seam_server::TrailingSlash::Rewrite), "/_seam/page/").await;
+	assert_eq!(status, StatusCode::OK);
+	assert!(html.starts_with("<body>home"));
+}
+
+#[tokio::test]
+async fn trailing_slash_strict_keeps_registered_form_only() {
+	// Without a root page, whose locale-prefixed route would take `plain` as a locale
+	let router = SeamServer::new()
+		.page(page_def("/plain", "<body>plain</body>", vec![]))
+		.trailing_slash(seam_server::TrailingSlash::Strict)
+		.into_axum_router();
+	let (status, _) = get_page(router, "/_seam/page/plain/").await;
+	assert_eq!(status, StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn trailing_slash_rewrite_covers_locale_prefixed_routes() {
+	let router = SeamServer::new()
+		.page(page_def("/items", "<html><body>items</body></html>", vec![]))
+		.i18n_config(seam_server::I18nConfig {
+			locales: vec!["en".into(), "zh".into()],
+			default: "en".into(),
+			mode: "memory".into(),
+			cache: false,
+			route_hashes: HashMap::new(),
+			content_hashes: HashMap::new(),
+			messages: HashMap::new(),
+			dist_dir: None,
+			routing: seam_server::LocaleRouting::Hybrid,
+		})
+		.trailing_slash(seam_server::TrailingSlash::Rewrite)
+		.into_axum_router();
+	let (status, html) = get_page(router, "/_seam/page/zh/items/").await;
+	assert_eq!(status, StatusCode::OK);
+	assert!(html.starts_with(r#"<html lang="zh"><body>items"#), "{html}");
+}
+
+fn routing_router(routing: seam_server::LocaleRouting) -> axum::Router {
+	SeamServer::new()
+		.page(page_def("/page", "<html><body>page</body></html>", vec![]))
+		.i18n_config(seam_server::I18nConfig {
+			locales: vec!["en".into(), "fr".into(), "de".into()],
+			default: "en".into(),
+			mode: "memory".into(),
+			cache: false,
+			route_hashes: HashMap::new(),
+			content_hashes: HashMap::new(),
+			messages: HashMap::new(),
+			dist_dir: None,
+			routing,
+		})
+		.into_axum_router()
+}
+
+async fn get_page_with_cookie(router: axum::Router, path: &str, cookie: &str) -> String {
+	let req = Request::builder().uri(path).header("cookie", cookie).body(Body::empty()).unwrap();
+	send_raw_request(router, req).await.1
+}
+
+#[tokio::test]
+async fn hybrid_routing_prefers_url_prefix_then_cookie() {
+	let router = || routing_router(seam_server::LocaleRouting::Hybrid);
+	let html = get_page_with_cookie(router(), "/_seam/page/fr/page", "seam-locale=de").await;
+	assert!(html.starts_with(r#"<html lang="fr">"#), "{html}");
+	let html = get_page_with_cookie(router(), "/_seam/page/page", "seam-locale=de").await;
+	assert!(html.starts_with(r#"<html lang="de">"#), "{html}");
+}
+
+#[tokio::test]
+async fn hidden_routing_has_no_prefixed_routes() {
+	let router = || routing_router(seam_server::LocaleRouting::Hidden);
+	let (status, _) = get_page(router(), "/_seam/page/fr/page").await;
+	assert_eq!(status, StatusCode::NOT_FOUND);
+	let html = get_page_with_cookie(router(), "/_seam/page/page", "seam-locale=de").await;
+	assert!(html.starts_with(r#"<html lang="de">"#), "{html}");
+}
+
+#[tokio::test]
+async fn unchanged_template_mtime_yields_not_modified() {
+	let mtime = std::time::UNIX_EPOCH + std::time::Duration::from_secs(1_700_000_000);
+	let since = seam_server::dispatch::http_date(mtime);
+	let mut page = page_def("/about", "<html><body><h1>About</h1></body></html>", vec![]);
+	page.template_mtime = Some(mtime);
+	let mut dynamic = page_def("/feed", "<html><body></body></html>", vec![items_loader()]);
+	dynamic.template_mtime = Some(mtime);
+	let router = items_server("").page(page).page(dynamic).into_axum_router();
+
+	let get = |path: &str, since: Option<&str>| {
+		let mut req = Request::builder().method("GET").uri(path);
+		if let Some(since) = since {
+			req = req.header("if-modified-since", since);
+		}
+		router.clone().oneshot(req.body(Body::empty()).unwrap())
+	};
+
+	let resp = get("/_seam/page/about", None).await.unwrap();
+	assert_eq!(resp.status(), StatusCode::OK);
+	assert_eq!(resp.headers()["last-modified"], since.as_str());
+
+	let resp = get("/_seam/page/about", Some(&since)).await.unwrap();
+	assert_eq!(resp.status(), StatusCode::NOT_MODIFIED);
+
+	let earlier = seam_server::dispatch::http_date(mtime - std::time::Duration::from_secs(60));
+	let resp = get("/_seam/page/about", Some(&earlier)).await.unwrap();
+	assert_eq!(resp.status(), StatusCode::OK);
+
+	// Loader data opts the page out of conditional GETs
+	let resp = get("/_seam/page/feed", Some(&since)).await.unwrap();
+	assert_eq!(resp.status(), StatusCode::OK);
+	assert!(resp.headers().get("last-modified").is_none());
+}
+
+/// A page whose loader fails (unregistered procedure) plus both status templates.
+fn status_page_server() -> SeamServer {
+	let loader = LoaderDef {
+		data_key: "boom".into(),
+		procedure: "missing".into(),
+		input_fn: Arc::new(|_params| serde_json::json!({})),
+	};
+	SeamServer::new()
+		.page(page_def("/broken", "<html><body></body></html>", vec![loader]))
+		.not_found_page("<h1><!--seam:status--> <!--seam:path--></h1>")
+		.error_page("<h1><!--seam:status--></h1>")
+}
+
+#[tokio::test]
+async fn status_pages_render_for_unknown_page_and_failing_loader() {
+	let router = status_page_server().into_axum_router();
+	let (status, html) = get_page(router.clone(), "/_seam/page/missing").await;
+	assert_eq!((status, html.as_str()), (StatusCode::NOT_FOUND, "<h1>404 /missing</h1>"));
+	let (status, html) = get_page(router.clone(), "/_seam/page/broken").await;
+	assert_eq!((status, html.as_str()), (StatusCode::INTERNAL_SERVER_ERROR, "<h1>500</h1>"));
+
+	// Non-page URLs keep the bare 404
+	let (status, body) = get_page(router, "/_seam/other").await;
+	assert_eq!(status, StatusCode::NOT_FOUND);
+	assert!(body.is_empty());
+}
+
+#[tokio::test]
+async fn unset_status_pages_keep_plain_responses() {
+	let (status, body) = get_page(items_server("").into_axum_router(), "/_seam/page/missing").await;
+	assert_eq!(status, StatusCode::NOT_FOUND);
+	assert!(body.is_empty());
+}
@@ -0,0 +1,73 @@
+/* src/server/adapter/axum/src/tests/raw.rs */
+
+use std::collections::HashMap;
+
+use super::*;
+use seam_server::RpcHashMap;
+use seam_server::procedure::RawDef;
+
+fn raw_server() -> SeamServer {
+	SeamServer::new().raw(RawDef {
+		name: "exportCsv".into(),
+		input_schema: serde_json::json!({"properties": {"rows": {"type": "uint8"}}}),
+		error_schema: None,
+		context_keys: vec![],
+		suppress: None,
+		handler: Arc::new(|input, _ctx| {
+			Box::pin(async move {
+				let rows = input["rows"].as_u64().unwrap_or(0);
+				let mut csv = String::from("id,name\n");
+				for i in 1..=rows {
+					csv.push_str(&format!("{i},row{i}\n"));
+				}
+				Ok(("text/csv".to_string(), csv.into_bytes()))
+			})
+		}),
+	})
+}
+
+fn raw_router() -> axum::Router {
+	raw_server().into_axum_router()
+}
+
+fn raw_request(body: &str) -> Request<Body> {
+	Request::builder()
+		.method("POST")
+		.uri("/_seam/procedure/exportCsv")
+		.header("content-type", "application/json")
+		.body(Body::from(body.to_string()))
+		.unwrap()
+}
+
+#[tokio::test]
+async fn raw_procedure_returns_body_verbatim() {
+	let resp = raw_router().oneshot(raw_request(r#"{"rows":2}"#)).await.unwrap();
+	assert_eq!(resp.status(), StatusCode::OK);
+	assert_eq!(resp.headers()["content-type"], "text/csv");
+	let bytes = resp.into_body().collect().await.unwrap().to_bytes();
+	assert_eq!(&bytes[..], b"id,name\n1,row1\n2,row2\n");
+}
+
+#[tokio::test]
+async fn raw_procedure_keeps_its_name_under_obfuscation() {
+	let map = RpcHashMap { salt: "s".into(), batch: "b4tch".into(), procedures: HashMap::new() };
+	let router = raw_server().rpc_hash_map(map).into_axum_router();
+	let resp = router.oneshot(raw_request(r#"{"rows":1}"#)).await.unwrap();
+	assert_eq!(resp.status(), StatusCode::OK);
+	assert_eq!(resp.headers()["content-type"], "text/csv");
+}
+
+#[tokio::test]
+async fn raw_procedure_errors_stay_json() {
+	let (status, body) = send_raw_request(raw_router(), raw_request(r#"{"rows":"x"}"#)).await;
+	assert_eq!(status, StatusCode::BAD_REQUEST);
+	let json: serde_json::Value = serde_json::from_str(&body).unwrap();
+	assert_eq!(json["error"]["code"], "VALIDATION_ERROR");
+}
+
+#[tokio::test]
+async fn manifest_lists_raw_without_output() {
+	let (_, json) = send_request(raw_router(), "GET", "/_seam/manifest.json", None).await;
+	assert_eq!(json["procedures"]["exportCsv"]["kind"], "raw");
+	assert!(json["procedures"]["exportCsv"].get("output").is_none());
+}
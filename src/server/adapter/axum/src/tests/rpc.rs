@@ -14,6 +14,7 @@ async fn manifest_returns_procedures() {
 	let (status, json) = send_request(router, "GET", "/_seam/manifest.json", None).await;
 	assert_eq!(status, StatusCode::OK);
 	assert!(json.get("procedures").is_some(), "manifest must contain 'procedures' key");
+	assert_eq!(json["hash"].as_str().map(str::len), Some(16));
 }
 
 #[tokio::test]
@@ -35,6 +36,15 @@ async fn rpc_invalid_json() {
 	assert_eq!(json["error"]["code"], "VALIDATION_ERROR");
 }
 
+#[tokio::test]
+async fn rpc_oversized_body_is_rejected() {
+	let body = format!(r#"{{"name":"{}"}}"#, "x".repeat(seam_server::dispatch::MAX_BODY_BYTES));
+	let (status, json) =
+		send_request(test_router(), "POST", "/_seam/procedure/greet", Some(&body)).await;
+	assert_eq!(status, StatusCode::PAYLOAD_TOO_LARGE);
+	assert_eq!(json["error"]["code"], "PAYLOAD_TOO_LARGE");
+}
+
 #[tokio::test]
 async fn rpc_not_found() {
 	let router = test_router();
@@ -101,6 +111,7 @@ fn validation_router(mode: seam_server::ValidationMode) -> axum::Router {
 		context_keys: vec![],
 		suppress: None,
 		cache: None,
+		timeout: None,
 		handler: Arc::new(|input, _ctx| {
 			Box::pin(async move {
 				let name = input.get("name").and_then(|v| v.as_str()).unwrap_or("World");
@@ -126,6 +137,16 @@ async fn validation_rejects_invalid_input() {
 	assert_eq!(details[0]["expected"], "string");
 }
 
+#[tokio::test]
+async fn validation_rejects_missing_required_property() {
+	let router = validation_router(seam_server::ValidationMode::Always);
+	let (status, json) = send_request(router, "POST", "/_seam/procedure/greet", Some("{}")).await;
+	assert_eq!(status, StatusCode::BAD_REQUEST);
+	assert_eq!(json["error"]["code"], "VALIDATION_ERROR");
+	let details = json["error"]["details"].as_array().expect("details must be array");
+	assert!(!details.is_empty(), "missing property must be reported");
+}
+
 #[tokio::test]
 async fn validation_accepts_valid_input() {
 	let router = validation_router(seam_server::ValidationMode::Always);
@@ -199,3 +220,154 @@ async fn public_files_are_served_before_fallback_routes() {
 
 	let _ = std::fs::remove_dir_all(&dir);
 }
+
+fn sleepy_procedure(
+	name: &str,
+	delay: std::time::Duration,
+	timeout: Option<std::time::Duration>,
+) -> ProcedureDef {
+	ProcedureDef {
+		name: name.into(),
+		proc_type: ProcedureType::Query,
+		input_schema: serde_json::json!({}),
+		output_schema: serde_json::json!({}),
+		error_schema: None,
+		context_keys: vec![],
+		suppress: None,
+		cache: None,
+		timeout,
+		handler: Arc::new(move |_input, _ctx| {
+			Box::pin(async move {
+				tokio::time::sleep(delay).await;
+				Ok(serde_json::json!({"done": true}))
+			})
+		}),
+	}
+}
+
+fn timeout_router() -> axum::Router {
+	use std::time::Duration;
+	SeamServer::new()
+		.transport_config(seam_server::TransportConfig {
+			procedure_timeout: Duration::from_millis(50),
+			..Default::default()
+		})
+		.procedure(sleepy_procedure("fast", Duration::ZERO, None))
+		.procedure(sleepy_procedure("slow", Duration::from_secs(5), None))
+		// Per-procedure override outlasts the 50ms global default
+		.procedure(sleepy_procedure(
+			"patient",
+			Duration::from_millis(100),
+			Some(Duration::from_secs(5)),
+		))
+		.into_axum_router()
+}
+
+#[tokio::test]
+async fn rpc_fast_procedure_within_timeout() {
+	let (status, json) =
+		send_request(timeout_router(), "POST", "/_seam/procedure/fast", Some("{}")).await;
+	assert_eq!(status, StatusCode::OK);
+	assert_eq!(json["data"]["done"], true);
+}
+
+#[tokio::test]
+async fn rpc_slow_procedure_times_out() {
+	let (status, json) =
+		send_request(timeout_router(), "POST", "/_seam/procedure/slow", Some("{}")).await;
+	assert_eq!(status, StatusCode::GATEWAY_TIMEOUT);
+	assert_eq!(json["error"]["code"], "TIMEOUT");
+	assert!(json["error"]["message"].as_str().unwrap().contains("'slow'"));
+}
+
+#[tokio::test]
+async fn rpc_per_procedure_timeout_overrides_default() {
+	let (status, _) =
+		send_request(timeout_router(), "POST", "/_seam/procedure/patient", Some("{}")).await;
+	assert_eq!(status, StatusCode::OK);
+}
+
+#[tokio::test]
+async fn batch_slow_call_times_out_independently() {
+	let body = r#"{"calls":[{"procedure":"slow","input":{}},{"procedure":"fast","input":{}}]}"#;
+	let (_, json) =
+		send_request(timeout_router(), "POST", "/_seam/procedure/_batch", Some(body)).await;
+	let results = json["data"]["results"].as_array().unwrap();
+	assert_eq!(results[0]["error"]["code"], "TIMEOUT");
+	assert_eq!(results[1]["ok"], true);
+}
+
+// Request context: `context_keys` pull extracted header/cookie values into `ctx`
+fn context_router() -> axum::Router {
+	SeamServer::new()
+		.context(
+			"auth",
+			seam_server::context::ContextFieldDef {
+				extract: "header:authorization".into(),
+				schema: serde_json::json!({"type": "string", "nullable": true}),
+			},
+		)
+		.procedure(ProcedureDef {
+			name: "whoami".into(),
+			proc_type: ProcedureType::Query,
+			input_schema: serde_json::json!({}),
+			output_schema: serde_json::json!({}),
+			error_schema: None,
+			context_keys: vec!["auth".into()],
+			suppress: None,
+			cache: None,
+			timeout: None,
+			handler: Arc::new(|_input, ctx| {
+				Box::pin(async move { Ok(serde_json::json!({"auth": ctx["auth"]})) })
+			}),
+		})
+		.into_axum_router()
+}
+
+#[tokio::test]
+async fn rpc_procedure_reads_header_from_context() {
+	let req = Request::builder()
+		.method("POST")
+		.uri("/_seam/procedure/whoami")
+		.header("content-type", "application/json")
+		.header("authorization", "Bearer t0k")
+		.body(Body::from("{}"))
+		.unwrap();
+	let resp = context_router().oneshot(req).await.unwrap();
+	assert_eq!(resp.status(), StatusCode::OK);
+	let bytes = resp.into_body().collect().await.unwrap().to_bytes();
+	let json: serde_json::Value = serde_json::from_slice(&bytes).unwrap();
+	assert_eq!(json["data"]["auth"], "Bearer t0k");
+}
+
+#[tokio::test]
+async fn rpc_context_missing_header_is_null() {
+	let (status, json) =
+		send_request(context_router(), "POST", "/_seam/procedure/whoami", Some("{}")).await;
+	assert_eq!(status, StatusCode::OK);
+	assert_eq!(json["data"]["auth"], serde_json::Value::Null);
+}
+
+#[tokio::test]
+async fn closure_procedure_is_served_and_listed() {
+	let offset = 10;
+	let router = SeamServer::new()
+		.procedure_fn(
+			"addOffset",
+			serde_json::json!({"properties": {"n": {"type": "int32"}}}),
+			serde_json::json!({"properties": {"sum": {"type": "int32"}}}),
+			move |input, _ctx| async move {
+				let n = input["n"].as_i64().unwrap_or(0);
+				Ok(serde_json::json!({"sum": n + offset}))
+			},
+		)
+		.into_axum_router();
+
+	let (status, json) =
+		send_request(router.clone(), "POST", "/_seam/procedure/addOffset", Some(r#"{"n":5}"#)).await;
+	assert_eq!(status, StatusCode::OK);
+	assert_eq!(json["data"]["sum"], 15);
+
+	let (_, manifest) = send_request(router, "GET", "/_seam/manifest.json", None).await;
+	assert_eq!(manifest["procedures"]["addOffset"]["kind"], "query");
+}
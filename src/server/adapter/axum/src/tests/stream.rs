@@ -30,6 +30,7 @@ fn heartbeat_stream_router(interval: std::time::Duration) -> axum::Router {
 			heartbeat_interval: interval,
 			sse_idle_timeout: std::time::Duration::from_secs(12),
 			pong_timeout: std::time::Duration::from_secs(5),
+			procedure_timeout: std::time::Duration::from_secs(30),
 		})
 		.stream(StreamDef {
 			name: "countStream".into(),
@@ -144,3 +145,54 @@ async fn manifest_stream_chunk_output() {
 	assert!(stream_entry["chunkOutput"].is_object());
 	assert!(stream_entry.get("output").is_none());
 }
+
+fn ndjson_request(body: &str) -> Request<Body> {
+	accept_request("application/x-ndjson", body)
+}
+
+fn accept_request(accept: &str, body: &str) -> Request<Body> {
+	Request::builder()
+		.method("POST")
+		.uri("/_seam/procedure/countStream")
+		.header("content-type", "application/json")
+		.header("accept", accept)
+		.body(Body::from(body.to_string()))
+		.unwrap()
+}
+
+#[tokio::test]
+async fn stream_accept_header_picks_ndjson_by_media_range() {
+	let cases = [
+		("Application/X-NDJSON; charset=utf-8", "application/x-ndjson"),
+		("text/event-stream, application/x-ndjson;q=0.5", "application/x-ndjson"),
+		("application/x-ndjson;q=0, text/event-stream", "text/event-stream"),
+		("application/x-ndjson; q=0.0", "text/event-stream"),
+		("application/x-ndjsonx", "text/event-stream"),
+		("*/*", "text/event-stream"),
+	];
+	for (accept, expected) in cases {
+		let resp = stream_router().oneshot(accept_request(accept, r#"{"n": 1}"#)).await.unwrap();
+		assert_eq!(resp.headers()["content-type"], expected, "accept: {accept}");
+	}
+}
+
+#[tokio::test]
+async fn stream_as_ndjson_writes_one_value_per_line() {
+	let resp = stream_router().oneshot(ndjson_request(r#"{"n": 3}"#)).await.unwrap();
+	assert_eq!(resp.status(), StatusCode::OK);
+	assert_eq!(resp.headers()["content-type"], "application/x-ndjson");
+	let bytes = resp.into_body().collect().await.unwrap().to_bytes();
+	let body = String::from_utf8(bytes.to_vec()).unwrap();
+	assert_eq!(body, "{\"value\":0}\n{\"value\":1}\n{\"value\":2}\n");
+	let values: Vec<serde_json::Value> =
+		body.lines().map(|l| serde_json::from_str(l).unwrap()).collect();
+	assert_eq!(values.len(), 3);
+}
+
+#[tokio::test]
+async fn stream_as_ndjson_setup_error_uses_json_envelope() {
+	let (status, body) = send_raw_request(stream_router(), ndjson_request("not json")).await;
+	assert_eq!(status, StatusCode::BAD_REQUEST);
+	let json: serde_json::Value = serde_json::from_str(&body).unwrap();
+	assert_eq!(json["error"]["code"], "VALIDATION_ERROR");
+}
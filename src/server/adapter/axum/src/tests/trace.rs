@@ -0,0 +1,84 @@
+/* src/server/adapter/axum/src/tests/trace.rs */
+
+use std::fmt::Debug;
+use std::sync::Mutex;
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use tracing::field::{Field, Visit};
+use tracing::span::{Attributes, Id, Record};
+use tracing::{Event, Metadata, Subscriber};
+
+use super::*;
+
+/// Records span names with their fields, and event fields, as flat strings.
+#[derive(Clone, Default)]
+struct Recorder {
+	next_id: Arc<AtomicU64>,
+	spans: Arc<Mutex<Vec<String>>>,
+	events: Arc<Mutex<Vec<String>>>,
+}
+
+struct FieldText<'a>(&'a mut String);
+
+impl Visit for FieldText<'_> {
+	fn record_debug(&mut self, field: &Field, value: &dyn Debug) {
+		self.0.push_str(&format!(" {}={value:?}", field.name()));
+	}
+}
+
+impl Subscriber for Recorder {
+	fn enabled(&self, _: &Metadata<'_>) -> bool {
+		true
+	}
+
+	fn new_span(&self, span: &Attributes<'_>) -> Id {
+		let mut text = span.metadata().name().to_string();
+		span.record(&mut FieldText(&mut text));
+		self.spans.lock().unwrap().push(text);
+		Id::from_u64(self.next_id.fetch_add(1, Ordering::Relaxed) + 1)
+	}
+
+	fn record(&self, _: &Id, values: &Record<'_>) {
+		let mut text = String::from("record");
+		values.record(&mut FieldText(&mut text));
+		self.events.lock().unwrap().push(text);
+	}
+
+	fn record_follows_from(&self, _: &Id, _: &Id) {}
+
+	fn event(&self, event: &Event<'_>) {
+		let mut text = String::from("event");
+		event.record(&mut FieldText(&mut text));
+		self.events.lock().unwrap().push(text);
+	}
+
+	fn enter(&self, _: &Id) {}
+
+	fn exit(&self, _: &Id) {}
+}
+
+#[tokio::test]
+async fn rpc_call_opens_span_with_duration() {
+	let recorder = Recorder::default();
+	let _guard = tracing::subscriber::set_default(recorder.clone());
+
+	let (status, _) =
+		send_request(test_router(), "POST", "/_seam/procedure/greet", Some(r#"{"name":"A"}"#)).await;
+	assert_eq!(status, StatusCode::OK);
+
+	let spans = recorder.spans.lock().unwrap();
+	assert!(spans.iter().any(|s| s == "seam.rpc procedure=\"greet\""), "{spans:?}");
+	let events = recorder.events.lock().unwrap();
+	assert!(events.iter().any(|e| e.starts_with("record duration_ms=")), "{events:?}");
+}
+
+#[tokio::test]
+async fn failed_rpc_emits_error_code_event() {
+	let recorder = Recorder::default();
+	let _guard = tracing::subscriber::set_default(recorder.clone());
+
+	send_request(test_router(), "POST", "/_seam/procedure/greet", Some("not json")).await;
+
+	let events = recorder.events.lock().unwrap();
+	assert!(events.iter().any(|e| e.contains("code=\"VALIDATION_ERROR\"")), "{events:?}");
+}
@@ -0,0 +1,85 @@
+/* src/server/adapter/axum/src/trace.rs */
+
+//! Request-scoped `tracing` spans for RPC, page, and subscription handlers.
+//! Without the `tracing` feature every helper reduces to a plain await.
+
+use std::future::Future;
+
+use seam_server::SeamError;
+
+use crate::error::AxumError;
+
+#[derive(Clone, Copy)]
+pub(crate) enum Op {
+	Rpc,
+	Page,
+	Subscribe,
+}
+
+/// Error types a traced handler can fail with; the span reports their `SeamError` code.
+#[cfg_attr(not(feature = "tracing"), allow(dead_code))]
+pub(crate) trait SeamCode {
+	fn seam_error(&self) -> &SeamError;
+}
+
+impl SeamCode for SeamError {
+	fn seam_error(&self) -> &SeamError {
+		self
+	}
+}
+
+impl SeamCode for AxumError {
+	fn seam_error(&self) -> &SeamError {
+		&self.0
+	}
+}
+
+/// Run `fut` in a `seam.rpc` / `seam.page` / `seam.subscribe` span for `name` (procedure
+/// or route pattern). `duration_ms` is recorded once `fut` settles, which for streamed
+/// responses is when the body starts, not when it ends.
+#[cfg(feature = "tracing")]
+pub(crate) async fn traced<T, E: SeamCode>(
+	op: Op,
+	name: &str,
+	fut: impl Future<Output = Result<T, E>>,
+) -> Result<T, E> {
+	use tracing::Instrument;
+	use tracing::field::Empty;
+
+	let span = match op {
+		Op::Rpc => tracing::info_span!("seam.rpc", procedure = name, duration_ms = Empty),
+		Op::Page => {
+			tracing::info_span!("seam.page", route = name, locale = Empty, duration_ms = Empty)
+		}
+		Op::Subscribe => {
+			tracing::info_span!("seam.subscribe", procedure = name, duration_ms = Empty)
+		}
+	};
+	let start = std::time::Instant::now();
+	let result = fut.instrument(span.clone()).await;
+	span.record("duration_ms", u64::try_from(start.elapsed().as_millis()).unwrap_or(u64::MAX));
+	if let Err(ref err) = result {
+		let err = err.seam_error();
+		tracing::error!(parent: &span, code = err.code(), message = err.message(), "request failed");
+	}
+	result
+}
+
+#[cfg(not(feature = "tracing"))]
+pub(crate) async fn traced<T, E: SeamCode>(
+	_op: Op,
+	_name: &str,
+	fut: impl Future<Output = Result<T, E>>,
+) -> Result<T, E> {
+	fut.await
+}
+
+/// Attach the resolved locale to the current `seam.page` span.
+pub(crate) fn record_locale(locale: Option<&str>) {
+	#[cfg(feature = "tracing")]
+	if let Some(locale) = locale {
+		tracing::Span::current().record("locale", locale);
+	}
+	#[cfg(not(feature = "tracing"))]
+	let _ = locale;
+}
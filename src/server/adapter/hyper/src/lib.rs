@@ -0,0 +1,46 @@
+/* src/server/adapter/hyper/src/lib.rs */
+#![cfg_attr(test, allow(clippy::unwrap_used))]
+
+mod page;
+mod service;
+
+use std::net::SocketAddr;
+
+use hyper::server::conn::http1;
+use hyper_util::rt::TokioIo;
+use seam_server::SeamServer;
+
+/// Re-export seam-server core for convenience
+pub use seam_server;
+pub use service::SeamService;
+
+/// Extension trait that converts a `SeamServer` into a hyper service.
+pub trait IntoHyperService {
+	fn into_hyper_service(self) -> SeamService;
+	fn serve(self, addr: &str) -> impl std::future::Future<Output = std::io::Result<()>>;
+}
+
+impl IntoHyperService for SeamServer {
+	fn into_hyper_service(self) -> SeamService {
+		SeamService::from_parts(self.into_parts())
+	}
+
+	#[allow(clippy::print_stdout)]
+	async fn serve(self, addr: &str) -> std::io::Result<()> {
+		let service = self.into_hyper_service();
+		let listener = tokio::net::TcpListener::bind(addr).await?;
+		let local_addr: SocketAddr = listener.local_addr()?;
+		println!("Seam Rust backend running on http://localhost:{}", local_addr.port());
+		loop {
+			let (stream, _) = listener.accept().await?;
+			let service = service.clone();
+			tokio::spawn(async move {
+				// Connection-level errors (client hangups, malformed HTTP) only affect that client
+				let _ = http1::Builder::new().serve_connection(TokioIo::new(stream), service).await;
+			});
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests;
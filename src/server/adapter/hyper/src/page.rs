@@ -0,0 +1,196 @@
+/* src/server/adapter/hyper/src/page.rs */
+
+use std::cmp::Reverse;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use bytes::Bytes;
+use http_body_util::Full;
+use hyper::header::{self, HeaderValue};
+use hyper::http::request::Parts;
+use hyper::{Response, StatusCode};
+use percent_encoding::percent_decode_str;
+use seam_server::dispatch::{read_prerendered, trailing_slash_location};
+use seam_server::page::PageDef;
+use seam_server::{SeamError, TrailingSlash};
+
+use crate::service::{AppState, extract_raw_context, header_str, response};
+
+/// Hyper has no router, so page routes are matched here segment by segment.
+pub(crate) struct PageRoutes {
+	routes: Vec<PageRoute>,
+	has_url_prefix: bool,
+	trailing_slash: TrailingSlash,
+}
+
+struct PageRoute {
+	segments: Vec<Segment>,
+	page: Arc<PageDef>,
+}
+
+enum Segment {
+	Static(String),
+	Param(String),
+	CatchAll(String),
+}
+
+/// A matched page with its path params; `_seam_locale` is held apart from the loader params.
+struct PageMatch<'a> {
+	page: &'a Arc<PageDef>,
+	params: HashMap<String, String>,
+	locale: Option<String>,
+}
+
+impl PageRoutes {
+	pub(crate) fn new(
+		pages: Vec<PageDef>,
+		has_url_prefix: bool,
+		trailing_slash: TrailingSlash,
+	) -> Self {
+		let routes = pages
+			.into_iter()
+			.map(|page| PageRoute { segments: parse_route(&page.route), page: Arc::new(page) })
+			.collect();
+		Self { routes, has_url_prefix, trailing_slash }
+	}
+
+	/// Match a path relative to `/_seam/page`. Routes with more literal segments win, so
+	/// `/blog/new` beats `/blog/{id}`; ties go to the earlier registration.
+	fn find(&self, path: &str) -> Option<PageMatch<'_>> {
+		let segments: Vec<&str> = path.split('/').filter(|s| !s.is_empty()).collect();
+		self.find_segments(&segments, None).or_else(|| {
+			// Locale-prefixed routes only exist when the url_prefix strategy is active
+			let (locale, rest) = segments.split_first().filter(|_| self.has_url_prefix)?;
+			self.find_segments(rest, Some(locale.to_string()))
+		})
+	}
+
+	fn find_segments(&self, segments: &[&str], locale: Option<String>) -> Option<PageMatch<'_>> {
+		self
+			.routes
+			.iter()
+			.filter_map(|route| match_segments(&route.segments, segments).map(|p| (route, p)))
+			.min_by_key(|(route, _)| {
+				// `min_by_key` keeps the first minimum, so ties go to registration order
+				let literals = route.segments.iter().filter(|s| matches!(s, Segment::Static(_))).count();
+				let catch_all = route.segments.iter().any(|s| matches!(s, Segment::CatchAll(_)));
+				(catch_all, Reverse(literals))
+			})
+			.map(|(route, params)| PageMatch { page: &route.page, params, locale })
+	}
+}
+
+fn parse_route(route: &str) -> Vec<Segment> {
+	route
+		.split('/')
+		.filter(|s| !s.is_empty())
+		.map(|s| {
+			if let Some(name) = s.strip_prefix("{*").and_then(|s| s.strip_suffix('}')) {
+				Segment::CatchAll(name.to_string())
+			} else if let Some(name) = s.strip_prefix('{').and_then(|s| s.strip_suffix('}')) {
+				Segment::Param(name.to_string())
+			} else {
+				Segment::Static(s.to_string())
+			}
+		})
+		.collect()
+}
+
+/// Path params are percent-decoded, as axum and actix hand them to loaders. A param that
+/// does not decode to UTF-8 fails the match.
+fn match_segments(route: &[Segment], path: &[&str]) -> Option<HashMap<String, String>> {
+	let mut params = HashMap::new();
+	for (i, segment) in route.iter().enumerate() {
+		match segment {
+			Segment::CatchAll(name) => {
+				// Like axum's `{*rest}`, a catch-all needs at least one segment
+				if i >= path.len() {
+					return None;
+				}
+				params.insert(name.clone(), decode(&path[i..].join("/"))?);
+				return Some(params);
+			}
+			Segment::Static(lit) => {
+				if path.get(i) != Some(&lit.as_str()) {
+					return None;
+				}
+			}
+			Segment::Param(name) => {
+				params.insert(name.clone(), decode(path.get(i)?)?);
+			}
+		}
+	}
+	(route.len() == path.len()).then_some(params)
+}
+
+fn decode(segment: &str) -> Option<String> {
+	percent_decode_str(segment).decode_utf8().ok().map(std::borrow::Cow::into_owned)
+}
+
+/// `GET /_seam/page/*`. Errors become the registered status page when there is one.
+pub(crate) async fn handle_page(
+	state: &AppState,
+	req: &Parts,
+) -> Result<Response<Full<Bytes>>, SeamError> {
+	let path = req.uri.path();
+	let route_path = path.strip_prefix("/_seam/page").unwrap_or(path);
+	if !route_path.is_empty() && !route_path.starts_with('/') {
+		return Err(SeamError::not_found("Not found"));
+	}
+	render_page(state, req, route_path).await.or_else(|err| {
+		let (status, html) = state.dispatch.status_page(&err, path).ok_or(err)?;
+		let status = StatusCode::from_u16(status).unwrap_or(StatusCode::INTERNAL_SERVER_ERROR);
+		Ok(response(status, "text/html; charset=utf-8", html))
+	})
+}
+
+async fn render_page(
+	state: &AppState,
+	req: &Parts,
+	route_path: &str,
+) -> Result<Response<Full<Bytes>>, SeamError> {
+	let path = req.uri.path();
+	let Some(PageMatch { page, params, locale: path_locale }) = state.pages.find(route_path) else {
+		return Err(SeamError::not_found("Page not found"));
+	};
+	// Segment matching ignores empty segments; a trailing slash is only part of the
+	// registered form for catch-all routes
+	if route_path.len() > 1 && route_path.ends_with('/') && !page.route.contains("{*") {
+		match state.pages.trailing_slash {
+			TrailingSlash::Redirect => return Ok(redirect_trailing_slash(req)),
+			TrailingSlash::Rewrite => {}
+			TrailingSlash::Strict => return Err(SeamError::not_found("Page not found")),
+		}
+	}
+
+	if let Some(html) = read_prerendered(page, path).await {
+		return Ok(html_response(html));
+	}
+
+	let locale = state.dispatch.page_locale(
+		path_locale.as_deref(),
+		req.uri.path_and_query().map(hyper::http::uri::PathAndQuery::as_str).unwrap_or(""),
+		header_str(&req.headers, header::COOKIE),
+		header_str(&req.headers, header::ACCEPT_LANGUAGE),
+	)?;
+
+	let config = state.dispatch.procedures.context_config();
+	let raw_ctx = extract_raw_context(config, &req.headers, &req.uri);
+	let output = state.dispatch.run_loaders(page, &params, raw_ctx).await?;
+	let html = state.dispatch.render_page(page, output, locale.as_deref(), req.uri.query());
+	Ok(html_response(html))
+}
+
+/// 308 from `/dashboard/` to `/dashboard`; see `trailing_slash_location`.
+fn redirect_trailing_slash(req: &Parts) -> Response<Full<Bytes>> {
+	let location = trailing_slash_location(req.uri.path(), req.uri.query());
+	let mut resp = response(StatusCode::PERMANENT_REDIRECT, "text/plain", Bytes::new());
+	if let Ok(value) = HeaderValue::from_str(&location) {
+		resp.headers_mut().insert(header::LOCATION, value);
+	}
+	resp
+}
+
+fn html_response(html: String) -> Response<Full<Bytes>> {
+	response(StatusCode::OK, "text/html; charset=utf-8", html)
+}
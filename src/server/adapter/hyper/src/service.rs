@@ -0,0 +1,169 @@
+/* src/server/adapter/hyper/src/service.rs */
+
+use std::convert::Infallible;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+
+use bytes::Bytes;
+use http_body_util::{BodyExt, Full, LengthLimitError, Limited};
+use hyper::body::Body;
+use hyper::header::{self, HeaderMap, HeaderValue};
+use hyper::{Method, Request, Response, StatusCode, Uri};
+use seam_server::context::{ContextConfig, RawContextMap};
+use seam_server::dispatch::{Dispatcher, MAX_BODY_BYTES, body_too_large, error_body};
+use seam_server::manifest::build_manifest;
+use seam_server::{SeamError, SeamParts};
+
+use crate::page::PageRoutes;
+
+pub(crate) struct AppState {
+	pub manifest_json: serde_json::Value,
+	pub dispatch: Dispatcher,
+	pub pages: PageRoutes,
+}
+
+/// `/_seam/*` request handler. Cheap to clone; every clone shares one `AppState`.
+///
+/// Implements hyper's `Service`, so it can be passed to `serve_connection` directly,
+/// or wrapped in `service_fn(move |req| { let s = svc.clone(); async move { s.handle(req).await } })`
+/// when it sits behind other routing.
+#[derive(Clone)]
+pub struct SeamService {
+	state: Arc<AppState>,
+}
+
+impl SeamService {
+	pub fn from_parts(mut parts: SeamParts) -> Self {
+		// Subscriptions, streams, uploads, raw procedures, and channels need a transport this
+		// adapter does not provide, so the manifest only advertises queries and commands
+		let manifest_json = serde_json::to_value(build_manifest(
+			&parts.procedures,
+			&[],
+			&[],
+			&[],
+			&[],
+			Default::default(),
+			&parts.context_config,
+		))
+		.expect("manifest serialization");
+
+		let dispatch = Dispatcher::from_parts(&mut parts);
+		let pages = PageRoutes::new(parts.pages, dispatch.has_url_prefix(), parts.trailing_slash);
+		let state = AppState { manifest_json, dispatch, pages };
+		Self { state: Arc::new(state) }
+	}
+
+	/// Answer one request. Never fails: Seam errors become `{ ok: false, error }` responses,
+	/// and paths outside `/_seam/*` get a 404.
+	pub async fn handle<B>(&self, req: Request<B>) -> Result<Response<Full<Bytes>>, Infallible>
+	where
+		B: Body,
+		B::Error: Into<Box<dyn std::error::Error + Send + Sync>>,
+	{
+		Ok(self.dispatch(req).await.unwrap_or_else(|err| error_response(&err)))
+	}
+
+	async fn dispatch<B>(&self, req: Request<B>) -> Result<Response<Full<Bytes>>, SeamError>
+	where
+		B: Body,
+		B::Error: Into<Box<dyn std::error::Error + Send + Sync>>,
+	{
+		let state = &self.state;
+		let path = req.uri().path().to_string();
+
+		if path == "/_seam/manifest.json" && req.method() == Method::GET {
+			state.dispatch.check_manifest()?;
+			return Ok(json_response(StatusCode::OK, &state.manifest_json));
+		}
+
+		if let Some(name) = path.strip_prefix("/_seam/procedure/")
+			&& req.method() == Method::POST
+		{
+			let name = name.to_string();
+			let raw_ctx =
+				extract_raw_context(state.dispatch.procedures.context_config(), req.headers(), req.uri());
+			let body = read_body(req.into_body()).await?;
+			let data = state.dispatch.call_procedure(&name, &body, raw_ctx).await?;
+			return Ok(json_response(StatusCode::OK, &serde_json::json!({ "ok": true, "data": data })));
+		}
+
+		if path.starts_with("/_seam/page") && req.method() == Method::GET {
+			let (parts, _) = req.into_parts();
+			return crate::page::handle_page(state, &parts).await;
+		}
+
+		Err(SeamError::not_found("Not found"))
+	}
+}
+
+impl<B> hyper::service::Service<Request<B>> for SeamService
+where
+	B: Body + Send + 'static,
+	B::Data: Send,
+	B::Error: Into<Box<dyn std::error::Error + Send + Sync>>,
+{
+	type Response = Response<Full<Bytes>>;
+	type Error = Infallible;
+	type Future = Pin<Box<dyn Future<Output = Result<Self::Response, Infallible>> + Send>>;
+
+	fn call(&self, req: Request<B>) -> Self::Future {
+		let service = self.clone();
+		Box::pin(async move { service.handle(req).await })
+	}
+}
+
+/// Buffer a request body up to `MAX_BODY_BYTES`; anything larger is a 413.
+async fn read_body<B>(body: B) -> Result<Bytes, SeamError>
+where
+	B: Body,
+	B::Error: Into<Box<dyn std::error::Error + Send + Sync>>,
+{
+	match Limited::new(body, MAX_BODY_BYTES).collect().await {
+		Ok(collected) => Ok(collected.to_bytes()),
+		Err(e) if e.is::<LengthLimitError>() => Err(body_too_large()),
+		Err(e) => Err(SeamError::validation(format!("Failed to read request body: {e}"))),
+	}
+}
+
+/// Extract raw context values from HTTP request (headers, cookies, query).
+pub(crate) fn extract_raw_context(
+	config: &ContextConfig,
+	headers: &HeaderMap,
+	uri: &Uri,
+) -> RawContextMap {
+	let header_list: Vec<(String, String)> = headers
+		.iter()
+		.filter_map(|(k, v)| v.to_str().ok().map(|v| (k.as_str().to_string(), v.to_string())))
+		.collect();
+	seam_server::extract_raw_context(
+		config,
+		&header_list,
+		header_str(headers, header::COOKIE),
+		uri.query(),
+	)
+}
+
+pub(crate) fn header_str(headers: &HeaderMap, name: header::HeaderName) -> Option<&str> {
+	headers.get(name).and_then(|v| v.to_str().ok())
+}
+
+pub(crate) fn response(
+	status: StatusCode,
+	content_type: &'static str,
+	body: impl Into<Bytes>,
+) -> Response<Full<Bytes>> {
+	let mut resp = Response::new(Full::new(body.into()));
+	*resp.status_mut() = status;
+	resp.headers_mut().insert(header::CONTENT_TYPE, HeaderValue::from_static(content_type));
+	resp
+}
+
+fn json_response(status: StatusCode, value: &serde_json::Value) -> Response<Full<Bytes>> {
+	response(status, "application/json", serde_json::to_vec(value).unwrap_or_default())
+}
+
+fn error_response(err: &SeamError) -> Response<Full<Bytes>> {
+	let status = StatusCode::from_u16(err.status()).unwrap_or(StatusCode::INTERNAL_SERVER_ERROR);
+	json_response(status, &error_body(err))
+}
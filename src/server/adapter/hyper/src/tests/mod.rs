@@ -0,0 +1,70 @@
+/* src/server/adapter/hyper/src/tests/mod.rs */
+
+mod page;
+mod rpc;
+
+use std::sync::Arc;
+
+use bytes::Bytes;
+use http_body_util::{BodyExt, Full};
+use hyper::service::Service;
+use hyper::{Method, Request, StatusCode};
+use seam_server::procedure::{ProcedureDef, ProcedureType};
+
+use super::*;
+
+fn test_server() -> SeamServer {
+	SeamServer::new().procedure(ProcedureDef {
+		name: "greet".into(),
+		proc_type: ProcedureType::Query,
+		input_schema: serde_json::json!({"properties": {"name": {"type": "string"}}}),
+		output_schema: serde_json::json!({"properties": {"message": {"type": "string"}}}),
+		error_schema: None,
+		context_keys: vec![],
+		suppress: None,
+		cache: None,
+		timeout: None,
+		handler: Arc::new(|input, _ctx| {
+			Box::pin(async move {
+				let name = input.get("name").and_then(|v| v.as_str()).unwrap_or("World");
+				Ok(serde_json::json!({"message": format!("Hello, {}!", name)}))
+			})
+		}),
+	})
+}
+
+/// Run one request through the service's `Service::call`, as `serve_connection` would.
+async fn send(
+	server: SeamServer,
+	method: Method,
+	uri: &str,
+	body: &str,
+) -> (StatusCode, hyper::HeaderMap, String) {
+	let service = server.into_hyper_service();
+	let req = Request::builder()
+		.method(method)
+		.uri(uri)
+		.body(Full::new(Bytes::from(body.to_string())))
+		.unwrap();
+	let resp = service.call(req).await.unwrap();
+	let status = resp.status();
+	let headers = resp.headers().clone();
+	let bytes = resp.into_body().collect().await.unwrap().to_bytes();
+	(status, headers, String::from_utf8_lossy(&bytes).to_string())
+}
+
+#[tokio::test]
+async fn manifest_lists_procedures() {
+	let (status, _, body) = send(test_server(), Method::GET, "/_seam/manifest.json", "").await;
+	assert_eq!(status, StatusCode::OK);
+	let manifest: serde_json::Value = serde_json::from_str(&body).unwrap();
+	assert_eq!(manifest["procedures"]["greet"]["kind"], "query");
+}
+
+#[tokio::test]
+async fn unknown_path_is_not_found() {
+	let (status, _, body) = send(test_server(), Method::GET, "/elsewhere", "").await;
+	assert_eq!(status, StatusCode::NOT_FOUND);
+	let json: serde_json::Value = serde_json::from_str(&body).unwrap();
+	assert_eq!(json["error"]["code"], "NOT_FOUND");
+}
@@ -0,0 +1,117 @@
+/* src/server/adapter/hyper/src/tests/page.rs */
+
+use super::*;
+use seam_server::TrailingSlash;
+use seam_server::page::{LoaderDef, PageDef};
+
+fn page_def(route: &str, template: &str, loaders: Vec<LoaderDef>) -> PageDef {
+	PageDef {
+		route: route.into(),
+		template: template.into(),
+		locale_templates: None,
+		loaders,
+		data_id: "__data".into(),
+		layout_chain: vec![],
+		page_loader_keys: vec![],
+		i18n_keys: vec![],
+		projections: None,
+		pagination: None,
+		prerender: false,
+		static_dir: None,
+		template_mtime: None,
+		compiled: None,
+	}
+}
+
+fn greeting_server() -> SeamServer {
+	let loader = LoaderDef {
+		data_key: "greeting".into(),
+		procedure: "greet".into(),
+		input_fn: Arc::new(|params| serde_json::json!({"name": params.get("name")})),
+	};
+	test_server()
+		.page(page_def(
+			"/hello/{name}",
+			"<html><body><p><!--seam:message--></p></body></html>",
+			vec![loader],
+		))
+		.page(page_def("/hello/world", "<html><body>static</body></html>", vec![]))
+		.page(page_def("/docs/{*rest}", "<html><body>docs</body></html>", vec![]))
+}
+
+#[tokio::test]
+async fn page_renders_loader_data() {
+	let (status, headers, html) =
+		send(greeting_server(), Method::GET, "/_seam/page/hello/Ann", "").await;
+	assert_eq!(status, StatusCode::OK);
+	assert_eq!(headers["content-type"], "text/html; charset=utf-8");
+	assert!(html.starts_with("<html><body><p>Hello, Ann!</p>"));
+	assert!(html.contains(r#"<script id="__data" type="application/json">"#));
+}
+
+#[tokio::test]
+async fn page_params_are_percent_decoded() {
+	let (status, _, html) =
+		send(greeting_server(), Method::GET, "/_seam/page/hello/Ann%20Lee", "").await;
+	assert_eq!(status, StatusCode::OK);
+	assert!(html.starts_with("<html><body><p>Hello, Ann Lee!</p>"), "{html}");
+}
+
+#[tokio::test]
+async fn literal_route_beats_param_route() {
+	let (status, _, html) = send(greeting_server(), Method::GET, "/_seam/page/hello/world", "").await;
+	assert_eq!(status, StatusCode::OK);
+	assert!(html.starts_with("<html><body>static"));
+}
+
+#[tokio::test]
+async fn catch_all_matches_nested_path() {
+	let (status, _, html) = send(greeting_server(), Method::GET, "/_seam/page/docs/a/b/", "").await;
+	assert_eq!(status, StatusCode::OK);
+	assert!(html.starts_with("<html><body>docs"));
+	let (status, _, _) = send(greeting_server(), Method::GET, "/_seam/page/docs", "").await;
+	assert_eq!(status, StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn page_trailing_slash_redirects() {
+	let (status, headers, _) =
+		send(greeting_server(), Method::GET, "/_seam/page/hello/Ann/?x=1", "").await;
+	assert_eq!(status, StatusCode::PERMANENT_REDIRECT);
+	assert_eq!(headers["location"], "/hello/Ann?x=1");
+}
+
+#[tokio::test]
+async fn page_trailing_slash_strict_is_not_found() {
+	let server = greeting_server().trailing_slash(TrailingSlash::Strict);
+	let (status, _, _) = send(server, Method::GET, "/_seam/page/hello/Ann/", "").await;
+	assert_eq!(status, StatusCode::NOT_FOUND);
+}
+
+/// A page whose loader fails (unregistered procedure) plus both status templates.
+fn status_page_server() -> SeamServer {
+	let loader = LoaderDef {
+		data_key: "boom".into(),
+		procedure: "missing".into(),
+		input_fn: Arc::new(|_params| serde_json::json!({})),
+	};
+	SeamServer::new()
+		.page(page_def("/broken", "<html><body></body></html>", vec![loader]))
+		.not_found_page("<h1><!--seam:status--> <!--seam:path--></h1>")
+		.error_page("<h1><!--seam:status--></h1>")
+}
+
+#[tokio::test]
+async fn status_pages_render_for_unknown_page_and_failing_loader() {
+	let get = |path| send(status_page_server(), Method::GET, path, "");
+	let (status, headers, html) = get("/_seam/page/missing").await;
+	assert_eq!((status, html.as_str()), (StatusCode::NOT_FOUND, "<h1>404 /missing</h1>"));
+	assert_eq!(headers["content-type"], "text/html; charset=utf-8");
+	let (status, _, html) = get("/_seam/page/broken").await;
+	assert_eq!((status, html.as_str()), (StatusCode::INTERNAL_SERVER_ERROR, "<h1>500</h1>"));
+
+	// Non-page URLs keep the JSON 404
+	let (status, headers, _) = get("/_seam/other").await;
+	assert_eq!(status, StatusCode::NOT_FOUND);
+	assert_eq!(headers["content-type"], "application/json");
+}
@@ -0,0 +1,42 @@
+/* src/server/adapter/hyper/src/tests/rpc.rs */
+
+use super::*;
+
+#[tokio::test]
+async fn query_returns_data() {
+	let (status, headers, body) =
+		send(test_server(), Method::POST, "/_seam/procedure/greet", r#"{"name":"Hyper"}"#).await;
+	assert_eq!(status, StatusCode::OK);
+	assert_eq!(headers["content-type"], "application/json");
+	let json: serde_json::Value = serde_json::from_str(&body).unwrap();
+	assert_eq!(json, serde_json::json!({"ok": true, "data": {"message": "Hello, Hyper!"}}));
+}
+
+#[tokio::test]
+async fn invalid_input_fails_validation() {
+	let (status, _, body) =
+		send(test_server(), Method::POST, "/_seam/procedure/greet", r#"{"name":42}"#).await;
+	assert_eq!(status, StatusCode::BAD_REQUEST);
+	let json: serde_json::Value = serde_json::from_str(&body).unwrap();
+	assert_eq!(json["error"]["code"], "VALIDATION_ERROR");
+}
+
+#[tokio::test]
+async fn batch_preserves_call_order() {
+	let body = r#"{"calls":[{"procedure":"greet","input":{"name":"a"}},{"procedure":"nope"}]}"#;
+	let (status, _, body) = send(test_server(), Method::POST, "/_seam/procedure/_batch", body).await;
+	assert_eq!(status, StatusCode::OK);
+	let json: serde_json::Value = serde_json::from_str(&body).unwrap();
+	let results = json["data"]["results"].as_array().unwrap();
+	assert_eq!(results[0]["data"]["message"], "Hello, a!");
+	assert_eq!(results[1]["error"]["code"], "NOT_FOUND");
+}
+
+#[tokio::test]
+async fn oversized_body_is_rejected() {
+	let body = format!(r#"{{"name":"{}"}}"#, "x".repeat(seam_server::dispatch::MAX_BODY_BYTES));
+	let (status, _, body) = send(test_server(), Method::POST, "/_seam/procedure/greet", &body).await;
+	assert_eq!(status, StatusCode::PAYLOAD_TOO_LARGE);
+	let json: serde_json::Value = serde_json::from_str(&body).unwrap();
+	assert_eq!(json["error"]["code"], "PAYLOAD_TOO_LARGE");
+}
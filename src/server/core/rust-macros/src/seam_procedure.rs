@@ -10,6 +10,8 @@ pub(crate) struct ProcedureAttr {
 	pub error: Option<syn::Path>,
 	pub context: Option<syn::Path>,
 	pub state: Option<syn::Path>,
+	/// Handler deadline in milliseconds, from `timeout = "500ms" | "5s" | "2m"`
+	pub timeout_ms: Option<u64>,
 }
 
 impl Parse for ProcedureAttr {
@@ -18,6 +20,7 @@ impl Parse for ProcedureAttr {
 		let mut error = None;
 		let mut context = None;
 		let mut state = None;
+		let mut timeout_ms = None;
 
 		while !input.is_empty() {
 			let ident: syn::Ident = input.parse()?;
@@ -34,17 +37,39 @@ impl Parse for ProcedureAttr {
 			} else if ident == "state" {
 				input.parse::<Token![=]>()?;
 				state = Some(input.parse::<syn::Path>()?);
+			} else if ident == "timeout" {
+				input.parse::<Token![=]>()?;
+				let lit: LitStr = input.parse()?;
+				timeout_ms = Some(parse_duration_ms(&lit)?);
 			} else {
 				return Err(syn::Error::new_spanned(
 					ident,
-					"expected `name`, `error`, `context`, or `state`",
+					"expected `name`, `error`, `context`, `state`, or `timeout`",
 				));
 			}
 			// consume optional trailing comma
 			let _ = input.parse::<Token![,]>();
 		}
 
-		Ok(ProcedureAttr { name, error, context, state })
+		Ok(ProcedureAttr { name, error, context, state, timeout_ms })
+	}
+}
+
+/// Parse `"500ms"`, `"5s"`, or `"2m"` into milliseconds.
+fn parse_duration_ms(lit: &LitStr) -> syn::Result<u64> {
+	let value = lit.value();
+	let (digits, scale) = if let Some(n) = value.strip_suffix("ms") {
+		(n, 1)
+	} else if let Some(n) = value.strip_suffix('s') {
+		(n, 1_000)
+	} else if let Some(n) = value.strip_suffix('m') {
+		(n, 60_000)
+	} else {
+		(value.as_str(), 0)
+	};
+	match digits.parse::<u64>().ok().filter(|&n| n > 0).and_then(|n| n.checked_mul(scale)) {
+		Some(ms) if ms > 0 => Ok(ms),
+		_ => Err(syn::Error::new_spanned(lit, "expected a duration like \"500ms\", \"5s\", or \"2m\"")),
 	}
 }
 
@@ -74,6 +99,11 @@ pub(crate) fn expand_with_type(
 		None => quote! {},
 	};
 
+	let timeout_expr = match attr.timeout_ms {
+		Some(ms) => quote! { Some(std::time::Duration::from_millis(#ms)) },
+		None => quote! { None },
+	};
+
 	let error_schema_expr = match attr.error {
 		Some(path) => quote! { Some(<#path as seam_server::SeamType>::jtd_schema()) },
 		None => quote! { None },
@@ -169,6 +199,7 @@ pub(crate) fn expand_with_type(
 				context_keys: #context_keys_expr,
 				suppress: None,
 				cache: None,
+				timeout: #timeout_expr,
 				handler: #handler_body,
 			}
 		}
@@ -3,7 +3,7 @@
 use proc_macro2::TokenStream;
 use quote::quote;
 use syn::parse::{Parse, ParseStream};
-use syn::{ItemFn, LitStr, ReturnType, Token, Type};
+use syn::{ItemFn, LitInt, LitStr, ReturnType, Token, Type};
 
 use crate::seam_procedure::{ensure_arg_count, extract_input_type};
 
@@ -11,6 +11,10 @@ struct SubscriptionAttr {
 	name: Option<String>,
 	context: Option<syn::Path>,
 	state: Option<syn::Path>,
+	/// `buffer = 64` queue capacity; `None` keeps the unbuffered stream
+	buffer: Option<usize>,
+	/// `overflow = "drop_oldest" | "drop_newest" | "block"`, `OverflowPolicy` variant name
+	overflow: Option<syn::Ident>,
 }
 
 impl Parse for SubscriptionAttr {
@@ -18,6 +22,8 @@ impl Parse for SubscriptionAttr {
 		let mut name = None;
 		let mut context = None;
 		let mut state = None;
+		let mut buffer = None;
+		let mut overflow = None;
 
 		while !input.is_empty() {
 			let ident: syn::Ident = input.parse()?;
@@ -31,17 +37,47 @@ impl Parse for SubscriptionAttr {
 			} else if ident == "state" {
 				input.parse::<Token![=]>()?;
 				state = Some(input.parse::<syn::Path>()?);
+			} else if ident == "buffer" {
+				input.parse::<Token![=]>()?;
+				let lit: LitInt = input.parse()?;
+				match lit.base10_parse::<usize>()? {
+					0 => return Err(syn::Error::new_spanned(lit, "buffer capacity must be at least 1")),
+					n => buffer = Some(n),
+				}
+			} else if ident == "overflow" {
+				input.parse::<Token![=]>()?;
+				let lit: LitStr = input.parse()?;
+				let variant = match lit.value().as_str() {
+					"drop_oldest" => "DropOldest",
+					"drop_newest" => "DropNewest",
+					"block" => "Block",
+					_ => {
+						return Err(syn::Error::new_spanned(
+							lit,
+							"expected \"drop_oldest\", \"drop_newest\", or \"block\"",
+						));
+					}
+				};
+				overflow = Some(syn::Ident::new(variant, lit.span()));
 			} else {
-				return Err(syn::Error::new_spanned(ident, "expected `name`, `context`, or `state`"));
+				return Err(syn::Error::new_spanned(
+					ident,
+					"expected `name`, `context`, `state`, `buffer`, or `overflow`",
+				));
 			}
 			let _ = input.parse::<Token![,]>();
 		}
 
-		Ok(SubscriptionAttr { name, context, state })
+		if buffer.is_none() && overflow.is_some() {
+			return Err(input.error("`overflow` requires `buffer`"));
+		}
+
+		Ok(SubscriptionAttr { name, context, state, buffer, overflow })
 	}
 }
 
 #[allow(clippy::needless_pass_by_value)]
+#[allow(clippy::too_many_lines)]
 pub fn expand(attr: TokenStream, item: ItemFn) -> syn::Result<TokenStream> {
 	let parsed_attr: SubscriptionAttr = syn::parse2(attr)?;
 
@@ -54,6 +90,7 @@ pub fn expand(attr: TokenStream, item: ItemFn) -> syn::Result<TokenStream> {
 	let expected_args =
 		1 + usize::from(parsed_attr.context.is_some()) + usize::from(parsed_attr.state.is_some());
 	ensure_arg_count(&item, expected_args, "subscription")?;
+	let buffer_expr = buffer_expr(parsed_attr.buffer, parsed_attr.overflow);
 	let factory_params = match parsed_attr.state.clone() {
 		Some(state_path) => quote! { state: std::sync::Arc<#state_path> },
 		None => quote! {},
@@ -143,12 +180,28 @@ pub fn expand(attr: TokenStream, item: ItemFn) -> syn::Result<TokenStream> {
 				error_schema: None,
 				context_keys: #context_keys_expr,
 				suppress: None,
+				buffer: #buffer_expr,
 				handler: #handler_body,
 			}
 		}
 	})
 }
 
+/// `SubscriptionDef::buffer` initializer; `overflow` defaults to the lossless `Block`.
+fn buffer_expr(capacity: Option<usize>, overflow: Option<syn::Ident>) -> TokenStream {
+	let Some(capacity) = capacity else {
+		return quote! { None };
+	};
+	let overflow =
+		overflow.unwrap_or_else(|| syn::Ident::new("Block", proc_macro2::Span::call_site()));
+	quote! {
+		Some(seam_server::SubscriptionBuffer {
+			capacity: #capacity,
+			overflow: seam_server::OverflowPolicy::#overflow,
+		})
+	}
+}
+
 fn extract_output_type(item: &ItemFn) -> syn::Result<Type> {
 	match &item.sig.output {
 		ReturnType::Type(_, ty) => {
@@ -8,9 +8,9 @@ pub fn expand(input: &DeriveInput) -> syn::Result<TokenStream> {
 	let name = &input.ident;
 	let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
 
-	let body = match &input.data {
-		Data::Struct(data) => expand_struct(&data.fields)?,
-		Data::Enum(data) => expand_enum(data)?,
+	let (body, keys) = match &input.data {
+		Data::Struct(data) => expand_struct(&data.fields, name)?,
+		Data::Enum(data) => (expand_enum(data)?, None),
 		_ => {
 			return Err(syn::Error::new_spanned(
 				&input.ident,
@@ -19,11 +19,13 @@ pub fn expand(input: &DeriveInput) -> syn::Result<TokenStream> {
 		}
 	};
 
+	let keys = keys.map(|keys| quote! { const KEYS: seam_server::FlatKeys = #keys; });
 	Ok(quote! {
 		impl #impl_generics seam_server::SeamType for #name #ty_generics #where_clause {
 			fn jtd_schema() -> serde_json::Value {
 				#body
 			}
+			#keys
 		}
 	})
 }
@@ -46,59 +48,143 @@ fn expand_enum(data: &syn::DataEnum) -> syn::Result<TokenStream> {
 	})
 }
 
-fn has_seam_optional(field: &syn::Field) -> bool {
-	for attr in &field.attrs {
-		if attr.path().is_ident("seam") {
-			// Parse #[seam(optional)]
-			if let Ok(nested) = attr.parse_args_with(
-				syn::punctuated::Punctuated::<syn::Ident, syn::Token![,]>::parse_terminated,
-			) {
-				for ident in &nested {
-					if ident == "optional" {
-						return true;
+/// True when the field carries `#[seam(<flag>)]`, alone or among other flags.
+fn has_seam_flag(field: &syn::Field, flag: &str) -> bool {
+	field.attrs.iter().filter(|attr| attr.path().is_ident("seam")).any(|attr| {
+		attr
+			.parse_args_with(syn::punctuated::Punctuated::<syn::Ident, syn::Token![,]>::parse_terminated)
+			.is_ok_and(|nested| nested.iter().any(|ident| ident == flag))
+	})
+}
+
+/// `#[serde(skip_serializing_if = ...)]` on the field: serde omits `None` instead of
+/// writing `null`.
+fn skips_serializing_none(field: &syn::Field) -> bool {
+	field.attrs.iter().filter(|attr| attr.path().is_ident("serde")).any(|attr| {
+		let mut found = false;
+		// Other serde keys are skipped over; a parse error just means "not found"
+		let _ = attr.parse_nested_meta(|meta| {
+			found |= meta.path.is_ident("skip_serializing_if");
+			if meta.input.peek(syn::Token![=]) {
+				meta.value()?.parse::<syn::Expr>()?;
+			} else if meta.input.peek(syn::token::Paren) {
+				meta.parse_nested_meta(|inner| {
+					if inner.input.peek(syn::Token![=]) {
+						inner.value()?.parse::<syn::Expr>()?;
 					}
-				}
+					Ok(())
+				})?;
 			}
-		}
+			Ok(())
+		});
+		found
+	})
+}
+
+/// Tuple structs serialize like serde does: a newtype is transparent, so `UserId(u32)`
+/// has the schema of `u32`. Wider tuples serialize as JSON arrays; JTD can only describe
+/// those as `elements`, so every field must share one type.
+fn expand_tuple(fields: &syn::FieldsUnnamed) -> syn::Result<(TokenStream, Option<TokenStream>)> {
+	let mut types = fields.unnamed.iter().map(|f| &f.ty);
+	let Some(first) = types.next() else {
+		return Err(syn::Error::new_spanned(fields, "SeamType requires at least one field"));
+	};
+	if fields.unnamed.len() == 1 {
+		// Transparent, so flattening the newtype flattens the wrapped struct's keys
+		return Ok((
+			quote! { <#first as seam_server::SeamType>::jtd_schema() },
+			Some(quote! { <#first as seam_server::SeamType>::KEYS }),
+		));
 	}
-	false
+	let first_tokens = quote!(#first).to_string();
+	if let Some(other) = types.find(|ty| quote!(#ty).to_string() != first_tokens) {
+		return Err(syn::Error::new_spanned(
+			other,
+			"SeamType tuple structs with several fields need one shared field type (JTD `elements`)",
+		));
+	}
+	Ok((
+		quote! { serde_json::json!({ "elements": <#first as seam_server::SeamType>::jtd_schema() }) },
+		None,
+	))
 }
 
-fn expand_struct(fields: &Fields) -> syn::Result<TokenStream> {
-	let Fields::Named(named) = fields else {
-		return Err(syn::Error::new_spanned(fields, "SeamType requires named fields"));
+fn expand_struct(
+	fields: &Fields,
+	name: &syn::Ident,
+) -> syn::Result<(TokenStream, Option<TokenStream>)> {
+	let named = match fields {
+		Fields::Named(named) => named,
+		Fields::Unnamed(unnamed) => return expand_tuple(unnamed),
+		Fields::Unit => {
+			return Err(syn::Error::new_spanned(fields, "SeamType requires named fields"));
+		}
 	};
 
 	let mut required_inserts = Vec::new();
 	let mut optional_inserts = Vec::new();
+	let mut flatten_merges = Vec::new();
+	let mut own_keys = Vec::new();
+	let mut flattened_keys = Vec::new();
 
 	for field in &named.named {
+		// Bookkeeping fields (usually also `#[serde(skip)]`) stay out of the wire schema,
+		// so their type need not implement SeamType
+		if has_seam_flag(field, "skip") {
+			continue;
+		}
 		let field_name = field.ident.as_ref().expect("named field has identifier");
 		let key = field_name.to_string();
 		let ty = &field.ty;
-		let is_optional = has_seam_optional(field);
+		let is_optional = has_seam_flag(field, "optional");
+		let is_nullable = has_seam_flag(field, "nullable");
 
+		if has_seam_flag(field, "flatten") {
+			if is_optional || is_nullable {
+				return Err(syn::Error::new_spanned(
+					field,
+					"#[seam(flatten)] cannot be combined with optional or nullable",
+				));
+			}
+			flatten_merges.push(flatten_merge(ty));
+			flattened_keys.push(quote! { <#ty as seam_server::SeamType>::KEYS });
+			continue;
+		}
+		own_keys.push(key.clone());
 		if is_option_type(ty) {
 			let inner = extract_option_inner(ty)
 				.ok_or_else(|| syn::Error::new_spanned(ty, "could not extract inner type from Option"))?;
-			// Option<T>: nullable schema. Placement depends on #[seam(optional)].
+			// Option<T> is an optional property by default. serde writes `None` as `null`
+			// unless the field has `skip_serializing_if`, so only then is it `field?: T`;
+			// otherwise it is `field?: T | null`. #[seam(nullable)] makes it a required
+			// nullable property (`field: T | null`), and #[seam(optional, nullable)] keeps
+			// it optional while always accepting null.
+			let nullable = if is_nullable || !skips_serializing_none(field) {
+				quote! {
+					if let Some(obj) = schema.as_object_mut() {
+						obj.insert("nullable".to_string(), serde_json::Value::Bool(true));
+					}
+				}
+			} else {
+				quote! {}
+			};
 			let insert = quote! {
 				let mut schema = <#inner as seam_server::SeamType>::jtd_schema();
-				if let Some(obj) = schema.as_object_mut() {
-					obj.insert("nullable".to_string(), serde_json::Value::Bool(true));
-				}
+				#nullable
 			};
-			if is_optional {
-				optional_inserts.push(quote! {
+			if is_nullable && !is_optional {
+				required_inserts.push(quote! {
 					#insert
-					opt_props.insert(#key.to_string(), schema);
+					props.insert(#key.to_string(), schema);
 				});
 			} else {
-				required_inserts.push(quote! {
+				optional_inserts.push(quote! {
 					#insert
-					props.insert(#key.to_string(), schema);
+					opt_props.insert(#key.to_string(), schema);
 				});
 			}
+		} else if is_nullable {
+			return Err(syn::Error::new_spanned(ty, "#[seam(nullable)] requires an Option<T> field"));
 		} else if is_optional {
 			// Non-Option with #[seam(optional)]: field may be absent, not nullable
 			optional_inserts.push(quote! {
@@ -117,11 +203,17 @@ fn expand_struct(fields: &Fields) -> syn::Result<TokenStream> {
 		}
 	}
 
-	Ok(quote! {
+	let clash_check = flatten_clash_check(name, !flattened_keys.is_empty());
+	let keys = quote! {
+		seam_server::FlatKeys { own: &[#(#own_keys),*], flattened: &[#(#flattened_keys),*] }
+	};
+	let body = quote! {
+		#clash_check
 		let mut props = serde_json::Map::new();
 		let mut opt_props = serde_json::Map::new();
 		#(#required_inserts)*
 		#(#optional_inserts)*
+		#(#flatten_merges)*
 
 		let mut schema = serde_json::Map::new();
 		schema.insert("properties".to_string(), serde_json::Value::Object(props));
@@ -129,7 +221,37 @@ fn expand_struct(fields: &Fields) -> syn::Result<TokenStream> {
 			schema.insert("optionalProperties".to_string(), serde_json::Value::Object(opt_props));
 		}
 		serde_json::Value::Object(schema)
-	})
+	};
+	Ok((body, Some(keys)))
+}
+
+/// Inner keys are only known to the compiler, so the clash check is a const assertion
+/// rather than a macro error. It fails the build when the impl is compiled.
+fn flatten_clash_check(name: &syn::Ident, has_flatten: bool) -> TokenStream {
+	if !has_flatten {
+		return quote! {};
+	}
+	let msg = format!("`{name}`: a #[seam(flatten)] field repeats a key of the merged object");
+	quote! {
+		const { assert!(!<Self as seam_server::SeamType>::KEYS.has_duplicate(), #msg) };
+	}
+}
+
+/// Inline a `#[seam(flatten)]` field's properties into the parent, like `#[serde(flatten)]`.
+/// Key clashes never get here: the derive's `KEYS` const assertion rejects them at build.
+fn flatten_merge(ty: &Type) -> TokenStream {
+	quote! {
+		let flat = <#ty as seam_server::SeamType>::jtd_schema();
+		for (section, optional) in [("properties", false), ("optionalProperties", true)] {
+			let Some(fields) = flat.get(section).and_then(serde_json::Value::as_object) else {
+				continue;
+			};
+			for (key, value) in fields {
+				let target = if optional { &mut opt_props } else { &mut props };
+				target.insert(key.clone(), value.clone());
+			}
+		}
+	}
 }
 
 fn is_option_type(ty: &Type) -> bool {
@@ -0,0 +1,23 @@
+/* src/server/core/rust-macros/tests/fail/derive_flatten_collision.rs */
+
+use seam_macros::SeamType;
+
+#[derive(SeamType)]
+struct Pagination {
+	page: u32,
+}
+
+#[derive(SeamType)]
+struct Paged {
+	#[seam(flatten)]
+	pagination: Pagination,
+}
+
+#[derive(SeamType)]
+struct ClashingPage {
+	page: String,
+	#[seam(flatten)]
+	paged: Paged,
+}
+
+fn main() {}
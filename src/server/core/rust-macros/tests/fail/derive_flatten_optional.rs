@@ -0,0 +1,16 @@
+/* src/server/core/rust-macros/tests/fail/derive_flatten_optional.rs */
+
+use seam_macros::SeamType;
+
+#[derive(SeamType)]
+struct Inner {
+	page: u32,
+}
+
+#[derive(SeamType)]
+struct Outer {
+	#[seam(flatten, optional)]
+	inner: Inner,
+}
+
+fn main() {}
@@ -3,6 +3,6 @@
 use seam_macros::SeamType;
 
 #[derive(SeamType)]
-struct Point(f64, f64);
+struct Pair(String, u32);
 
 fn main() {}
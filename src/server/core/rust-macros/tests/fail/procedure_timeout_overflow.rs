@@ -0,0 +1,10 @@
+/* src/server/core/rust-macros/tests/fail/procedure_timeout_overflow.rs */
+
+use seam_macros::seam_procedure;
+
+#[seam_procedure(timeout = "999999999999999999m")]
+async fn bad(input: String) -> Result<String, seam_server::SeamError> {
+  Ok(input)
+}
+
+fn main() {}
@@ -0,0 +1,12 @@
+/* src/server/core/rust-macros/tests/fail/subscription_bad_overflow.rs */
+
+use seam_macros::seam_subscription;
+
+#[seam_subscription(buffer = 16, overflow = "drop_all")]
+async fn ticks(
+  _input: String,
+) -> Result<seam_server::BoxStream<Result<String, seam_server::SeamError>>, seam_server::SeamError> {
+  unimplemented!()
+}
+
+fn main() {}
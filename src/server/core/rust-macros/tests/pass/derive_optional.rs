@@ -7,7 +7,9 @@ use serde::{Deserialize, Serialize};
 struct Profile {
   name: String,
   bio: Option<String>,
-  #[seam(optional)]
+  #[seam(nullable)]
+  location: Option<String>,
+  #[seam(optional, nullable)]
   nickname: Option<String>,
 }
 
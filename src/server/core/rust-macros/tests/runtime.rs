@@ -89,6 +89,184 @@ fn derive_schema_validates() {
 	assert!(validate_compiled(&compiled, &invalid).is_err());
 }
 
+// -- Optional vs nullable fields --
+
+#[derive(SeamType, Serialize, Deserialize)]
+struct ProfileFields {
+	name: String,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	nickname: Option<String>,
+	status: Option<String>,
+	#[seam(nullable)]
+	bio: Option<String>,
+	#[seam(optional, nullable)]
+	avatar: Option<String>,
+	#[seam(optional)]
+	tags: Vec<String>,
+}
+
+#[test]
+fn derive_option_field_is_optional_not_nullable() {
+	assert_eq!(
+		ProfileFields::jtd_schema(),
+		json!({
+			"properties": {
+				"name": {"type": "string"},
+				"bio": {"type": "string", "nullable": true},
+			},
+			"optionalProperties": {
+				"nickname": {"type": "string"},
+				"status": {"type": "string", "nullable": true},
+				"avatar": {"type": "string", "nullable": true},
+				"tags": {"elements": {"type": "string"}},
+			},
+		}),
+	);
+}
+
+#[test]
+fn derive_optional_and_nullable_validate_differently() {
+	let compiled = compile_schema(&ProfileFields::jtd_schema()).unwrap();
+
+	// Optional field may be absent; nullable field must be present
+	assert!(validate_compiled(&compiled, &json!({"name": "a", "bio": null})).is_ok());
+	assert!(validate_compiled(&compiled, &json!({"name": "a"})).is_err());
+
+	// An Option serde omits when None rejects an explicit null; optional + nullable accepts it
+	assert!(
+		validate_compiled(&compiled, &json!({"name": "a", "bio": null, "nickname": null})).is_err()
+	);
+	assert!(validate_compiled(&compiled, &json!({"name": "a", "bio": null, "avatar": null})).is_ok());
+}
+
+#[test]
+fn derive_option_fields_set_to_none_validate_after_serializing() {
+	let compiled = compile_schema(&ProfileFields::jtd_schema()).unwrap();
+	let none = ProfileFields {
+		name: "a".into(),
+		nickname: None,
+		status: None,
+		bio: None,
+		avatar: None,
+		tags: vec![],
+	};
+	let wire = serde_json::to_value(none).unwrap();
+	// `status` goes out as null, `nickname` is omitted
+	assert_eq!(wire["status"], json!(null));
+	assert!(wire.get("nickname").is_none());
+	assert!(validate_compiled(&compiled, &wire).is_ok());
+}
+
+// -- Skipped fields --
+
+#[derive(SeamType, Serialize, Deserialize)]
+#[allow(dead_code)]
+struct CachedProfile {
+	name: String,
+	#[seam(skip)]
+	#[serde(skip)]
+	cache: Option<std::sync::Mutex<Vec<u8>>>,
+	#[seam(optional)]
+	bio: String,
+}
+
+#[test]
+fn derive_skip_field_absent_from_schema() {
+	assert_eq!(
+		CachedProfile::jtd_schema(),
+		json!({
+			"properties": {"name": {"type": "string"}},
+			"optionalProperties": {"bio": {"type": "string"}},
+		}),
+	);
+}
+
+// -- Flattened fields --
+
+#[derive(SeamType, Serialize, Deserialize)]
+struct Pagination {
+	page: u32,
+	#[seam(optional)]
+	size: u32,
+}
+
+#[derive(SeamType, Serialize, Deserialize)]
+struct UserPage {
+	users: Vec<String>,
+	#[seam(flatten)]
+	#[serde(flatten)]
+	pagination: Pagination,
+}
+
+#[test]
+fn derive_flatten_inlines_fields() {
+	assert_eq!(
+		UserPage::jtd_schema(),
+		json!({
+			"properties": {"users": {"elements": {"type": "string"}}, "page": {"type": "uint32"}},
+			"optionalProperties": {"size": {"type": "uint32"}},
+		}),
+	);
+	let wire = UserPage { users: vec![], pagination: Pagination { page: 1, size: 20 } };
+	let compiled = compile_schema(&UserPage::jtd_schema()).unwrap();
+	assert!(validate_compiled(&compiled, &serde_json::to_value(wire).unwrap()).is_ok());
+}
+
+// -- Set fields --
+
+#[derive(SeamType, Serialize, Deserialize)]
+struct TaggedItem {
+	tags: std::collections::HashSet<String>,
+	scores: std::collections::BTreeSet<u32>,
+}
+
+#[test]
+fn derive_set_fields_as_elements() {
+	assert_eq!(
+		TaggedItem::jtd_schema(),
+		json!({
+			"properties": {
+				"tags": {"elements": {"type": "string"}},
+				"scores": {"elements": {"type": "uint32"}},
+			},
+		}),
+	);
+}
+
+// -- Tuple structs --
+
+#[derive(SeamType, Serialize, Deserialize)]
+struct UserId(u32);
+
+#[derive(SeamType, Serialize, Deserialize)]
+struct Point(f64, f64);
+
+#[derive(SeamType, Serialize, Deserialize)]
+struct Owner {
+	id: UserId,
+	home: Option<Point>,
+}
+
+#[test]
+fn derive_newtype_is_transparent() {
+	assert_eq!(UserId::jtd_schema(), json!({"type": "uint32"}));
+	assert_eq!(serde_json::to_value(UserId(7)).unwrap(), json!(7));
+	assert_eq!(
+		Owner::jtd_schema(),
+		json!({
+			"properties": {"id": {"type": "uint32"}},
+			"optionalProperties": {"home": {"elements": {"type": "float64"}, "nullable": true}},
+		}),
+	);
+}
+
+#[test]
+fn derive_homogeneous_tuple_as_elements() {
+	assert_eq!(Point::jtd_schema(), json!({"elements": {"type": "float64"}}));
+	let compiled = compile_schema(&Point::jtd_schema()).unwrap();
+	assert!(validate_compiled(&compiled, &serde_json::to_value(Point(1.0, 2.5)).unwrap()).is_ok());
+}
+
 // -- Procedure with context --
 
 #[derive(SeamType, Serialize, Deserialize)]
@@ -112,6 +290,17 @@ struct AppState {
 	prefix: String,
 }
 
+#[seam_procedure(name = "greetWithDeadline", timeout = "2s")]
+async fn greet_deadline(input: GreetInput) -> Result<GreetOutput, SeamError> {
+	greet(input).await
+}
+
+#[test]
+fn procedure_timeout_attribute() {
+	assert_eq!(greet_deadline_procedure().timeout, Some(std::time::Duration::from_secs(2)));
+	assert_eq!(greet_procedure().timeout, None);
+}
+
 #[seam_procedure(context = AuthCtx)]
 async fn get_profile(input: ProfileInput, ctx: AuthCtx) -> Result<ProfileOutput, SeamError> {
 	Ok(ProfileOutput { user_id: ctx.user_id, display: input.format })
@@ -215,6 +404,35 @@ async fn subscription_with_state() {
 	assert_eq!(first, json!({"value": "sub"}));
 }
 
+#[seam_subscription(buffer = 64, overflow = "drop_oldest")]
+async fn on_ticks(
+	_input: EventInput,
+) -> Result<seam_server::BoxStream<Result<EventOutput, SeamError>>, SeamError> {
+	Ok(Box::pin(stream::iter(vec![Ok(EventOutput { value: "tick".into() })])))
+}
+
+#[seam_subscription(buffer = 8)]
+async fn on_ticks_blocking(
+	_input: EventInput,
+) -> Result<seam_server::BoxStream<Result<EventOutput, SeamError>>, SeamError> {
+	Ok(Box::pin(stream::iter(vec![Ok(EventOutput { value: "tick".into() })])))
+}
+
+#[test]
+fn subscription_buffer_attribute() {
+	use seam_server::{OverflowPolicy, SubscriptionBuffer};
+	assert_eq!(
+		on_ticks_subscription().buffer,
+		Some(SubscriptionBuffer { capacity: 64, overflow: OverflowPolicy::DropOldest })
+	);
+	// `overflow` defaults to the lossless policy
+	assert_eq!(
+		on_ticks_blocking_subscription().buffer,
+		Some(SubscriptionBuffer { capacity: 8, overflow: OverflowPolicy::Block })
+	);
+	assert_eq!(on_state_subscription(Arc::new(AppState { prefix: String::new() })).buffer, None);
+}
+
 #[seam_stream(state = AppState)]
 async fn stream_state(
 	_input: EventInput,
@@ -1,7 +1,7 @@
 /* src/server/core/rust/src/build_loader/loader.rs */
 
 use std::collections::HashMap;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
 
 use crate::page::{LoaderDef, PageDef};
@@ -70,16 +70,18 @@ type LocaleLayoutMap = HashMap<String, HashMap<String, LayoutTemplate>>;
 
 /// Resolve a layout chain: walk from child to root, collecting templates.
 /// Returns the full document template with <!--seam:outlet--> replaced by page content.
+/// Page content wrapped in `<seam-fill name="x">` goes to `<!--seam:outlet:x-->` at
+/// whichever layout level declares it instead; named outlets nobody fills stay empty.
 pub(super) fn resolve_layout_chain(
 	layout_id: &str,
 	page_template: &str,
 	layouts: &HashMap<String, LayoutTemplate>,
 ) -> String {
-	let mut result = page_template.to_string();
+	let (mut result, fills) = split_outlet_fills(page_template);
 	let mut current = Some(layout_id.to_string());
 	while let Some(id) = current {
 		if let Some((tmpl, parent)) = layouts.get(&id) {
-			result = tmpl.replace("<!--seam:outlet-->", &result);
+			result = fill_named_outlets(tmpl, &fills).replace("<!--seam:outlet-->", &result);
 			current = parent.clone();
 		} else {
 			break;
@@ -88,6 +90,51 @@ pub(super) fn resolve_layout_chain(
 	result
 }
 
+const FILL_OPEN: &str = "<seam-fill name=\"";
+const FILL_CLOSE: &str = "</seam-fill>";
+const NAMED_OUTLET: &str = "<!--seam:outlet:";
+
+/// Pull `<seam-fill name="x">...</seam-fill>` blocks out of a page template.
+/// Returns the remaining content (for the default outlet) and fill bodies by name.
+fn split_outlet_fills(page: &str) -> (String, HashMap<String, String>) {
+	let mut fills = HashMap::new();
+	let mut remaining = String::with_capacity(page.len());
+	let mut rest = page;
+	while let Some(start) = rest.find(FILL_OPEN) {
+		let after = &rest[start + FILL_OPEN.len()..];
+		let parsed = after.find("\">").and_then(|name_end| {
+			let body = &after[name_end + 2..];
+			let body_end = body.find(FILL_CLOSE)?;
+			Some((&after[..name_end], &body[..body_end], &body[body_end + FILL_CLOSE.len()..]))
+		});
+		let Some((name, body, tail)) = parsed else {
+			break;
+		};
+		remaining.push_str(&rest[..start]);
+		fills.entry(name.to_string()).or_insert_with(String::new).push_str(body);
+		rest = tail;
+	}
+	remaining.push_str(rest);
+	(remaining, fills)
+}
+
+/// Replace each `<!--seam:outlet:name-->` in a layout with its fill (or nothing).
+fn fill_named_outlets(layout: &str, fills: &HashMap<String, String>) -> String {
+	let mut out = String::with_capacity(layout.len());
+	let mut rest = layout;
+	while let Some(start) = rest.find(NAMED_OUTLET) {
+		let after = &rest[start + NAMED_OUTLET.len()..];
+		let Some(end) = after.find("-->") else {
+			break;
+		};
+		out.push_str(&rest[..start]);
+		out.push_str(fills.get(&after[..end]).map_or("", String::as_str));
+		rest = &after[end + 3..];
+	}
+	out.push_str(rest);
+	out
+}
+
 /// Walk a layout entry chain from child to root, calling `f(id, entry)` at each level.
 fn walk_layout_chain(
 	start: &str,
@@ -105,9 +152,60 @@ fn walk_layout_chain(
 	}
 }
 
+/// Template files under the build output directory.
+#[derive(Clone, Copy)]
+struct TemplateFiles<'a> {
+	base: &'a Path,
+	/// The manifest's `gzip_templates`: the build wrote a `<path>.gz` for each template
+	gzip: bool,
+}
+
+impl TemplateFiles<'_> {
+	/// The file to load for `rel` and whether it is gzipped. The `.gz` sibling wins only
+	/// when it is at least as new as the plain file, so an `.html` edited after the build
+	/// is not shadowed by its stale compressed copy.
+	fn source(self, rel: &str) -> (PathBuf, bool) {
+		let path = self.base.join(rel);
+		if self.gzip {
+			let mut gz_path = path.clone().into_os_string();
+			gz_path.push(".gz");
+			let gz_path = PathBuf::from(gz_path);
+			if let Some(gz_mtime) = file_mtime(&gz_path)
+				&& file_mtime(&path).is_none_or(|mtime| gz_mtime >= mtime)
+			{
+				return (gz_path, true);
+			}
+		}
+		(path, false)
+	}
+
+	/// Read a template, decompressing a `.gz` source once here so requests still render
+	/// from the in-memory string.
+	fn read(self, rel: &str) -> std::io::Result<String> {
+		use std::io::Read as _;
+		match self.source(rel) {
+			(path, true) => {
+				let mut html = String::new();
+				flate2::read::GzDecoder::new(std::fs::File::open(path)?).read_to_string(&mut html)?;
+				Ok(html)
+			}
+			(path, false) => std::fs::read_to_string(path),
+		}
+	}
+
+	/// Modification time of the file `read` would pick.
+	fn mtime(self, rel: &str) -> Option<std::time::SystemTime> {
+		file_mtime(&self.source(rel).0)
+	}
+}
+
+fn file_mtime(path: &Path) -> Option<std::time::SystemTime> {
+	std::fs::metadata(path).and_then(|m| m.modified()).ok()
+}
+
 /// Load default and per-locale layout templates from disk.
 fn load_layout_templates(
-	base: &Path,
+	files: TemplateFiles<'_>,
 	manifest: &RouteManifest,
 	default_locale: Option<&str>,
 ) -> Result<(HashMap<String, LayoutTemplate>, LocaleLayoutMap), Box<dyn std::error::Error>> {
@@ -115,7 +213,7 @@ fn load_layout_templates(
 	let mut defaults: HashMap<String, LayoutTemplate> = HashMap::new();
 	for (id, entry) in &manifest.layouts {
 		if let Some(tmpl_path) = pick_template(&entry.template, &entry.templates, default_locale) {
-			let tmpl = std::fs::read_to_string(base.join(&tmpl_path))?;
+			let tmpl = files.read(&tmpl_path)?;
 			defaults.insert(id.clone(), (tmpl, entry.parent.clone()));
 		}
 	}
@@ -126,7 +224,7 @@ fn load_layout_templates(
 		for (id, entry) in &manifest.layouts {
 			if let Some(ref templates) = entry.templates {
 				for (locale, tmpl_path) in templates {
-					let tmpl = std::fs::read_to_string(base.join(tmpl_path))?;
+					let tmpl = files.read(tmpl_path)?;
 					per_locale
 						.entry(locale.clone())
 						.or_default()
@@ -169,6 +267,32 @@ fn collect_i18n_keys(entry: &RouteEntry, layouts: &HashMap<String, LayoutEntry>)
 	keys
 }
 
+/// Newest mtime of every file the resolved page depends on: its template, each layout
+/// up the chain, and the manifest that wires them together. Any of them changing
+/// alters the output, so `Last-Modified` must move with the latest.
+fn page_mtime(
+	files: TemplateFiles<'_>,
+	tmpl_path: &str,
+	entry: &RouteEntry,
+	manifest: &RouteManifest,
+	default_locale: Option<&str>,
+) -> Option<std::time::SystemTime> {
+	let mut newest = files.mtime(tmpl_path)?;
+	let mut sources = vec![file_mtime(&files.base.join("route-manifest.json"))];
+	if let Some(ref layout_id) = entry.layout {
+		walk_layout_chain(layout_id, &manifest.layouts, |_, layout| {
+			if let Some(path) = pick_template(&layout.template, &layout.templates, default_locale) {
+				sources.push(files.mtime(&path));
+			}
+		});
+	}
+	for mtime in sources {
+		// An unreadable dependency leaves no safe validator
+		newest = newest.max(mtime?);
+	}
+	Some(newest)
+}
+
 /// Load page definitions from seam build output on disk.
 /// Reads route-manifest.json, loads templates, constructs PageDef with loaders.
 pub fn load_build_output(dir: &str) -> Result<Vec<PageDef>, Box<dyn std::error::Error>> {
@@ -177,27 +301,26 @@ pub fn load_build_output(dir: &str) -> Result<Vec<PageDef>, Box<dyn std::error::
 	let content = std::fs::read_to_string(&manifest_path)?;
 	let manifest: RouteManifest = serde_json::from_str(&content)?;
 	let default_locale = manifest.i18n.as_ref().map(|c| c.default.as_str());
+	let files = TemplateFiles { base, gzip: manifest.gzip_templates };
 
 	let (layout_templates, layout_locale_templates) =
-		load_layout_templates(base, &manifest, default_locale)?;
+		load_layout_templates(files, &manifest, default_locale)?;
 
 	let mut pages = Vec::new();
 
 	for (route_path, entry) in &manifest.routes {
-		let page_template =
-			if let Some(tmpl_path) = pick_template(&entry.template, &entry.templates, default_locale) {
-				std::fs::read_to_string(base.join(&tmpl_path))?
-			} else {
-				continue;
-			};
+		let Some(tmpl_path) = pick_template(&entry.template, &entry.templates, default_locale) else {
+			continue;
+		};
+		let page_template = files.read(&tmpl_path)?;
+		let template_mtime = page_mtime(files, &tmpl_path, entry, &manifest, default_locale);
 
-		// Resolve layout chain and inject head_meta
-		let template =
-			resolve_with_head_meta(&entry.layout, &page_template, &layout_templates, &entry.head_meta);
+		// Resolve layout chain, head_meta, and route assets
+		let template = resolve_page_template(entry, &page_template, &layout_templates);
 
 		// Build locale-specific pre-resolved templates when i18n is active
 		let locale_templates = build_locale_templates(
-			base,
+			files,
 			entry,
 			&layout_templates,
 			&layout_locale_templates,
@@ -237,35 +360,63 @@ pub fn load_build_output(dir: &str) -> Result<Vec<PageDef>, Box<dyn std::error::
 			page_loader_keys,
 			i18n_keys,
 			projections: entry.projections.clone(),
+			pagination: None,
 			prerender,
 			static_dir,
+			template_mtime,
+			compiled: None,
 		});
 	}
 
 	Ok(pages)
 }
 
-/// Resolve layout chain for a page template and inject head_meta if present.
-fn resolve_with_head_meta(
-	layout_id: &Option<String>,
+/// Resolve layout chain for a page template, bubble its head_meta into the document
+/// `<head>`, and fill the asset slots with the route's bundles. Slots inside the metadata
+/// stay in the template, so they resolve per request.
+fn resolve_page_template(
+	entry: &RouteEntry,
 	page_template: &str,
 	layout_templates: &HashMap<String, LayoutTemplate>,
-	head_meta: &Option<String>,
 ) -> String {
-	if let Some(id) = layout_id {
-		let mut full = resolve_layout_chain(id, page_template, layout_templates);
-		if let Some(meta) = head_meta {
-			full = full.replace("</head>", &format!("{meta}</head>"));
-		}
-		full
+	let full = match entry.layout {
+		Some(ref id) => resolve_layout_chain(id, page_template, layout_templates),
+		None => page_template.to_string(),
+	};
+	let full = match entry.head_meta {
+		Some(ref meta) => bubble_head_meta(&full, meta),
+		None => full,
+	};
+	// Unfilled markers would otherwise render as empty data slots
+	match entry.assets {
+		Some(ref assets) => seam_engine::replace_asset_slots(&full, assets),
+		None => seam_engine::strip_asset_slots(&full),
+	}
+}
+
+/// Splice page metadata into `<head>`, same placement as the engine's render path. A page
+/// `<title>` replaces the layout's, since a document has one title.
+pub(super) fn bubble_head_meta(html: &str, meta: &str) -> String {
+	let mut html = html.to_string();
+	if meta.to_ascii_lowercase().contains("<title")
+		&& let Some(start) = html.find("<title")
+		&& html.find("</head>").is_none_or(|head_end| start < head_end)
+		&& let Some(len) = html[start..].find("</title>")
+	{
+		html.replace_range(start..start + len + "</title>".len(), "");
+	}
+	if html.contains("<head>") || html.contains(r#"<meta charset="utf-8">"#) {
+		seam_engine::inject_head_meta(&html, meta)
 	} else {
-		page_template.to_string()
+		// A `<head>` with attributes has no anchor for the engine: append before it closes
+		let html = seam_engine::drop_default_head_meta(&html, meta);
+		html.replacen("</head>", &format!("{meta}</head>"), 1)
 	}
 }
 
 /// Build per-locale pre-resolved templates when i18n is active.
 fn build_locale_templates(
-	base: &Path,
+	files: TemplateFiles<'_>,
 	entry: &RouteEntry,
 	layout_templates: &HashMap<String, LayoutTemplate>,
 	layout_locale_templates: &LocaleLayoutMap,
@@ -280,10 +431,9 @@ fn build_locale_templates(
 
 	let mut lt = HashMap::new();
 	for (locale, tmpl_path) in templates {
-		let page_tmpl = std::fs::read_to_string(base.join(tmpl_path))?;
+		let page_tmpl = files.read(tmpl_path)?;
 		let locale_layouts = layout_locale_templates.get(locale).unwrap_or(layout_templates);
-		let resolved =
-			resolve_with_head_meta(&entry.layout, &page_tmpl, locale_layouts, &entry.head_meta);
+		let resolved = resolve_page_template(entry, &page_tmpl, locale_layouts);
 		lt.insert(locale.clone(), resolved);
 	}
 
@@ -327,6 +477,7 @@ pub fn load_i18n_config(dir: &str) -> Option<crate::page::I18nConfig> {
 		content_hashes: i18n.content_hashes,
 		messages,
 		dist_dir,
+		routing: i18n.routing,
 	})
 }
 
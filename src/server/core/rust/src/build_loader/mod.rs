@@ -40,9 +40,13 @@ pub fn load_public_dir(dir: &str) -> Option<PathBuf> {
 }
 
 pub fn load_build(dir: &str) -> Result<BuildOutput, Box<dyn std::error::Error>> {
+	let rpc_hash_map = load_rpc_hash_map(dir);
+	if let Some(ref map) = rpc_hash_map {
+		map.validate()?;
+	}
 	Ok(BuildOutput {
 		pages: load_build_output(dir)?,
-		rpc_hash_map: load_rpc_hash_map(dir),
+		rpc_hash_map,
 		i18n_config: load_i18n_config(dir),
 		public_dir: load_public_dir(dir),
 	})
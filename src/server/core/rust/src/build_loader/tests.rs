@@ -3,7 +3,7 @@
 use std::collections::HashMap;
 
 use super::loader::{convert_route_path, load_build_output, parse_loaders};
-use super::types::ParamConfig;
+use super::types::{ParamConfig, RpcHashMap};
 use super::{load_build, load_public_dir};
 
 #[test]
@@ -141,6 +141,64 @@ fn resolve_layout_nested() {
 	assert_eq!(result, "<html><nav>nav</nav><div>page</div></html>");
 }
 
+#[test]
+fn resolve_layout_named_outlets() {
+	let mut layouts = HashMap::new();
+	layouts.insert(
+		"root".to_string(),
+		("<html><!--seam:outlet--><footer><!--seam:outlet:footer--></footer></html>".to_string(), None),
+	);
+	layouts.insert(
+		"shell".to_string(),
+		(
+			"<aside><!--seam:outlet:sidebar--></aside><main><!--seam:outlet--></main>".to_string(),
+			Some("root".to_string()),
+		),
+	);
+
+	let page = concat!(
+		r#"<seam-fill name="sidebar"><a>nav</a></seam-fill>"#,
+		"<h1>page</h1>",
+		r#"<seam-fill name="footer">(c)</seam-fill>"#,
+	);
+	let result = super::loader::resolve_layout_chain("shell", page, &layouts);
+	assert_eq!(
+		result,
+		"<html><aside><a>nav</a></aside><main><h1>page</h1></main><footer>(c)</footer></html>"
+	);
+}
+
+#[test]
+fn resolve_layout_fills_two_named_outlets_in_one_layout() {
+	let mut layouts = HashMap::new();
+	layouts.insert(
+		"shell".to_string(),
+		(
+			"<nav><!--seam:outlet:sidebar--></nav><main><!--seam:outlet--></main><aside><!--seam:outlet:aside--></aside>"
+				.to_string(),
+			None,
+		),
+	);
+	let page = concat!(
+		r#"<seam-fill name="aside"><p>ads</p></seam-fill>"#,
+		"<h1>page</h1>",
+		r#"<seam-fill name="sidebar"><a>nav</a></seam-fill>"#,
+	);
+	let result = super::loader::resolve_layout_chain("shell", page, &layouts);
+	assert_eq!(result, "<nav><a>nav</a></nav><main><h1>page</h1></main><aside><p>ads</p></aside>");
+}
+
+#[test]
+fn resolve_layout_unfilled_named_outlet_is_empty() {
+	let mut layouts = HashMap::new();
+	layouts.insert(
+		"root".to_string(),
+		("<aside><!--seam:outlet:sidebar--></aside><!--seam:outlet-->".to_string(), None),
+	);
+	let result = super::loader::resolve_layout_chain("root", "<p>only main</p>", &layouts);
+	assert_eq!(result, "<aside></aside><p>only main</p>");
+}
+
 #[test]
 fn load_build_output_from_disk() {
 	let dir = std::env::temp_dir().join("seam-test-build-loader");
@@ -197,10 +255,176 @@ fn load_build_output_from_disk() {
 	assert_eq!(pages[0].loaders[0].procedure, "getSession");
 	assert_eq!(pages[0].loaders[1].data_key, "page");
 	assert_eq!(pages[0].loaders[1].procedure, "getHomeData");
+	let newest = ["templates/index.html", "templates/root.html", "route-manifest.json"]
+		.map(|file| std::fs::metadata(dir.join(file)).unwrap().modified().unwrap())
+		.into_iter()
+		.max();
+	assert_eq!(pages[0].template_mtime, newest);
+	// Loaders make the output dynamic, so no Last-Modified
+	assert!(pages[0].last_modified().is_none());
 
 	let _ = std::fs::remove_dir_all(&dir);
 }
 
+#[test]
+fn gzipped_template_matches_plain() {
+	use std::io::Write as _;
+	use std::time::{Duration, SystemTime};
+
+	let html = "<!DOCTYPE html><html><body><h1><!--seam:title--></h1></body></html>";
+	// `gzip` is the manifest flag; `gz_age` backdates the .gz against its plain sibling
+	let load = |name: &str, gzip: bool, gz_age: Option<Duration>| {
+		let dir = std::env::temp_dir().join(name);
+		let _ = std::fs::remove_dir_all(&dir);
+		std::fs::create_dir_all(dir.join("templates")).unwrap();
+		let plain = dir.join("templates/index.html");
+		if let Some(age) = gz_age {
+			std::fs::write(&plain, "plain").unwrap();
+			let mut enc = flate2::write::GzEncoder::new(Vec::new(), flate2::Compression::default());
+			enc.write_all(html.as_bytes()).unwrap();
+			let gz = dir.join("templates/index.html.gz");
+			std::fs::write(&gz, enc.finish().unwrap()).unwrap();
+			let now = SystemTime::now();
+			std::fs::File::options().write(true).open(&plain).unwrap().set_modified(now).unwrap();
+			std::fs::File::options().write(true).open(&gz).unwrap().set_modified(now - age).unwrap();
+		} else {
+			std::fs::write(&plain, html).unwrap();
+		}
+		let manifest = serde_json::json!({
+			"routes": { "/": { "template": "templates/index.html", "loaders": {} } },
+			"gzip_templates": gzip,
+		});
+		std::fs::write(dir.join("route-manifest.json"), manifest.to_string()).unwrap();
+		let pages = load_build_output(dir.to_str().unwrap()).unwrap();
+		let _ = std::fs::remove_dir_all(&dir);
+		pages.into_iter().next().unwrap().template
+	};
+
+	let plain = load("seam-test-template-plain", false, None);
+	assert!(plain.contains("<h1><!--seam:title--></h1>"));
+	assert_eq!(load("seam-test-template-gz", true, Some(Duration::ZERO)), plain);
+	// Without the flag, or when the .html was written after the .gz, the plain file wins
+	assert_eq!(load("seam-test-template-gz-off", false, Some(Duration::ZERO)), "plain");
+	assert_eq!(load("seam-test-template-gz-stale", true, Some(Duration::from_secs(60))), "plain");
+}
+
+#[test]
+fn head_meta_bubbles_into_layout_head() {
+	let dir = std::env::temp_dir().join("seam-test-build-loader-head-meta");
+	let _ = std::fs::remove_dir_all(&dir);
+	std::fs::create_dir_all(dir.join("templates")).unwrap();
+	std::fs::write(
+		dir.join("templates/root.html"),
+		concat!(
+			r#"<!DOCTYPE html><html><head><meta charset="utf-8"><title>Site</title></head>"#,
+			"<body><!--seam:outlet--></body></html>",
+		),
+	)
+	.unwrap();
+	std::fs::write(dir.join("templates/post.html"), "<h1><!--seam:title--></h1>").unwrap();
+	std::fs::write(dir.join("templates/about.html"), "<p>about</p>").unwrap();
+	let manifest = serde_json::json!({
+		"layouts": { "root": { "template": "templates/root.html" } },
+		"routes": {
+			"/post": {
+				"template": "templates/post.html",
+				"layout": "root",
+				"loaders": {},
+				"head_meta": "<title><!--seam:title--></title>"
+			},
+			"/about": { "template": "templates/about.html", "layout": "root", "loaders": {} }
+		}
+	});
+	std::fs::write(dir.join("route-manifest.json"), manifest.to_string()).unwrap();
+
+	let pages = load_build_output(dir.to_str().unwrap()).unwrap();
+	let page = |route: &str| pages.iter().find(|p| p.route == route).unwrap();
+	let html =
+		seam_injector::inject_no_script(&page("/post").template, &serde_json::json!({"title": "Hi"}));
+	assert!(
+		html
+			.starts_with(r#"<!DOCTYPE html><html><head><meta charset="utf-8"><title>Hi</title></head>"#),
+		"{html}"
+	);
+	// Pages without metadata keep the layout title
+	assert!(page("/about").template.contains("<title>Site</title>"));
+
+	let _ = std::fs::remove_dir_all(&dir);
+}
+
+#[test]
+fn route_assets_merge_with_global_assets() {
+	let dir = std::env::temp_dir().join("seam-test-build-loader-route-assets");
+	let _ = std::fs::remove_dir_all(&dir);
+	std::fs::create_dir_all(dir.join("templates")).unwrap();
+	std::fs::write(
+		dir.join("templates/chart.html"),
+		concat!(
+			"<!DOCTYPE html><html><head><!--seam:page-styles--></head><body><div>chart</div>",
+			r#"<script type="module" src="/_seam/static/main.js"></script><!--seam:page-scripts-->"#,
+			"</body></html>",
+		),
+	)
+	.unwrap();
+	std::fs::write(dir.join("templates/plain.html"), "<p><!--seam:page-scripts--></p>").unwrap();
+	let manifest = serde_json::json!({
+		"routes": {
+			"/chart": {
+				"template": "templates/chart.html",
+				"loaders": {},
+				"assets": {
+					"styles": ["chart.css"],
+					"scripts": ["main.js", "chart.js"],
+					"preload": [],
+					"prefetch": []
+				}
+			},
+			"/plain": { "template": "templates/plain.html", "loaders": {} }
+		}
+	});
+	std::fs::write(dir.join("route-manifest.json"), manifest.to_string()).unwrap();
+
+	let pages = load_build_output(dir.to_str().unwrap()).unwrap();
+	let page = |route: &str| pages.iter().find(|p| p.route == route).unwrap();
+	let chart = &page("/chart").template;
+	assert_eq!(chart.matches(r#"src="/_seam/static/main.js""#).count(), 1, "{chart}");
+	assert!(chart.contains(r#"<script type="module" src="/_seam/static/chart.js"></script></body>"#));
+	assert!(chart.contains(r#"<link rel="stylesheet" href="/_seam/static/chart.css"></head>"#));
+	assert_eq!(page("/plain").template, "<p></p>");
+
+	let _ = std::fs::remove_dir_all(&dir);
+}
+
+#[test]
+fn bubble_head_meta_keeps_head_blocks_in_order() {
+	let blocks = concat!(
+		r#"<script type="application/ld+json" data-seam-head>{"@type":"WebPage"}</script>"#,
+		"<style data-seam-head>body{margin:0}</style>",
+	);
+	let html = super::loader::bubble_head_meta(
+		r#"<html><head><meta charset="utf-8"></head><body><style>.card{}</style></body></html>"#,
+		blocks,
+	);
+	assert_eq!(
+		html,
+		format!(
+			r#"<html><head><meta charset="utf-8">{blocks}</head><body><style>.card{{}}</style></body></html>"#
+		)
+	);
+}
+
+#[test]
+fn bubble_head_meta_without_head_anchor() {
+	let html = super::loader::bubble_head_meta(
+		r#"<html><head data-x="1"><title>Site</title></head><body><title>svg</title></body></html>"#,
+		"<title>Page</title>",
+	);
+	assert_eq!(
+		html,
+		r#"<html><head data-x="1"><title>Page</title></head><body><title>svg</title></body></html>"#
+	);
+}
+
 #[test]
 fn load_build_output_with_route_params() {
 	let dir = std::env::temp_dir().join("seam-test-build-loader-params");
@@ -290,6 +514,37 @@ fn load_build_includes_public_dir() {
 	let _ = std::fs::remove_dir_all(&dir);
 }
 
+#[test]
+fn touching_only_a_layout_moves_last_modified() {
+	let dir = std::env::temp_dir().join("seam-test-build-loader-layout-mtime");
+	let _ = std::fs::remove_dir_all(&dir);
+	std::fs::create_dir_all(dir.join("templates")).unwrap();
+	std::fs::write(dir.join("templates/root.html"), "<body><!--seam:outlet--></body>").unwrap();
+	std::fs::write(dir.join("templates/about.html"), "<h1>About</h1>").unwrap();
+	let manifest = serde_json::json!({
+		"layouts": { "root": { "template": "templates/root.html", "loaders": {} } },
+		"routes": { "/about": { "template": "templates/about.html", "layout": "root", "loaders": {} } }
+	});
+	std::fs::write(dir.join("route-manifest.json"), manifest.to_string()).unwrap();
+
+	let set_mtime = |file: &str, time| {
+		std::fs::File::options().write(true).open(dir.join(file)).unwrap().set_modified(time).unwrap();
+	};
+	let built = std::time::UNIX_EPOCH + std::time::Duration::from_secs(1_700_000_000);
+	for file in ["templates/root.html", "templates/about.html", "route-manifest.json"] {
+		set_mtime(file, built);
+	}
+	let since = crate::dispatch::http_date(built);
+	let page = load_build_output(dir.to_str().unwrap()).unwrap().remove(0);
+	assert!(crate::dispatch::not_modified_since(page.last_modified().unwrap(), Some(&since)));
+
+	set_mtime("templates/root.html", built + std::time::Duration::from_secs(60));
+	let page = load_build_output(dir.to_str().unwrap()).unwrap().remove(0);
+	assert!(!crate::dispatch::not_modified_since(page.last_modified().unwrap(), Some(&since)));
+
+	let _ = std::fs::remove_dir_all(&dir);
+}
+
 #[test]
 fn load_build_output_prerender_page() {
 	let dir = std::env::temp_dir().join("seam-test-build-loader-prerender");
@@ -334,3 +589,45 @@ fn load_build_output_prerender_page() {
 	let _ = std::fs::remove_dir_all(&dir);
 	let _ = std::fs::remove_dir_all(&static_dir);
 }
+
+fn hash_map(batch: &str, procedures: &[(&str, &str)]) -> RpcHashMap {
+	RpcHashMap {
+		salt: "s".into(),
+		batch: batch.into(),
+		procedures: procedures.iter().map(|(n, h)| ((*n).to_string(), (*h).to_string())).collect(),
+	}
+}
+
+#[test]
+fn rpc_hash_collision_detected() {
+	let map = hash_map("b0", &[("getUser", "h1"), ("listPosts", "h2"), ("getPost", "h1")]);
+	assert_eq!(map.collision(), Some(("getPost", "getUser", "h1")));
+	// A procedure shadowed by the batch endpoint counts too
+	let map = hash_map("h9", &[("getUser", "h9")]);
+	assert_eq!(map.collision(), Some(("_batch", "getUser", "h9")));
+	assert!(hash_map("b0", &[("getUser", "h1"), ("getPost", "h2")]).collision().is_none());
+}
+
+#[test]
+fn reverse_lookup_rejects_collisions() {
+	let err = hash_map("b0", &[("getUser", "h1"), ("getPost", "h1")]).reverse_lookup().unwrap_err();
+	assert_eq!(
+		err,
+		r#"RPC hash map maps both "getPost" and "getUser" to "h1"; rebuild the hash map"#
+	);
+}
+
+#[test]
+fn load_build_rejects_colliding_hash_map() {
+	let dir = std::env::temp_dir().join("seam-test-build-loader-hash-collision");
+	let _ = std::fs::remove_dir_all(&dir);
+	std::fs::create_dir_all(&dir).unwrap();
+	std::fs::write(dir.join("route-manifest.json"), r#"{"routes":{}}"#).unwrap();
+	let map = r#"{"salt":"s","batch":"b0","procedures":{"getUser":"h1","getPost":"h1"}}"#;
+	std::fs::write(dir.join("rpc-hash-map.json"), map).unwrap();
+
+	let err = load_build(dir.to_str().unwrap()).err().unwrap();
+	assert!(err.to_string().contains(r#""getPost" and "getUser""#), "{err}");
+
+	let _ = std::fs::remove_dir_all(&dir);
+}
@@ -13,6 +13,9 @@ pub(super) struct RouteManifest {
 	pub(super) data_id: Option<String>,
 	#[serde(default)]
 	pub(super) i18n: Option<I18nManifest>,
+	/// Set by `build.gzip_templates`: templates have `.gz` siblings to load instead
+	#[serde(default)]
+	pub(super) gzip_templates: bool,
 }
 
 #[derive(Deserialize)]
@@ -29,6 +32,8 @@ pub(super) struct I18nManifest {
 	pub(super) route_hashes: HashMap<String, String>,
 	#[serde(default)]
 	pub(super) content_hashes: HashMap<String, HashMap<String, String>>,
+	#[serde(default)]
+	pub(super) routing: crate::resolve::LocaleRouting,
 }
 
 #[derive(Deserialize)]
@@ -55,6 +60,9 @@ pub(super) struct RouteEntry {
 	pub(super) loaders: serde_json::Value,
 	#[serde(default)]
 	pub(super) head_meta: Option<String>,
+	/// Route-specific bundles merged into the document's asset slots
+	#[serde(default)]
+	pub(super) assets: Option<seam_engine::PageAssets>,
 	#[serde(default)]
 	pub(super) i18n_keys: Vec<String>,
 	#[serde(default)]
@@ -133,8 +141,35 @@ pub struct RpcHashMap {
 }
 
 impl RpcHashMap {
-	/// Build a reverse lookup: hash -> original name
-	pub fn reverse_lookup(&self) -> HashMap<String, String> {
-		self.procedures.iter().map(|(name, hash)| (hash.clone(), name.clone())).collect()
+	/// Build a reverse lookup: hash -> original name. Errors when two names share a hash
+	/// (see [`Self::validate`]).
+	pub fn reverse_lookup(&self) -> Result<HashMap<String, String>, String> {
+		self.validate()?;
+		Ok(self.procedures.iter().map(|(name, hash)| (hash.clone(), name.clone())).collect())
+	}
+
+	/// Reject a map where two names share a hash: one procedure route would silently
+	/// shadow the other. Maps written by `seam build` are collision-free.
+	pub fn validate(&self) -> Result<(), String> {
+		match self.collision() {
+			Some((a, b, hash)) => {
+				Err(format!("RPC hash map maps both {a:?} and {b:?} to {hash:?}; rebuild the hash map"))
+			}
+			None => Ok(()),
+		}
+	}
+
+	/// First two names sharing a hash, and that hash. `_batch` takes part, since its
+	/// endpoint is matched before procedure names.
+	pub fn collision(&self) -> Option<(&str, &str, &str)> {
+		let mut entries: Vec<(&String, &String)> = self.procedures.iter().collect();
+		entries.sort();
+		let mut seen = HashMap::from([(self.batch.as_str(), "_batch")]);
+		for (name, hash) in entries {
+			if let Some(prev) = seen.insert(hash.as_str(), name.as_str()) {
+				return Some((prev, name.as_str(), hash.as_str()));
+			}
+		}
+		None
 	}
 }
@@ -57,6 +57,7 @@ impl ChannelDef {
 				context_keys: vec![],
 				suppress: None,
 				cache: None,
+				timeout: None,
 				handler: msg_def.handler,
 			});
 
@@ -93,6 +94,7 @@ impl ChannelDef {
 			error_schema: None,
 			context_keys: vec![],
 			suppress: None,
+			buffer: None,
 			handler: self.subscribe_handler,
 		}];
 
@@ -0,0 +1,160 @@
+/* src/server/core/rust/src/dispatch/buffer.rs */
+
+use std::collections::VecDeque;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+
+use futures_util::StreamExt;
+use serde_json::Value;
+use tokio::sync::Notify;
+use tokio::task::JoinHandle;
+
+use crate::errors::SeamError;
+use crate::procedure::{BoxStream, OverflowPolicy, SubscriptionBuffer};
+
+type Event = Result<Value, SeamError>;
+
+struct Queue {
+	events: Mutex<VecDeque<Event>>,
+	capacity: usize,
+	/// Set once the handler stream ends; the queue is drained before the buffer ends.
+	done: AtomicBool,
+	readable: Notify,
+	writable: Notify,
+}
+
+/// Aborts the producer when the client side of the buffer is dropped (disconnect).
+struct AbortOnDrop(JoinHandle<()>);
+
+impl Drop for AbortOnDrop {
+	fn drop(&mut self) {
+		self.0.abort();
+	}
+}
+
+/// Put a subscription's event stream behind its configured buffer. A background task
+/// pulls from the handler as fast as it produces, so `overflow` decides what a slow
+/// client misses. Without a buffer the stream is returned unchanged.
+pub fn buffer_events(
+	stream: BoxStream<Event>,
+	buffer: Option<SubscriptionBuffer>,
+) -> BoxStream<Event> {
+	let Some(SubscriptionBuffer { capacity, overflow }) = buffer else {
+		return stream;
+	};
+	let queue = Arc::new(Queue {
+		events: Mutex::new(VecDeque::with_capacity(capacity)),
+		capacity: capacity.max(1),
+		done: AtomicBool::new(false),
+		readable: Notify::new(),
+		writable: Notify::new(),
+	});
+
+	let producer = Arc::clone(&queue);
+	let task = tokio::spawn(async move {
+		let mut stream = stream;
+		while let Some(event) = stream.next().await {
+			producer.push(event, overflow).await;
+		}
+		producer.done.store(true, Ordering::Release);
+		producer.readable.notify_one();
+	});
+
+	Box::pin(futures_util::stream::unfold((queue, AbortOnDrop(task)), |(queue, task)| async move {
+		let event = queue.pop().await?;
+		Some((event, (queue, task)))
+	}))
+}
+
+impl Queue {
+	fn lock(&self) -> std::sync::MutexGuard<'_, VecDeque<Event>> {
+		// A panic while holding the lock cannot leave the deque half-updated
+		self.events.lock().unwrap_or_else(std::sync::PoisonError::into_inner)
+	}
+
+	async fn push(&self, event: Event, overflow: OverflowPolicy) {
+		loop {
+			{
+				let mut events = self.lock();
+				if events.len() < self.capacity {
+					events.push_back(event);
+					break;
+				}
+				match overflow {
+					OverflowPolicy::DropOldest => {
+						events.pop_front();
+						events.push_back(event);
+						break;
+					}
+					OverflowPolicy::DropNewest => return,
+					OverflowPolicy::Block => {}
+				}
+			}
+			// `notify_one` stores a permit, so a pop between unlock and await is not lost
+			self.writable.notified().await;
+		}
+		self.readable.notify_one();
+	}
+
+	async fn pop(&self) -> Option<Event> {
+		loop {
+			// Read `done` first: every push happens before it is set, so an empty queue
+			// after observing it means the stream is fully drained
+			let done = self.done.load(Ordering::Acquire);
+			if let Some(event) = self.lock().pop_front() {
+				self.writable.notify_one();
+				return Some(event);
+			}
+			if done {
+				return None;
+			}
+			self.readable.notified().await;
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use std::time::Duration;
+
+	use super::*;
+
+	fn numbers(n: u64) -> BoxStream<Event> {
+		Box::pin(futures_util::stream::iter((0..n).map(|i| Ok(Value::from(i)))))
+	}
+
+	async fn collect_after_producer_runs(stream: BoxStream<Event>) -> Vec<Value> {
+		// Let the producer run ahead of the (not yet polling) client
+		tokio::time::sleep(Duration::from_millis(50)).await;
+		stream.map(Result::unwrap).collect().await
+	}
+
+	fn buffer(capacity: usize, overflow: OverflowPolicy) -> SubscriptionBuffer {
+		SubscriptionBuffer { capacity, overflow }
+	}
+
+	#[tokio::test]
+	async fn drop_oldest_keeps_latest_events() {
+		let stream = buffer_events(numbers(10), Some(buffer(3, OverflowPolicy::DropOldest)));
+		assert_eq!(collect_after_producer_runs(stream).await, [7, 8, 9]);
+	}
+
+	#[tokio::test]
+	async fn drop_newest_keeps_first_events() {
+		let stream = buffer_events(numbers(10), Some(buffer(3, OverflowPolicy::DropNewest)));
+		assert_eq!(collect_after_producer_runs(stream).await, [0, 1, 2]);
+	}
+
+	#[tokio::test]
+	async fn block_is_lossless() {
+		let stream = buffer_events(numbers(10), Some(buffer(3, OverflowPolicy::Block)));
+		let expected: Vec<Value> = (0..10).map(Value::from).collect();
+		assert_eq!(collect_after_producer_runs(stream).await, expected);
+	}
+
+	#[tokio::test]
+	async fn no_buffer_passes_stream_through() {
+		let stream = buffer_events(numbers(4), None);
+		assert_eq!(collect_after_producer_runs(stream).await, [0, 1, 2, 3]);
+	}
+}
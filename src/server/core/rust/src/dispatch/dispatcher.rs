@@ -0,0 +1,217 @@
+/* src/server/core/rust/src/dispatch/dispatcher.rs */
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use serde_json::Value;
+
+use super::page::has_status_page;
+use super::{
+	LoaderOutput, PreparedPage, Procedures, i18n_query_procedure, prepare_page, render_page_html,
+	render_status_page, resolve_page_locale,
+};
+use crate::context::RawContextMap;
+use crate::errors::SeamError;
+use crate::page::{I18nConfig, PageDef, StatusPages};
+use crate::resolve::ResolveStrategy;
+use crate::server::SeamParts;
+use crate::validation::should_validate;
+
+const I18N_QUERY: &str = "seam.i18n.query";
+
+/// Cap on a buffered request body, matching axum's `DefaultBodyLimit`. Adapters without
+/// a framework limit enforce it themselves and answer 413 past it.
+pub const MAX_BODY_BYTES: usize = 2 * 1024 * 1024;
+
+/// The 413 every adapter answers for a body past [`MAX_BODY_BYTES`].
+pub fn body_too_large() -> SeamError {
+	SeamError::new("PAYLOAD_TOO_LARGE", format!("Request body exceeds {MAX_BODY_BYTES} bytes"), 413)
+}
+
+/// The request-independent state every adapter derives from `SeamParts`: the query and
+/// command table, hashed-name lookup, and locale resolution. Adapters keep routing,
+/// transports, and response encoding.
+pub struct Dispatcher {
+	pub procedures: Arc<Procedures>,
+	/// Hashed name -> original name while RPC name obfuscation is on.
+	pub rpc_hash_map: Option<HashMap<String, String>>,
+	pub batch_hash: Option<String>,
+	pub i18n_config: Option<I18nConfig>,
+	pub strategies: Vec<Box<dyn ResolveStrategy>>,
+	/// Data scripts carry a `data-integrity` digest (`SeamServer::data_integrity`).
+	pub data_integrity: bool,
+	/// See `SeamServer::iflocale_when_unset`.
+	pub iflocale_when_unset: bool,
+	pub status_pages: StatusPages,
+}
+
+impl Dispatcher {
+	/// Take the queries, commands, hash map, i18n config, strategies, context config, and
+	/// status pages out of `parts`, and copy the data-script settings; the rest stays for
+	/// the adapter.
+	/// Panics on a user procedure in the reserved `seam.` namespace, or on a colliding hash
+	/// map handed to `SeamServer::rpc_hash_map` (`load_build` rejects one from disk).
+	pub fn from_parts(parts: &mut SeamParts) -> Self {
+		let (rpc_hash_map, batch_hash) = match parts.rpc_hash_map.take() {
+			Some(m) => {
+				let mut rev = m.reverse_lookup().unwrap_or_else(|e| panic!("{e}"));
+				// Built-in and raw procedures bypass hash obfuscation (identity mapping);
+				// `seam build` leaves raw names unhashed
+				rev.insert(I18N_QUERY.to_string(), I18N_QUERY.to_string());
+				rev.extend(parts.raws.iter().map(|r| (r.name.clone(), r.name.clone())));
+				(Some(rev), Some(m.batch))
+			}
+			None => (None, None),
+		};
+
+		let mut handlers: HashMap<_, _> = std::mem::take(&mut parts.procedures)
+			.into_iter()
+			.map(|p| (p.name.clone(), Arc::new(p)))
+			.collect();
+		// Validate user-defined names before registering built-in ones
+		check_reserved_names("procedure", handlers.keys());
+		let i18n_config = parts.i18n_config.take();
+		if let Some(ref i18n) = i18n_config {
+			handlers.insert(I18N_QUERY.to_string(), Arc::new(i18n_query_procedure(i18n)));
+		}
+
+		let strategies = std::mem::take(&mut parts.strategies);
+		let strategies =
+			if strategies.is_empty() { crate::resolve::default_strategies() } else { strategies };
+
+		let procedures = Arc::new(Procedures::new(
+			handlers,
+			should_validate(&parts.validation_mode),
+			std::mem::take(&mut parts.context_config),
+			parts.transport_config.procedure_timeout,
+		));
+		Self {
+			procedures,
+			rpc_hash_map,
+			batch_hash,
+			i18n_config,
+			strategies,
+			data_integrity: parts.data_integrity,
+			iflocale_when_unset: parts.iflocale_when_unset,
+			status_pages: std::mem::take(&mut parts.status_pages),
+		}
+	}
+
+	/// Locale-prefixed page routes only exist when the url_prefix strategy is active.
+	pub fn has_url_prefix(&self) -> bool {
+		self.strategies.iter().any(|s| s.kind() == "url_prefix")
+	}
+
+	/// The manifest would expose the names hashing hides.
+	pub fn check_manifest(&self) -> Result<(), SeamError> {
+		match self.rpc_hash_map {
+			Some(_) => Err(SeamError::forbidden("Manifest disabled")),
+			None => Ok(()),
+		}
+	}
+
+	/// Matches both the original `_batch` endpoint and its hashed name.
+	pub fn is_batch(&self, name: &str) -> bool {
+		name == "_batch" || self.batch_hash.as_deref() == Some(name)
+	}
+
+	/// Original name for a client-sent procedure name. Under obfuscation an unknown hash
+	/// is NOT_FOUND, so plain names cannot bypass it.
+	pub fn resolve_name(&self, name: &str) -> Result<String, SeamError> {
+		match self.rpc_hash_map {
+			Some(ref map) => map.get(name).cloned().ok_or_else(|| SeamError::not_found("Not found")),
+			None => Ok(name.to_string()),
+		}
+	}
+
+	/// `POST /_seam/procedure/{name}` for queries, commands, and batches: the `data`
+	/// payload of the `{ ok: true, data }` envelope.
+	pub async fn call_procedure(
+		&self,
+		name: &str,
+		body: &[u8],
+		raw_ctx: RawContextMap,
+	) -> Result<Value, SeamError> {
+		if self.is_batch(name) {
+			return self.procedures.batch(body, self.rpc_hash_map.as_ref(), raw_ctx).await;
+		}
+		let resolved = self.resolve_name(name)?;
+		self.procedures.call_json(&resolved, body, &raw_ctx).await
+	}
+
+	/// Request locale from the strategy chain; see [`resolve_page_locale`].
+	pub fn page_locale(
+		&self,
+		path_locale: Option<&str>,
+		url: &str,
+		cookie_header: Option<&str>,
+		accept_language: Option<&str>,
+	) -> Result<Option<String>, SeamError> {
+		resolve_page_locale(
+			self.i18n_config.as_ref(),
+			&self.strategies,
+			path_locale,
+			url,
+			cookie_header,
+			accept_language,
+		)
+	}
+
+	/// Run the page's loaders; see [`Procedures::run_loaders`]. A failing loader stays a
+	/// per-key `__error` marker unless a status page covers its error: then the page fails
+	/// with it, so the adapter renders that template instead.
+	pub async fn run_loaders(
+		&self,
+		page: &PageDef,
+		params: &HashMap<String, String>,
+		raw_ctx: RawContextMap,
+	) -> Result<LoaderOutput, SeamError> {
+		let mut output = self.procedures.run_loaders(page, params, raw_ctx).await?;
+		match output.take_first_error(page) {
+			Some(err) if has_status_page(&self.status_pages, &err) => Err(err),
+			_ => Ok(output),
+		}
+	}
+
+	/// Slot data and data-script JSON for a streamed render; see [`prepare_page`].
+	/// `query` is the raw request query string, read for pagination.
+	pub fn prepare_page(
+		&self,
+		page: &PageDef,
+		output: LoaderOutput,
+		locale: Option<&str>,
+		query: Option<&str>,
+	) -> PreparedPage {
+		let mut prepared = prepare_page(page, output, locale, query, self.i18n_config.as_ref());
+		prepared.integrity = self.data_integrity;
+		prepared.iflocale_when_unset = self.iflocale_when_unset;
+		prepared
+	}
+
+	/// Buffered page HTML; see [`render_page_html`].
+	pub fn render_page(
+		&self,
+		page: &PageDef,
+		output: LoaderOutput,
+		locale: Option<&str>,
+		query: Option<&str>,
+	) -> String {
+		render_page_html(page, &self.prepare_page(page, output, locale, query), locale)
+	}
+
+	/// Status and HTML of the registered template for a page error; see
+	/// [`render_status_page`].
+	pub fn status_page(&self, err: &SeamError, path: &str) -> Option<(u16, String)> {
+		render_status_page(&self.status_pages, err, path)
+	}
+}
+
+/// Panic when a user definition claims the reserved `seam.` namespace. `kind` names the
+/// definition in the message ("subscription", "stream", ...).
+pub fn check_reserved_names<'a>(kind: &str, names: impl IntoIterator<Item = &'a String>) {
+	for name in names {
+		if name.starts_with("seam.") {
+			panic!("{kind} name {name:?} uses reserved \"seam.\" namespace");
+		}
+	}
+}
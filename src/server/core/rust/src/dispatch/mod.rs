@@ -0,0 +1,229 @@
+/* src/server/core/rust/src/dispatch/mod.rs */
+
+//! Framework-agnostic request dispatch shared by the HTTP adapters. Adapters own routing
+//! and response encoding; everything between parsed input and JSON output lives here.
+
+mod buffer;
+mod dispatcher;
+mod page;
+mod projection;
+mod sse;
+
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
+
+use serde_json::Value;
+use tokio::task::JoinSet;
+
+use crate::context::{ContextConfig, RawContextMap, resolve_context};
+use crate::errors::SeamError;
+use crate::procedure::ProcedureDef;
+use crate::validation::{CompiledSchema, ValidationDetail, compile_schema, validate_compiled};
+
+pub use buffer::buffer_events;
+pub use dispatcher::{Dispatcher, MAX_BODY_BYTES, body_too_large, check_reserved_names};
+pub use page::{
+	LoaderOutput, PreparedPage, build_script_data, http_date, i18n_query_procedure, inject_i18n_data,
+	lookup_i18n_messages, not_modified_since, prepare_page, read_prerendered, render_page_html,
+	render_status_page, resolve_page_locale, trailing_slash_location,
+};
+pub use projection::apply_projection;
+pub use sse::{SseFrame, with_sse_lifecycle};
+
+/// Query/command handlers with their compiled input schemas and call settings.
+pub struct Procedures {
+	handlers: HashMap<String, Arc<ProcedureDef>>,
+	input_schemas: HashMap<String, CompiledSchema>,
+	context_config: ContextConfig,
+	default_timeout: Duration,
+}
+
+impl Procedures {
+	/// Input schemas are compiled up front when `validate` is set; otherwise calls skip
+	/// validation entirely.
+	pub fn new(
+		handlers: HashMap<String, Arc<ProcedureDef>>,
+		validate: bool,
+		context_config: ContextConfig,
+		default_timeout: Duration,
+	) -> Self {
+		let input_schemas =
+			if validate { compile_schemas(&handlers, |p| &p.input_schema) } else { HashMap::new() };
+		Self { handlers, input_schemas, context_config, default_timeout }
+	}
+
+	pub fn get(&self, name: &str) -> Option<&Arc<ProcedureDef>> {
+		self.handlers.get(name)
+	}
+
+	pub fn context_config(&self) -> &ContextConfig {
+		&self.context_config
+	}
+
+	/// Validate `input` against the procedure's compiled schema, if any.
+	pub fn validate(&self, name: &str, input: &Value) -> Result<(), SeamError> {
+		check_input(self.input_schemas.get(name), "procedure", name, input)
+	}
+
+	/// Validate, resolve context, and run a query/command under its timeout.
+	pub async fn call(
+		&self,
+		name: &str,
+		input: Value,
+		raw_ctx: &RawContextMap,
+	) -> Result<Value, SeamError> {
+		let proc = self.lookup(name)?;
+		self.call_resolved(proc, input, raw_ctx).await
+	}
+
+	/// `call` with the input still a JSON request body. An unknown procedure is reported
+	/// before the body is parsed.
+	pub async fn call_json(
+		&self,
+		name: &str,
+		body: &[u8],
+		raw_ctx: &RawContextMap,
+	) -> Result<Value, SeamError> {
+		let proc = self.lookup(name)?;
+		let input = serde_json::from_slice(body).map_err(|e| SeamError::validation(e.to_string()))?;
+		self.call_resolved(proc, input, raw_ctx).await
+	}
+
+	fn lookup(&self, name: &str) -> Result<&Arc<ProcedureDef>, SeamError> {
+		self.get(name).ok_or_else(|| SeamError::not_found(format!("Procedure '{name}' not found")))
+	}
+
+	async fn call_resolved(
+		&self,
+		proc: &ProcedureDef,
+		input: Value,
+		raw_ctx: &RawContextMap,
+	) -> Result<Value, SeamError> {
+		self.validate(&proc.name, &input)?;
+		let ctx = resolve_context(&self.context_config, raw_ctx, &proc.context_keys)?;
+		self.call_with_timeout(proc, input, ctx).await
+	}
+
+	/// Run a query/command handler, failing with `TIMEOUT` once its deadline passes.
+	/// The handler future is dropped on expiry, cancelling its work at the next await.
+	pub async fn call_with_timeout(
+		&self,
+		proc: &ProcedureDef,
+		input: Value,
+		ctx: Value,
+	) -> Result<Value, SeamError> {
+		let limit = proc.timeout.unwrap_or(self.default_timeout);
+		let call = (proc.handler)(input, ctx);
+		if limit.is_zero() {
+			return call.await;
+		}
+		tokio::select! {
+			result = call => result,
+			() = tokio::time::sleep(limit) => Err(SeamError::timeout(format!(
+				"Procedure '{}' timed out after {}ms",
+				proc.name,
+				limit.as_millis()
+			))),
+		}
+	}
+
+	/// Run a `_batch` body concurrently and return the `{ results }` payload in call order.
+	/// Per-call failures become error items; `names` maps hashed procedure names back.
+	pub async fn batch(
+		self: &Arc<Self>,
+		body: &[u8],
+		names: Option<&HashMap<String, String>>,
+		raw_ctx: RawContextMap,
+	) -> Result<Value, SeamError> {
+		let batch: BatchRequest = serde_json::from_slice(body)
+			.map_err(|_| SeamError::validation("Batch request must have a 'calls' array"))?;
+
+		let raw_ctx = Arc::new(raw_ctx);
+		let mut join_set = JoinSet::new();
+		for (idx, call) in batch.calls.into_iter().enumerate() {
+			let procedures = Arc::clone(self);
+			let raw_ctx = Arc::clone(&raw_ctx);
+			let name = names.and_then(|m| m.get(&call.procedure).cloned()).unwrap_or(call.procedure);
+			join_set.spawn(async move {
+				let item = match procedures.call(&name, call.input, &raw_ctx).await {
+					Ok(data) => serde_json::json!({ "ok": true, "data": data }),
+					Err(e) => error_body(&e),
+				};
+				(idx, item)
+			});
+		}
+
+		// Collect results preserving original order
+		let mut indexed = Vec::new();
+		while let Some(result) = join_set.join_next().await {
+			indexed.push(result.map_err(|e| SeamError::internal(e.to_string()))?);
+		}
+		indexed.sort_by_key(|(i, _)| *i);
+		let results: Vec<Value> = indexed.into_iter().map(|(_, item)| item).collect();
+		Ok(serde_json::json!({ "results": results }))
+	}
+}
+
+#[derive(serde::Deserialize)]
+struct BatchRequest {
+	calls: Vec<BatchCall>,
+}
+
+#[derive(serde::Deserialize)]
+struct BatchCall {
+	procedure: String,
+	#[serde(default)]
+	input: Value,
+}
+
+/// Compile each definition's input schema, skipping ones that fail to compile.
+pub fn compile_schemas<T>(
+	defs: &HashMap<String, T>,
+	schema: impl Fn(&T) -> &Value,
+) -> HashMap<String, CompiledSchema> {
+	defs
+		.iter()
+		.filter_map(|(name, def)| compile_schema(schema(def)).ok().map(|cs| (name.clone(), cs)))
+		.collect()
+}
+
+/// `VALIDATION_ERROR` naming the `kind` ("procedure", "subscription", ...) when `input`
+/// fails `schema`. A missing schema means validation is off for this definition.
+pub fn check_input(
+	schema: Option<&CompiledSchema>,
+	kind: &str,
+	name: &str,
+	input: &Value,
+) -> Result<(), SeamError> {
+	let Some(cs) = schema else {
+		return Ok(());
+	};
+	validate_compiled(cs, input).map_err(|(msg, details)| {
+		SeamError::validation_detailed(
+			format!("Input validation failed for {kind} '{name}': {msg}"),
+			details.iter().map(ValidationDetail::to_json).collect(),
+		)
+	})
+}
+
+/// The `{ code, message, transient, details? }` object every transport reports errors with.
+pub fn error_object(err: &SeamError) -> Value {
+	let mut obj = serde_json::json!({
+		"code": err.code(),
+		"message": err.message(),
+		"transient": false,
+	});
+	if let Some(details) = err.details() {
+		obj["details"] = Value::Array(details.to_vec());
+	}
+	obj
+}
+
+/// `{ ok: false, error }` response envelope.
+pub fn error_body(err: &SeamError) -> Value {
+	serde_json::json!({ "ok": false, "error": error_object(err) })
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,429 @@
+/* src/server/core/rust/src/dispatch/page.rs */
+
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use serde_json::{Map, Value};
+use tokio::task::JoinSet;
+
+use super::{Procedures, apply_projection};
+use crate::context::{RawContextMap, resolve_context};
+use crate::errors::SeamError;
+use crate::escape::ascii_escape_json;
+use crate::page::{I18nConfig, PageDef, StatusPages};
+use crate::procedure::{ProcedureDef, ProcedureType};
+use crate::resolve::{ResolveData, ResolveStrategy, resolve_chain};
+
+/// Keyed loader results plus the `__loaders` metadata the client uses to refetch.
+pub struct LoaderOutput {
+	pub data: Map<String, Value>,
+	pub meta: Map<String, Value>,
+	/// Errors behind the `__error` markers in `data`, by data key
+	pub errors: Vec<(String, SeamError)>,
+}
+
+impl LoaderOutput {
+	/// The failed loader declared first on `page`, so the pick doesn't depend on which
+	/// task finished first.
+	pub fn take_first_error(&mut self, page: &PageDef) -> Option<SeamError> {
+		let index = page
+			.loaders
+			.iter()
+			.find_map(|l| self.errors.iter().position(|(key, _)| *key == l.data_key))?;
+		Some(self.errors.swap_remove(index).1)
+	}
+}
+
+impl Procedures {
+	/// Run page loaders concurrently and collect keyed results + metadata.
+	/// Per-loader error boundary: each loader fails independently, producing an
+	/// error marker instead of aborting the entire page.
+	pub async fn run_loaders(
+		self: &Arc<Self>,
+		page: &PageDef,
+		params: &HashMap<String, String>,
+		raw_ctx: RawContextMap,
+	) -> Result<LoaderOutput, SeamError> {
+		let mut join_set = JoinSet::new();
+		let raw_ctx = Arc::new(raw_ctx);
+
+		for loader in &page.loaders {
+			let input = (loader.input_fn)(params);
+			let proc_name = loader.procedure.clone();
+			let data_key = loader.data_key.clone();
+			let procedures = Arc::clone(self);
+			let raw_ctx = Arc::clone(&raw_ctx);
+
+			join_set.spawn(async move {
+				let result: Result<Value, SeamError> = async {
+					let proc = procedures
+						.get(&proc_name)
+						.ok_or_else(|| SeamError::internal(format!("Procedure '{proc_name}' not found")))?;
+					procedures.validate(&proc_name, &input)?;
+					let ctx = resolve_context(&procedures.context_config, &raw_ctx, &proc.context_keys)?;
+					(proc.handler)(input.clone(), ctx).await
+				}
+				.await;
+
+				(data_key, result, proc_name, input)
+			});
+		}
+
+		let mut data = Map::new();
+		let mut meta = Map::new();
+		let mut errors = Vec::new();
+		while let Some(join_result) = join_set.join_next().await {
+			let (key, result, procedure, input) =
+				join_result.map_err(|e| SeamError::internal(e.to_string()))?; // JoinError (task panic) = infrastructure failure
+			match result {
+				Ok(value) => {
+					data.insert(key.clone(), value);
+					meta.insert(key, serde_json::json!({ "procedure": procedure, "input": input }));
+				}
+				Err(err) => {
+					data.insert(
+						key.clone(),
+						serde_json::json!({
+							"__error": true,
+							"code": err.code(),
+							"message": err.message(),
+						}),
+					);
+					meta.insert(
+						key.clone(),
+						serde_json::json!({ "procedure": procedure, "input": input, "error": true }),
+					);
+					errors.push((key, err));
+				}
+			}
+		}
+		Ok(LoaderOutput { data, meta, errors })
+	}
+}
+
+/// Locale for a page request from the strategy chain. `path_locale` is the URL prefix
+/// segment, which must name a configured locale. None when i18n is off.
+pub fn resolve_page_locale(
+	i18n: Option<&I18nConfig>,
+	strategies: &[Box<dyn ResolveStrategy>],
+	path_locale: Option<&str>,
+	url: &str,
+	cookie_header: Option<&str>,
+	accept_language: Option<&str>,
+) -> Result<Option<String>, SeamError> {
+	let Some(i18n) = i18n else {
+		return Ok(None);
+	};
+	if let Some(loc) = path_locale
+		&& !i18n.locales.iter().any(|l| l == loc)
+	{
+		return Err(SeamError::not_found("Unknown locale"));
+	}
+	let data = ResolveData {
+		url,
+		path_locale,
+		cookie_header,
+		accept_language,
+		locales: &i18n.locales,
+		default_locale: &i18n.default,
+	};
+	Ok(Some(resolve_chain(strategies, &data)))
+}
+
+/// True when `If-Modified-Since` is at or after `last_modified`. HTTP dates carry whole
+/// seconds, so the sub-second part of the mtime is ignored; an unparsable header is a miss.
+pub fn not_modified_since(last_modified: SystemTime, if_modified_since: Option<&str>) -> bool {
+	let Some(since) = if_modified_since.and_then(|v| httpdate::parse_http_date(v).ok()) else {
+		return false;
+	};
+	let secs = |t: SystemTime| t.duration_since(UNIX_EPOCH).map_or(0, |d| d.as_secs());
+	secs(last_modified) <= secs(since)
+}
+
+/// `Last-Modified` header value for `time`.
+pub fn http_date(time: SystemTime) -> String {
+	httpdate::fmt_http_date(time)
+}
+
+/// `Location` for the 308 from `/dashboard/` to `/dashboard`: the public URL outside
+/// `/_seam/page`, with the query kept.
+pub fn trailing_slash_location(path: &str, query: Option<&str>) -> String {
+	let path = path.trim_end_matches('/');
+	let path = path.strip_prefix("/_seam/page").unwrap_or(path);
+	let path = if path.is_empty() { "/" } else { path };
+	match query {
+		Some(query) => format!("{path}?{query}"),
+		None => path.to_string(),
+	}
+}
+
+/// SSG short-circuit: the pre-rendered HTML for `path` (under `/_seam/page`), if the page
+/// is prerendered and the file exists. Callers fall back to dynamic rendering otherwise.
+pub async fn read_prerendered(page: &PageDef, path: &str) -> Option<String> {
+	if !page.prerender {
+		return None;
+	}
+	let static_dir = page.static_dir.as_ref()?;
+	let route_path = path.strip_prefix("/_seam/page").unwrap_or(path);
+	let sub_path = if route_path == "/" { "" } else { route_path };
+	let html_path = static_dir.join(sub_path.trim_start_matches('/')).join("index.html");
+	tokio::fs::read_to_string(&html_path).await.ok()
+}
+
+/// A page error swapped for its registered template, as status and HTML: NotFound uses
+/// `not_found`, every other error `error`. `path` is the request path, reported to the
+/// template relative to `/_seam/page`. None keeps the usual JSON error response.
+/// The template sees the error code but not its message, which may carry internals.
+pub fn render_status_page(
+	pages: &StatusPages,
+	err: &SeamError,
+	path: &str,
+) -> Option<(u16, String)> {
+	let status = err.status();
+	let template = status_template(pages, err)?;
+	let path = path.strip_prefix("/_seam/page").unwrap_or(path);
+	let data = serde_json::json!({
+		"status": status,
+		"code": err.code(),
+		"path": if path.is_empty() { "/" } else { path },
+	});
+	Some((status, seam_injector::inject_no_script(template, &data)))
+}
+
+fn status_template<'a>(pages: &'a StatusPages, err: &SeamError) -> Option<&'a String> {
+	if err.status() == 404 { pages.not_found.as_ref() } else { pages.error.as_ref() }
+}
+
+/// Whether [`render_status_page`] has a template for `err`.
+pub(super) fn has_status_page(pages: &StatusPages, err: &SeamError) -> bool {
+	status_template(pages, err).is_some()
+}
+
+/// Page data ready to render: slot values for the template and the ASCII-escaped JSON
+/// for the data script.
+pub struct PreparedPage {
+	pub inject_data: Value,
+	pub script_json: String,
+	/// Give the data script a `data-integrity` digest (`SeamServer::data_integrity`).
+	pub integrity: bool,
+	/// Render `iflocale` blocks without a locale (`SeamServer::iflocale_when_unset`).
+	pub iflocale_when_unset: bool,
+}
+
+impl PreparedPage {
+	/// Injector options for this page's template: `iflocale` blocks resolve against
+	/// the request locale.
+	pub fn render_options<'a>(&self, locale: Option<&'a str>) -> seam_injector::RenderOptions<'a> {
+		seam_injector::RenderOptions {
+			locale,
+			iflocale_when_unset: self.iflocale_when_unset,
+			..Default::default()
+		}
+	}
+
+	/// The `<script>` element for the page's data id, with an optional CSP nonce.
+	pub fn data_script(&self, page: &PageDef, nonce: Option<&str>) -> String {
+		seam_injector::data_script(&self.script_json, &self.script_options(page, nonce))
+	}
+
+	fn script_options<'a>(
+		&self,
+		page: &'a PageDef,
+		nonce: Option<&'a str>,
+	) -> seam_injector::InjectOptions<'a> {
+		seam_injector::InjectOptions { data_id: &page.data_id, nonce, integrity: self.integrity }
+	}
+}
+
+/// Everything between loader output and template injection: projection, pagination
+/// against the raw request `query`, slot flattening, `_layouts` grouping, `_i18n`, and
+/// `__loaders` metadata.
+pub fn prepare_page(
+	page: &PageDef,
+	output: LoaderOutput,
+	locale: Option<&str>,
+	query: Option<&str>,
+	i18n: Option<&I18nConfig>,
+) -> PreparedPage {
+	let LoaderOutput { mut data, meta, .. } = output;
+
+	// Prune to projected fields before template injection
+	apply_projection(&mut data, &page.projections);
+
+	let mut data = Value::Object(data);
+	// Slots and the data script both see only the requested page
+	if let Some(ref def) = page.pagination {
+		let pagination = seam_engine::Pagination::from_query(&def.path, query, def.default_size);
+		seam_engine::paginate(&mut data, &pagination);
+	}
+	let inject_data = seam_engine::flatten_for_slots(&data);
+	let Value::Object(data) = data else {
+		unreachable!("loader data is an object");
+	};
+
+	let mut script_data = build_script_data(&data, page);
+	if let (Some(loc), Some(i18n)) = (locale, i18n) {
+		inject_i18n_data(&mut script_data, loc, i18n, &page.route);
+	}
+	if !meta.is_empty() {
+		script_data.insert("__loaders".to_string(), Value::Object(meta));
+	}
+
+	let json = serde_json::to_string(&Value::Object(script_data)).unwrap_or_default();
+	PreparedPage {
+		inject_data,
+		script_json: ascii_escape_json(&json),
+		integrity: false,
+		iflocale_when_unset: false,
+	}
+}
+
+/// Buffered page render from [`prepare_page`] output: slot injection, `lang`, and the
+/// data script before the last `</body>`.
+pub fn render_page_html(page: &PageDef, prepared: &PreparedPage, locale: Option<&str>) -> String {
+	let options = prepared.render_options(locale);
+	let html = match page.compiled {
+		Some(ref compiled) => seam_injector::inject_precompiled_with_options(
+			compiled.for_locale(locale),
+			&prepared.inject_data,
+			&options,
+		),
+		None => {
+			let template = locale
+				.and_then(|loc| page.locale_templates.as_ref().and_then(|lt| lt.get(loc)))
+				.unwrap_or(&page.template);
+			seam_injector::inject_no_script_with_options(template, &prepared.inject_data, &options)
+		}
+	};
+	let html = match locale {
+		Some(loc) => seam_engine::inject_html_lang(&html, loc),
+		None => html,
+	};
+
+	let options = prepared.script_options(page, None);
+	seam_engine::inject_data_script_with_options(&html, &prepared.script_json, &options)
+}
+
+/// Build the client-side script data JSON, grouping layout-claimed keys under `_layouts`.
+pub fn build_script_data(data: &Map<String, Value>, page: &PageDef) -> Map<String, Value> {
+	if page.layout_chain.is_empty() {
+		return data.clone();
+	}
+
+	let claimed_keys: HashSet<&str> =
+		page.layout_chain.iter().flat_map(|e| e.loader_keys.iter().map(String::as_str)).collect();
+
+	// Page data = keys not claimed by any layout
+	let mut script_data: Map<String, Value> = data
+		.iter()
+		.filter(|(k, _)| !claimed_keys.contains(k.as_str()))
+		.map(|(k, v)| (k.clone(), v.clone()))
+		.collect();
+
+	// Per-layout _layouts grouping
+	let mut layouts_map = Map::new();
+	for entry in &page.layout_chain {
+		let layout_data: Map<String, Value> = entry
+			.loader_keys
+			.iter()
+			.filter_map(|key| data.get(key).map(|v| (key.clone(), v.clone())))
+			.collect();
+		if !layout_data.is_empty() {
+			layouts_map.insert(entry.id.clone(), Value::Object(layout_data));
+		}
+	}
+	if !layouts_map.is_empty() {
+		script_data.insert("_layouts".to_string(), Value::Object(layouts_map));
+	}
+
+	script_data
+}
+
+/// Inject _i18n data into script_data for client hydration.
+pub fn inject_i18n_data(
+	script_data: &mut Map<String, Value>,
+	locale: &str,
+	i18n: &I18nConfig,
+	route: &str,
+) {
+	let route_hash = i18n.route_hashes.get(route).cloned().unwrap_or_default();
+	let messages = lookup_i18n_messages(i18n, &route_hash, locale);
+
+	let mut i18n_data = Map::new();
+	i18n_data.insert("locale".into(), Value::String(locale.to_string()));
+	i18n_data.insert("messages".into(), messages);
+
+	if i18n.cache && !route_hash.is_empty() {
+		if let Some(hash) = i18n.content_hashes.get(&route_hash).and_then(|m| m.get(locale)) {
+			i18n_data.insert("hash".into(), Value::String(hash.clone()));
+		}
+		if let Ok(router) = serde_json::to_value(&i18n.content_hashes) {
+			i18n_data.insert("router".into(), router);
+		}
+	}
+
+	script_data.insert("_i18n".into(), Value::Object(i18n_data));
+}
+
+/// Look up pre-resolved messages by route hash + locale. Zero merge, zero filter.
+pub fn lookup_i18n_messages(i18n: &I18nConfig, route_hash: &str, locale: &str) -> Value {
+	// Paged mode: read from disk
+	if i18n.mode == "paged" {
+		if let Some(ref dist_dir) = i18n.dist_dir {
+			let path = dist_dir.join("i18n").join(route_hash).join(format!("{locale}.json"));
+			if let Ok(content) = std::fs::read_to_string(&path)
+				&& let Ok(parsed) = serde_json::from_str::<Value>(&content)
+			{
+				return parsed;
+			}
+		}
+		return Value::Object(Map::new());
+	}
+
+	// Memory mode: direct lookup
+	i18n
+		.messages
+		.get(locale)
+		.and_then(|route_msgs| route_msgs.get(route_hash))
+		.cloned()
+		.unwrap_or(Value::Object(Map::new()))
+}
+
+/// Built-in `seam.i18n.query` procedure (route-hash-based lookup). Unknown locales fall
+/// back to the default.
+pub fn i18n_query_procedure(i18n: &I18nConfig) -> ProcedureDef {
+	let i18n = i18n.clone();
+	let valid_locales: HashSet<String> = i18n.locales.iter().cloned().collect();
+	ProcedureDef {
+		name: "seam.i18n.query".to_string(),
+		proc_type: ProcedureType::Query,
+		input_schema: serde_json::json!({}),
+		output_schema: serde_json::json!({}),
+		error_schema: None,
+		context_keys: vec![],
+		suppress: None,
+		cache: None,
+		timeout: None,
+		handler: Arc::new(move |input: Value, _ctx: Value| {
+			let i18n = i18n.clone();
+			let valid = valid_locales.clone();
+			Box::pin(async move {
+				let route_hash = input.get("route").and_then(|v| v.as_str()).unwrap_or("").to_string();
+				let raw_locale = input.get("locale").and_then(|v| v.as_str()).unwrap_or(&i18n.default);
+				let locale =
+					if valid.contains(raw_locale) { raw_locale.to_string() } else { i18n.default.clone() };
+
+				let messages = lookup_i18n_messages(&i18n, &route_hash, &locale);
+				let hash = i18n
+					.content_hashes
+					.get(&route_hash)
+					.and_then(|m| m.get(&locale))
+					.cloned()
+					.unwrap_or_default();
+
+				Ok(serde_json::json!({ "hash": hash, "messages": messages }))
+			})
+		}),
+	}
+}
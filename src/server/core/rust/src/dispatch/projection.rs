@@ -1,4 +1,4 @@
-/* src/server/adapter/axum/src/handler/projection.rs */
+/* src/server/core/rust/src/dispatch/projection.rs */
 
 use std::collections::HashMap;
 
@@ -6,7 +6,7 @@ use serde_json::Value;
 
 /// Prune loader data to only include projected fields.
 /// None projections = keep all data.
-pub(super) fn apply_projection(
+pub fn apply_projection(
 	data: &mut serde_json::Map<String, Value>,
 	projections: &Option<HashMap<String, Vec<String>>>,
 ) {
@@ -0,0 +1,103 @@
+/* src/server/core/rust/src/dispatch/sse.rs */
+
+use std::time::Duration;
+
+use futures_util::StreamExt;
+use tokio::sync::mpsc;
+
+use crate::procedure::BoxStream;
+
+/// One write on an SSE response. Adapters encode each in their own event type.
+#[derive(Debug, PartialEq, Eq)]
+pub enum SseFrame<T> {
+	Data(T),
+	/// `: heartbeat` comment that keeps intermediaries from dropping an idle connection
+	Heartbeat,
+	/// `event: complete` with `{}` data; always the last frame
+	Complete,
+}
+
+/// Wrap an SSE data stream with heartbeats and an idle timeout.
+///
+/// - Emits `Heartbeat` at once and then every `heartbeat_interval` (`Duration::ZERO` disables)
+/// - Tracks idle time since the last **data** item (heartbeats do NOT reset it)
+/// - On idle timeout: yields `Complete` then ends, so dead clients are reaped
+/// - On natural stream end: yields `Complete` then ends
+pub fn with_sse_lifecycle<T: Send + 'static>(
+	data_stream: BoxStream<T>,
+	heartbeat_interval: Duration,
+	idle_timeout: Duration,
+) -> BoxStream<SseFrame<T>> {
+	let (tx, rx) = mpsc::channel::<SseFrame<T>>(32);
+
+	tokio::spawn(async move {
+		let mut data_stream = data_stream;
+		let heartbeat_enabled = heartbeat_interval > Duration::ZERO;
+		// `interval` rejects a zero period; the branch is disabled in that case anyway
+		let mut heartbeat = tokio::time::interval(heartbeat_interval.max(Duration::from_millis(1)));
+		if heartbeat_enabled && tx.send(SseFrame::Heartbeat).await.is_err() {
+			return;
+		}
+		heartbeat.tick().await;
+
+		let idle_enabled = idle_timeout > Duration::ZERO;
+		let idle_sleep = tokio::time::sleep(idle_timeout);
+		tokio::pin!(idle_sleep);
+
+		loop {
+			tokio::select! {
+				item = data_stream.next() => {
+					let Some(item) = item else {
+						let _ = tx.send(SseFrame::Complete).await;
+						break;
+					};
+					if idle_enabled {
+						idle_sleep.as_mut().reset(tokio::time::Instant::now() + idle_timeout);
+					}
+					if tx.send(SseFrame::Data(item)).await.is_err() {
+						break;
+					}
+				}
+				_ = heartbeat.tick(), if heartbeat_enabled => {
+					if tx.send(SseFrame::Heartbeat).await.is_err() {
+						break;
+					}
+				}
+				_ = &mut idle_sleep, if idle_enabled => {
+					let _ = tx.send(SseFrame::Complete).await;
+					break;
+				}
+			}
+		}
+	});
+
+	Box::pin(futures_util::stream::unfold(rx, |mut rx| async move {
+		rx.recv().await.map(|frame| (frame, rx))
+	}))
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	async fn collect(stream: BoxStream<SseFrame<u32>>) -> Vec<SseFrame<u32>> {
+		stream.collect().await
+	}
+
+	#[tokio::test]
+	async fn heartbeat_first_and_complete_after_source_ends() {
+		let data = Box::pin(futures_util::stream::iter([1, 2]));
+		let frames = collect(with_sse_lifecycle(data, Duration::from_secs(60), Duration::ZERO)).await;
+		assert_eq!(
+			frames,
+			[SseFrame::Heartbeat, SseFrame::Data(1), SseFrame::Data(2), SseFrame::Complete]
+		);
+	}
+
+	#[tokio::test]
+	async fn idle_source_is_completed_after_timeout() {
+		let data = Box::pin(futures_util::stream::pending::<u32>());
+		let frames = collect(with_sse_lifecycle(data, Duration::ZERO, Duration::from_millis(20))).await;
+		assert_eq!(frames, [SseFrame::Complete]);
+	}
+}
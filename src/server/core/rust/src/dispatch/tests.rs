@@ -0,0 +1,225 @@
+/* src/server/core/rust/src/dispatch/tests.rs */
+
+use super::*;
+use crate::page::{LoaderDef, PageDef, StatusPages};
+use crate::procedure::ProcedureType;
+use crate::server::SeamServer;
+use serde_json::json;
+
+fn greet() -> ProcedureDef {
+	ProcedureDef {
+		name: "greet".into(),
+		proc_type: ProcedureType::Query,
+		input_schema: json!({"properties": {"name": {"type": "string"}}}),
+		output_schema: json!({}),
+		error_schema: None,
+		context_keys: vec![],
+		suppress: None,
+		cache: None,
+		timeout: None,
+		handler: Arc::new(|input, _ctx| {
+			Box::pin(async move {
+				Ok(json!({"message": format!("Hello, {}!", input["name"].as_str().unwrap_or("?"))}))
+			})
+		}),
+	}
+}
+
+fn slow() -> ProcedureDef {
+	ProcedureDef {
+		name: "slow".into(),
+		input_schema: json!({}),
+		timeout: Some(Duration::from_millis(10)),
+		handler: Arc::new(|_input, _ctx| {
+			Box::pin(async {
+				tokio::time::sleep(Duration::from_secs(5)).await;
+				Ok(json!(null))
+			})
+		}),
+		..greet()
+	}
+}
+
+/// Parses `input.raw` with `?`, relying on `From<serde_json::Error>`.
+fn parse() -> ProcedureDef {
+	ProcedureDef {
+		name: "parse".into(),
+		input_schema: json!({}),
+		handler: Arc::new(|input, _ctx| {
+			Box::pin(async move {
+				let value: Value = serde_json::from_str(input["raw"].as_str().unwrap_or_default())?;
+				Ok(value)
+			})
+		}),
+		..greet()
+	}
+}
+
+fn procedures(validate: bool) -> Arc<Procedures> {
+	let handlers =
+		[greet(), slow(), parse()].into_iter().map(|p| (p.name.clone(), Arc::new(p))).collect();
+	Arc::new(Procedures::new(handlers, validate, ContextConfig::new(), Duration::ZERO))
+}
+
+#[tokio::test]
+async fn call_runs_handler() {
+	let result = procedures(true).call("greet", json!({"name": "Ann"}), &RawContextMap::new()).await;
+	assert_eq!(result.unwrap(), json!({"message": "Hello, Ann!"}));
+}
+
+#[tokio::test]
+async fn call_unknown_procedure_is_not_found() {
+	let err = procedures(true).call("nope", json!({}), &RawContextMap::new()).await.unwrap_err();
+	assert_eq!(err.code(), "NOT_FOUND");
+	assert_eq!(err.message(), "Procedure 'nope' not found");
+}
+
+#[tokio::test]
+async fn call_validates_only_when_enabled() {
+	let input = json!({"name": 42});
+	let err = procedures(true).call("greet", input.clone(), &RawContextMap::new()).await.unwrap_err();
+	assert_eq!(err.code(), "VALIDATION_ERROR");
+	assert!(err.message().starts_with("Input validation failed for procedure 'greet'"));
+	assert!(procedures(false).call("greet", input, &RawContextMap::new()).await.is_ok());
+}
+
+#[tokio::test]
+async fn call_times_out() {
+	let err = procedures(false).call("slow", json!({}), &RawContextMap::new()).await.unwrap_err();
+	assert_eq!(err.code(), "TIMEOUT");
+}
+
+#[tokio::test]
+async fn question_mark_converts_json_error_to_internal() {
+	let ok = procedures(false).call("parse", json!({"raw": "[1]"}), &RawContextMap::new()).await;
+	assert_eq!(ok.unwrap(), json!([1]));
+	let err = procedures(false)
+		.call("parse", json!({"raw": "{oops"}), &RawContextMap::new())
+		.await
+		.unwrap_err();
+	assert_eq!(err.code(), "INTERNAL_ERROR");
+	assert_eq!(err.status(), 500);
+}
+
+#[tokio::test]
+async fn batch_keeps_call_order_and_isolates_errors() {
+	let body = br#"{"calls":[{"procedure":"greet","input":{"name":"a"}},{"procedure":"nope"}]}"#;
+	let result = procedures(true).batch(body, None, RawContextMap::new()).await.unwrap();
+	assert_eq!(result["results"][0], json!({"ok": true, "data": {"message": "Hello, a!"}}));
+	assert_eq!(result["results"][1]["ok"], false);
+	assert_eq!(result["results"][1]["error"]["code"], "NOT_FOUND");
+}
+
+#[tokio::test]
+async fn batch_resolves_hashed_names() {
+	let names = HashMap::from([("h1".to_string(), "greet".to_string())]);
+	let body = br#"{"calls":[{"procedure":"h1","input":{"name":"b"}}]}"#;
+	let result = procedures(true).batch(body, Some(&names), RawContextMap::new()).await.unwrap();
+	assert_eq!(result["results"][0]["data"]["message"], "Hello, b!");
+}
+
+#[tokio::test]
+async fn batch_rejects_malformed_body() {
+	let err = procedures(true).batch(b"[]", None, RawContextMap::new()).await.unwrap_err();
+	assert_eq!(err.code(), "VALIDATION_ERROR");
+}
+
+/// `/hello/{name}` with a working `greeting` loader and a `missing` one that fails.
+fn hello_page() -> PageDef {
+	let loader = |key: &str, procedure: &str| LoaderDef {
+		data_key: key.into(),
+		procedure: procedure.into(),
+		input_fn: Arc::new(|params| json!({"name": params.get("name")})),
+	};
+	PageDef {
+		route: "/hello/{name}".into(),
+		template: String::new(),
+		locale_templates: None,
+		loaders: vec![loader("greeting", "greet"), loader("missing", "nope")],
+		data_id: "__data".into(),
+		layout_chain: vec![],
+		page_loader_keys: vec![],
+		i18n_keys: vec![],
+		projections: None,
+		pagination: None,
+		prerender: false,
+		static_dir: None,
+		template_mtime: None,
+		compiled: None,
+	}
+}
+
+#[tokio::test]
+async fn run_loaders_marks_failed_loader() {
+	let page = hello_page();
+	let params = HashMap::from([("name".to_string(), "Ann".to_string())]);
+	let output = procedures(true).run_loaders(&page, &params, RawContextMap::new()).await.unwrap();
+	assert_eq!(output.data["greeting"], json!({"message": "Hello, Ann!"}));
+	assert_eq!(output.data["missing"]["__error"], true);
+	assert_eq!(output.meta["missing"]["error"], true);
+	assert_eq!(output.meta["greeting"], json!({"procedure": "greet", "input": {"name": "Ann"}}));
+	assert_eq!(output.errors.len(), 1);
+	assert_eq!(output.errors[0].0, "missing");
+}
+
+async fn run_hello(server: SeamServer) -> (Result<LoaderOutput, SeamError>, Dispatcher) {
+	let dispatch = Dispatcher::from_parts(&mut server.procedure(greet()).into_parts());
+	let params = HashMap::from([("name".to_string(), "Ann".to_string())]);
+	let output = dispatch.run_loaders(&hello_page(), &params, RawContextMap::new()).await;
+	(output, dispatch)
+}
+
+#[tokio::test]
+async fn failing_loader_fails_page_only_with_error_page() {
+	let (output, _) = run_hello(SeamServer::new()).await;
+	assert!(output.is_ok_and(|o| o.data["missing"]["__error"] == true));
+
+	let (output, dispatch) =
+		run_hello(SeamServer::new().error_page("<h1><!--seam:code--></h1>")).await;
+	let Err(err) = output else { panic!("loader error must fail the page") };
+	assert_eq!(
+		dispatch.status_page(&err, "/_seam/page/hello/Ann"),
+		Some((500, "<h1>INTERNAL_ERROR</h1>".to_string()))
+	);
+}
+
+#[test]
+fn status_page_picks_template_by_status_and_hides_message() {
+	let pages = StatusPages {
+		not_found: Some("<p><!--seam:status--> <!--seam:path--></p>".into()),
+		error: Some("<p><!--seam:status--> <!--seam:code-->|<!--seam:message--></p>".into()),
+	};
+	let not_found = SeamError::not_found("no such user 42");
+	assert_eq!(
+		render_status_page(&pages, &not_found, "/_seam/page/users/42"),
+		Some((404, "<p>404 /users/42</p>".into()))
+	);
+	assert_eq!(
+		render_status_page(&pages, &not_found, "/_seam/page"),
+		Some((404, "<p>404 /</p>".into()))
+	);
+	let internal = SeamError::internal("db password rejected");
+	assert_eq!(
+		render_status_page(&pages, &internal, "/_seam/page/x"),
+		Some((500, "<p>500 INTERNAL_ERROR|</p>".into()))
+	);
+	let only_404 = StatusPages { error: None, ..pages };
+	assert_eq!(render_status_page(&only_404, &internal, "/_seam/page/x"), None);
+}
+
+#[test]
+fn error_body_includes_details() {
+	let err = SeamError::validation_detailed("bad", vec![json!({"path": "/name"})]);
+	assert_eq!(
+		error_body(&err),
+		json!({
+			"ok": false,
+			"error": {
+				"code": "VALIDATION_ERROR",
+				"message": "bad",
+				"transient": false,
+				"details": [{"path": "/name"}],
+			},
+		})
+	);
+}
@@ -19,6 +19,7 @@ fn default_status(code: &str) -> u16 {
 		"RATE_LIMITED" => 429,
 		"CONTEXT_ERROR" => 400,
 		"INTERNAL_ERROR" => 500,
+		"TIMEOUT" => 504,
 		_ => 500,
 	}
 }
@@ -47,6 +48,11 @@ impl SeamError {
 		Self::with_code("VALIDATION_ERROR", msg)
 	}
 
+	/// Malformed client input; shares `VALIDATION_ERROR` so the wire codes stay unchanged.
+	pub fn bad_request(msg: impl Into<String>) -> Self {
+		Self::with_code("VALIDATION_ERROR", msg)
+	}
+
 	pub fn not_found(msg: impl Into<String>) -> Self {
 		Self::with_code("NOT_FOUND", msg)
 	}
@@ -71,6 +77,10 @@ impl SeamError {
 		Self::with_code("CONTEXT_ERROR", msg)
 	}
 
+	pub fn timeout(msg: impl Into<String>) -> Self {
+		Self::with_code("TIMEOUT", msg)
+	}
+
 	pub fn code(&self) -> &str {
 		&self.code
 	}
@@ -96,6 +106,19 @@ impl fmt::Display for SeamError {
 
 impl std::error::Error for SeamError {}
 
+/// Lets handlers `?` a JSON (de)serialization failure; it surfaces as `INTERNAL_ERROR`.
+impl From<serde_json::Error> for SeamError {
+	fn from(err: serde_json::Error) -> Self {
+		Self::internal(err.to_string())
+	}
+}
+
+impl From<std::io::Error> for SeamError {
+	fn from(err: std::io::Error) -> Self {
+		Self::internal(err.to_string())
+	}
+}
+
 #[cfg(test)]
 mod tests {
 	use super::*;
@@ -109,6 +132,7 @@ mod tests {
 		assert_eq!(default_status("RATE_LIMITED"), 429);
 		assert_eq!(default_status("CONTEXT_ERROR"), 400);
 		assert_eq!(default_status("INTERNAL_ERROR"), 500);
+		assert_eq!(default_status("TIMEOUT"), 504);
 	}
 
 	#[test]
@@ -139,6 +163,23 @@ mod tests {
 		assert_eq!(SeamError::forbidden("x").status(), 403);
 		assert_eq!(SeamError::rate_limited("x").status(), 429);
 		assert_eq!(SeamError::context_error("x").status(), 400);
+		assert_eq!(SeamError::timeout("x").status(), 504);
+		assert_eq!(SeamError::bad_request("x").status(), 400);
+	}
+
+	#[test]
+	fn convenience_constructor_codes() {
+		assert_eq!(SeamError::internal("x").code(), "INTERNAL_ERROR");
+		assert_eq!(SeamError::not_found("x").code(), "NOT_FOUND");
+		assert_eq!(SeamError::bad_request("x").code(), "VALIDATION_ERROR");
+	}
+
+	#[test]
+	fn from_io_error_is_internal() {
+		let err: SeamError = std::io::Error::other("disk full").into();
+		assert_eq!(err.code(), "INTERNAL_ERROR");
+		assert_eq!(err.status(), 500);
+		assert_eq!(err.message(), "disk full");
 	}
 
 	#[test]
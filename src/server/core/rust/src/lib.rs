@@ -4,13 +4,17 @@
 pub mod build_loader;
 pub mod channel;
 pub mod context;
+pub mod dispatch;
 pub mod errors;
 pub mod escape;
 pub mod manifest;
 pub mod page;
+pub mod pagination;
 pub mod procedure;
 pub mod resolve;
 pub mod server;
+#[cfg(any(test, feature = "test-util"))]
+pub mod testing;
 pub mod validation;
 
 // Re-exports for ergonomic use
@@ -25,18 +29,20 @@ pub use context::{
 };
 pub use errors::SeamError;
 pub use escape::ascii_escape_json;
-pub use page::I18nConfig;
+pub use page::{CspNonce, I18nConfig, StatusPages};
+pub use pagination::Page;
 pub use procedure::{
-	BoxFuture, BoxStream, ProcedureDef, ProcedureType, SeamFileHandle, StreamDef, StreamHandlerFn,
-	StreamParams, SubscriptionDef, SubscriptionParams, UploadDef, UploadHandlerFn, map_stream_output,
+	BoxFuture, BoxStream, OverflowPolicy, ProcedureDef, ProcedureType, RawDef, RawHandlerFn,
+	SeamFileHandle, StreamDef, StreamHandlerFn, StreamParams, SubscriptionBuffer, SubscriptionDef,
+	SubscriptionParams, UploadDef, UploadHandlerFn, map_stream_output,
 };
 pub use resolve::{
-	ResolveData, ResolveStrategy, default_strategies, from_accept_language, from_cookie,
-	from_url_prefix, from_url_query, resolve_chain,
+	LocaleRouting, ResolveData, ResolveStrategy, default_strategies, from_accept_language,
+	from_cookie, from_url_prefix, from_url_query, resolve_chain,
 };
 pub use seam_macros::{SeamType, seam_command, seam_procedure, seam_subscription};
 pub use seam_macros::{seam_stream, seam_upload};
-pub use server::{SeamParts, SeamServer, TransportConfig};
+pub use server::{SeamParts, SeamServer, TrailingSlash, TransportConfig};
 pub use validation::{
 	CompiledSchema, ValidationDetail, ValidationMode, compile_schema, should_validate,
 	validate_compiled, validate_input,
@@ -46,6 +52,79 @@ pub use validation::{
 /// Derive with `#[derive(SeamType)]` or implement manually.
 pub trait SeamType {
 	fn jtd_schema() -> serde_json::Value;
+
+	/// Object keys the derive contributes, so `#[seam(flatten)]` clashes fail the build.
+	/// Manual impls keep the empty default.
+	#[doc(hidden)]
+	const KEYS: FlatKeys = FlatKeys::EMPTY;
+}
+
+/// Property keys of a derived struct: its own fields plus each `#[seam(flatten)]` field's
+/// keys, so a clash anywhere in the merged object is detectable in a const context.
+#[doc(hidden)]
+pub struct FlatKeys {
+	pub own: &'static [&'static str],
+	pub flattened: &'static [FlatKeys],
+}
+
+impl FlatKeys {
+	pub const EMPTY: Self = Self { own: &[], flattened: &[] };
+
+	/// True when some key reaches the merged object more than once.
+	pub const fn has_duplicate(&self) -> bool {
+		self.repeats_in(self)
+	}
+
+	const fn repeats_in(&self, root: &FlatKeys) -> bool {
+		let mut i = 0;
+		while i < self.own.len() {
+			if root.count(self.own[i]) > 1 {
+				return true;
+			}
+			i += 1;
+		}
+		let mut i = 0;
+		while i < self.flattened.len() {
+			if self.flattened[i].repeats_in(root) {
+				return true;
+			}
+			i += 1;
+		}
+		false
+	}
+
+	const fn count(&self, key: &str) -> usize {
+		let mut n = 0;
+		let mut i = 0;
+		while i < self.own.len() {
+			if same_key(self.own[i], key) {
+				n += 1;
+			}
+			i += 1;
+		}
+		let mut i = 0;
+		while i < self.flattened.len() {
+			n += self.flattened[i].count(key);
+			i += 1;
+		}
+		n
+	}
+}
+
+// `str` equality is not callable in const fns yet
+const fn same_key(a: &str, b: &str) -> bool {
+	let (a, b) = (a.as_bytes(), b.as_bytes());
+	if a.len() != b.len() {
+		return false;
+	}
+	let mut i = 0;
+	while i < a.len() {
+		if a[i] != b[i] {
+			return false;
+		}
+		i += 1;
+	}
+	true
 }
 
 // -- Primitive SeamType impls --
@@ -77,6 +156,19 @@ impl<T: SeamType> SeamType for Vec<T> {
 	}
 }
 
+// Sets serialize as JSON arrays, so they share the `Vec` schema
+impl<T: SeamType> SeamType for std::collections::HashSet<T> {
+	fn jtd_schema() -> serde_json::Value {
+		serde_json::json!({ "elements": T::jtd_schema() })
+	}
+}
+
+impl<T: SeamType> SeamType for std::collections::BTreeSet<T> {
+	fn jtd_schema() -> serde_json::Value {
+		serde_json::json!({ "elements": T::jtd_schema() })
+	}
+}
+
 impl<T: SeamType> SeamType for Option<T> {
 	fn jtd_schema() -> serde_json::Value {
 		let mut schema = T::jtd_schema();
@@ -120,6 +212,15 @@ mod tests {
 		assert_eq!(Vec::<String>::jtd_schema(), serde_json::json!({"elements": {"type": "string"}}),);
 	}
 
+	#[test]
+	fn set_schemas() {
+		let elements = serde_json::json!({"elements": {"type": "string"}});
+		assert_eq!(std::collections::HashSet::<String>::jtd_schema(), elements);
+		assert_eq!(std::collections::BTreeSet::<String>::jtd_schema(), elements);
+		let tags: std::collections::HashSet<String> = ["a".to_string()].into();
+		assert_eq!(serde_json::to_value(tags).unwrap(), serde_json::json!(["a"]));
+	}
+
 	#[test]
 	fn option_schema() {
 		assert_eq!(
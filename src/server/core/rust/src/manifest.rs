@@ -4,14 +4,20 @@ use std::collections::BTreeMap;
 
 use serde::Serialize;
 use serde_json::Value;
+use sha2::{Digest, Sha256};
 
 use crate::channel::ChannelMeta;
 use crate::context::ContextConfig;
-use crate::procedure::{ProcedureDef, ProcedureType, StreamDef, SubscriptionDef, UploadDef};
+use crate::procedure::{
+	ProcedureDef, ProcedureType, RawDef, StreamDef, SubscriptionDef, UploadDef,
+};
 
 #[derive(Serialize)]
 pub struct Manifest {
 	pub version: u32,
+	/// `schema_hash()` at build time, so clients can detect drift without recomputing.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub hash: Option<String>,
 	#[serde(skip_serializing_if = "BTreeMap::is_empty")]
 	pub context: BTreeMap<String, ContextManifestEntry>,
 	pub procedures: BTreeMap<String, ProcedureSchema>,
@@ -21,6 +27,52 @@ pub struct Manifest {
 	pub transport_defaults: BTreeMap<String, Value>,
 }
 
+impl Manifest {
+	/// Deterministic content hash (16 hex chars) of everything except `hash` itself.
+	/// Keys are sorted at every level, so it is independent of registration order.
+	pub fn schema_hash(&self) -> String {
+		let mut value = serde_json::to_value(self).unwrap_or_default();
+		if let Value::Object(map) = &mut value {
+			map.remove("hash");
+		}
+		let mut canonical = String::new();
+		write_canonical(&value, &mut canonical);
+		let digest = Sha256::digest(canonical.as_bytes());
+		digest[..8].iter().map(|b| format!("{b:02x}")).collect()
+	}
+}
+
+/// Compact JSON with object keys sorted, regardless of `serde_json` map ordering features.
+fn write_canonical(value: &Value, out: &mut String) {
+	match value {
+		Value::Object(map) => {
+			let mut entries: Vec<_> = map.iter().collect();
+			entries.sort_by(|a, b| a.0.cmp(b.0));
+			out.push('{');
+			for (i, (key, val)) in entries.into_iter().enumerate() {
+				if i > 0 {
+					out.push(',');
+				}
+				out.push_str(&Value::String(key.clone()).to_string());
+				out.push(':');
+				write_canonical(val, out);
+			}
+			out.push('}');
+		}
+		Value::Array(items) => {
+			out.push('[');
+			for (i, item) in items.iter().enumerate() {
+				if i > 0 {
+					out.push(',');
+				}
+				write_canonical(item, out);
+			}
+			out.push(']');
+		}
+		scalar => out.push_str(&scalar.to_string()),
+	}
+}
+
 #[derive(Serialize)]
 pub struct ContextManifestEntry {
 	pub extract: String,
@@ -46,11 +98,16 @@ pub struct ProcedureSchema {
 	pub cache: Option<serde_json::Value>,
 }
 
+fn context_keys(keys: &[String]) -> Option<Vec<String>> {
+	if keys.is_empty() { None } else { Some(keys.to_vec()) }
+}
+
 pub fn build_manifest(
 	procedures: &[ProcedureDef],
 	subscriptions: &[SubscriptionDef],
 	streams: &[StreamDef],
 	uploads: &[UploadDef],
+	raws: &[RawDef],
 	channels: BTreeMap<String, ChannelMeta>,
 	context_config: &ContextConfig,
 ) -> Manifest {
@@ -60,7 +117,6 @@ pub fn build_manifest(
 			ProcedureType::Query => "query",
 			ProcedureType::Command => "command",
 		};
-		let context = if proc.context_keys.is_empty() { None } else { Some(proc.context_keys.clone()) };
 		map.insert(
 			proc.name.clone(),
 			ProcedureSchema {
@@ -69,14 +125,13 @@ pub fn build_manifest(
 				output: Some(proc.output_schema.clone()),
 				chunk_output: None,
 				error: proc.error_schema.clone(),
-				context,
+				context: context_keys(&proc.context_keys),
 				suppress: proc.suppress.clone(),
 				cache: proc.cache.clone(),
 			},
 		);
 	}
 	for sub in subscriptions {
-		let context = if sub.context_keys.is_empty() { None } else { Some(sub.context_keys.clone()) };
 		map.insert(
 			sub.name.clone(),
 			ProcedureSchema {
@@ -85,15 +140,13 @@ pub fn build_manifest(
 				output: Some(sub.output_schema.clone()),
 				chunk_output: None,
 				error: sub.error_schema.clone(),
-				context,
+				context: context_keys(&sub.context_keys),
 				suppress: sub.suppress.clone(),
 				cache: None,
 			},
 		);
 	}
 	for stream in streams {
-		let context =
-			if stream.context_keys.is_empty() { None } else { Some(stream.context_keys.clone()) };
 		map.insert(
 			stream.name.clone(),
 			ProcedureSchema {
@@ -102,15 +155,13 @@ pub fn build_manifest(
 				output: None,
 				chunk_output: Some(stream.chunk_output_schema.clone()),
 				error: stream.error_schema.clone(),
-				context,
+				context: context_keys(&stream.context_keys),
 				suppress: stream.suppress.clone(),
 				cache: None,
 			},
 		);
 	}
 	for upload in uploads {
-		let context =
-			if upload.context_keys.is_empty() { None } else { Some(upload.context_keys.clone()) };
 		map.insert(
 			upload.name.clone(),
 			ProcedureSchema {
@@ -119,12 +170,28 @@ pub fn build_manifest(
 				output: Some(upload.output_schema.clone()),
 				chunk_output: None,
 				error: upload.error_schema.clone(),
-				context,
+				context: context_keys(&upload.context_keys),
 				suppress: upload.suppress.clone(),
 				cache: None,
 			},
 		);
 	}
+	// Raw bodies have no JTD shape, so only the input is described
+	for raw in raws {
+		map.insert(
+			raw.name.clone(),
+			ProcedureSchema {
+				proc_type: "raw".to_string(),
+				input: raw.input_schema.clone(),
+				output: None,
+				chunk_output: None,
+				error: raw.error_schema.clone(),
+				context: context_keys(&raw.context_keys),
+				suppress: raw.suppress.clone(),
+				cache: None,
+			},
+		);
+	}
 
 	let mut context_manifest = BTreeMap::new();
 	for (key, field) in context_config {
@@ -134,13 +201,16 @@ pub fn build_manifest(
 		);
 	}
 
-	Manifest {
+	let mut manifest = Manifest {
 		version: 2,
+		hash: None,
 		context: context_manifest,
 		procedures: map,
 		channels,
 		transport_defaults: BTreeMap::new(),
-	}
+	};
+	manifest.hash = Some(manifest.schema_hash());
+	manifest
 }
 
 #[cfg(test)]
@@ -180,6 +250,71 @@ mod tests {
 		})
 	}
 
+	fn query(name: &str, output: Value) -> ProcedureDef {
+		ProcedureDef {
+			name: name.to_string(),
+			proc_type: ProcedureType::Query,
+			input_schema: serde_json::json!({}),
+			output_schema: output,
+			error_schema: None,
+			context_keys: vec![],
+			suppress: None,
+			cache: None,
+			timeout: None,
+			handler: dummy_handler(),
+		}
+	}
+
+	#[test]
+	fn schema_hash_ignores_registration_order() {
+		let out = serde_json::json!({"properties": {"b": {"type": "string"}, "a": {"type": "int32"}}});
+		let forward = [query("alpha", out.clone()), query("beta", serde_json::json!({}))];
+		let reversed = [query("beta", serde_json::json!({})), query("alpha", out)];
+		let ctx = ContextConfig::new();
+		let h1 = build_manifest(&forward, &[], &[], &[], &[], BTreeMap::new(), &ctx).schema_hash();
+		let h2 = build_manifest(&reversed, &[], &[], &[], &[], BTreeMap::new(), &ctx).schema_hash();
+		assert_eq!(h1, h2);
+		assert_eq!(h1.len(), 16);
+	}
+
+	#[test]
+	fn schema_hash_changes_with_schema() {
+		let ctx = ContextConfig::new();
+		let a = build_manifest(
+			&[query("q", serde_json::json!({}))],
+			&[],
+			&[],
+			&[],
+			&[],
+			BTreeMap::new(),
+			&ctx,
+		);
+		let b = build_manifest(
+			&[query("q", serde_json::json!({"type": "string"}))],
+			&[],
+			&[],
+			&[],
+			&[],
+			BTreeMap::new(),
+			&ctx,
+		);
+		assert_ne!(a.schema_hash(), b.schema_hash());
+	}
+
+	#[test]
+	fn manifest_json_carries_hash() {
+		let manifest = build_manifest(&[], &[], &[], &[], &[], BTreeMap::new(), &ContextConfig::new());
+		let json = serde_json::to_value(&manifest).unwrap();
+		assert_eq!(json["hash"], manifest.schema_hash());
+	}
+
+	#[test]
+	fn canonical_json_sorts_nested_keys() {
+		let mut out = String::new();
+		write_canonical(&serde_json::json!({"b": [{"d": 1, "c": "x"}], "a": null}), &mut out);
+		assert_eq!(out, r#"{"a":null,"b":[{"c":"x","d":1}]}"#);
+	}
+
 	#[test]
 	fn command_procedure_emits_command_type() {
 		let procs = vec![ProcedureDef {
@@ -191,9 +326,11 @@ mod tests {
 			context_keys: vec![],
 			suppress: None,
 			cache: None,
+			timeout: None,
 			handler: dummy_handler(),
 		}];
-		let manifest = build_manifest(&procs, &[], &[], &[], BTreeMap::new(), &ContextConfig::new());
+		let manifest =
+			build_manifest(&procs, &[], &[], &[], &[], BTreeMap::new(), &ContextConfig::new());
 		let schema = manifest.procedures.get("createUser").unwrap();
 		assert_eq!(schema.proc_type, "command");
 	}
@@ -210,9 +347,11 @@ mod tests {
 			context_keys: vec![],
 			suppress: None,
 			cache: None,
+			timeout: None,
 			handler: dummy_handler(),
 		}];
-		let manifest = build_manifest(&procs, &[], &[], &[], BTreeMap::new(), &ContextConfig::new());
+		let manifest =
+			build_manifest(&procs, &[], &[], &[], &[], BTreeMap::new(), &ContextConfig::new());
 		let json = serde_json::to_value(&manifest).unwrap();
 		assert_eq!(json["procedures"]["risky"]["error"], error);
 	}
@@ -228,9 +367,11 @@ mod tests {
 			context_keys: vec![],
 			suppress: None,
 			cache: None,
+			timeout: None,
 			handler: dummy_handler(),
 		}];
-		let manifest = build_manifest(&procs, &[], &[], &[], BTreeMap::new(), &ContextConfig::new());
+		let manifest =
+			build_manifest(&procs, &[], &[], &[], &[], BTreeMap::new(), &ContextConfig::new());
 		let json = serde_json::to_value(&manifest).unwrap();
 		assert!(json["procedures"]["safe"].get("error").is_none());
 	}
@@ -245,9 +386,11 @@ mod tests {
 			error_schema: Some(error.clone()),
 			context_keys: vec![],
 			suppress: None,
+			buffer: None,
 			handler: dummy_sub_handler(),
 		}];
-		let manifest = build_manifest(&[], &subs, &[], &[], BTreeMap::new(), &ContextConfig::new());
+		let manifest =
+			build_manifest(&[], &subs, &[], &[], &[], BTreeMap::new(), &ContextConfig::new());
 		let json = serde_json::to_value(&manifest).unwrap();
 		assert_eq!(json["procedures"]["onEvent"]["kind"], "subscription");
 		assert_eq!(json["procedures"]["onEvent"]["error"], error);
@@ -263,7 +406,7 @@ mod tests {
 				schema: serde_json::json!({"type": "string"}),
 			},
 		);
-		let manifest = build_manifest(&[], &[], &[], &[], BTreeMap::new(), &config);
+		let manifest = build_manifest(&[], &[], &[], &[], &[], BTreeMap::new(), &config);
 		let json = serde_json::to_value(&manifest).unwrap();
 		assert_eq!(json["context"]["token"]["extract"], "header:authorization");
 		assert_eq!(json["context"]["token"]["schema"]["type"], "string");
@@ -280,9 +423,11 @@ mod tests {
 			context_keys: vec!["token".into(), "userId".into()],
 			suppress: None,
 			cache: None,
+			timeout: None,
 			handler: dummy_handler(),
 		}];
-		let manifest = build_manifest(&procs, &[], &[], &[], BTreeMap::new(), &ContextConfig::new());
+		let manifest =
+			build_manifest(&procs, &[], &[], &[], &[], BTreeMap::new(), &ContextConfig::new());
 		let json = serde_json::to_value(&manifest).unwrap();
 		let ctx = json["procedures"]["secure"]["context"].as_array().unwrap();
 		assert_eq!(ctx, &[serde_json::json!("token"), serde_json::json!("userId")]);
@@ -290,7 +435,7 @@ mod tests {
 
 	#[test]
 	fn manifest_v2_full_format() {
-		let manifest = build_manifest(&[], &[], &[], &[], BTreeMap::new(), &ContextConfig::new());
+		let manifest = build_manifest(&[], &[], &[], &[], &[], BTreeMap::new(), &ContextConfig::new());
 		let json = serde_json::to_value(&manifest).unwrap();
 		assert_eq!(json["version"], 2);
 		assert!(json["procedures"].is_object());
@@ -322,13 +467,31 @@ mod tests {
 			suppress: None,
 			handler: dummy_stream_handler(),
 		}];
-		let manifest = build_manifest(&[], &[], &streams, &[], BTreeMap::new(), &ContextConfig::new());
+		let manifest =
+			build_manifest(&[], &[], &streams, &[], &[], BTreeMap::new(), &ContextConfig::new());
 		let json = serde_json::to_value(&manifest).unwrap();
 		assert_eq!(json["procedures"]["countStream"]["kind"], "stream");
 		assert!(json["procedures"]["countStream"]["chunkOutput"].is_object());
 		assert!(json["procedures"]["countStream"].get("output").is_none());
 	}
 
+	#[test]
+	fn raw_has_no_output_schema() {
+		let raws = vec![crate::procedure::RawDef {
+			name: "exportCsv".to_string(),
+			input_schema: serde_json::json!({}),
+			error_schema: None,
+			context_keys: vec![],
+			suppress: None,
+			handler: Arc::new(|_, _| Box::pin(async { Ok(("text/csv".to_string(), Vec::new())) })),
+		}];
+		let manifest =
+			build_manifest(&[], &[], &[], &[], &raws, BTreeMap::new(), &ContextConfig::new());
+		let json = serde_json::to_value(&manifest).unwrap();
+		assert_eq!(json["procedures"]["exportCsv"]["kind"], "raw");
+		assert!(json["procedures"]["exportCsv"].get("output").is_none());
+	}
+
 	#[test]
 	fn upload_emits_output() {
 		let uploads = vec![crate::procedure::UploadDef {
@@ -340,7 +503,8 @@ mod tests {
 			suppress: None,
 			handler: dummy_upload_handler(),
 		}];
-		let manifest = build_manifest(&[], &[], &[], &uploads, BTreeMap::new(), &ContextConfig::new());
+		let manifest =
+			build_manifest(&[], &[], &[], &uploads, &[], BTreeMap::new(), &ContextConfig::new());
 		let json = serde_json::to_value(&manifest).unwrap();
 		assert_eq!(json["procedures"]["echoUpload"]["kind"], "upload");
 		assert!(json["procedures"]["echoUpload"]["output"].is_object());
@@ -358,9 +522,11 @@ mod tests {
 			context_keys: vec![],
 			suppress: Some(vec!["unused".to_string()]),
 			cache: None,
+			timeout: None,
 			handler: dummy_handler(),
 		}];
-		let manifest = build_manifest(&procs, &[], &[], &[], BTreeMap::new(), &ContextConfig::new());
+		let manifest =
+			build_manifest(&procs, &[], &[], &[], &[], BTreeMap::new(), &ContextConfig::new());
 		let json = serde_json::to_value(&manifest).unwrap();
 		let suppress = json["procedures"]["warned"]["suppress"].as_array().unwrap();
 		assert_eq!(suppress, &[serde_json::json!("unused")]);
@@ -377,9 +543,11 @@ mod tests {
 			context_keys: vec![],
 			suppress: None,
 			cache: None,
+			timeout: None,
 			handler: dummy_handler(),
 		}];
-		let manifest = build_manifest(&procs, &[], &[], &[], BTreeMap::new(), &ContextConfig::new());
+		let manifest =
+			build_manifest(&procs, &[], &[], &[], &[], BTreeMap::new(), &ContextConfig::new());
 		let json = serde_json::to_value(&manifest).unwrap();
 		assert!(json["procedures"]["clean"].get("suppress").is_none());
 	}
@@ -395,9 +563,11 @@ mod tests {
 			context_keys: vec![],
 			suppress: None,
 			cache: Some(serde_json::json!({"ttl": 30})),
+			timeout: None,
 			handler: dummy_handler(),
 		}];
-		let manifest = build_manifest(&procs, &[], &[], &[], BTreeMap::new(), &ContextConfig::new());
+		let manifest =
+			build_manifest(&procs, &[], &[], &[], &[], BTreeMap::new(), &ContextConfig::new());
 		let json = serde_json::to_value(&manifest).unwrap();
 		assert_eq!(json["procedures"]["cached"]["cache"]["ttl"], 30);
 	}
@@ -413,9 +583,11 @@ mod tests {
 			context_keys: vec![],
 			suppress: None,
 			cache: Some(serde_json::json!(false)),
+			timeout: None,
 			handler: dummy_handler(),
 		}];
-		let manifest = build_manifest(&procs, &[], &[], &[], BTreeMap::new(), &ContextConfig::new());
+		let manifest =
+			build_manifest(&procs, &[], &[], &[], &[], BTreeMap::new(), &ContextConfig::new());
 		let json = serde_json::to_value(&manifest).unwrap();
 		assert_eq!(json["procedures"]["nocache"]["cache"], false);
 	}
@@ -431,9 +603,11 @@ mod tests {
 			context_keys: vec![],
 			suppress: None,
 			cache: None,
+			timeout: None,
 			handler: dummy_handler(),
 		}];
-		let manifest = build_manifest(&procs, &[], &[], &[], BTreeMap::new(), &ContextConfig::new());
+		let manifest =
+			build_manifest(&procs, &[], &[], &[], &[], BTreeMap::new(), &ContextConfig::new());
 		let json = serde_json::to_value(&manifest).unwrap();
 		assert!(json["procedures"]["default"].get("cache").is_none());
 	}
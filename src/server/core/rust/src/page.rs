@@ -3,6 +3,8 @@
 use std::collections::HashMap;
 use std::sync::Arc;
 
+pub use seam_injector::CompiledTemplate;
+
 pub type LoaderInputFn = Arc<dyn Fn(&HashMap<String, String>) -> serde_json::Value + Send + Sync>;
 
 pub struct LoaderDef {
@@ -11,6 +13,16 @@ pub struct LoaderDef {
 	pub input_fn: LoaderInputFn,
 }
 
+/// Loader array a page serves one window of. The request query picks the window
+/// (`?page=2&size=10`); see `seam_engine::paginate`.
+#[derive(Debug, Clone)]
+pub struct PaginationDef {
+	/// Dot path into loader data, e.g. `list.items`
+	pub path: String,
+	/// Page size when the query names none.
+	pub default_size: usize,
+}
+
 /// One entry in a layout chain (outer to inner order).
 /// Each layout owns a set of loader data keys.
 pub struct LayoutChainEntry {
@@ -35,12 +47,67 @@ pub struct PageDef {
 	pub i18n_keys: Vec<String>,
 	/// Per-loader field projections for schema narrowing. None = no narrowing.
 	pub projections: Option<HashMap<String, Vec<String>>>,
+	/// Slice one loader array to the requested page before rendering. None = send it whole.
+	pub pagination: Option<PaginationDef>,
 	/// SSG: serve pre-rendered static HTML instead of running loaders.
 	pub prerender: bool,
 	/// SSG: directory containing pre-rendered HTML files.
 	pub static_dir: Option<std::path::PathBuf>,
+	/// Mtime of the page template file, set by `load_build_output`. None = no conditional GETs.
+	pub template_mtime: Option<std::time::SystemTime>,
+	/// Parsed templates, filled in by `SeamServer::into_parts`. None = parse per request.
+	pub compiled: Option<CompiledPage>,
+}
+
+impl PageDef {
+	/// `Last-Modified` for conditional GETs. Only loader-free pages qualify: loader data
+	/// can change while the template file stays the same.
+	pub fn last_modified(&self) -> Option<std::time::SystemTime> {
+		self.template_mtime.filter(|_| self.loaders.is_empty())
+	}
+}
+
+/// Page templates tokenized and parsed once, so renders skip both steps.
+#[derive(Debug)]
+pub struct CompiledPage {
+	pub template: CompiledTemplate,
+	/// Keyed by locale, mirroring `PageDef::locale_templates`.
+	pub locale_templates: HashMap<String, CompiledTemplate>,
 }
 
+impl CompiledPage {
+	pub fn compile(page: &PageDef) -> Self {
+		let locale_templates = page
+			.locale_templates
+			.iter()
+			.flatten()
+			.map(|(locale, tmpl)| (locale.clone(), seam_injector::compile(tmpl)))
+			.collect();
+		Self { template: seam_injector::compile(&page.template), locale_templates }
+	}
+
+	/// Compiled template for `locale`, falling back to the default template.
+	pub fn for_locale(&self, locale: Option<&str>) -> &CompiledTemplate {
+		locale.and_then(|l| self.locale_templates.get(l)).unwrap_or(&self.template)
+	}
+}
+
+/// HTML templates rendered in place of bare status codes. Each is injected with
+/// `{ status, code, path }`; `None` keeps the plain response.
+#[derive(Debug, Clone, Default)]
+pub struct StatusPages {
+	/// Rendered with 404 when no page route matches or a page reports NotFound
+	pub not_found: Option<String>,
+	/// Rendered with the error's status when a page render or one of its loaders fails.
+	/// Without it, loader errors stay per-key `__error` markers.
+	pub error: Option<String>,
+}
+
+/// Per-response CSP nonce. Middleware that sets the `Content-Security-Policy` header
+/// inserts this into request extensions so the page data script carries a matching `nonce`.
+#[derive(Debug, Clone)]
+pub struct CspNonce(pub String);
+
 /// Runtime i18n configuration loaded from build output.
 #[derive(Clone)]
 pub struct I18nConfig {
@@ -56,4 +123,5 @@ pub struct I18nConfig {
 	pub messages: HashMap<String, HashMap<String, serde_json::Value>>,
 	/// Paged mode: base directory for on-demand reads
 	pub dist_dir: Option<std::path::PathBuf>,
+	pub routing: crate::resolve::LocaleRouting,
 }
@@ -0,0 +1,75 @@
+/* src/server/core/rust/src/pagination.rs */
+
+use serde::{Deserialize, Serialize};
+
+use crate::SeamType;
+
+/// Marker in a schema's JTD `metadata` that lets codegen render the shared `SeamPage<T>`
+/// type instead of an inline object.
+pub const PAGE_METADATA: &str = "Page";
+
+/// One page of a cursor-paginated list, serialized as `{ items, nextCursor }`.
+/// `next_cursor` is `None` once the list is exhausted.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Page<T> {
+	pub items: Vec<T>,
+	pub next_cursor: Option<String>,
+}
+
+impl<T: Clone> Page<T> {
+	/// Page over `items`, with the cursor taken from the last item. An empty slice has no
+	/// cursor, so a client stops after the first empty page.
+	pub fn from_slice(items: &[T], cursor_fn: impl FnOnce(&T) -> String) -> Self {
+		Self { items: items.to_vec(), next_cursor: items.last().map(cursor_fn) }
+	}
+}
+
+impl<T: SeamType> SeamType for Page<T> {
+	fn jtd_schema() -> serde_json::Value {
+		serde_json::json!({
+			"properties": {
+				"items": { "elements": T::jtd_schema() },
+				"nextCursor": { "type": "string", "nullable": true },
+			},
+			"metadata": { "seamType": PAGE_METADATA },
+		})
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn schema_wraps_item_schema() {
+		assert_eq!(
+			Page::<u32>::jtd_schema(),
+			serde_json::json!({
+				"properties": {
+					"items": { "elements": { "type": "uint32" } },
+					"nextCursor": { "type": "string", "nullable": true },
+				},
+				"metadata": { "seamType": "Page" },
+			})
+		);
+	}
+
+	#[test]
+	fn from_slice_takes_cursor_from_last_item() {
+		let page = Page::from_slice(&[3u32, 7, 9], u32::to_string);
+		assert_eq!(page.items, vec![3, 7, 9]);
+		assert_eq!(page.next_cursor.as_deref(), Some("9"));
+		assert_eq!(
+			serde_json::to_value(&page).unwrap(),
+			serde_json::json!({ "items": [3, 7, 9], "nextCursor": "9" })
+		);
+	}
+
+	#[test]
+	fn empty_slice_has_no_cursor() {
+		let page = Page::<u32>::from_slice(&[], u32::to_string);
+		assert!(page.items.is_empty());
+		assert_eq!(page.next_cursor, None);
+	}
+}
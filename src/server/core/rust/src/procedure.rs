@@ -3,6 +3,7 @@
 use std::future::Future;
 use std::pin::Pin;
 use std::sync::Arc;
+use std::time::Duration;
 
 use futures_core::Stream;
 use futures_util::StreamExt;
@@ -61,9 +62,40 @@ pub struct ProcedureDef {
 	pub context_keys: Vec<String>,
 	pub suppress: Option<Vec<String>>,
 	pub cache: Option<serde_json::Value>,
+	/// Overrides `TransportConfig::procedure_timeout` for this procedure.
+	pub timeout: Option<Duration>,
 	pub handler: HandlerFn,
 }
 
+impl ProcedureDef {
+	/// Query procedure around a plain async closure, for handlers registered without
+	/// `#[seam_procedure]` (tests, plugins). Other fields take their defaults and can be
+	/// overridden with struct update syntax.
+	pub fn from_fn<F, Fut>(
+		name: impl Into<String>,
+		input_schema: serde_json::Value,
+		output_schema: serde_json::Value,
+		handler: F,
+	) -> Self
+	where
+		F: Fn(serde_json::Value, serde_json::Value) -> Fut + Send + Sync + 'static,
+		Fut: Future<Output = Result<serde_json::Value, SeamError>> + Send + 'static,
+	{
+		Self {
+			name: name.into(),
+			proc_type: ProcedureType::Query,
+			input_schema,
+			output_schema,
+			error_schema: None,
+			context_keys: Vec::new(),
+			suppress: None,
+			cache: None,
+			timeout: None,
+			handler: Arc::new(move |input, ctx| Box::pin(handler(input, ctx))),
+		}
+	}
+}
+
 pub struct SubscriptionDef {
 	pub name: String,
 	pub input_schema: serde_json::Value,
@@ -71,9 +103,30 @@ pub struct SubscriptionDef {
 	pub error_schema: Option<serde_json::Value>,
 	pub context_keys: Vec<String>,
 	pub suppress: Option<Vec<String>>,
+	/// Bounded queue between the handler stream and the client. `None` forwards events
+	/// as the transport pulls them, with no queue of its own.
+	pub buffer: Option<SubscriptionBuffer>,
 	pub handler: SubscriptionHandlerFn,
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SubscriptionBuffer {
+	pub capacity: usize,
+	pub overflow: OverflowPolicy,
+}
+
+/// What a full subscription buffer does with the next event.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum OverflowPolicy {
+	/// Evict the oldest queued event to make room; slow clients see the latest state.
+	DropOldest,
+	/// Discard the incoming event; slow clients see a prefix of the stream.
+	DropNewest,
+	/// Stop pulling from the handler until the client catches up. Lossless.
+	#[default]
+	Block,
+}
+
 pub struct StreamParams {
 	pub input: serde_json::Value,
 	pub ctx: serde_json::Value,
@@ -97,6 +150,13 @@ pub type UploadHandlerFn = Arc<
 		+ Sync,
 >;
 
+/// Returns `(content_type, body)`, sent as-is with no JSON envelope.
+pub type RawHandlerFn = Arc<
+	dyn Fn(serde_json::Value, serde_json::Value) -> BoxFuture<Result<(String, Vec<u8>), SeamError>>
+		+ Send
+		+ Sync,
+>;
+
 /// File received from a multipart upload request.
 pub struct SeamFileHandle {
 	pub name: Option<String>,
@@ -123,3 +183,14 @@ pub struct UploadDef {
 	pub suppress: Option<Vec<String>>,
 	pub handler: UploadHandlerFn,
 }
+
+/// A procedure answering with bytes (a CSV export, a PDF) instead of JSON. It takes JSON
+/// input like a query; the manifest lists it as `raw` with no output schema.
+pub struct RawDef {
+	pub name: String,
+	pub input_schema: serde_json::Value,
+	pub error_schema: Option<serde_json::Value>,
+	pub context_keys: Vec<String>,
+	pub suppress: Option<Vec<String>>,
+	pub handler: RawHandlerFn,
+}
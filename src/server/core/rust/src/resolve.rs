@@ -100,19 +100,15 @@ impl ResolveStrategy for FromAcceptLanguage {
 			}
 			let mut segments = part.split(';');
 			let lang = segments.next().unwrap_or("").trim();
-			let mut q = 1.0_f64;
-			for s in segments {
-				let s = s.trim();
-				if let Some(val) = s.strip_prefix("q=")
-					&& let Ok(v) = val.parse::<f64>()
-				{
-					q = v;
-				}
+			let q = segments.find_map(|s| s.trim().strip_prefix("q=")).map_or(1.0, parse_quality);
+			// q=0 means "not acceptable", not "lowest priority"
+			if q > 0.0 {
+				entries.push((lang, q));
 			}
-			entries.push((lang, q));
 		}
 
-		entries.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap_or(std::cmp::Ordering::Equal));
+		// Stable sort: equal weights keep header order
+		entries.sort_by(|a, b| b.1.total_cmp(&a.1));
 
 		for (lang, _) in &entries {
 			if locale_set.contains(lang) {
@@ -131,6 +127,14 @@ impl ResolveStrategy for FromAcceptLanguage {
 	}
 }
 
+/// A `q=` weight in [0, 1]; anything malformed counts as the default 1.0.
+fn parse_quality(value: &str) -> f64 {
+	match value.trim().parse::<f64>() {
+		Ok(q) if (0.0..=1.0).contains(&q) => q,
+		_ => 1.0,
+	}
+}
+
 pub fn from_accept_language() -> Box<dyn ResolveStrategy> {
 	Box::new(FromAcceptLanguage)
 }
@@ -181,6 +185,31 @@ pub fn default_strategies() -> Vec<Box<dyn ResolveStrategy>> {
 	vec![from_url_prefix(), from_cookie("seam-locale"), from_accept_language()]
 }
 
+/// How page URLs carry the locale, from `i18n.routing` in the build manifest. Picks the
+/// strategy chain when the server does not set one with `resolve_strategies`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, serde::Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum LocaleRouting {
+	/// Every page lives under `/{locale}/...`; unprefixed URLs get the default locale.
+	Prefix,
+	/// No locale in the URL: cookie, then `Accept-Language`.
+	Hidden,
+	/// Locale-prefixed routes for SEO-critical pages, and cookie / `Accept-Language`
+	/// resolution for unprefixed ones. An explicit prefix always wins.
+	#[default]
+	Hybrid,
+}
+
+impl LocaleRouting {
+	pub fn strategies(self) -> Vec<Box<dyn ResolveStrategy>> {
+		match self {
+			Self::Prefix => vec![from_url_prefix()],
+			Self::Hidden => vec![from_cookie("seam-locale"), from_accept_language()],
+			Self::Hybrid => default_strategies(),
+		}
+	}
+}
+
 #[cfg(test)]
 mod tests {
 	use super::*;
@@ -288,6 +317,31 @@ mod tests {
 		assert_eq!(FromAcceptLanguage.resolve(&data), Some("zh".into()));
 	}
 
+	#[test]
+	fn accept_language_weighted_order() {
+		let locs = locales();
+		let data = make_data("", None, None, Some("fr;q=0.9, ja;q=0.7, en;q=0.8"), &locs, "en");
+		assert_eq!(FromAcceptLanguage.resolve(&data), Some("en".into()));
+		let data = make_data("", None, None, Some("ja;q=0.5, en;q=oops"), &locs, "en");
+		assert_eq!(FromAcceptLanguage.resolve(&data), Some("en".into()));
+	}
+
+	#[test]
+	fn accept_language_q_zero_excludes() {
+		let locs = locales();
+		let data = make_data("", None, None, Some("zh;q=0, ja;q=0.1"), &locs, "en");
+		assert_eq!(FromAcceptLanguage.resolve(&data), Some("ja".into()));
+		let data = make_data("", None, None, Some("en;q=0.0, zh-TW;q=0"), &locs, "en");
+		assert_eq!(FromAcceptLanguage.resolve(&data), None);
+	}
+
+	#[test]
+	fn accept_language_region_fallback() {
+		let locs = vec!["en".to_string(), "fr".to_string()];
+		let data = make_data("", None, None, Some("fr-CA, en;q=0.9"), &locs, "en");
+		assert_eq!(FromAcceptLanguage.resolve(&data), Some("fr".into()));
+	}
+
 	#[test]
 	fn accept_language_no_match() {
 		let locs = locales();
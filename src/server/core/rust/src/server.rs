@@ -1,6 +1,7 @@
 /* src/server/core/rust/src/server.rs */
 
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, HashSet};
+use std::future::Future;
 use std::path::PathBuf;
 use std::time::Duration;
 
@@ -8,16 +9,22 @@ use crate::build_loader::BuildOutput;
 use crate::build_loader::RpcHashMap;
 use crate::channel::{ChannelDef, ChannelMeta};
 use crate::context::{ContextConfig, ContextFieldDef};
-use crate::page::{I18nConfig, PageDef};
-use crate::procedure::{ProcedureDef, StreamDef, SubscriptionDef, UploadDef};
+use crate::errors::SeamError;
+use crate::page::{CompiledPage, I18nConfig, PageDef, StatusPages};
+use crate::procedure::{ProcedureDef, RawDef, StreamDef, SubscriptionDef, UploadDef};
 use crate::resolve::ResolveStrategy;
 use crate::validation::ValidationMode;
 
 /// Transport reliability configuration shared across all backends.
 pub struct TransportConfig {
+	/// Keep-alive period: SSE comment frames and WS heartbeat messages. `Duration::ZERO` disables.
 	pub heartbeat_interval: Duration,
+	/// Close SSE streams after this long without data. `Duration::ZERO` disables.
 	pub sse_idle_timeout: Duration,
 	pub pong_timeout: Duration,
+	/// Deadline for query/command handlers, unless `ProcedureDef::timeout` overrides it.
+	/// `Duration::ZERO` (the default) disables, so handlers run unbounded unless opted in.
+	pub procedure_timeout: Duration,
 }
 
 impl Default for TransportConfig {
@@ -26,10 +33,23 @@ impl Default for TransportConfig {
 			heartbeat_interval: Duration::from_secs(8),
 			sse_idle_timeout: Duration::from_secs(12),
 			pong_timeout: Duration::from_secs(5),
+			procedure_timeout: Duration::ZERO,
 		}
 	}
 }
 
+/// How a page URL with a trailing slash (`/dashboard/`) is served. `/` is never affected.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum TrailingSlash {
+	/// Answer with a 308 to the slash-less URL, keeping one canonical address per page.
+	#[default]
+	Redirect,
+	/// Serve the page directly under both forms.
+	Rewrite,
+	/// Only the registered form matches; the other 404s.
+	Strict,
+}
+
 /// Framework-agnostic parts extracted from `SeamServer`.
 /// Adapter crates consume this to build framework-specific routers.
 pub struct SeamParts {
@@ -37,6 +57,7 @@ pub struct SeamParts {
 	pub subscriptions: Vec<SubscriptionDef>,
 	pub streams: Vec<StreamDef>,
 	pub uploads: Vec<UploadDef>,
+	pub raws: Vec<RawDef>,
 	pub pages: Vec<PageDef>,
 	pub rpc_hash_map: Option<RpcHashMap>,
 	pub i18n_config: Option<I18nConfig>,
@@ -46,6 +67,14 @@ pub struct SeamParts {
 	pub context_config: ContextConfig,
 	pub validation_mode: ValidationMode,
 	pub transport_config: TransportConfig,
+	/// Compress `/_seam/*` responses according to the client's `Accept-Encoding`.
+	pub compression: bool,
+	pub trailing_slash: TrailingSlash,
+	pub status_pages: StatusPages,
+	/// Page data scripts carry a `data-integrity` digest of their JSON.
+	pub data_integrity: bool,
+	/// `iflocale` blocks render when a page has no locale (i18n off).
+	pub iflocale_when_unset: bool,
 }
 
 impl SeamParts {
@@ -59,6 +88,7 @@ pub struct SeamServer {
 	subscriptions: Vec<SubscriptionDef>,
 	streams: Vec<StreamDef>,
 	uploads: Vec<UploadDef>,
+	raws: Vec<RawDef>,
 	channels: Vec<ChannelDef>,
 	pages: Vec<PageDef>,
 	rpc_hash_map: Option<RpcHashMap>,
@@ -68,6 +98,11 @@ pub struct SeamServer {
 	context_config: ContextConfig,
 	validation_mode: ValidationMode,
 	transport_config: TransportConfig,
+	compression: bool,
+	trailing_slash: TrailingSlash,
+	status_pages: StatusPages,
+	data_integrity: bool,
+	iflocale_when_unset: bool,
 }
 
 impl SeamServer {
@@ -77,6 +112,7 @@ impl SeamServer {
 			subscriptions: Vec::new(),
 			streams: Vec::new(),
 			uploads: Vec::new(),
+			raws: Vec::new(),
 			channels: Vec::new(),
 			pages: Vec::new(),
 			rpc_hash_map: None,
@@ -86,6 +122,11 @@ impl SeamServer {
 			context_config: ContextConfig::new(),
 			validation_mode: ValidationMode::Dev,
 			transport_config: TransportConfig::default(),
+			compression: false,
+			trailing_slash: TrailingSlash::default(),
+			status_pages: StatusPages::default(),
+			data_integrity: false,
+			iflocale_when_unset: false,
 		}
 	}
 
@@ -94,6 +135,21 @@ impl SeamServer {
 		self
 	}
 
+	/// Register a query built from an async closure; see [`ProcedureDef::from_fn`].
+	pub fn procedure_fn<F, Fut>(
+		self,
+		name: impl Into<String>,
+		input_schema: serde_json::Value,
+		output_schema: serde_json::Value,
+		handler: F,
+	) -> Self
+	where
+		F: Fn(serde_json::Value, serde_json::Value) -> Fut + Send + Sync + 'static,
+		Fut: Future<Output = Result<serde_json::Value, SeamError>> + Send + 'static,
+	{
+		self.procedure(ProcedureDef::from_fn(name, input_schema, output_schema, handler))
+	}
+
 	pub fn subscription(mut self, sub: SubscriptionDef) -> Self {
 		self.subscriptions.push(sub);
 		self
@@ -109,6 +165,11 @@ impl SeamServer {
 		self
 	}
 
+	pub fn raw(mut self, raw: RawDef) -> Self {
+		self.raws.push(raw);
+		self
+	}
+
 	pub fn channel(mut self, channel: ChannelDef) -> Self {
 		self.channels.push(channel);
 		self
@@ -195,6 +256,97 @@ impl SeamServer {
 		self
 	}
 
+	/// Opt in to gzip/brotli compression of `/_seam/*` responses. Off by default.
+	pub fn compression(mut self, enabled: bool) -> Self {
+		self.compression = enabled;
+		self
+	}
+
+	/// Handling of page URLs with a trailing slash. Defaults to `TrailingSlash::Redirect`.
+	pub fn trailing_slash(mut self, mode: TrailingSlash) -> Self {
+		self.trailing_slash = mode;
+		self
+	}
+
+	/// Give page data scripts a `data-integrity="sha256-<base64>"` digest of their JSON,
+	/// for the client to check before hydrating. Off by default.
+	pub fn data_integrity(mut self, enabled: bool) -> Self {
+		self.data_integrity = enabled;
+		self
+	}
+
+	/// Render `iflocale` blocks on pages with no locale (i18n off) instead of dropping
+	/// them, so single-locale apps keep their default-language markup. Off by default.
+	pub fn iflocale_when_unset(mut self, enabled: bool) -> Self {
+		self.iflocale_when_unset = enabled;
+		self
+	}
+
+	/// HTML template for unknown pages, rendered with status 404.
+	pub fn not_found_page(mut self, template: impl Into<String>) -> Self {
+		self.status_pages.not_found = Some(template.into());
+		self
+	}
+
+	/// HTML template for failed page renders, rendered with the error's status. Setting it
+	/// makes a loader that returns `Err` fail the page too, instead of leaving an `__error`
+	/// marker under its data key.
+	pub fn error_page(mut self, template: impl Into<String>) -> Self {
+		self.status_pages.error = Some(template.into());
+		self
+	}
+
+	/// Fold a sub-server's definitions into this one, so feature modules can each build a
+	/// `SeamServer` and compose them. Procedures, subscriptions, streams, uploads, raw
+	/// procedures, channels, pages, and context fields are concatenated; server-wide
+	/// settings (transport, validation, strategies, ...) stay this server's, with `other`
+	/// only filling unset build outputs.
+	///
+	/// # Panics
+	/// When both servers register the same procedure name (of any kind), channel name, or
+	/// page route. Colliding names would silently shadow each other at dispatch.
+	pub fn merge(mut self, other: SeamServer) -> Self {
+		let names: HashSet<&str> = self.rpc_names().collect();
+		if let Some(name) = other.rpc_names().find(|n| names.contains(n)) {
+			panic!("cannot merge servers: procedure name {name:?} is registered in both");
+		}
+		let channels: HashSet<&str> = self.channels.iter().map(|c| c.name.as_str()).collect();
+		if let Some(c) = other.channels.iter().find(|c| channels.contains(c.name.as_str())) {
+			panic!("cannot merge servers: channel {:?} is registered in both", c.name);
+		}
+		let routes: HashSet<&str> = self.pages.iter().map(|p| p.route.as_str()).collect();
+		if let Some(p) = other.pages.iter().find(|p| routes.contains(p.route.as_str())) {
+			panic!("cannot merge servers: page route {:?} is registered in both", p.route);
+		}
+
+		self.procedures.extend(other.procedures);
+		self.subscriptions.extend(other.subscriptions);
+		self.streams.extend(other.streams);
+		self.uploads.extend(other.uploads);
+		self.raws.extend(other.raws);
+		self.channels.extend(other.channels);
+		self.pages.extend(other.pages);
+		for (key, field) in other.context_config {
+			self.context_config.entry(key).or_insert(field);
+		}
+		self.rpc_hash_map = self.rpc_hash_map.or(other.rpc_hash_map);
+		self.i18n_config = self.i18n_config.or(other.i18n_config);
+		self.public_dir = self.public_dir.or(other.public_dir);
+		self
+	}
+
+	/// Every name callable at `/_seam/procedure/{name}`; all kinds share one namespace.
+	fn rpc_names(&self) -> impl Iterator<Item = &str> {
+		self
+			.procedures
+			.iter()
+			.map(|p| p.name.as_str())
+			.chain(self.subscriptions.iter().map(|s| s.name.as_str()))
+			.chain(self.streams.iter().map(|s| s.name.as_str()))
+			.chain(self.uploads.iter().map(|u| u.name.as_str()))
+			.chain(self.raws.iter().map(|r| r.name.as_str()))
+	}
+
 	/// Consume the builder, returning framework-agnostic parts for an adapter.
 	/// Channels are expanded into their Level 0 primitives (commands + subscriptions).
 	pub fn into_parts(self) -> SeamParts {
@@ -210,20 +362,40 @@ impl SeamServer {
 			channel_metas.insert(name, meta);
 		}
 
+		// Parse every page template once here instead of on each request
+		let mut pages = self.pages;
+		for page in &mut pages {
+			if page.compiled.is_none() {
+				page.compiled = Some(CompiledPage::compile(page));
+			}
+		}
+
+		// Without an explicit chain, the build's `i18n.routing` decides how locales resolve
+		let strategies = match self.i18n_config {
+			Some(ref i18n) if self.strategies.is_empty() => i18n.routing.strategies(),
+			_ => self.strategies,
+		};
+
 		SeamParts {
 			procedures,
 			subscriptions,
 			streams: self.streams,
 			uploads: self.uploads,
-			pages: self.pages,
+			raws: self.raws,
+			pages,
 			rpc_hash_map: self.rpc_hash_map,
 			i18n_config: self.i18n_config,
 			public_dir: self.public_dir,
-			strategies: self.strategies,
+			strategies,
 			channel_metas,
 			context_config: self.context_config,
 			validation_mode: self.validation_mode,
 			transport_config: self.transport_config,
+			compression: self.compression,
+			trailing_slash: self.trailing_slash,
+			status_pages: self.status_pages,
+			data_integrity: self.data_integrity,
+			iflocale_when_unset: self.iflocale_when_unset,
 		}
 	}
 }
@@ -236,13 +408,114 @@ impl Default for SeamServer {
 
 #[cfg(test)]
 mod tests {
-	use super::TransportConfig;
+	use super::{SeamServer, TransportConfig};
+	use crate::page::PageDef;
+	use crate::procedure::{ProcedureDef, ProcedureType};
+	use std::sync::Arc;
 	use std::time::Duration;
 
+	fn query(name: &str) -> ProcedureDef {
+		ProcedureDef {
+			name: name.into(),
+			proc_type: ProcedureType::Query,
+			input_schema: serde_json::json!({}),
+			output_schema: serde_json::json!({}),
+			error_schema: None,
+			context_keys: vec![],
+			suppress: None,
+			cache: None,
+			timeout: None,
+			handler: Arc::new(|_input, _ctx| Box::pin(async { Ok(serde_json::json!(null)) })),
+		}
+	}
+
+	fn page(route: &str) -> PageDef {
+		PageDef {
+			route: route.into(),
+			template: String::new(),
+			locale_templates: None,
+			loaders: vec![],
+			data_id: "__data".into(),
+			layout_chain: vec![],
+			page_loader_keys: vec![],
+			i18n_keys: vec![],
+			projections: None,
+			pagination: None,
+			prerender: false,
+			static_dir: None,
+			template_mtime: None,
+			compiled: None,
+		}
+	}
+
+	#[test]
+	fn merge_concatenates_definitions() {
+		let users = SeamServer::new().procedure(query("getUser")).page(page("/users"));
+		let blog = SeamServer::new()
+			.namespace("blog", vec![query("getPost"), query("listPosts")])
+			.page(page("/blog"));
+		let parts = users.merge(blog).into_parts();
+		let names: Vec<_> = parts.procedures.iter().map(|p| p.name.as_str()).collect();
+		assert_eq!(names, ["getUser", "blog.getPost", "blog.listPosts"]);
+		let routes: Vec<_> = parts.pages.iter().map(|p| p.route.as_str()).collect();
+		assert_eq!(routes, ["/users", "/blog"]);
+	}
+
+	#[test]
+	#[should_panic(expected = "procedure name \"getUser\" is registered in both")]
+	fn merge_rejects_duplicate_procedure_name() {
+		let a = SeamServer::new().procedure(query("getUser"));
+		let b = SeamServer::new().procedure(query("listUsers")).procedure(query("getUser"));
+		let _ = a.merge(b);
+	}
+
+	#[test]
+	#[should_panic(expected = "page route \"/\" is registered in both")]
+	fn merge_rejects_duplicate_page_route() {
+		let _ = SeamServer::new().page(page("/")).merge(SeamServer::new().page(page("/")));
+	}
+
 	#[test]
 	fn transport_config_uses_8_second_heartbeat_and_12_second_idle_timeout_by_default() {
 		let config = TransportConfig::default();
 		assert_eq!(config.heartbeat_interval, Duration::from_secs(8));
 		assert_eq!(config.sse_idle_timeout, Duration::from_secs(12));
 	}
+
+	#[test]
+	fn into_parts_precompiles_page_templates() {
+		let template = "<p><!--seam:title--></p>";
+		let page = PageDef {
+			route: "/".into(),
+			template: template.into(),
+			locale_templates: Some([("fr".to_string(), "<p>fr</p>".to_string())].into()),
+			loaders: vec![],
+			data_id: "__data".into(),
+			layout_chain: vec![],
+			page_loader_keys: vec![],
+			i18n_keys: vec![],
+			projections: None,
+			pagination: None,
+			prerender: false,
+			static_dir: None,
+			template_mtime: None,
+			compiled: None,
+		};
+		let parts = SeamServer::new().page(page).into_parts();
+		let compiled = parts.pages[0].compiled.as_ref().expect("compiled at into_parts");
+
+		let data = serde_json::json!({"title": "Hi"});
+		assert_eq!(
+			seam_injector::inject_precompiled(compiled.for_locale(None), &data),
+			seam_injector::inject_no_script(template, &data),
+		);
+		assert_eq!(
+			seam_injector::inject_precompiled(compiled.for_locale(Some("fr")), &data),
+			"<p>fr</p>"
+		);
+		assert_eq!(
+			seam_injector::inject_precompiled(compiled.for_locale(Some("de")), &data),
+			"<p>Hi</p>"
+		);
+	}
 }
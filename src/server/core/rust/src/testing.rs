@@ -0,0 +1,101 @@
+/* src/server/core/rust/src/testing.rs */
+
+//! In-process procedure calls for unit tests (`test-util` feature). Runs the same
+//! validation, context resolution, and timeout as an adapter, without HTTP.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use serde_json::Value;
+
+use crate::context::RawContextMap;
+use crate::dispatch::Procedures;
+use crate::errors::SeamError;
+use crate::server::SeamServer;
+use crate::validation::should_validate;
+
+/// Calls a server's queries and commands directly.
+///
+/// ```ignore
+/// let client = TestClient::new(server);
+/// let user = client.invoke("getUser", json!({ "id": 1 })).await?;
+/// ```
+pub struct TestClient {
+	procedures: Procedures,
+}
+
+impl TestClient {
+	/// Registers the server's procedures, including channel commands, under its
+	/// validation mode and transport timeout.
+	pub fn new(server: SeamServer) -> Self {
+		let parts = server.into_parts();
+		let handlers: HashMap<_, _> =
+			parts.procedures.into_iter().map(|p| (p.name.clone(), Arc::new(p))).collect();
+		let procedures = Procedures::new(
+			handlers,
+			should_validate(&parts.validation_mode),
+			parts.context_config,
+			parts.transport_config.procedure_timeout,
+		);
+		Self { procedures }
+	}
+
+	/// Call procedure `name` with no request context.
+	pub async fn invoke(&self, name: &str, input: Value) -> Result<Value, SeamError> {
+		self.invoke_with_context(name, input, &RawContextMap::new()).await
+	}
+
+	/// Call procedure `name` as if the request carried `raw_ctx` (header, cookie, and
+	/// query values keyed by context field).
+	pub async fn invoke_with_context(
+		&self,
+		name: &str,
+		input: Value,
+		raw_ctx: &RawContextMap,
+	) -> Result<Value, SeamError> {
+		self.procedures.call(name, input, raw_ctx).await
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use serde_json::json;
+
+	use super::TestClient;
+	use crate::errors::SeamError;
+	use crate::procedure::ProcedureDef;
+	use crate::server::SeamServer;
+
+	fn client() -> TestClient {
+		let get_user = ProcedureDef::from_fn(
+			"getUser",
+			json!({ "properties": { "id": { "type": "int32" } } }),
+			json!({}),
+			|input, _ctx| async move {
+				match input["id"].as_i64() {
+					Some(1) => Ok(json!({ "name": "Ada" })),
+					_ => Err(SeamError::not_found("no such user")),
+				}
+			},
+		);
+		TestClient::new(SeamServer::new().procedure(get_user))
+	}
+
+	#[tokio::test]
+	async fn invoke_returns_handler_output() {
+		let user = client().invoke("getUser", json!({ "id": 1 })).await.unwrap();
+		assert_eq!(user, json!({ "name": "Ada" }));
+	}
+
+	#[tokio::test]
+	async fn invoke_returns_seam_errors() {
+		let client = client();
+		let err = client.invoke("getUser", json!({ "id": 2 })).await.unwrap_err();
+		assert_eq!((err.code(), err.message()), ("NOT_FOUND", "no such user"));
+
+		let err = client.invoke("getUser", json!({ "id": "x" })).await.unwrap_err();
+		assert_eq!(err.code(), "VALIDATION_ERROR");
+		let err = client.invoke("missing", json!({})).await.unwrap_err();
+		assert_eq!(err.code(), "NOT_FOUND");
+	}
+}
@@ -38,6 +38,21 @@ describe('headConfigToHtml', () => {
 		expect(headConfigToHtml({})).toBe('')
 	})
 
+	it('keeps blocks in order and neutralizes closing tags in their content', () => {
+		expect(
+			headConfigToHtml({
+				blocks: [
+					{ style: 'a::after{content:"</style>"}' },
+					{ jsonLd: '{"name":"</script><b>"}' },
+				],
+			}),
+		).toBe(
+			'<style data-seam-head>a::after{content:"<\\/style>"}</style>' +
+				'<script type="application/ld+json" data-seam-head>' +
+				'{"name":"\\u003c/script>\\u003cb>"}</script>',
+		)
+	})
+
 	it('combines title, meta, and link', () => {
 		const html = headConfigToHtml({
 			title: 'My Page',
@@ -74,6 +74,45 @@ describe('handlePageRequest -- nested layouts', () => {
 	})
 })
 
+// ---------------------------------------------------------------------------
+// Named outlets
+// ---------------------------------------------------------------------------
+describe('handlePageRequest -- named outlets', () => {
+	it('fills each named outlet from the matching seam-fill block', async () => {
+		const shell: LayoutDef = {
+			id: 'shell',
+			template:
+				'<html><body><aside><!--seam:outlet:sidebar--></aside><main><!--seam:outlet--></main><footer><!--seam:outlet:footer--></footer></body></html>',
+			loaders: {},
+		}
+		const page: PageDef = {
+			template:
+				'<seam-fill name="sidebar"><a>nav</a></seam-fill><h1><!--seam:title--></h1><seam-fill name="footer">(c)</seam-fill>',
+			loaders: { page: () => ({ procedure: 'getTitle', input: {} }) },
+			layoutChain: [shell],
+		}
+		const procs = makeProcedures(['getTitle', mockProcedure(() => ({ title: 'Home' }))])
+		const result = await handlePageRequest(page, {}, procs)
+
+		expect(result.status).toBe(200)
+		expect(result.html).toContain(
+			'<aside><a>nav</a></aside><main><h1>Home</h1></main><footer>(c)</footer>',
+		)
+	})
+
+	it('renders named outlets the page does not fill as empty', async () => {
+		const shell: LayoutDef = {
+			id: 'shell',
+			template: '<aside><!--seam:outlet:sidebar--></aside><main><!--seam:outlet--></main>',
+			loaders: {},
+		}
+		const page: PageDef = { template: '<h1>page</h1>', loaders: {}, layoutChain: [shell] }
+		const result = await handlePageRequest(page, {}, makeProcedures())
+
+		expect(result.html).toContain('<aside></aside><main><h1>page</h1></main>')
+	})
+})
+
 // ---------------------------------------------------------------------------
 // Route params forwarded to layout and page loaders
 // ---------------------------------------------------------------------------
@@ -103,6 +103,19 @@ function selectTemplate(
 	return defaultTemplate
 }
 
+const FILL_RE = /<seam-fill name="([^"]*)">([\s\S]*?)<\/seam-fill>/g
+const NAMED_OUTLET_RE = /<!--seam:outlet:([^>]*?)-->/g
+
+/** Pull `<seam-fill name="x">` blocks out of a page template, keyed by outlet name */
+function splitOutletFills(template: string): { rest: string; fills: Map<string, string> } {
+	const fills = new Map<string, string>()
+	const rest = template.replace(FILL_RE, (_, name: string, body: string) => {
+		fills.set(name, (fills.get(name) ?? '') + body)
+		return ''
+	})
+	return { rest, fills }
+}
+
 /** Look up pre-resolved messages for a route + locale. Zero merge, zero filter. */
 function lookupMessages(
 	config: I18nConfig,
@@ -194,11 +207,17 @@ export async function handlePageRequest(
 
 		// Compose template: nest page inside layouts via outlet substitution
 		const pageTemplate = selectTemplate(page.template, page.localeTemplates, locale)
-		let composedTemplate = pageTemplate
+		// Named outlets (<!--seam:outlet:x-->) take the page's <seam-fill name="x"> blocks
+		const { rest, fills } = splitOutletFills(pageTemplate)
+		let composedTemplate = rest
 		for (let i = layoutChain.length - 1; i >= 0; i--) {
 			const layout = layoutChain[i] as LayoutDef
 			const layoutTemplate = selectTemplate(layout.template, layout.localeTemplates, locale)
-			composedTemplate = layoutTemplate.replace('<!--seam:outlet-->', composedTemplate)
+			const filled = layoutTemplate.replace(
+				NAMED_OUTLET_RE,
+				(_, name: string) => fills.get(name) ?? '',
+			)
+			composedTemplate = filled.replace('<!--seam:outlet-->', () => composedTemplate)
 		}
 
 		// Resolve head metadata from headFn (overrides manifest head_meta)
@@ -10,10 +10,13 @@ interface HeadLink {
 	[key: string]: string | undefined
 }
 
+type HeadBlock = { style: string } | { jsonLd: string }
+
 interface HeadConfig {
 	title?: string
 	meta?: HeadMeta[]
 	link?: HeadLink[]
+	blocks?: HeadBlock[]
 }
 
 export type HeadFn = (data: Record<string, unknown>) => HeadConfig
@@ -41,5 +44,21 @@ export function headConfigToHtml(config: HeadConfig): string {
 		}
 		html += '>'
 	}
+	for (const block of config.blocks ?? []) {
+		html += headBlockToHtml(block)
+	}
 	return html
 }
+
+/**
+ * Raw-text content can't be entity-escaped, so only the closing sequence is neutralized:
+ * CSS reads `<\/` as `</`, and JSON reads `\u003c` as `<`.
+ * The marker matches the build-time output, so SPA navigation can remove the tag.
+ */
+function headBlockToHtml(block: HeadBlock): string {
+	if ('style' in block) {
+		return `<style data-seam-head>${block.style.replace(/<\//g, '<\\/')}</style>`
+	}
+	const json = block.jsonLd.replace(/</g, '\\u003c')
+	return `<script type="application/ld+json" data-seam-head>${json}</script>`
+}
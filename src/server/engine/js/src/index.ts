@@ -2,13 +2,16 @@
 
 export {
 	renderPage,
+	renderPageWithMetrics,
 	parseBuildOutput,
 	parseI18nConfig,
 	parseRpcHashMap,
 	asciiEscapeJson,
 	i18nQuery,
+	extractTemplate,
+	sentinelToSlots,
 	inject,
 	injectNoScript,
 } from './wasm-bridge.js'
 export { escapeHtml } from './escape.js'
-export type { InjectOptions } from './wasm-bridge.js'
+export type { InjectOptions, RenderMetrics } from './wasm-bridge.js'
@@ -6,11 +6,14 @@ import { fileURLToPath } from 'node:url'
 import {
 	__wbg_set_wasm,
 	render_page as wasmRenderPage,
+	render_page_with_metrics as wasmRenderPageWithMetrics,
 	parse_build_output as wasmParseBuildOutput,
 	parse_i18n_config as wasmParseI18nConfig,
 	parse_rpc_hash_map as wasmParseRpcHashMap,
 	ascii_escape_json as wasmAsciiEscapeJson,
 	i18n_query as wasmI18nQuery,
+	extract_template as wasmExtractTemplate,
+	sentinel_to_slots as wasmSentinelToSlots,
 	inject as wasmInject,
 	inject_no_script as wasmInjectNoScript,
 } from '../pkg/engine.js'
@@ -39,6 +42,24 @@ export function renderPage(
 	return wasmRenderPage(template, loaderDataJson, configJson, i18nOptsJson ?? '')
 }
 
+export interface RenderMetrics {
+	tokenize_us: number
+	parse_us: number
+	render_us: number
+	slot_count: number
+}
+
+export function renderPageWithMetrics(
+	template: string,
+	loaderDataJson: string,
+	configJson: string,
+	i18nOptsJson?: string,
+): { html: string; metrics: RenderMetrics } {
+	return JSON.parse(
+		wasmRenderPageWithMetrics(template, loaderDataJson, configJson, i18nOptsJson ?? ''),
+	) as { html: string; metrics: RenderMetrics }
+}
+
 export function parseBuildOutput(manifestJson: string): string {
 	return wasmParseBuildOutput(manifestJson)
 }
@@ -64,6 +85,16 @@ export function i18nQuery(
 	return wasmI18nQuery(keysJson, locale, defaultLocale, messagesJson)
 }
 
+// --- Skeleton functions ---
+
+export function extractTemplate(axesJson: string, variantsJson: string): string {
+	return wasmExtractTemplate(axesJson, variantsJson)
+}
+
+export function sentinelToSlots(html: string): string {
+	return wasmSentinelToSlots(html)
+}
+
 // --- Injector functions (re-exported for convenience) ---
 
 export function inject(
@@ -4,6 +4,7 @@
 pub mod build;
 pub mod escape;
 pub mod page;
+pub mod pagination;
 pub mod render;
 pub mod slots;
 
@@ -11,10 +12,12 @@ pub mod slots;
 pub use build::{PageDefOutput, parse_build_output, parse_i18n_config, parse_rpc_hash_map};
 pub use escape::ascii_escape_json;
 pub use page::{
-	I18nOpts, LayoutChainEntry, PageAssets, PageConfig, build_seam_data, filter_i18n_messages,
-	flatten_for_slots, i18n_query, inject_data_script, inject_head_meta, inject_html_lang,
+	I18nOpts, LayoutChainEntry, PageAssets, PageConfig, build_seam_data, drop_default_head_meta,
+	filter_i18n_messages, flatten_for_slots, i18n_query, inject_data_script,
+	inject_data_script_with_options, inject_head_meta, inject_html_lang,
 };
-pub use render::render_page;
+pub use pagination::{DEFAULT_PAGE_SIZE, Pagination, paginate};
+pub use render::{RenderMetrics, render_page, render_page_with_metrics};
 pub use slots::{
 	generate_prefetch_tags, generate_script_tags, generate_style_tags, replace_asset_slots,
 	strip_asset_slots,
@@ -1,5 +1,6 @@
 /* src/server/engine/rust/src/page.rs */
 
+use seam_injector::InjectOptions;
 use serde::{Deserialize, Serialize};
 
 /// One entry in a layout chain (outer to inner order).
@@ -27,6 +28,9 @@ pub struct PageAssets {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PageConfig {
 	pub layout_chain: Vec<LayoutChainEntry>,
+	/// Id of the injected data `<script>`; must match `frontend.data_id`, which the
+	/// client reads during hydration.
+	#[serde(default = "default_data_id")]
 	pub data_id: String,
 	#[serde(default)]
 	pub head_meta: Option<String>,
@@ -36,6 +40,21 @@ pub struct PageConfig {
 	/// Lives in config (not loader data) to avoid `flatten_for_slots` contamination.
 	#[serde(default)]
 	pub loader_metadata: Option<serde_json::Map<String, serde_json::Value>>,
+	/// Serve one page of a loader array; see `pagination::paginate`.
+	#[serde(default)]
+	pub pagination: Option<crate::pagination::Pagination>,
+	/// Add a `data-integrity` digest to the data script; see
+	/// `seam_injector::InjectOptions::integrity`. Off by default.
+	#[serde(default)]
+	pub data_integrity: bool,
+	/// Render `iflocale` blocks when no locale is set; see
+	/// `seam_injector::RenderOptions::iflocale_when_unset`. Off by default.
+	#[serde(default)]
+	pub iflocale_when_unset: bool,
+}
+
+fn default_data_id() -> String {
+	"__data".to_string()
 }
 
 /// i18n options for page rendering, passed as JSON.
@@ -55,6 +74,10 @@ pub struct I18nOpts {
 /// Flatten keyed loader results for slot resolution: spread nested object
 /// values to the top level so slots like `<!--seam:tagline-->` can resolve from
 /// data like `{page: {tagline: "..."}}`.
+///
+/// Only one level is spread, and values are copied whole: nested objects keep their
+/// shape for dotted paths (`user.address.city`) and arrays are never spread, so
+/// `each` still iterates the original items. Existing top-level keys win.
 pub fn flatten_for_slots(keyed: &serde_json::Value) -> serde_json::Value {
 	let Some(obj) = keyed.as_object() else {
 		return keyed.clone();
@@ -175,7 +198,16 @@ pub fn filter_i18n_messages(messages: &serde_json::Value, keys: &[String]) -> se
 
 /// Inject a `<script>` tag with JSON data before `</body>`.
 pub fn inject_data_script(html: &str, data_id: &str, json: &str) -> String {
-	let script = format!(r#"<script id="{data_id}" type="application/json">{json}</script>"#);
+	inject_data_script_with_options(html, json, &InjectOptions { data_id, ..Default::default() })
+}
+
+/// `inject_data_script` with the script's id, `nonce`, and integrity digest from `options`.
+pub fn inject_data_script_with_options(
+	html: &str,
+	json: &str,
+	options: &InjectOptions<'_>,
+) -> String {
+	let script = seam_injector::data_script(json, options);
 	if let Some(pos) = html.rfind("</body>") {
 		let mut result = String::with_capacity(html.len() + script.len());
 		result.push_str(&html[..pos]);
@@ -187,24 +219,54 @@ pub fn inject_data_script(html: &str, data_id: &str, json: &str) -> String {
 	}
 }
 
-/// Set `<html lang="...">` attribute.
+/// Set `<html lang="...">` attribute, replacing a build-time `frontend.lang` value.
 pub fn inject_html_lang(html: &str, locale: &str) -> String {
+	let locale = seam_injector::escape_attr_value(locale);
+	let Some(start) = html.find("<html") else {
+		return html.to_string();
+	};
+	let tag_end = html[start..].find('>').map_or(html.len(), |i| start + i);
+	if let Some(attr) = html[start..tag_end].find(" lang=\"") {
+		let value_start = start + attr + " lang=\"".len();
+		if let Some(len) = html[value_start..tag_end].find('"') {
+			return format!("{}{locale}{}", &html[..value_start], &html[value_start + len..]);
+		}
+	}
 	html.replacen("<html", &format!("<html lang=\"{locale}\""), 1)
 }
 
-/// Inject page-level head metadata after `<meta charset="utf-8">`.
+const DEFAULT_CHARSET_META: &str = r#"<meta charset="utf-8">"#;
+const DEFAULT_VIEWPORT_META: &str =
+	r#"<meta name="viewport" content="width=device-width, initial-scale=1">"#;
+
+/// Inject page-level head metadata after `<meta charset="utf-8">`, or at the start of
+/// `<head>` when the page replaces the default charset.
 pub fn inject_head_meta(html: &str, meta_html: &str) -> String {
-	let charset = r#"<meta charset="utf-8">"#;
-	if let Some(pos) = html.find(charset) {
-		let insert_at = pos + charset.len();
+	let html = drop_default_head_meta(html, meta_html);
+	let anchor = if html.contains(DEFAULT_CHARSET_META) { DEFAULT_CHARSET_META } else { "<head>" };
+	if let Some(pos) = html.find(anchor) {
+		let insert_at = pos + anchor.len();
 		let mut result = String::with_capacity(html.len() + meta_html.len());
 		result.push_str(&html[..insert_at]);
 		result.push_str(meta_html);
 		result.push_str(&html[insert_at..]);
 		result
 	} else {
-		html.to_string()
+		html
+	}
+}
+
+/// Remove the document's default charset/viewport metas when `meta_html` defines its own.
+pub fn drop_default_head_meta(html: &str, meta_html: &str) -> String {
+	let meta = meta_html.to_ascii_lowercase();
+	let mut html = html.to_string();
+	if meta.contains("<meta charset") {
+		html = html.replacen(DEFAULT_CHARSET_META, "", 1);
 	}
+	if meta.contains(r#"name="viewport""#) {
+		html = html.replacen(DEFAULT_VIEWPORT_META, "", 1);
+	}
+	html
 }
 
 /// Process an i18n query: look up requested keys from locale messages,
@@ -255,6 +317,35 @@ mod tests {
 		assert_eq!(flat["title"], "Top");
 	}
 
+	#[test]
+	fn flatten_keeps_nested_objects_for_dotted_paths() {
+		let input = json!({"profile": {"user": {"address": {"city": "Oslo"}}}});
+		let flat = flatten_for_slots(&input);
+		assert_eq!(flat["user"], json!({"address": {"city": "Oslo"}}));
+		assert_eq!(flat["profile"]["user"]["address"]["city"], "Oslo");
+		let html = seam_injector::inject_no_script(
+			"<p><!--seam:user.address.city--></p><p><!--seam:profile.user.address.city--></p>",
+			&flat,
+		);
+		assert_eq!(html, "<p>Oslo</p><p>Oslo</p>");
+	}
+
+	#[test]
+	fn flatten_keeps_arrays_of_objects_intact() {
+		let items = json!([{"name": "a", "tags": ["x"]}, {"name": "b", "tags": []}]);
+		let input = json!({"list": {"items": items}, "ids": [1, 2]});
+		let flat = flatten_for_slots(&input);
+		assert_eq!(flat["items"], items);
+		assert_eq!(flat["ids"], json!([1, 2]));
+		// Array entries are not spread into the top level
+		assert!(flat.get("name").is_none() && flat.get("0").is_none());
+		let html = seam_injector::inject_no_script(
+			"<!--seam:each:items--><i><!--seam:$.name--></i><!--seam:endeach-->",
+			&flat,
+		);
+		assert_eq!(html, "<i>a</i><i>b</i>");
+	}
+
 	#[test]
 	fn build_seam_data_no_layout() {
 		let data = json!({"title": "Hello", "count": 42});
@@ -264,6 +355,9 @@ mod tests {
 			head_meta: None,
 			page_assets: None,
 			loader_metadata: None,
+			pagination: None,
+			data_integrity: false,
+			iflocale_when_unset: false,
 		};
 		let result = build_seam_data(&data, &config, None);
 		assert_eq!(result["title"], "Hello");
@@ -283,6 +377,9 @@ mod tests {
 			head_meta: None,
 			page_assets: None,
 			loader_metadata: None,
+			pagination: None,
+			data_integrity: false,
+			iflocale_when_unset: false,
 		};
 		let result = build_seam_data(&data, &config, None);
 		assert_eq!(result["pageKey"], "page_val");
@@ -303,6 +400,9 @@ mod tests {
 			head_meta: None,
 			page_assets: None,
 			loader_metadata: None,
+			pagination: None,
+			data_integrity: false,
+			iflocale_when_unset: false,
 		};
 		let result = build_seam_data(&data, &config, None);
 		assert_eq!(result["page_data"], "p");
@@ -322,6 +422,9 @@ mod tests {
 			head_meta: None,
 			page_assets: None,
 			loader_metadata: None,
+			pagination: None,
+			data_integrity: false,
+			iflocale_when_unset: false,
 		};
 		let i18n = I18nOpts {
 			locale: "zh".into(),
@@ -374,6 +477,38 @@ mod tests {
 		assert!(result.starts_with(r#"<html lang="zh""#));
 	}
 
+	#[test]
+	fn inject_html_lang_escapes_locale() {
+		let result = inject_html_lang("<html><head></head></html>", r#"en"><x"#);
+		assert!(result.starts_with(r#"<html lang="en&quot;&gt;&lt;x""#), "{result}");
+	}
+
+	#[test]
+	fn inject_html_lang_overrides_configured_lang() {
+		let html = r#"<!DOCTYPE html><html lang="en"><head></head></html>"#;
+		let result = inject_html_lang(html, "zh");
+		assert_eq!(result, r#"<!DOCTYPE html><html lang="zh"><head></head></html>"#);
+	}
+
+	#[test]
+	fn head_meta_viewport_replaces_default() {
+		let html = concat!(
+			r#"<html><head><meta charset="utf-8">"#,
+			r#"<meta name="viewport" content="width=device-width, initial-scale=1">"#,
+			"</head></html>"
+		);
+		let result = inject_head_meta(html, r#"<meta name="viewport" content="width=320">"#);
+		assert_eq!(result.matches("viewport").count(), 1);
+		assert!(result.contains(r#"<meta charset="utf-8"><meta name="viewport" content="width=320">"#));
+	}
+
+	#[test]
+	fn head_meta_charset_replaces_default() {
+		let html = r#"<html><head><meta charset="utf-8"><title>T</title></head></html>"#;
+		let result = inject_head_meta(html, r#"<meta charset="iso-8859-1">"#);
+		assert_eq!(result, r#"<html><head><meta charset="iso-8859-1"><title>T</title></head></html>"#);
+	}
+
 	#[test]
 	fn inject_head_meta_test() {
 		let html = r#"<html><head><meta charset="utf-8"><title>Test</title></head></html>"#;
@@ -437,6 +572,9 @@ mod tests {
 			head_meta: None,
 			page_assets: None,
 			loader_metadata: Some(meta),
+			pagination: None,
+			data_integrity: false,
+			iflocale_when_unset: false,
 		};
 		let result = build_seam_data(&data, &config, None);
 		assert_eq!(result["__loaders"]["todos"]["procedure"], "listTodos");
@@ -459,6 +597,9 @@ mod tests {
 			head_meta: None,
 			page_assets: None,
 			loader_metadata: Some(meta),
+			pagination: None,
+			data_integrity: false,
+			iflocale_when_unset: false,
 		};
 		let result = build_seam_data(&data, &config, None);
 		// __loaders at top level, not under _layouts
@@ -0,0 +1,123 @@
+/* src/server/engine/rust/src/pagination.rs */
+
+use serde::{Deserialize, Serialize};
+
+/// Page size when the request names none, or names zero.
+pub const DEFAULT_PAGE_SIZE: usize = 20;
+
+/// Which loader array to paginate and the requested window, passed as JSON in
+/// `PageConfig::pagination`. `page` is 1-based.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Pagination {
+	/// Dot path into loader data, e.g. `list.items`
+	pub path: String,
+	#[serde(default = "default_page")]
+	pub page: usize,
+	#[serde(default = "default_size")]
+	pub size: usize,
+}
+
+fn default_page() -> usize {
+	1
+}
+
+fn default_size() -> usize {
+	DEFAULT_PAGE_SIZE
+}
+
+impl Pagination {
+	/// Read `page` and `size` from a raw query string (`page=2&size=10`), the same
+	/// source loaders read their inputs from. Missing or malformed values fall back
+	/// to page 1 and `default_size`.
+	pub fn from_query(path: &str, query: Option<&str>, default_size: usize) -> Self {
+		let mut pagination = Self { path: path.to_string(), page: 1, size: default_size };
+		for pair in query.unwrap_or_default().split('&') {
+			let (key, value) = pair.split_once('=').unwrap_or((pair, ""));
+			let Ok(n) = value.parse::<usize>() else {
+				continue;
+			};
+			match key {
+				"page" => pagination.page = n,
+				"size" => pagination.size = n,
+				_ => {}
+			}
+		}
+		pagination
+	}
+}
+
+/// Slice the array at `pagination.path` down to the requested page, in place, and add
+/// top-level `__page` and `__pageCount` for templates. Runs before the data script is
+/// built, so the client receives only the visible items. A page past the end yields an
+/// empty array; a missing or non-array path leaves the data untouched.
+pub fn paginate(loader_data: &mut serde_json::Value, pagination: &Pagination) {
+	let size = if pagination.size == 0 { DEFAULT_PAGE_SIZE } else { pagination.size };
+	let page = pagination.page.max(1);
+	let Some(serde_json::Value::Array(items)) = lookup_mut(loader_data, &pagination.path) else {
+		return;
+	};
+	let page_count = items.len().div_ceil(size).max(1);
+	let start = (page - 1).saturating_mul(size).min(items.len());
+	let end = start.saturating_add(size).min(items.len());
+	items.truncate(end);
+	items.drain(..start);
+
+	if let Some(obj) = loader_data.as_object_mut() {
+		obj.insert("__page".to_string(), page.into());
+		obj.insert("__pageCount".to_string(), page_count.into());
+	}
+}
+
+fn lookup_mut<'a>(
+	data: &'a mut serde_json::Value,
+	path: &str,
+) -> Option<&'a mut serde_json::Value> {
+	path.split('.').try_fold(data, |current, key| current.get_mut(key))
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use serde_json::json;
+
+	fn hundred_items() -> serde_json::Value {
+		let items: Vec<_> = (1..=100).map(|n| json!({"n": n})).collect();
+		json!({"list": {"items": items}, "title": "All"})
+	}
+
+	#[test]
+	fn slices_second_page_of_ten() {
+		let mut data = hundred_items();
+		paginate(&mut data, &Pagination { path: "list.items".into(), page: 2, size: 10 });
+		let items = data["list"]["items"].as_array().unwrap();
+		assert_eq!(items.len(), 10);
+		assert_eq!(items[0], json!({"n": 11}));
+		assert_eq!(items[9], json!({"n": 20}));
+		assert_eq!(data["__page"], 2);
+		assert_eq!(data["__pageCount"], 10);
+		assert_eq!(data["title"], "All");
+	}
+
+	#[test]
+	fn page_count_rounds_up_and_past_end_is_empty() {
+		let mut data = json!({"xs": [1, 2, 3, 4, 5]});
+		paginate(&mut data, &Pagination { path: "xs".into(), page: 4, size: 2 });
+		assert_eq!(data["xs"], json!([]));
+		assert_eq!(data["__pageCount"], 3);
+	}
+
+	#[test]
+	fn missing_path_leaves_data_untouched() {
+		let mut data = json!({"xs": 1});
+		paginate(&mut data, &Pagination { path: "xs".into(), page: 1, size: 2 });
+		assert_eq!(data, json!({"xs": 1}));
+	}
+
+	#[test]
+	fn from_query_reads_page_and_size() {
+		let p = Pagination::from_query("list.items", Some("size=10&sort=name&page=2"), 20);
+		assert_eq!((p.page, p.size), (2, 10));
+		let p = Pagination::from_query("list.items", Some("page=abc"), 25);
+		assert_eq!((p.page, p.size), (1, 25));
+	}
+}
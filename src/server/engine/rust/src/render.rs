@@ -2,10 +2,24 @@
 
 use crate::escape::ascii_escape_json;
 use crate::page::{
-	I18nOpts, PageConfig, build_seam_data, flatten_for_slots, inject_data_script, inject_head_meta,
-	inject_html_lang,
+	I18nOpts, PageConfig, build_seam_data, flatten_for_slots, inject_data_script_with_options,
+	inject_head_meta, inject_html_lang,
 };
 use crate::slots::{replace_asset_slots, strip_asset_slots};
+use seam_injector::{InjectOptions, RenderOptions};
+use std::time::Duration;
+use web_time::Instant;
+
+/// Phase timings for the page template in `render_page_with_metrics`.
+/// Head metadata and data-script assembly are not included.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct RenderMetrics {
+	pub tokenize: Duration,
+	pub parse: Duration,
+	pub render: Duration,
+	/// Text, attribute, and style slots in the template
+	pub slot_count: usize,
+}
 
 /// Render a page: inject data into template, assemble data script,
 /// apply head metadata and locale attributes.
@@ -24,7 +38,30 @@ pub fn render_page(
 	config_json: &str,
 	i18n_opts_json: Option<&str>,
 ) -> String {
-	let loader_data: serde_json::Value =
+	render_page_inner(template, loader_data_json, config_json, i18n_opts_json, None)
+}
+
+/// `render_page` that also reports how long each injector phase took.
+pub fn render_page_with_metrics(
+	template: &str,
+	loader_data_json: &str,
+	config_json: &str,
+	i18n_opts_json: Option<&str>,
+) -> (String, RenderMetrics) {
+	let mut metrics = RenderMetrics::default();
+	let html =
+		render_page_inner(template, loader_data_json, config_json, i18n_opts_json, Some(&mut metrics));
+	(html, metrics)
+}
+
+fn render_page_inner(
+	template: &str,
+	loader_data_json: &str,
+	config_json: &str,
+	i18n_opts_json: Option<&str>,
+	metrics: Option<&mut RenderMetrics>,
+) -> String {
+	let mut loader_data: serde_json::Value =
 		serde_json::from_str(loader_data_json).unwrap_or(serde_json::Value::Null);
 	let config: PageConfig = match serde_json::from_str(config_json) {
 		Ok(c) => c,
@@ -41,16 +78,30 @@ pub fn render_page(
 		None => strip_asset_slots(template),
 	};
 
-	// Step 2: Flatten loader data for slot resolution
+	// Step 2: Cut paginated arrays down before both slots and the data script see them
+	if let Some(ref pagination) = config.pagination {
+		crate::pagination::paginate(&mut loader_data, pagination);
+	}
+
+	// Step 2b: Flatten loader data for slot resolution
 	let flat_data = flatten_for_slots(&loader_data);
+	// The request locale drives iflocale blocks
+	let options = RenderOptions {
+		locale: i18n_opts.as_ref().map(|o| o.locale.as_str()),
+		iflocale_when_unset: config.iflocale_when_unset,
+		..RenderOptions::default()
+	};
 
 	// Step 3: Inject slots into template (no data script)
-	let mut html = seam_injector::inject_no_script(&working, &flat_data);
+	let mut html = match metrics {
+		Some(m) => inject_measured(&working, &flat_data, &options, m),
+		None => seam_injector::inject_no_script_with_options(&working, &flat_data, &options),
+	};
 
 	// Step 4: Inject page-level head metadata
 	if let Some(ref meta) = config.head_meta {
 		// Inject the head_meta with slot data resolved
-		let injected_meta = seam_injector::inject_no_script(meta, &flat_data);
+		let injected_meta = seam_injector::inject_no_script_with_options(meta, &flat_data, &options);
 		html = inject_head_meta(&html, &injected_meta);
 	}
 
@@ -63,7 +114,31 @@ pub fn render_page(
 	let seam_data = build_seam_data(&loader_data, &config, i18n_opts.as_ref());
 	let json = serde_json::to_string(&seam_data).unwrap_or_default();
 	let escaped = ascii_escape_json(&json);
-	inject_data_script(&html, &config.data_id, &escaped)
+	let script_options = InjectOptions {
+		data_id: &config.data_id,
+		integrity: config.data_integrity,
+		..InjectOptions::default()
+	};
+	inject_data_script_with_options(&html, &escaped, &script_options)
+}
+
+fn inject_measured(
+	template: &str,
+	data: &serde_json::Value,
+	options: &RenderOptions<'_>,
+	metrics: &mut RenderMetrics,
+) -> String {
+	let start = Instant::now();
+	let tokens = seam_injector::tokenize_template(template);
+	let tokenized = Instant::now();
+	let compiled = seam_injector::parse_template(&tokens);
+	let parsed = Instant::now();
+	let html = seam_injector::inject_precompiled_with_options(&compiled, data, options);
+	metrics.tokenize = tokenized - start;
+	metrics.parse = parsed - tokenized;
+	metrics.render = parsed.elapsed();
+	metrics.slot_count = compiled.slot_count();
+	html
 }
 
 #[cfg(test)]
@@ -75,6 +150,24 @@ mod tests {
 		r#"<html><head><meta charset="utf-8"><title>Test</title></head><body><p><!--seam:title--></p></body></html>"#.to_string()
 	}
 
+	#[test]
+	fn render_adds_data_integrity_when_configured() {
+		let data = json!({"title": "Hello"}).to_string();
+		let config = json!({"layout_chain": [], "data_integrity": true}).to_string();
+		let result = render_page(&simple_template(), &data, &config, None);
+		let json = r#"{"title":"Hello"}"#;
+		let expected = seam_injector::data_integrity(json);
+		assert!(
+			result.contains(&format!(
+				r#"<script id="__data" data-integrity="{expected}" type="application/json">{json}</script>"#
+			)),
+			"{result}"
+		);
+
+		let plain = render_page(&simple_template(), &data, r#"{"layout_chain":[]}"#, None);
+		assert!(!plain.contains("data-integrity"));
+	}
+
 	#[test]
 	fn render_basic_page() {
 		let template = simple_template();
@@ -87,6 +180,44 @@ mod tests {
 		assert!(result.contains(r#""title":"Hello""#));
 	}
 
+	#[test]
+	fn render_honors_configured_data_id() {
+		let data = json!({"title": "Hello"}).to_string();
+		let config = json!({"layout_chain": [], "data_id": "__app_state"}).to_string();
+		let result = render_page(&simple_template(), &data, &config, None);
+		assert!(result.contains(r#"<script id="__app_state" type="application/json">"#));
+		assert!(!result.contains(r#"id="__data""#));
+	}
+
+	#[test]
+	fn render_defaults_data_id_when_unset() {
+		let data = json!({"title": "Hello"}).to_string();
+		let config = json!({"layout_chain": []}).to_string();
+		let result = render_page(&simple_template(), &data, &config, None);
+		assert!(result.contains("<p>Hello</p>"));
+		assert!(result.contains(r#"<script id="__data" type="application/json">"#));
+	}
+
+	#[test]
+	fn render_paginates_loader_array() {
+		let items: Vec<_> = (1..=100).map(|n| json!({"n": n})).collect();
+		let data = json!({"list": {"items": items}}).to_string();
+		let config = json!({
+			"layout_chain": [],
+			"pagination": {"path": "list.items", "page": 2, "size": 10}
+		})
+		.to_string();
+		let template = concat!(
+			"<body><!--seam:each:items--><i><!--seam:$.n--></i><!--seam:endeach-->",
+			"<p><!--seam:__page-->/<!--seam:__pageCount--></p></body>"
+		);
+		let result = render_page(template, &data, &config, None);
+		assert!(result.contains("<i>11</i>") && result.contains("<i>20</i>"), "{result}");
+		assert!(!result.contains("<i>21</i>") && !result.contains(r#"{"n":21}"#));
+		assert!(result.contains("<p>2/10</p>"));
+		assert!(result.contains(r#""__pageCount":10"#));
+	}
+
 	#[test]
 	fn render_with_layout() {
 		let template = simple_template();
@@ -122,6 +253,24 @@ mod tests {
 		assert!(result.contains(r#""_i18n""#));
 	}
 
+	#[test]
+	fn render_resolves_iflocale_against_i18n_locale() {
+		let template = "<html><body><!--seam:iflocale:zh--><p>zh</p><!--seam:endiflocale-->\
+			<!--seam:iflocale:en--><p>en</p><!--seam:endiflocale--></body></html>";
+		let config = json!({"layout_chain": [], "data_id": "__data"}).to_string();
+		let i18n = json!({"locale": "zh", "default_locale": "en", "messages": {}}).to_string();
+
+		let result = render_page(template, "{}", &config, Some(&i18n));
+		assert!(result.contains("<p>zh</p>"));
+		assert!(!result.contains("<p>en</p>"));
+		// Without i18n the locale is unset and neither block renders
+		let result = render_page(template, "{}", &config, None);
+		assert!(!result.contains("<p>zh</p>") && !result.contains("<p>en</p>"));
+		let config = json!({"layout_chain": [], "iflocale_when_unset": true}).to_string();
+		let result = render_page(template, "{}", &config, None);
+		assert!(result.contains("<p>zh</p>") && result.contains("<p>en</p>"));
+	}
+
 	#[test]
 	fn render_with_head_meta() {
 		let template = simple_template();
@@ -208,4 +357,34 @@ mod tests {
 		// Data injection still works
 		assert!(result.contains("<p>Hello</p>"));
 	}
+
+	#[test]
+	fn render_page_with_metrics_matches_and_counts_slots() {
+		let config = json!({"layout_chain": [], "data_id": "__data"}).to_string();
+		let data = json!({"title": "Hi"}).to_string();
+		let plain = render_page(&simple_template(), &data, &config, None);
+		let (html, metrics) = render_page_with_metrics(&simple_template(), &data, &config, None);
+		assert_eq!(html, plain);
+		assert_eq!(metrics.slot_count, 1);
+	}
+
+	#[test]
+	fn render_page_with_metrics_records_every_phase_within_the_call() {
+		// Enough slots that no phase can finish inside one clock tick
+		let body = "<p><!--seam:title--></p>".repeat(500);
+		let template =
+			format!(r#"<html><head><meta charset="utf-8"></head><body>{body}</body></html>"#);
+		let config = json!({"layout_chain": []}).to_string();
+		let data = json!({"title": "Hi"}).to_string();
+		let start = Instant::now();
+		let (_, metrics) = render_page_with_metrics(&template, &data, &config, None);
+		let wall = start.elapsed();
+		assert_eq!(metrics.slot_count, 500);
+		for (phase, took) in
+			[("tokenize", metrics.tokenize), ("parse", metrics.parse), ("render", metrics.render)]
+		{
+			assert!(!took.is_zero(), "{phase} not recorded");
+		}
+		assert!(metrics.tokenize + metrics.parse + metrics.render <= wall);
+	}
 }
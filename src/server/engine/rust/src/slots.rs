@@ -43,12 +43,27 @@ pub fn strip_asset_slots(template: &str) -> String {
 		.replace("<!--seam:prefetch-->", "")
 }
 
-/// Replace asset slot markers in template with actual tags.
+/// Replace asset slot markers in template with actual tags. Files the template already
+/// references (the global assets `wrap_document` emits) and repeats within a list are
+/// dropped, so merging global and route assets never loads a file twice.
 pub fn replace_asset_slots(template: &str, assets: &PageAssets) -> String {
+	let fresh = |files: &[String]| -> Vec<String> {
+		let mut out: Vec<String> = Vec::with_capacity(files.len());
+		for f in files {
+			let referenced = template.contains(&format!("\"/_seam/static/{f}\""));
+			if !referenced && !out.contains(f) {
+				out.push(f.clone());
+			}
+		}
+		out
+	};
 	template
-		.replace("<!--seam:page-styles-->", &generate_style_tags(&assets.styles))
-		.replace("<!--seam:page-scripts-->", &generate_script_tags(&assets.scripts, &assets.preload))
-		.replace("<!--seam:prefetch-->", &generate_prefetch_tags(&assets.prefetch))
+		.replace("<!--seam:page-styles-->", &generate_style_tags(&fresh(&assets.styles)))
+		.replace(
+			"<!--seam:page-scripts-->",
+			&generate_script_tags(&fresh(&assets.scripts), &fresh(&assets.preload)),
+		)
+		.replace("<!--seam:prefetch-->", &generate_prefetch_tags(&fresh(&assets.prefetch)))
 }
 
 #[cfg(test)]
@@ -100,4 +115,25 @@ mod tests {
 		assert!(!result.contains("<!--seam:page-scripts-->"));
 		assert!(!result.contains("<!--seam:prefetch-->"));
 	}
+
+	#[test]
+	fn replace_asset_slots_merges_global_without_duplicates() {
+		let template = concat!(
+			r#"<head><link rel="stylesheet" href="/_seam/static/main.css"><!--seam:page-styles--></head>"#,
+			r#"<body><script type="module" src="/_seam/static/main.js"></script>"#,
+			"<!--seam:page-scripts--></body>"
+		);
+		let assets = PageAssets {
+			styles: vec!["main.css".into(), "chart.css".into()],
+			scripts: vec!["main.js".into(), "chart.js".into(), "chart.js".into()],
+			..PageAssets::default()
+		};
+		let result = replace_asset_slots(template, &assets);
+		for file in ["main.js", "chart.js", "main.css", "chart.css"] {
+			assert_eq!(result.matches(&format!("/_seam/static/{file}\"")).count(), 1, "{file}: {result}");
+		}
+		assert!(
+			result.contains(r#"<script type="module" src="/_seam/static/chart.js"></script></body>"#)
+		);
+	}
 }
@@ -1,7 +1,7 @@
 /* src/server/engine/wasm/src/lib.rs */
 #![cfg_attr(test, allow(clippy::unwrap_used))]
 
-use serde_json::Value;
+use serde_json::{Value, json};
 use wasm_bindgen::prelude::*;
 
 // --- Engine functions ---
@@ -17,6 +17,30 @@ pub fn render_page(
 	seam_engine::render_page(template, loader_data_json, config_json, i18n)
 }
 
+/// `render_page` returning `{"html": ..., "metrics": {"tokenize_us", "parse_us",
+/// "render_us", "slot_count"}}` for profiling.
+#[wasm_bindgen]
+pub fn render_page_with_metrics(
+	template: &str,
+	loader_data_json: &str,
+	config_json: &str,
+	i18n_opts_json: &str,
+) -> String {
+	let i18n = if i18n_opts_json.is_empty() { None } else { Some(i18n_opts_json) };
+	let (html, m) =
+		seam_engine::render_page_with_metrics(template, loader_data_json, config_json, i18n);
+	json!({
+		"html": html,
+		"metrics": {
+			"tokenize_us": m.tokenize.as_micros() as u64,
+			"parse_us": m.parse.as_micros() as u64,
+			"render_us": m.render.as_micros() as u64,
+			"slot_count": m.slot_count,
+		},
+	})
+	.to_string()
+}
+
 #[wasm_bindgen]
 pub fn parse_build_output(manifest_json: &str) -> String {
 	match seam_engine::parse_build_output(manifest_json) {
@@ -59,12 +83,40 @@ pub fn i18n_query(
 	serde_json::to_string(&result).unwrap_or_else(|_| "{}".to_string())
 }
 
+// --- Build-time skeleton functions (for in-browser authoring tools) ---
+
+/// Merge variant HTML into one slot template. `axes_json` is a `[{path, kind, values}]`
+/// array and `variants_json` a string array in axis-combination order.
+#[wasm_bindgen]
+pub fn extract_template(axes_json: &str, variants_json: &str) -> String {
+	let axes: Vec<seam_skeleton::Axis> = match serde_json::from_str(axes_json) {
+		Ok(a) => a,
+		Err(e) => return error_json(&format!("invalid axes: {e}")),
+	};
+	let variants: Vec<String> = match serde_json::from_str(variants_json) {
+		Ok(v) => v,
+		Err(e) => return error_json(&format!("invalid variants: {e}")),
+	};
+	seam_skeleton::extract_template(&axes, &variants)
+}
+
+/// `{"error": msg}`, escaped so any message stays valid JSON.
+fn error_json(msg: &str) -> String {
+	serde_json::json!({ "error": msg }).to_string()
+}
+
+/// `%%SEAM:path%%` sentinels to `<!--seam:...-->` slot markers, same as the CLI build.
+#[wasm_bindgen]
+pub fn sentinel_to_slots(html: &str) -> String {
+	seam_skeleton::sentinel_to_slots(html)
+}
+
 // --- Re-exported injector functions (engine WASM is a superset) ---
 
 #[wasm_bindgen]
 pub fn inject(template: &str, data_json: &str, data_id: &str) -> String {
 	let data: Value = serde_json::from_str(data_json).unwrap_or(Value::Null);
-	seam_injector::inject(template, &data, data_id)
+	seam_injector::inject_with_id(template, &data, data_id)
 }
 
 #[wasm_bindgen]
@@ -72,3 +124,51 @@ pub fn inject_no_script(template: &str, data_json: &str) -> String {
 	let data: Value = serde_json::from_str(data_json).unwrap_or(Value::Null);
 	seam_injector::inject_no_script(template, &data)
 }
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn render_page_with_metrics_json() {
+		let config = r#"{"layout_chain":[],"data_id":"__data"}"#;
+		let out = render_page_with_metrics("<p><!--seam:a--></p>", r#"{"a":"x"}"#, config, "");
+		let parsed: Value = serde_json::from_str(&out).unwrap();
+		assert!(parsed["html"].as_str().unwrap().starts_with("<p>x</p>"));
+		assert_eq!(parsed["metrics"]["slot_count"], 1);
+		assert!(parsed["metrics"]["render_us"].is_u64());
+	}
+
+	#[test]
+	fn extract_template_from_json() {
+		let axes = r#"[{"path":"user","kind":"nullable","values":["present",null]}]"#;
+		let variants = r#"["<p><!--seam:user.name--></p>","<span>anon</span>"]"#;
+		let result = extract_template(axes, variants);
+		assert!(result.contains("<!--seam:if:user-->"), "{result}");
+		assert!(result.contains("<!--seam:else--><span>anon</span>"), "{result}");
+	}
+
+	#[test]
+	fn sentinel_to_slots_matches_native() {
+		let html =
+			r#"<a href="%%SEAM:link.url%%" class="x" style="color:%%SEAM:c%%">%%SEAM:link.text%%</a>"#;
+		let result = sentinel_to_slots(html);
+		assert_eq!(result, seam_skeleton::sentinel_to_slots(html));
+		assert!(result.contains("<!--seam:link.url:attr:href-->"));
+		assert!(result.contains("<!--seam:c:style:color-->"));
+		assert!(result.contains("<!--seam:link.text-->"));
+	}
+
+	#[test]
+	fn extract_template_malformed_json() {
+		let error = |out: String| -> String {
+			let value: serde_json::Value = serde_json::from_str(&out).expect("error output is JSON");
+			value["error"].as_str().unwrap().to_string()
+		};
+		assert!(error(extract_template("{", "[]")).starts_with("invalid axes"));
+		assert!(error(extract_template("[]", "[1]")).starts_with("invalid variants"));
+		// serde quotes the offending string in its message; the JSON must survive that
+		let msg = error(extract_template("[]", r#""a""#));
+		assert!(msg.contains(r#"string "a""#), "{msg}");
+	}
+}
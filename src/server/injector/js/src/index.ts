@@ -1,5 +1,10 @@
 /* src/server/injector/js/src/index.ts */
 
-export { inject } from './wasm-bridge.js'
+export { inject, injectWithDiagnostics, slotPositions } from './wasm-bridge.js'
 export { escapeHtml } from './escape.js'
-export type { InjectOptions } from './wasm-bridge.js'
+export type {
+	InjectDiagnosticsResult,
+	InjectOptions,
+	SlotPosition,
+	TemplateDiagnostic,
+} from './wasm-bridge.js'
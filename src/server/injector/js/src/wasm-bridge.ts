@@ -7,10 +7,63 @@ import {
 	__wbg_set_wasm,
 	inject as wasmInject,
 	inject_no_script as wasmInjectNoScript,
+	inject_with_diagnostics as wasmInjectWithDiagnostics,
+	inject_with_id as wasmInjectWithId,
+	slot_positions as wasmSlotPositions,
 } from '../pkg/injector.js'
 
 export interface InjectOptions {
 	skipDataScript?: boolean
+	/** Data script element id, defaults to `__data` */
+	dataId?: string
+}
+
+export interface TemplateDiagnostic {
+	kind:
+		| 'unmatchedBlockClose'
+		| 'unclosedBlock'
+		| 'duplicateMatchArm'
+		| 'danglingAttrSlot'
+		| 'missingSelectorTarget'
+		| 'depthLimitExceeded'
+		| 'rangeLimitExceeded'
+	directive: string
+}
+
+export interface InjectDiagnosticsResult {
+	html: string
+	diagnostics: TemplateDiagnostic[]
+}
+
+export interface SlotPosition {
+	kind:
+		| 'text'
+		| 'html'
+		| 'urlEncode'
+		| 'attr'
+		| 'attrIf'
+		| 'style'
+		| 'if'
+		| 'else'
+		| 'endIf'
+		| 'has'
+		| 'endHas'
+		| 'ifLocale'
+		| 'endIfLocale'
+		| 'each'
+		| 'endEach'
+		| 'match'
+		| 'when'
+		| 'endMatch'
+		| 'raw'
+		| 'endRaw'
+		| 'comment'
+		| 'endComment'
+	/** Data path the marker reads; empty for `else`, `endeach`, ... */
+	path: string
+	/** Byte offsets of the whole `<!--seam:...-->` comment */
+	start: number
+	end: number
 }
 
 const __dirname = dirname(fileURLToPath(import.meta.url))
@@ -30,5 +83,21 @@ export function inject(
 	if (options?.skipDataScript) {
 		return wasmInjectNoScript(template, json)
 	}
+	if (options?.dataId) {
+		return wasmInjectWithId(template, json, options.dataId)
+	}
 	return wasmInject(template, json)
 }
+
+/** Render without the data script, also returning template parse diagnostics */
+export function injectWithDiagnostics(
+	template: string,
+	data: Record<string, unknown>,
+): InjectDiagnosticsResult {
+	return JSON.parse(wasmInjectWithDiagnostics(template, JSON.stringify(data))) as InjectDiagnosticsResult
+}
+
+/** Locate the template's seam markers without rendering */
+export function slotPositions(template: string): SlotPosition[] {
+	return JSON.parse(wasmSlotPositions(template)) as SlotPosition[]
+}
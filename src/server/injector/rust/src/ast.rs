@@ -3,16 +3,77 @@
 #[derive(Debug)]
 pub(crate) enum AstNode {
 	Text(String),
-	Slot { path: String, mode: SlotMode },
-	Attr { path: String, attr_name: String },
-	StyleProp { path: String, css_property: String },
-	If { path: String, then_nodes: Vec<AstNode>, else_nodes: Vec<AstNode> },
-	Each { path: String, body_nodes: Vec<AstNode> },
-	Match { path: String, branches: Vec<(String, Vec<AstNode>)> },
+	Slot {
+		path: String,
+		mode: SlotMode,
+	},
+	Attr {
+		path: String,
+		attr_name: String,
+		/// `:attrif:` form: a valueless attribute, added when `path` is truthy
+		conditional: bool,
+		/// `path:urlencode:attr:name`: percent-encode the value before escaping
+		urlencode: bool,
+		/// `name@tag`: attach to the next `<tag>` instead of the next opening tag
+		target: Option<String>,
+	},
+	StyleProp {
+		path: String,
+		css_property: String,
+		unit: Option<String>,
+		important: bool,
+	},
+	If {
+		path: String,
+		then_nodes: Vec<AstNode>,
+		else_nodes: Vec<AstNode>,
+	},
+	/// Rendered when `path` resolves to a non-null value, however falsy
+	Has {
+		path: String,
+		body_nodes: Vec<AstNode>,
+	},
+	/// Rendered when the active locale is, or is a subtag of, one of `locales`
+	IfLocale {
+		locales: Vec<String>,
+		body_nodes: Vec<AstNode>,
+	},
+	Each {
+		path: String,
+		modifiers: EachModifiers,
+		body_nodes: Vec<AstNode>,
+		/// `else` body, rendered once when the loop yields no items
+		else_nodes: Vec<AstNode>,
+	},
+	Match {
+		path: String,
+		branches: Vec<(String, Vec<AstNode>)>,
+	},
 }
 
 #[derive(Debug)]
 pub(crate) enum SlotMode {
 	Text,
 	Html,
+	/// `path:urlencode`: RFC 3986 percent-encoded, for query strings
+	UrlEncode,
+}
+
+/// Iteration modifiers trailing the path: `each:items:reverse`, `each:items:sort:$.name`,
+/// `each:items:offset:2:limit:5`, `each:items:where:$.active`. Applied to a view of the
+/// array; loader data is never reordered. Order is fixed regardless of spelling:
+/// where, sort, reverse, offset, limit.
+#[derive(Debug, Default)]
+pub(crate) struct EachModifiers {
+	/// `each:range:count`: the path holds a number and the loop runs over `1..=count`
+	pub(crate) range: bool,
+	/// Item-relative key (`$.active`); only items where it is truthy are rendered
+	pub(crate) where_path: Option<String>,
+	pub(crate) reverse: bool,
+	/// Item-relative key (`$.name`) for a stable ascending sort, applied before `reverse`
+	pub(crate) sort_by: Option<String>,
+	/// Items skipped from the front; past the end yields nothing
+	pub(crate) offset: usize,
+	/// Maximum items rendered after `offset`
+	pub(crate) limit: Option<usize>,
 }
@@ -2,10 +2,14 @@
 
 use serde_json::Value;
 
+/// Walk a dot-separated path. Numeric segments index into arrays (`items.0.name`).
 pub(crate) fn resolve<'a>(path: &str, data: &'a Value) -> Option<&'a Value> {
 	let mut current = data;
 	for key in path.split('.') {
-		current = current.get(key)?;
+		current = match current {
+			Value::Array(arr) => arr.get(key.parse::<usize>().ok()?)?,
+			_ => current.get(key)?,
+		};
 	}
 	Some(current)
 }
@@ -39,6 +43,63 @@ pub(crate) fn stringify(value: &Value) -> String {
 	}
 }
 
+/// First tag in `html` that is not a comment, and whether it opens an element (`<name`)
+/// rather than closing one. Attribute and style slots target the opening tag this finds.
+/// A `<` that starts no tag (`1 < 2` in raw HTML text) is skipped.
+pub(crate) fn next_tag(html: &str) -> Option<(usize, bool)> {
+	let bytes = html.as_bytes();
+	let mut from = 0;
+	while let Some(rel) = html[from..].find('<') {
+		let at = from + rel;
+		let rest = &html[at..];
+		if rest.starts_with("<!--") {
+			from = at + rest.find("-->")? + 3;
+			continue;
+		}
+		match bytes.get(at + 1) {
+			Some(b) if b.is_ascii_alphabetic() => return Some((at, true)),
+			Some(b'/' | b'!' | b'?') => return Some((at, false)),
+			_ => from = at + 1,
+		}
+	}
+	None
+}
+
+/// Index just past the `>` that closes the tag opened at `start`. Quoted attribute values
+/// are skipped, so `data-html="<b>"` does not end the tag early.
+pub(crate) fn tag_end(html: &str, start: usize) -> Option<usize> {
+	let mut quote = None;
+	for (i, b) in html.bytes().enumerate().skip(start) {
+		match quote {
+			Some(q) if b == q => quote = None,
+			Some(_) => {}
+			None if matches!(b, b'"' | b'\'') => quote = Some(b),
+			None if b == b'>' => return Some(i + 1),
+			None => {}
+		}
+	}
+	None
+}
+
+/// Numeric `when` predicate: `lo..hi` (both ends inclusive, either may be omitted),
+/// or a comparison `>=n`, `>n`, `<=n`, `<n`. Anything else never matches.
+pub(crate) fn numeric_arm_matches(arm: &str, n: f64) -> bool {
+	let bound = |s: &str| s.trim().parse::<f64>().ok();
+	if let Some((lo, hi)) = arm.split_once("..") {
+		let lo = if lo.is_empty() { Some(f64::NEG_INFINITY) } else { bound(lo) };
+		let hi = if hi.is_empty() { Some(f64::INFINITY) } else { bound(hi) };
+		return matches!((lo, hi), (Some(lo), Some(hi)) if lo <= n && n <= hi);
+	}
+	let cmp = |prefix: &str, test: fn(f64, f64) -> bool| {
+		arm.strip_prefix(prefix).and_then(bound).map(|b| test(n, b))
+	};
+	cmp(">=", |n, b| n >= b)
+		.or_else(|| cmp("<=", |n, b| n <= b))
+		.or_else(|| cmp(">", |n, b| n > b))
+		.or_else(|| cmp("<", |n, b| n < b))
+		.unwrap_or(false)
+}
+
 // HTML boolean attributes: present means true, absent means false.
 // When value is truthy, render as `attr=""`. When falsy, omit entirely.
 const HTML_BOOLEAN_ATTRS: &[&str] = &[
@@ -153,6 +214,20 @@ pub(crate) fn format_style_value(css_property: &str, value: &Value) -> Option<St
 	}
 }
 
+/// Style value for a slot with an explicit unit (`margin-top:rem`). Numbers, and
+/// strings holding a bare number, get the unit; other strings already carry one.
+pub(crate) fn format_style_value_with_unit(value: &Value, unit: &str) -> Option<String> {
+	match value {
+		Value::Number(n) => match n.as_i64() {
+			Some(i) => Some(format!("{i}{unit}")),
+			None => n.as_f64().map(|f| format!("{f}{unit}")),
+		},
+		Value::String(s) if s.trim().parse::<f64>().is_ok() => Some(format!("{}{unit}", s.trim())),
+		Value::String(s) if !s.is_empty() => Some(s.clone()),
+		_ => None,
+	}
+}
+
 pub(crate) fn escape_html(s: &str) -> String {
 	let mut out = String::with_capacity(s.len());
 	for ch in s.chars() {
@@ -168,6 +243,24 @@ pub(crate) fn escape_html(s: &str) -> String {
 	out
 }
 
+/// RFC 3986 percent-encoding: every byte outside the unreserved set
+/// (`A-Z a-z 0-9 - . _ ~`) becomes `%XX`, so reserved characters like `&`, `=`, `/`,
+/// and `?` cannot break out of a query component.
+pub(crate) fn percent_encode(s: &str) -> String {
+	const HEX: &[u8; 16] = b"0123456789ABCDEF";
+	let mut out = String::with_capacity(s.len());
+	for &b in s.as_bytes() {
+		if b.is_ascii_alphanumeric() || matches!(b, b'-' | b'.' | b'_' | b'~') {
+			out.push(char::from(b));
+		} else {
+			out.push('%');
+			out.push(char::from(HEX[usize::from(b >> 4)]));
+			out.push(char::from(HEX[usize::from(b & 0xF)]));
+		}
+	}
+	out
+}
+
 #[cfg(test)]
 mod tests {
 	use super::*;
@@ -204,6 +297,21 @@ mod tests {
 		assert_eq!(resolve("a.b", &data), None);
 	}
 
+	#[test]
+	fn resolve_array_index() {
+		let data = json!({"items": [{"name": "a"}, {"name": "b"}]});
+		assert_eq!(resolve("items.1.name", &data), Some(&json!("b")));
+		assert_eq!(resolve("items.9.name", &data), None);
+		assert_eq!(resolve("items.x", &data), None);
+	}
+
+	#[test]
+	fn resolve_index_on_non_array() {
+		let data = json!({"items": {"name": "a"}, "n": 5});
+		assert_eq!(resolve("items.0", &data), None);
+		assert_eq!(resolve("n.0", &data), None);
+	}
+
 	// -- is_truthy --
 
 	#[test]
@@ -256,6 +364,13 @@ mod tests {
 		assert_eq!(escape_html("<>&\"'"), "&lt;&gt;&amp;&quot;&#x27;");
 	}
 
+	#[test]
+	fn percent_encode_reserved_and_utf8() {
+		assert_eq!(percent_encode("a b&c=d/e?f#g"), "a%20b%26c%3Dd%2Fe%3Ff%23g");
+		assert_eq!(percent_encode("A-z_0.9~"), "A-z_0.9~");
+		assert_eq!(percent_encode("é"), "%C3%A9");
+	}
+
 	#[test]
 	fn escape_html_safe_string() {
 		assert_eq!(escape_html("hello world"), "hello world");
@@ -290,6 +405,15 @@ mod tests {
 		assert_eq!(format_style_value("color", &json!("red")), Some("red".to_string()));
 	}
 
+	#[test]
+	fn format_style_value_with_explicit_unit() {
+		assert_eq!(format_style_value_with_unit(&json!(1.5), "rem"), Some("1.5rem".to_string()));
+		assert_eq!(format_style_value_with_unit(&json!(0), "s"), Some("0s".to_string()));
+		assert_eq!(format_style_value_with_unit(&json!("50"), "%"), Some("50%".to_string()));
+		assert_eq!(format_style_value_with_unit(&json!("2em"), "rem"), Some("2em".to_string()));
+		assert_eq!(format_style_value_with_unit(&json!(null), "rem"), None);
+	}
+
 	#[test]
 	fn format_style_value_null_skipped() {
 		assert_eq!(format_style_value("margin-top", &json!(null)), None);
@@ -4,23 +4,58 @@
 mod ast;
 mod helpers;
 mod parser;
+mod position;
 mod render;
 mod token;
 
-pub use parser::{DiagnosticKind, ParseDiagnostic};
+pub use parser::{DEFAULT_MAX_DEPTH, DiagnosticKind, ParseDiagnostic};
+pub use position::{SlotKind, SlotPosition, slot_positions};
+pub use render::{DEFAULT_MAX_RANGE, RenderOptions, escape_attr_value};
 
+use ast::AstNode;
 use parser::parse_with_diagnostics;
-use render::{RenderContext, inject_attributes, inject_style_attributes, render};
-use token::tokenize;
+use render::{RenderContext, render, render_to_writer, splice_phase_b};
+use token::{Token, tokenize};
 
 use serde_json::Value;
 use std::borrow::Cow;
+use std::io::{self, Write};
 
-/// Inject data into template and append data script before </body>.
-pub fn inject(template: &str, data: &Value, data_id: &str) -> String {
+/// Default `id` of the injected data script, matching the client runtime's lookup.
+pub const DEFAULT_DATA_ID: &str = "__data";
+
+/// Inject data into template and append the `DEFAULT_DATA_ID` script before </body>.
+pub fn inject(template: &str, data: &Value) -> String {
+	inject_with_id(template, data, DEFAULT_DATA_ID)
+}
+
+/// Like `inject`, with a custom data script `id` (`frontend.data_id` in seam.toml).
+pub fn inject_with_id(template: &str, data: &Value, data_id: &str) -> String {
+	inject_with_options(template, data, &InjectOptions { data_id, ..InjectOptions::default() })
+}
+
+/// Attributes of the data script appended by `inject_with_options`.
+#[derive(Debug, Clone, Copy)]
+pub struct InjectOptions<'a> {
+	pub data_id: &'a str,
+	/// CSP nonce for the inline script, generated per response by the caller.
+	pub nonce: Option<&'a str>,
+	/// Add `data-integrity="sha256-<base64>"`, a digest of the script's JSON text the
+	/// client can check before hydrating. Off by default.
+	pub integrity: bool,
+}
+
+impl Default for InjectOptions<'_> {
+	fn default() -> Self {
+		Self { data_id: DEFAULT_DATA_ID, nonce: None, integrity: false }
+	}
+}
+
+/// Like `inject`, with the data script's `id` and `nonce` taken from `options`.
+pub fn inject_with_options(template: &str, data: &Value, options: &InjectOptions<'_>) -> String {
 	let mut result = inject_no_script(template, data);
 
-	let script = format!(r#"<script id="{data_id}" type="application/json">{data}</script>"#);
+	let script = data_script(&data.to_string(), options);
 	if let Some(pos) = result.rfind("</body>") {
 		result.insert_str(pos, &script);
 	} else {
@@ -30,43 +65,211 @@ pub fn inject(template: &str, data: &Value, data_id: &str) -> String {
 	result
 }
 
+/// Build the `<script type="application/json">` element carrying serialized page data.
+pub fn data_script(json: &str, options: &InjectOptions<'_>) -> String {
+	let mut attrs = format!(r#"id="{}""#, escape_attr_value(options.data_id));
+	if let Some(nonce) = options.nonce {
+		attrs.push_str(&format!(r#" nonce="{}""#, escape_attr_value(nonce)));
+	}
+	if options.integrity {
+		attrs.push_str(&format!(r#" data-integrity="{}""#, data_integrity(json)));
+	}
+	format!(r#"<script {attrs} type="application/json">{json}</script>"#)
+}
+
+/// SRI-style `sha256-<base64>` digest of `json` exactly as it appears in the script body.
+pub fn data_integrity(json: &str) -> String {
+	use base64::Engine;
+	use sha2::{Digest, Sha256};
+
+	let digest = Sha256::digest(json.as_bytes());
+	format!("sha256-{}", base64::engine::general_purpose::STANDARD.encode(digest))
+}
+
 /// Inject data into template without appending the data script.
 pub fn inject_no_script(template: &str, data: &Value) -> String {
 	inject_no_script_with_diagnostics(template, data).0
 }
 
-/// Like `inject_no_script` but also returns parse diagnostics for malformed
-/// templates (unmatched block-close, unclosed block-open).
+/// Fill an HTML fragment (an HTMX partial, an email snippet) with `data`.
+///
+/// Fragment-safe by contract: no data script is added and the output never depends
+/// on `<html>`/`<body>` being present. Attribute and style slots are still spliced
+/// onto their target tags, so the result is the final fragment markup.
+pub fn render_fragment(template: &str, data: &Value) -> String {
+	render_precompiled(&compile(template), data, RenderOptions::default())
+}
+
+/// Like `inject_no_script`, with non-default rendering behavior.
+pub fn inject_no_script_with_options(
+	template: &str,
+	data: &Value,
+	options: &RenderOptions<'_>,
+) -> String {
+	render_precompiled(&compile(template), data, *options)
+}
+
+/// Like `inject_no_script` but also returns diagnostics for malformed templates
+/// (unmatched block-close, unclosed block-open, blocks nested past the depth limit)
+/// and for data the render had to cap (`each:range` past `max_range`).
 pub fn inject_no_script_with_diagnostics(
 	template: &str,
 	data: &Value,
 ) -> (String, Vec<ParseDiagnostic>) {
-	// Null-byte marker safety: Phase B uses \x00SEAM_ATTR_N\x00 / \x00SEAM_STYLE_N\x00
-	// as deferred attribute-injection placeholders. HTML spec forbids U+0000, so valid
-	// templates never contain them. Strip any stray null bytes from malformed SSR output
-	// to prevent marker collisions in the find/indexOf lookups.
-	let clean: Cow<'_, str> = if template.contains('\0') {
-		Cow::Owned(template.replace('\0', ""))
-	} else {
-		Cow::Borrowed(template)
-	};
-	let tokens = tokenize(&clean);
-	let mut diagnostics = Vec::new();
-	let ast = parse_with_diagnostics(&tokens, &mut diagnostics);
-	let mut ctx = RenderContext { attrs: Vec::new(), style_attrs: Vec::new() };
-	let mut result = render(&ast, data, &mut ctx);
+	inject_precompiled_with_diagnostics(&compile(template), data, &RenderOptions::default())
+}
+
+/// Render `compiled` with `options`; its parse diagnostics come first, then those found
+/// while rendering.
+pub fn inject_precompiled_with_diagnostics(
+	compiled: &CompiledTemplate,
+	data: &Value,
+	options: &RenderOptions<'_>,
+) -> (String, Vec<ParseDiagnostic>) {
+	let mut ctx = RenderContext::new(*options);
+	let result = render(&compiled.ast, data, &mut ctx);
+	let mut diagnostics = compiled.diagnostics.clone();
+	diagnostics.append(&mut ctx.diagnostics);
+	(splice_phase_b(result, &mut ctx), diagnostics)
+}
+
+/// Streaming counterpart of `inject_no_script`: writes rendered output to `writer`
+/// incrementally, so large `each` expansions never materialize as one `String`.
+///
+/// Output is byte-identical to `inject_no_script`. Attribute and style slots are the
+/// exception to incremental writes: the element they target is buffered until its
+/// opening tag closes, then spliced and written.
+pub fn inject_no_script_to_writer(
+	template: &str,
+	data: &Value,
+	writer: &mut impl Write,
+) -> io::Result<()> {
+	inject_precompiled_to_writer(&compile(template), data, writer)
+}
 
-	// Phase B: splice style attributes first
-	if !ctx.style_attrs.is_empty() {
-		result = inject_style_attributes(result, &ctx.style_attrs);
+/// A template tokenized and parsed once, ready to render against any data.
+#[derive(Debug)]
+pub struct CompiledTemplate {
+	ast: Vec<AstNode>,
+	diagnostics: Vec<ParseDiagnostic>,
+}
+
+impl CompiledTemplate {
+	/// Parse diagnostics collected at compile time.
+	pub fn diagnostics(&self) -> &[ParseDiagnostic] {
+		&self.diagnostics
 	}
 
-	// Phase B: splice collected attributes
-	if !ctx.attrs.is_empty() {
-		result = inject_attributes(result, &ctx.attrs);
+	/// Number of text, attribute, and style slots, including those inside blocks.
+	pub fn slot_count(&self) -> usize {
+		fn count(nodes: &[AstNode]) -> usize {
+			nodes
+				.iter()
+				.map(|node| match node {
+					AstNode::Text(_) => 0,
+					AstNode::Slot { .. } | AstNode::Attr { .. } | AstNode::StyleProp { .. } => 1,
+					AstNode::If { then_nodes, else_nodes, .. } => count(then_nodes) + count(else_nodes),
+					AstNode::Has { body_nodes, .. } | AstNode::IfLocale { body_nodes, .. } => {
+						count(body_nodes)
+					}
+					AstNode::Each { body_nodes, else_nodes, .. } => count(body_nodes) + count(else_nodes),
+					AstNode::Match { branches, .. } => branches.iter().map(|(_, b)| count(b)).sum(),
+				})
+				.sum()
+		}
+		count(&self.ast)
 	}
+}
+
+/// Output of the tokenize phase; `parse_template` turns it into a `CompiledTemplate`.
+#[derive(Debug)]
+pub struct TokenizedTemplate {
+	tokens: Vec<Token>,
+}
+
+/// Tokenize and parse `template` up front so repeated renders skip both steps.
+pub fn compile(template: &str) -> CompiledTemplate {
+	parse_template(&tokenize_template(template))
+}
+
+/// `compile` allowing `max_depth` nested blocks instead of `DEFAULT_MAX_DEPTH`. Deeper
+/// blocks are dropped with a `DepthLimitExceeded` diagnostic.
+pub fn compile_with_max_depth(template: &str, max_depth: usize) -> CompiledTemplate {
+	parse_tokens(&tokenize_template(template), max_depth)
+}
+
+/// First half of `compile`, split out so callers can time the phases separately.
+pub fn tokenize_template(template: &str) -> TokenizedTemplate {
+	TokenizedTemplate { tokens: tokenize(&strip_null_bytes(template)) }
+}
+
+/// Second half of `compile`.
+pub fn parse_template(tokenized: &TokenizedTemplate) -> CompiledTemplate {
+	parse_tokens(tokenized, DEFAULT_MAX_DEPTH)
+}
+
+fn parse_tokens(tokenized: &TokenizedTemplate, max_depth: usize) -> CompiledTemplate {
+	let mut diagnostics = Vec::new();
+	let ast = parse_with_diagnostics(&tokenized.tokens, max_depth, &mut diagnostics);
+	CompiledTemplate { ast, diagnostics }
+}
+
+/// `inject_no_script` for a template already passed through `compile`.
+pub fn inject_precompiled(compiled: &CompiledTemplate, data: &Value) -> String {
+	render_precompiled(compiled, data, RenderOptions::default())
+}
 
-	(result, diagnostics)
+/// `inject_precompiled` with non-default rendering behavior, such as the request locale.
+pub fn inject_precompiled_with_options(
+	compiled: &CompiledTemplate,
+	data: &Value,
+	options: &RenderOptions<'_>,
+) -> String {
+	render_precompiled(compiled, data, *options)
+}
+
+fn render_precompiled(
+	compiled: &CompiledTemplate,
+	data: &Value,
+	options: RenderOptions<'_>,
+) -> String {
+	let mut ctx = RenderContext::new(options);
+	let result = render(&compiled.ast, data, &mut ctx);
+
+	// Phase B: splice style attributes, then collected attributes
+	splice_phase_b(result, &mut ctx)
+}
+
+/// `inject_no_script_to_writer` for a template already passed through `compile`.
+pub fn inject_precompiled_to_writer(
+	compiled: &CompiledTemplate,
+	data: &Value,
+	writer: &mut impl Write,
+) -> io::Result<()> {
+	inject_precompiled_to_writer_with_options(compiled, data, &RenderOptions::default(), writer)
+}
+
+/// `inject_precompiled_to_writer` with non-default rendering behavior.
+pub fn inject_precompiled_to_writer_with_options(
+	compiled: &CompiledTemplate,
+	data: &Value,
+	options: &RenderOptions<'_>,
+	writer: &mut impl Write,
+) -> io::Result<()> {
+	let mut ctx = RenderContext::new(*options);
+	render_to_writer(&compiled.ast, data, &mut ctx, writer)
+}
+
+// Null-byte marker safety: Phase B uses \x00SEAM_ATTR_N\x00 / \x00SEAM_STYLE_N\x00
+// as deferred attribute-injection placeholders. HTML spec forbids U+0000, so valid
+// templates never contain them. Strip any stray null bytes from malformed SSR output
+// to prevent marker collisions in the find/indexOf lookups.
+fn strip_null_bytes(template: &str) -> Cow<'_, str> {
+	if template.contains('\0') {
+		Cow::Owned(template.replace('\0', ""))
+	} else {
+		Cow::Borrowed(template)
+	}
 }
 
 #[cfg(test)]
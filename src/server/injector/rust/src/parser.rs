@@ -1,7 +1,9 @@
 /* src/server/injector/rust/src/parser.rs */
 
-use crate::ast::{AstNode, SlotMode};
-use crate::token::Token;
+use crate::ast::{AstNode, EachModifiers, SlotMode};
+use crate::helpers::next_tag;
+use crate::render::find_named_tag;
+use crate::token::{MARKER_CLOSE, MARKER_OPEN, Token};
 
 /// Diagnostic emitted when block directives are mismatched or unclosed.
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -16,26 +18,83 @@ pub enum DiagnosticKind {
 	UnmatchedBlockClose,
 	/// Block-open directive that reached EOF without matching close
 	UnclosedBlock,
+	/// `when` arm that can never render: it repeats an earlier arm's value or follows
+	/// the `when:_` default. Rendering keeps using the first arm.
+	DuplicateMatchArm,
+	/// Attribute or style slot whose target the template does not show: a closing tag or
+	/// a non-block directive comes before the next opening tag. Render still attaches it
+	/// to the first opening tag that follows in the output, and drops it if none does.
+	DanglingAttrSlot,
+	/// Attribute slot whose `@tag` selector names a tag that never opens after it in the
+	/// template. The attribute is dropped.
+	MissingSelectorTarget,
+	/// Block nested deeper than the compile depth limit (`DEFAULT_MAX_DEPTH` unless set
+	/// with `compile_with_max_depth`). The block and everything inside it render nothing.
+	DepthLimitExceeded,
+	/// `each:range` count above `RenderOptions::max_range`; reported at render time, and
+	/// only the first `max_range` items render.
+	RangeLimitExceeded,
+}
+
+/// Block nesting `compile` allows before it stops descending.
+pub const DEFAULT_MAX_DEPTH: usize = 64;
+
+/// Blocks open around the tokens being parsed, and how many may be.
+#[derive(Clone, Copy)]
+struct Depth {
+	level: usize,
+	max: usize,
+}
+
+impl Depth {
+	fn inner(self) -> Self {
+		Self { level: self.level + 1, ..self }
+	}
+
+	fn exhausted(self) -> bool {
+		self.level >= self.max
+	}
 }
 
 #[cfg(test)]
 fn parse(tokens: &[Token]) -> Vec<AstNode> {
 	let mut diagnostics = Vec::new();
-	parse_with_diagnostics(tokens, &mut diagnostics)
+	parse_with_diagnostics(tokens, DEFAULT_MAX_DEPTH, &mut diagnostics)
 }
 
 pub(crate) fn parse_with_diagnostics(
 	tokens: &[Token],
+	max_depth: usize,
 	diagnostics: &mut Vec<ParseDiagnostic>,
 ) -> Vec<AstNode> {
 	let mut pos = 0;
-	parse_until(tokens, &mut pos, &|_| false, diagnostics)
+	parse_until(tokens, &mut pos, &|_| false, Depth { level: 0, max: max_depth }, diagnostics)
+}
+
+fn is_block_open(directive: &str) -> bool {
+	["match:", "if:", "iflocale:", "has:", "each:"].iter().any(|p| directive.starts_with(p))
+}
+
+fn is_block_close(directive: &str) -> bool {
+	directive.starts_with("endif:")
+		|| matches!(directive, "endmatch" | "endeach" | "endhas" | "endiflocale")
+}
+
+/// Opening, branch, or closing marker of a block: `else` and `when:` arms included.
+fn is_block_marker(directive: &str) -> bool {
+	is_block_open(directive)
+		|| is_block_close(directive)
+		|| directive == "else"
+		|| directive.starts_with("when:")
 }
 
 fn is_orphan_block_close(directive: &str) -> bool {
 	directive.starts_with("endif:")
 		|| directive == "endmatch"
 		|| directive == "endeach"
+		|| directive == "endhas"
+		|| directive == "endiflocale"
+		|| directive == "endraw"
 		|| directive == "else"
 		|| directive.starts_with("when:")
 }
@@ -44,6 +103,7 @@ fn parse_until(
 	tokens: &[Token],
 	pos: &mut usize,
 	stop: &dyn Fn(&str) -> bool,
+	depth: Depth,
 	diagnostics: &mut Vec<ParseDiagnostic>,
 ) -> Vec<AstNode> {
 	let mut nodes = Vec::new();
@@ -59,25 +119,57 @@ fn parse_until(
 					return nodes;
 				}
 
-				if let Some(path) = directive.strip_prefix("match:") {
-					nodes.push(parse_match_block(path, tokens, pos, diagnostics));
+				if depth.exhausted() && is_block_open(directive) {
+					skip_deep_block(tokens, pos, diagnostics);
+				} else if directive == "#comment" {
+					skip_comment_block(tokens, pos, diagnostics);
+				} else if directive.starts_with('#') {
+					*pos += 1;
+				} else if directive == "raw" {
+					nodes.push(parse_raw_block(tokens, pos, diagnostics));
+				} else if let Some(path) = directive.strip_prefix("match:") {
+					nodes.push(parse_match_block(path, tokens, pos, depth, diagnostics));
 				} else if let Some(path) = directive.strip_prefix("if:") {
-					nodes.push(parse_if_block(path, tokens, pos, diagnostics));
+					nodes.push(parse_if_block(path, tokens, pos, depth, diagnostics));
+				} else if let Some(spec) = directive.strip_prefix("iflocale:") {
+					nodes.push(parse_iflocale_block(spec, tokens, pos, depth, diagnostics));
+				} else if let Some(path) = directive.strip_prefix("has:") {
+					nodes.push(parse_has_block(path, tokens, pos, depth, diagnostics));
 				} else if let Some(path) = directive.strip_prefix("each:") {
-					nodes.push(parse_each_block(path, tokens, pos, diagnostics));
+					nodes.push(parse_each_block(path, tokens, pos, depth, diagnostics));
 				} else if let Some(rest) = directive.find(":style:") {
 					let path = directive[..rest].to_string();
-					let css_property = directive[rest + 7..].to_string();
 					*pos += 1;
-					nodes.push(AstNode::StyleProp { path, css_property });
-				} else if let Some(rest) = directive.find(":attr:") {
+					check_attr_target(directive, None, tokens, *pos, diagnostics);
+					nodes.push(parse_style_spec(path, &directive[rest + 7..]));
+				} else if let Some(rest) = directive.find(":attrif:") {
 					let path = directive[..rest].to_string();
-					let attr_name = directive[rest + 6..].to_string();
+					let (attr_name, target) = split_tag_selector(&directive[rest + 8..]);
 					*pos += 1;
-					nodes.push(AstNode::Attr { path, attr_name });
+					check_attr_target(directive, target.as_deref(), tokens, *pos, diagnostics);
+					nodes.push(AstNode::Attr {
+						path,
+						attr_name,
+						conditional: true,
+						urlencode: false,
+						target,
+					});
+				} else if let Some(rest) = directive.find(":attr:") {
+					let path = &directive[..rest];
+					let (path, urlencode) = match path.strip_suffix(":urlencode") {
+						Some(path) => (path.to_string(), true),
+						None => (path.to_string(), false),
+					};
+					let (attr_name, target) = split_tag_selector(&directive[rest + 6..]);
+					*pos += 1;
+					check_attr_target(directive, target.as_deref(), tokens, *pos, diagnostics);
+					nodes.push(AstNode::Attr { path, attr_name, conditional: false, urlencode, target });
 				} else if let Some(path) = directive.strip_suffix(":html") {
 					*pos += 1;
 					nodes.push(AstNode::Slot { path: path.to_string(), mode: SlotMode::Html });
+				} else if let Some(path) = directive.strip_suffix(":urlencode") {
+					*pos += 1;
+					nodes.push(AstNode::Slot { path: path.to_string(), mode: SlotMode::UrlEncode });
 				} else if is_orphan_block_close(directive) {
 					diagnostics.push(ParseDiagnostic {
 						kind: DiagnosticKind::UnmatchedBlockClose,
@@ -97,11 +189,168 @@ fn parse_until(
 	nodes
 }
 
+/// `id@input` -> (`id`, Some(`input`)). Only the last `@` starts a selector, and only
+/// after a non-empty name, so `@click` and `@click@button` keep `@click` as the name.
+/// An empty selector keeps the default target.
+fn split_tag_selector(spec: &str) -> (String, Option<String>) {
+	match spec.rsplit_once('@') {
+		Some((name, tag)) if !name.is_empty() && !tag.is_empty() => {
+			(name.to_string(), Some(tag.to_string()))
+		}
+		Some((name, "")) if !name.is_empty() => (name.to_string(), None),
+		_ => (spec.to_string(), None),
+	}
+}
+
+fn is_attr_directive(directive: &str) -> bool {
+	directive.contains(":attr:") || directive.contains(":attrif:") || directive.contains(":style:")
+}
+
+/// Report an attribute/style slot whose target tag does not follow it. Text and
+/// further attribute slots may sit in between, as may comments, which render nothing,
+/// and block markers, which render only their bodies; any other directive ends the search.
+/// A `selector` slot instead needs a matching opening tag anywhere after it.
+fn check_attr_target(
+	directive: &str,
+	selector: Option<&str>,
+	tokens: &[Token],
+	from: usize,
+	diagnostics: &mut Vec<ParseDiagnostic>,
+) {
+	if let Some(name) = selector {
+		let found = tokens[from..].iter().any(|token| match token {
+			Token::Text(text) => find_named_tag(text, 0, name).is_some(),
+			Token::Marker(_) => false,
+		});
+		if !found {
+			diagnostics.push(ParseDiagnostic {
+				kind: DiagnosticKind::MissingSelectorTarget,
+				directive: directive.to_string(),
+			});
+		}
+		return;
+	}
+	let mut pos = from;
+	while pos < tokens.len() {
+		match &tokens[pos] {
+			Token::Marker(d) if d == "#comment" => {
+				if let Some(close) = comment_close(tokens, pos) {
+					pos = close;
+				}
+			}
+			Token::Marker(d) if d.starts_with('#') => {}
+			Token::Text(text) => {
+				if let Some((_, opens)) = next_tag(text) {
+					if !opens {
+						break;
+					}
+					return;
+				}
+			}
+			Token::Marker(d) if is_attr_directive(d) || is_block_marker(d) => {}
+			Token::Marker(_) => break,
+		}
+		pos += 1;
+	}
+	diagnostics.push(ParseDiagnostic {
+		kind: DiagnosticKind::DanglingAttrSlot,
+		directive: directive.to_string(),
+	});
+}
+
+/// Parse `raw ... endraw` into a single text node. Inner markers are re-emitted as
+/// written, so example seam syntax renders literally. A nested `raw` is literal too,
+/// which makes the first `endraw` close the block.
+fn parse_raw_block(
+	tokens: &[Token],
+	pos: &mut usize,
+	diagnostics: &mut Vec<ParseDiagnostic>,
+) -> AstNode {
+	*pos += 1;
+	let mut text = String::new();
+	while *pos < tokens.len() {
+		match &tokens[*pos] {
+			Token::Marker(d) if d == "endraw" => {
+				*pos += 1;
+				return AstNode::Text(text);
+			}
+			Token::Marker(d) => {
+				text.push_str(MARKER_OPEN);
+				text.push_str(d);
+				text.push_str(MARKER_CLOSE);
+			}
+			Token::Text(t) => text.push_str(t),
+		}
+		*pos += 1;
+	}
+	diagnostics
+		.push(ParseDiagnostic { kind: DiagnosticKind::UnclosedBlock, directive: "raw".to_string() });
+	AstNode::Text(text)
+}
+
+/// Index of the `#endcomment` closing the `#comment` at `open`. None means the opener is
+/// unclosed and drops only itself; rendering and diagnostics both follow this rule.
+fn comment_close(tokens: &[Token], open: usize) -> Option<usize> {
+	let offset =
+		tokens[open + 1..].iter().position(|t| matches!(t, Token::Marker(d) if d == "#endcomment"))?;
+	Some(open + 1 + offset)
+}
+
+/// Drop `#comment ... #endcomment` and everything inside it. Inner markers are inert,
+/// so they neither render nor produce diagnostics; the first `#endcomment` closes the
+/// block. Without one, the opener drops only itself like any `#note`, so a typo cannot
+/// swallow the rest of the page.
+fn skip_comment_block(tokens: &[Token], pos: &mut usize, diagnostics: &mut Vec<ParseDiagnostic>) {
+	match comment_close(tokens, *pos) {
+		Some(close) => *pos = close + 1,
+		None => {
+			*pos += 1;
+			diagnostics.push(ParseDiagnostic {
+				kind: DiagnosticKind::UnclosedBlock,
+				directive: "#comment".to_string(),
+			});
+		}
+	}
+}
+
+/// Drop a block opened past the depth limit, with its body and close. Nesting is counted
+/// in a loop rather than parsed, so a hostile template cannot recurse the stack away.
+fn skip_deep_block(tokens: &[Token], pos: &mut usize, diagnostics: &mut Vec<ParseDiagnostic>) {
+	if let Token::Marker(d) = &tokens[*pos] {
+		diagnostics
+			.push(ParseDiagnostic { kind: DiagnosticKind::DepthLimitExceeded, directive: d.clone() });
+	}
+	let mut open = 0usize;
+	while *pos < tokens.len() {
+		match &tokens[*pos] {
+			Token::Marker(d) if d == "#comment" => {
+				skip_comment_block(tokens, pos, diagnostics);
+				continue;
+			}
+			Token::Marker(d) if d == "raw" => {
+				parse_raw_block(tokens, pos, diagnostics);
+				continue;
+			}
+			Token::Marker(d) if is_block_open(d) => open += 1,
+			Token::Marker(d) if is_block_close(d) => {
+				open -= 1;
+				if open == 0 {
+					*pos += 1;
+					return;
+				}
+			}
+			_ => {}
+		}
+		*pos += 1;
+	}
+}
+
 /// Parse `match:path ... when:value ... endmatch` block.
 fn parse_match_block(
 	path: &str,
 	tokens: &[Token],
 	pos: &mut usize,
+	depth: Depth,
 	diagnostics: &mut Vec<ParseDiagnostic>,
 ) -> AstNode {
 	let path = path.to_string();
@@ -117,9 +366,20 @@ fn parse_match_block(
 			}
 			if let Some(value) = d.strip_prefix("when:") {
 				let value = value.to_string();
+				if branches.iter().any(|(v, _)| *v == value || v == "_") {
+					diagnostics.push(ParseDiagnostic {
+						kind: DiagnosticKind::DuplicateMatchArm,
+						directive: d.clone(),
+					});
+				}
 				*pos += 1;
-				let body =
-					parse_until(tokens, pos, &|d| d.starts_with("when:") || d == "endmatch", diagnostics);
+				let body = parse_until(
+					tokens,
+					pos,
+					&|d| d.starts_with("when:") || d == "endmatch",
+					depth.inner(),
+					diagnostics,
+				);
 				branches.push((value, body));
 			} else {
 				// Skip unexpected tokens between match and first when
@@ -143,18 +403,20 @@ fn parse_if_block(
 	path: &str,
 	tokens: &[Token],
 	pos: &mut usize,
+	depth: Depth,
 	diagnostics: &mut Vec<ParseDiagnostic>,
 ) -> AstNode {
 	let path = path.to_string();
 	*pos += 1;
 	let endif_tag = format!("endif:{path}");
-	let then_nodes = parse_until(tokens, pos, &|d| d == "else" || d == endif_tag, diagnostics);
+	let then_nodes =
+		parse_until(tokens, pos, &|d| d == "else" || d == endif_tag, depth.inner(), diagnostics);
 
 	let else_nodes = if *pos < tokens.len() {
 		if let Token::Marker(d) = &tokens[*pos] {
 			if d == "else" {
 				*pos += 1;
-				parse_until(tokens, pos, &|d| d == endif_tag, diagnostics)
+				parse_until(tokens, pos, &|d| d == endif_tag, depth.inner(), diagnostics)
 			} else {
 				Vec::new()
 			}
@@ -179,16 +441,119 @@ fn parse_if_block(
 	AstNode::If { path, then_nodes, else_nodes }
 }
 
-/// Parse `each:path ... endeach` block.
-fn parse_each_block(
+/// Parse `has:path ... endhas` block.
+fn parse_has_block(
 	path: &str,
 	tokens: &[Token],
 	pos: &mut usize,
+	depth: Depth,
 	diagnostics: &mut Vec<ParseDiagnostic>,
 ) -> AstNode {
-	let path = path.to_string();
 	*pos += 1;
-	let body_nodes = parse_until(tokens, pos, &|d| d == "endeach", diagnostics);
+	let body_nodes = parse_until(tokens, pos, &|d| d == "endhas", depth.inner(), diagnostics);
+	// Skip endhas token; if absent we hit EOF
+	if *pos < tokens.len() {
+		*pos += 1;
+	} else {
+		diagnostics.push(ParseDiagnostic {
+			kind: DiagnosticKind::UnclosedBlock,
+			directive: format!("has:{path}"),
+		});
+	}
+	AstNode::Has { path: path.to_string(), body_nodes }
+}
+
+/// `iflocale:fr,de-AT` takes a comma-separated list of locales or language prefixes.
+fn parse_iflocale_block(
+	spec: &str,
+	tokens: &[Token],
+	pos: &mut usize,
+	depth: Depth,
+	diagnostics: &mut Vec<ParseDiagnostic>,
+) -> AstNode {
+	*pos += 1;
+	let body_nodes = parse_until(tokens, pos, &|d| d == "endiflocale", depth.inner(), diagnostics);
+	if *pos < tokens.len() {
+		*pos += 1;
+	} else {
+		diagnostics.push(ParseDiagnostic {
+			kind: DiagnosticKind::UnclosedBlock,
+			directive: format!("iflocale:{spec}"),
+		});
+	}
+	let locales =
+		spec.split(',').map(str::trim).filter(|l| !l.is_empty()).map(str::to_string).collect();
+	AstNode::IfLocale { locales, body_nodes }
+}
+
+/// Split `margin-top:rem:!important` into the CSS property, an explicit unit, and the
+/// `!important` flag. Either suffix may be omitted.
+fn parse_style_spec(path: String, spec: &str) -> AstNode {
+	let mut parts = spec.split(':');
+	let css_property = parts.next().unwrap_or_default().to_string();
+	let mut unit = None;
+	let mut important = false;
+	for part in parts {
+		if part == "!important" {
+			important = true;
+		} else if !part.is_empty() {
+			unit = Some(part.to_string());
+		}
+	}
+	AstNode::StyleProp { path, css_property, unit, important }
+}
+
+/// Split `items:reverse:sort:$.name` into the array path and its modifiers.
+/// `range:pageCount` reads the path after `range`, unless that word is itself a modifier
+/// (`each:range:reverse` iterates an array named `range`).
+/// Unknown modifier words and non-numeric counts are ignored.
+pub(crate) fn parse_each_spec(spec: &str) -> (String, EachModifiers) {
+	let mut parts = spec.split(':').peekable();
+	let mut path = parts.next().unwrap_or_default().to_string();
+	let mut modifiers = EachModifiers::default();
+	if path == "range"
+		&& let Some(count_path) =
+			parts.next_if(|p| !matches!(*p, "reverse" | "sort" | "where" | "offset" | "limit"))
+	{
+		modifiers.range = true;
+		path = count_path.to_string();
+	}
+	while let Some(word) = parts.next() {
+		match word {
+			"reverse" => modifiers.reverse = true,
+			"sort" => modifiers.sort_by = parts.next().map(str::to_string),
+			"where" => modifiers.where_path = parts.next().map(str::to_string),
+			"offset" => {
+				if let Some(n) = parts.next().and_then(|n| n.parse().ok()) {
+					modifiers.offset = n;
+				}
+			}
+			"limit" => modifiers.limit = parts.next().and_then(|n| n.parse().ok()),
+			_ => {}
+		}
+	}
+	(path, modifiers)
+}
+
+/// Parse `each:path[:modifiers] ... endeach` block.
+fn parse_each_block(
+	spec: &str,
+	tokens: &[Token],
+	pos: &mut usize,
+	depth: Depth,
+	diagnostics: &mut Vec<ParseDiagnostic>,
+) -> AstNode {
+	let (path, modifiers) = parse_each_spec(spec);
+	*pos += 1;
+	let body_nodes =
+		parse_until(tokens, pos, &|d| d == "endeach" || d == "else", depth.inner(), diagnostics);
+	// A nested `if` consumes its own `else`, so one reaching here belongs to the loop
+	let else_nodes = if matches!(tokens.get(*pos), Some(Token::Marker(d)) if d == "else") {
+		*pos += 1;
+		parse_until(tokens, pos, &|d| d == "endeach", depth.inner(), diagnostics)
+	} else {
+		Vec::new()
+	};
 	// Skip endeach token; if absent we hit EOF
 	let closed = *pos < tokens.len();
 	if closed {
@@ -197,10 +562,10 @@ fn parse_each_block(
 	if !closed {
 		diagnostics.push(ParseDiagnostic {
 			kind: DiagnosticKind::UnclosedBlock,
-			directive: format!("each:{path}"),
+			directive: format!("each:{spec}"),
 		});
 	}
-	AstNode::Each { path, body_nodes }
+	AstNode::Each { path, modifiers, body_nodes, else_nodes }
 }
 
 #[cfg(test)]
@@ -244,7 +609,7 @@ mod tests {
 		let ast = parse(&tokens);
 		assert_eq!(ast.len(), 1);
 		match &ast[0] {
-			AstNode::Each { path, body_nodes } => {
+			AstNode::Each { path, body_nodes, .. } => {
 				assert_eq!(path, "items");
 				assert_eq!(body_nodes.len(), 1);
 				assert!(matches!(&body_nodes[0], AstNode::Text(s) if s == "body"));
@@ -297,7 +662,7 @@ mod tests {
 		let ast = parse(&tokens);
 		assert_eq!(ast.len(), 1);
 		match &ast[0] {
-			AstNode::StyleProp { path, css_property } => {
+			AstNode::StyleProp { path, css_property, unit: None, important: false } => {
 				assert_eq!(path, "color");
 				assert_eq!(css_property, "color");
 			}
@@ -305,6 +670,19 @@ mod tests {
 		}
 	}
 
+	#[test]
+	fn parse_style_unit_and_important() {
+		let tokens = vec![Token::Marker("mt:style:margin-top:rem:!important".to_string())];
+		let ast = parse(&tokens);
+		match &ast[0] {
+			AstNode::StyleProp { css_property, unit: Some(unit), important: true, .. } => {
+				assert_eq!(css_property, "margin-top");
+				assert_eq!(unit, "rem");
+			}
+			other => panic!("expected StyleProp, got {other:?}"),
+		}
+	}
+
 	#[test]
 	fn parse_empty_path_slot() {
 		let tokens = vec![Token::Marker(String::new())];
@@ -343,7 +721,7 @@ mod tests {
 			Token::Text("after".to_string()),
 		];
 		let mut diags = Vec::new();
-		let ast = parse_with_diagnostics(&tokens, &mut diags);
+		let ast = parse_with_diagnostics(&tokens, DEFAULT_MAX_DEPTH, &mut diags);
 		// Orphan endif should not produce a slot node
 		assert_eq!(ast.len(), 2);
 		assert!(matches!(&ast[0], AstNode::Text(s) if s == "before"));
@@ -357,7 +735,7 @@ mod tests {
 	fn unclosed_if_produces_warning() {
 		let tokens = vec![Token::Marker("if:x".to_string()), Token::Text("body".to_string())];
 		let mut diags = Vec::new();
-		let ast = parse_with_diagnostics(&tokens, &mut diags);
+		let ast = parse_with_diagnostics(&tokens, DEFAULT_MAX_DEPTH, &mut diags);
 		// AST still contains the If node (best-effort parse)
 		assert_eq!(ast.len(), 1);
 		assert!(matches!(&ast[0], AstNode::If { .. }));
@@ -375,7 +753,7 @@ mod tests {
 			Token::Marker("endif:shwo".to_string()),
 		];
 		let mut diags = Vec::new();
-		let ast = parse_with_diagnostics(&tokens, &mut diags);
+		let ast = parse_with_diagnostics(&tokens, DEFAULT_MAX_DEPTH, &mut diags);
 		assert_eq!(ast.len(), 1);
 		assert!(matches!(&ast[0], AstNode::If { .. }));
 		// Two diagnostics: the typo'd endif is swallowed into the if body as
@@ -386,11 +764,21 @@ mod tests {
 		assert!(kinds.contains(&&DiagnosticKind::UnclosedBlock));
 	}
 
+	#[test]
+	fn unclosed_raw_keeps_content() {
+		let tokens = vec![Token::Marker("raw".to_string()), Token::Marker("if:x".to_string())];
+		let mut diags = Vec::new();
+		let ast = parse_with_diagnostics(&tokens, DEFAULT_MAX_DEPTH, &mut diags);
+		assert!(matches!(&ast[..], [AstNode::Text(s)] if s == "<!--seam:if:x-->"));
+		assert_eq!(diags[0].kind, DiagnosticKind::UnclosedBlock);
+		assert_eq!(diags[0].directive, "raw");
+	}
+
 	#[test]
 	fn orphan_endmatch_and_endeach() {
 		let tokens = vec![Token::Marker("endmatch".to_string()), Token::Marker("endeach".to_string())];
 		let mut diags = Vec::new();
-		let ast = parse_with_diagnostics(&tokens, &mut diags);
+		let ast = parse_with_diagnostics(&tokens, DEFAULT_MAX_DEPTH, &mut diags);
 		assert!(ast.is_empty());
 		assert_eq!(diags.len(), 2);
 		assert!(diags.iter().all(|d| d.kind == DiagnosticKind::UnmatchedBlockClose));
@@ -406,7 +794,7 @@ mod tests {
 			Token::Marker("endif:x".to_string()),
 		];
 		let mut diags = Vec::new();
-		parse_with_diagnostics(&tokens, &mut diags);
+		parse_with_diagnostics(&tokens, DEFAULT_MAX_DEPTH, &mut diags);
 		assert!(diags.is_empty());
 	}
 }
@@ -0,0 +1,147 @@
+/* src/server/injector/rust/src/position.rs */
+
+use crate::parser::parse_each_spec;
+use crate::token::marker_spans;
+
+/// What a marker does, classified the same way the parser dispatches directives.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SlotKind {
+	Text,
+	Html,
+	/// `path:urlencode` text slot
+	UrlEncode,
+	Attr,
+	AttrIf,
+	Style,
+	If,
+	Else,
+	EndIf,
+	Has,
+	EndHas,
+	IfLocale,
+	EndIfLocale,
+	Each,
+	EndEach,
+	Match,
+	When,
+	EndMatch,
+	Raw,
+	EndRaw,
+	/// `#comment` block opener or a one-line `#note`
+	Comment,
+	EndComment,
+}
+
+impl SlotKind {
+	/// Stable camelCase name, used by the WASM bridge.
+	pub fn as_str(self) -> &'static str {
+		match self {
+			Self::Text => "text",
+			Self::Html => "html",
+			Self::UrlEncode => "urlEncode",
+			Self::Attr => "attr",
+			Self::AttrIf => "attrIf",
+			Self::Style => "style",
+			Self::If => "if",
+			Self::Else => "else",
+			Self::EndIf => "endIf",
+			Self::Has => "has",
+			Self::EndHas => "endHas",
+			Self::IfLocale => "ifLocale",
+			Self::EndIfLocale => "endIfLocale",
+			Self::Each => "each",
+			Self::EndEach => "endEach",
+			Self::Match => "match",
+			Self::When => "when",
+			Self::EndMatch => "endMatch",
+			Self::Raw => "raw",
+			Self::EndRaw => "endRaw",
+			Self::Comment => "comment",
+			Self::EndComment => "endComment",
+		}
+	}
+}
+
+/// A marker in the source template. `start..end` is the byte range of the whole
+/// `<!--seam:...-->` comment; `path` is the data path it reads, empty for markers
+/// that read none (`else`, `endeach`, ...). For `when` it holds the arm value,
+/// for `iflocale` the locale list.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SlotPosition {
+	pub kind: SlotKind,
+	pub path: String,
+	pub start: usize,
+	pub end: usize,
+}
+
+/// Locate every marker the renderer would act on, for editor tooling and error
+/// reporting. Markers inside `raw` and `#comment` blocks and non-slot markers inside
+/// opaque elements are inert text and are not reported.
+pub fn slot_positions(template: &str) -> Vec<SlotPosition> {
+	let markers = marker_spans(template);
+	let mut positions = Vec::new();
+	let mut inert_until = None;
+	for (i, (directive, span)) in markers.iter().enumerate() {
+		let directive = *directive;
+		if let Some(end) = inert_until {
+			if directive != end {
+				continue;
+			}
+			inert_until = None;
+		}
+		let (kind, path) = classify(directive);
+		if directive == "raw" {
+			inert_until = Some("endraw");
+		} else if directive == "#comment" && markers[i + 1..].iter().any(|(d, _)| *d == "#endcomment") {
+			// Unclosed `#comment` drops only itself, matching the parser
+			inert_until = Some("#endcomment");
+		}
+		positions.push(SlotPosition { kind, path, start: span.start, end: span.end });
+	}
+	positions
+}
+
+fn classify(directive: &str) -> (SlotKind, String) {
+	let bare = |kind| (kind, String::new());
+	match directive {
+		"raw" => return bare(SlotKind::Raw),
+		"endraw" => return bare(SlotKind::EndRaw),
+		"#endcomment" => return bare(SlotKind::EndComment),
+		"else" => return bare(SlotKind::Else),
+		"endeach" => return bare(SlotKind::EndEach),
+		"endhas" => return bare(SlotKind::EndHas),
+		"endiflocale" => return bare(SlotKind::EndIfLocale),
+		"endmatch" => return bare(SlotKind::EndMatch),
+		_ if directive.starts_with('#') => return bare(SlotKind::Comment),
+		_ => {}
+	}
+	let prefixed = [
+		("match:", SlotKind::Match),
+		("when:", SlotKind::When),
+		("if:", SlotKind::If),
+		("endif:", SlotKind::EndIf),
+		("has:", SlotKind::Has),
+		("iflocale:", SlotKind::IfLocale),
+	];
+	for (prefix, kind) in prefixed {
+		if let Some(path) = directive.strip_prefix(prefix) {
+			return (kind, path.to_string());
+		}
+	}
+	if let Some(spec) = directive.strip_prefix("each:") {
+		(SlotKind::Each, parse_each_spec(spec).0)
+	} else if let Some(idx) = directive.find(":style:") {
+		(SlotKind::Style, directive[..idx].to_string())
+	} else if let Some(idx) = directive.find(":attrif:") {
+		(SlotKind::AttrIf, directive[..idx].to_string())
+	} else if let Some(idx) = directive.find(":attr:") {
+		let path = &directive[..idx];
+		(SlotKind::Attr, path.strip_suffix(":urlencode").unwrap_or(path).to_string())
+	} else if let Some(path) = directive.strip_suffix(":html") {
+		(SlotKind::Html, path.to_string())
+	} else if let Some(path) = directive.strip_suffix(":urlencode") {
+		(SlotKind::UrlEncode, path.to_string())
+	} else {
+		(SlotKind::Text, directive.to_string())
+	}
+}
@@ -1,16 +1,23 @@
 /* src/server/injector/rust/src/render.rs */
 
+use std::cmp::Ordering;
+use std::io::{self, Write};
+
 use serde_json::Value;
 
-use crate::ast::{AstNode, SlotMode};
+use crate::ast::{AstNode, EachModifiers, SlotMode};
 use crate::helpers::{
-	escape_html, format_style_value, is_html_boolean_attr, is_truthy, resolve, stringify,
+	escape_html, format_style_value, format_style_value_with_unit, is_html_boolean_attr, is_truthy,
+	next_tag, numeric_arm_matches, percent_encode, resolve, stringify, tag_end,
 };
+use crate::parser::{DiagnosticKind, ParseDiagnostic};
 
 pub(crate) struct AttrEntry {
 	pub(crate) marker: String,
 	pub(crate) attr_name: String,
 	pub(crate) value: String,
+	/// Tag name from an `@tag` selector; `None` targets the next opening tag
+	pub(crate) target: Option<String>,
 }
 
 pub(crate) struct StyleAttrEntry {
@@ -19,116 +26,493 @@ pub(crate) struct StyleAttrEntry {
 	pub(crate) value: String,
 }
 
-pub(crate) struct RenderContext {
+/// Most `each:range` iterations `RenderOptions::default()` renders.
+pub const DEFAULT_MAX_RANGE: u64 = 10_000;
+
+/// Rendering behavior switches. `Default` is what the plain `inject*` functions use.
+#[derive(Debug, Clone, Copy)]
+pub struct RenderOptions<'a> {
+	/// Treat whitespace-only strings (`"   "`) as falsy in `if` blocks.
+	/// Off by default: only `""` is a falsy string.
+	pub blank_strings_falsy: bool,
+	/// Re-emit the attributes of every element a slot injects into in name order, so
+	/// output bytes do not depend on slot order. Off by default: insertion order.
+	pub sort_attributes: bool,
+	/// Active request locale, tested by `iflocale` blocks. `None` when i18n is off.
+	pub locale: Option<&'a str>,
+	/// Render `iflocale` bodies when `locale` is `None`. Off by default: they render nothing.
+	pub iflocale_when_unset: bool,
+	/// Cap on `each:range` iterations. A larger count renders the first `max_range` items
+	/// and reports `DiagnosticKind::RangeLimitExceeded`, so a count from data such as
+	/// `1e12` cannot exhaust memory.
+	pub max_range: u64,
+}
+
+impl Default for RenderOptions<'_> {
+	fn default() -> Self {
+		Self {
+			blank_strings_falsy: false,
+			sort_attributes: false,
+			locale: None,
+			iflocale_when_unset: false,
+			max_range: DEFAULT_MAX_RANGE,
+		}
+	}
+}
+
+pub(crate) struct RenderContext<'a> {
 	pub(crate) attrs: Vec<AttrEntry>,
 	pub(crate) style_attrs: Vec<StyleAttrEntry>,
+	pub(crate) options: RenderOptions<'a>,
+	/// Problems that depend on the data, found while rendering
+	pub(crate) diagnostics: Vec<ParseDiagnostic>,
+}
+
+impl<'a> RenderContext<'a> {
+	pub(crate) fn new(options: RenderOptions<'a>) -> Self {
+		Self { attrs: Vec::new(), style_attrs: Vec::new(), options, diagnostics: Vec::new() }
+	}
+
+	/// Reported once per directive: a nested loop would otherwise repeat it per item.
+	fn report(&mut self, kind: DiagnosticKind, directive: String) {
+		if !self.diagnostics.iter().any(|d| d.kind == kind && d.directive == directive) {
+			self.diagnostics.push(ParseDiagnostic { kind, directive });
+		}
+	}
+
+	fn is_condition_true(&self, value: &Value) -> bool {
+		match value {
+			Value::String(s) if self.options.blank_strings_falsy => !s.trim().is_empty(),
+			_ => is_truthy(value),
+		}
+	}
+
+	/// `fr` matches `fr` and `fr-CA`, never `fry`; comparison ignores ASCII case.
+	fn locale_matches(&self, locales: &[String]) -> bool {
+		let Some(active) = self.options.locale else {
+			return self.options.iflocale_when_unset;
+		};
+		locales.iter().any(|want| {
+			active.get(..want.len()).is_some_and(|head| head.eq_ignore_ascii_case(want))
+				&& matches!(active.as_bytes().get(want.len()), None | Some(b'-' | b'_'))
+		})
+	}
 }
 
 pub(crate) fn render(nodes: &[AstNode], data: &Value, ctx: &mut RenderContext) -> String {
-	let mut out = String::new();
+	let mut out = RenderOutput::buffered();
+	render_into(nodes, data, data, ctx, &mut out);
+	out.buf
+}
 
+/// Streaming variant of `render`: completed output is written to `writer` as
+/// rendering progresses instead of accumulating in one `String`.
+///
+/// Phase B attribute/style splicing still needs the target element in memory, so
+/// output from the first pending `\x00SEAM_*` marker through the closing `>` of the
+/// element it targets is held back and spliced before being written. Everything
+/// else is written as soon as the node producing it has rendered.
+pub(crate) fn render_to_writer(
+	nodes: &[AstNode],
+	data: &Value,
+	ctx: &mut RenderContext,
+	writer: &mut impl Write,
+) -> io::Result<()> {
+	let mut out = RenderOutput { buf: String::new(), sink: Some(writer), error: None };
+	render_into(nodes, data, data, ctx, &mut out);
+	out.finish(ctx)
+}
+
+/// Render target: a growing buffer, optionally drained into a writer.
+pub(crate) struct RenderOutput<'w> {
+	pub(crate) buf: String,
+	sink: Option<&'w mut dyn Write>,
+	error: Option<io::Error>,
+}
+
+impl RenderOutput<'_> {
+	fn buffered() -> Self {
+		Self { buf: String::new(), sink: None, error: None }
+	}
+
+	fn write_out(&mut self, chunk: &str) {
+		if chunk.is_empty() || self.error.is_some() {
+			return;
+		}
+		if let Some(sink) = self.sink.as_mut()
+			&& let Err(e) = sink.write_all(chunk.as_bytes())
+		{
+			self.error = Some(e);
+		}
+	}
+
+	/// Write out the longest prefix of the buffer that no pending marker still needs.
+	fn flush_ready(&mut self, ctx: &mut RenderContext) {
+		if self.sink.is_none() || self.error.is_some() {
+			return;
+		}
+		let Some(first) = self.buf.find('\0') else {
+			let chunk = std::mem::take(&mut self.buf);
+			self.write_out(&chunk);
+			return;
+		};
+		let last_marker_end = self.buf.rfind('\0').map_or(first, |p| p + 1);
+		// A closing tag resolves the marker as dangling, so only that much is needed
+		let target_end = next_tag(&self.buf[last_marker_end..]).and_then(|(lt, opens)| {
+			let abs = last_marker_end + lt;
+			if !opens {
+				return Some(abs);
+			}
+			tag_end(&self.buf, abs)
+		});
+		let target_end = target_end.and_then(|end| selector_targets_end(&self.buf, &ctx.attrs, end));
+		match target_end {
+			Some(end) => {
+				let rest = self.buf.split_off(end);
+				let segment = std::mem::replace(&mut self.buf, rest);
+				// Every marker in the segment is resolved here, so entries can be
+				// dropped and marker numbering restarts for the next segment
+				let spliced = splice_phase_b(segment, ctx);
+				self.write_out(&spliced);
+			}
+			None => {
+				let rest = self.buf.split_off(first);
+				let ready = std::mem::replace(&mut self.buf, rest);
+				self.write_out(&ready);
+			}
+		}
+	}
+
+	fn finish(mut self, ctx: &mut RenderContext) -> io::Result<()> {
+		let remaining = std::mem::take(&mut self.buf);
+		let spliced = splice_phase_b(remaining, ctx);
+		self.write_out(&spliced);
+		if let Some(e) = self.error {
+			return Err(e);
+		}
+		match self.sink {
+			Some(sink) => sink.flush(),
+			None => Ok(()),
+		}
+	}
+}
+
+/// Apply collected style then attribute injections and drain them from `ctx`.
+pub(crate) fn splice_phase_b(mut html: String, ctx: &mut RenderContext) -> String {
+	let sort = ctx.options.sort_attributes;
+	if !ctx.style_attrs.is_empty() {
+		html = inject_style_attributes(html, &ctx.style_attrs, sort);
+		ctx.style_attrs.clear();
+	}
+	if !ctx.attrs.is_empty() {
+		html = inject_attributes(html, &ctx.attrs, sort);
+		ctx.attrs.clear();
+	}
+	html
+}
+
+/// `data` is the current scope (with `$`/`$$` inside `each`); `root` is the page data
+/// that `/path` references resolve against.
+fn render_into(
+	nodes: &[AstNode],
+	data: &Value,
+	root: &Value,
+	ctx: &mut RenderContext,
+	out: &mut RenderOutput,
+) {
 	for node in nodes {
-		match node {
-			AstNode::Text(value) => out.push_str(value),
-
-			AstNode::Slot { path, mode } => {
-				let value = resolve(path, data);
-				match mode {
-					SlotMode::Html => {
-						out.push_str(&stringify(value.unwrap_or(&Value::Null)));
-					}
-					SlotMode::Text => {
-						out.push_str(&escape_html(&stringify(value.unwrap_or(&Value::Null))));
-					}
+		render_node(node, data, root, ctx, out);
+		out.flush_ready(ctx);
+	}
+}
+
+fn render_node(
+	node: &AstNode,
+	data: &Value,
+	root: &Value,
+	ctx: &mut RenderContext,
+	out: &mut RenderOutput,
+) {
+	match node {
+		AstNode::Text(value) => out.buf.push_str(value),
+
+		AstNode::Slot { path, mode } => {
+			let value = lookup(path, data, root);
+			match mode {
+				SlotMode::Html => {
+					out.buf.push_str(&stringify(value.unwrap_or(&Value::Null)));
+				}
+				SlotMode::Text => {
+					out.buf.push_str(&escape_html(&stringify(value.unwrap_or(&Value::Null))));
+				}
+				SlotMode::UrlEncode => {
+					// Percent-encoded output is pure ASCII with no HTML-special characters
+					out.buf.push_str(&percent_encode(&stringify(value.unwrap_or(&Value::Null))));
 				}
 			}
+		}
 
-			AstNode::Attr { path, attr_name } => {
-				if let Some(value) = resolve(path, data) {
+		AstNode::Attr { path, attr_name, conditional, urlencode, target } => {
+			if let Some(value) = lookup(path, data, root) {
+				let attr_value = if *conditional {
+					// The path only gates the attribute; it never becomes the value
+					ctx.is_condition_true(value).then(String::new)
+				} else if is_html_boolean_attr(attr_name) {
+					// Boolean HTML attrs: truthy -> attr="", falsy -> omit
+					is_truthy(value).then(String::new)
+				} else if *urlencode {
+					Some(percent_encode(&stringify(value)))
+				} else {
+					Some(escape_attr_value(&stringify(value)))
+				};
+				if let Some(value) = attr_value {
 					// Null-byte delimited markers (\x00SEAM_ATTR_N\x00) are collected here and
 					// resolved in Phase B (inject_attributes). Null bytes are safe delimiters
 					// because the HTML spec forbids U+0000 and we strip them from input.
-					if is_html_boolean_attr(attr_name) {
-						// Boolean HTML attrs: truthy -> attr="", falsy -> omit
-						if is_truthy(value) {
-							let marker = format!("\x00SEAM_ATTR_{}\x00", ctx.attrs.len());
-							ctx.attrs.push(AttrEntry {
-								marker: marker.clone(),
-								attr_name: attr_name.clone(),
-								value: String::new(),
-							});
-							out.push_str(&marker);
-						}
-					} else {
-						let marker = format!("\x00SEAM_ATTR_{}\x00", ctx.attrs.len());
-						ctx.attrs.push(AttrEntry {
-							marker: marker.clone(),
-							attr_name: attr_name.clone(),
-							value: escape_html(&stringify(value)),
-						});
-						out.push_str(&marker);
-					}
-				}
-			}
-
-			AstNode::StyleProp { path, css_property } => {
-				if let Some(value) = resolve(path, data)
-					&& let Some(formatted) = format_style_value(css_property, value)
-				{
-					let marker = format!("\x00SEAM_STYLE_{}\x00", ctx.style_attrs.len());
-					ctx.style_attrs.push(StyleAttrEntry {
+					let marker = format!("\x00SEAM_ATTR_{}\x00", ctx.attrs.len());
+					ctx.attrs.push(AttrEntry {
 						marker: marker.clone(),
-						css_property: css_property.clone(),
-						value: formatted,
+						attr_name: attr_name.clone(),
+						value,
+						target: target.clone(),
 					});
-					out.push_str(&marker);
+					out.buf.push_str(&marker);
 				}
 			}
+		}
 
-			AstNode::If { path, then_nodes, else_nodes } => {
-				let value = resolve(path, data);
-				if value.is_some_and(is_truthy) {
-					out.push_str(&render(then_nodes, data, ctx));
-				} else {
-					out.push_str(&render(else_nodes, data, ctx));
+		AstNode::StyleProp { path, css_property, unit, important } => {
+			let formatted = lookup(path, data, root).and_then(|value| match unit {
+				Some(unit) => format_style_value_with_unit(value, unit),
+				None => format_style_value(css_property, value),
+			});
+			if let Some(mut formatted) = formatted {
+				if *important {
+					formatted.push_str(" !important");
 				}
+				let marker = format!("\x00SEAM_STYLE_{}\x00", ctx.style_attrs.len());
+				ctx.style_attrs.push(StyleAttrEntry {
+					marker: marker.clone(),
+					css_property: css_property.clone(),
+					value: escape_attr_value(&formatted),
+				});
+				out.buf.push_str(&marker);
 			}
+		}
 
-			AstNode::Each { path, body_nodes } => {
-				if let Some(Value::Array(arr)) = resolve(path, data) {
-					for item in arr {
-						// Clone data and inject $ / $$ scope
-						let scoped = if let Value::Object(map) = data {
-							let mut new_map = map.clone();
-							if let Some(current_dollar) = new_map.get("$").cloned() {
-								new_map.insert("$$".to_string(), current_dollar);
-							}
-							new_map.insert("$".to_string(), item.clone());
-							Value::Object(new_map)
-						} else {
-							data.clone()
-						};
-						out.push_str(&render(body_nodes, &scoped, ctx));
-					}
-				}
+		AstNode::If { path, then_nodes, else_nodes } => {
+			let value = lookup(path, data, root);
+			if value.is_some_and(|v| ctx.is_condition_true(v)) {
+				render_into(then_nodes, data, root, ctx, out);
+			} else {
+				render_into(else_nodes, data, root, ctx, out);
 			}
+		}
 
-			AstNode::Match { path, branches } => {
-				let value = resolve(path, data);
-				let key = stringify(value.unwrap_or(&Value::Null));
-				for (branch_value, branch_nodes) in branches {
-					if *branch_value == key {
-						out.push_str(&render(branch_nodes, data, ctx));
-						break;
-					}
-				}
+		AstNode::Has { path, body_nodes } => {
+			// Presence, not truthiness: `0`, `false`, and `""` render; explicit null does not
+			if lookup(path, data, root).is_some_and(|v| !v.is_null()) {
+				render_into(body_nodes, data, root, ctx, out);
 			}
 		}
+
+		AstNode::IfLocale { locales, body_nodes } => {
+			if ctx.locale_matches(locales) {
+				render_into(body_nodes, data, root, ctx, out);
+			}
+		}
+
+		AstNode::Each { path, modifiers, body_nodes, else_nodes } => {
+			if !render_each(path, modifiers, body_nodes, data, root, ctx, out) {
+				render_into(else_nodes, data, root, ctx, out);
+			}
+		}
+
+		AstNode::Match { path, branches } => {
+			if let Some(branch_nodes) = select_match_arm(branches, lookup(path, data, root)) {
+				render_into(branch_nodes, data, root, ctx, out);
+			}
+		}
+	}
+}
+
+/// The `match` arm for `value`. Arms after `when:_` are unreachable; among the rest an
+/// exact literal beats a numeric range, and `_` catches whatever neither matched.
+fn select_match_arm<'a>(
+	branches: &'a [(String, Vec<AstNode>)],
+	value: Option<&Value>,
+) -> Option<&'a [AstNode]> {
+	// Null and missing match `when:null`, which is reserved like `_`: a string "null"
+	// falls through to the remaining arms. Without a `when:null` arm they keep matching
+	// the empty-string arm, as null stringifies to ""
+	let key = match value {
+		None | Some(Value::Null) => None,
+		Some(v) => Some(stringify(v)),
+	};
+	let default = branches.iter().position(|(v, _)| v == "_");
+	let reachable = &branches[..default.unwrap_or(branches.len())];
+	let number = value.and_then(Value::as_f64);
+	reachable
+		.iter()
+		.find(|(v, _)| match key {
+			None => v == "null",
+			Some(ref k) => v == k && v != "null",
+		})
+		.or_else(|| reachable.iter().find(|(v, _)| key.is_none() && v.is_empty()))
+		.or_else(|| number.and_then(|n| reachable.iter().find(|(v, _)| numeric_arm_matches(v, n))))
+		.or_else(|| default.map(|i| &branches[i]))
+		.map(|(_, nodes)| nodes.as_slice())
+}
+
+/// Render an `each` body once per item, with `$`/`$$` and the loop position in scope.
+/// Returns false when nothing was iterated: a missing or non-array value, or no items left.
+fn render_each(
+	path: &str,
+	modifiers: &EachModifiers,
+	body_nodes: &[AstNode],
+	data: &Value,
+	root: &Value,
+	ctx: &mut RenderContext,
+	out: &mut RenderOutput,
+) -> bool {
+	let range;
+	let arr = match lookup(path, data, root) {
+		Some(count) if modifiers.range => {
+			let count = count.as_u64().unwrap_or(0);
+			if count > ctx.options.max_range {
+				ctx.report(DiagnosticKind::RangeLimitExceeded, format!("each:range:{path}"));
+			}
+			range = range_items(count.min(ctx.options.max_range));
+			&range
+		}
+		Some(Value::Array(arr)) if !modifiers.range => arr,
+		_ => return false,
+	};
+	let items = each_items(arr, modifiers, ctx);
+	if items.is_empty() {
+		return false;
+	}
+	for (index, item) in items.into_iter().enumerate() {
+		// Clone data and inject $ / $$ scope, plus the loop position
+		let scoped = if let Value::Object(map) = data {
+			let mut new_map = map.clone();
+			if let Some(current_dollar) = new_map.get("$").cloned() {
+				new_map.insert("$$".to_string(), current_dollar);
+			}
+			new_map.insert("$".to_string(), item.clone());
+			new_map.insert("@index".to_string(), Value::from(index));
+			new_map.insert("@index1".to_string(), Value::from(index + 1));
+			new_map.insert("@even".to_string(), Value::Bool(index % 2 == 0));
+			new_map.insert("@odd".to_string(), Value::Bool(index % 2 == 1));
+			Value::Object(new_map)
+		} else {
+			data.clone()
+		};
+		render_into(body_nodes, &scoped, root, ctx, out);
+	}
+	true
+}
+
+/// `/path` is absolute and always reads the page root; other paths read the current scope.
+fn lookup<'a>(path: &str, data: &'a Value, root: &'a Value) -> Option<&'a Value> {
+	match path.strip_prefix('/') {
+		Some(rooted) => resolve(rooted, root),
+		None => resolve(path, data),
+	}
+}
+
+/// `1..=count` for `each:range`; a count that is not a non-negative integer is 0.
+fn range_items(count: u64) -> Vec<Value> {
+	(1..=count).map(Value::from).collect()
+}
+
+/// Filter and order `arr` for iteration per the `each` modifiers without touching the data.
+fn each_items<'a>(
+	arr: &'a [Value],
+	modifiers: &EachModifiers,
+	ctx: &RenderContext,
+) -> Vec<&'a Value> {
+	let mut items: Vec<&Value> = arr.iter().collect();
+	if let Some(ref key) = modifiers.where_path {
+		// Same truthiness as `if`; a missing field filters the item out
+		items.retain(|item| resolve_item(key, item).is_some_and(|v| ctx.is_condition_true(v)));
+	}
+	if let Some(ref key) = modifiers.sort_by {
+		// sort_by is stable: equal keys keep loader order
+		items.sort_by(|a, b| compare_sort_keys(resolve_item(key, a), resolve_item(key, b)));
+	}
+	if modifiers.reverse {
+		items.reverse();
+	}
+	let start = modifiers.offset.min(items.len());
+	let end = modifiers.limit.map_or(items.len(), |n| start.saturating_add(n).min(items.len()));
+	items.truncate(end);
+	items.drain(..start);
+	items
+}
+
+/// Resolve an item-relative path: `$` is the item itself, `$.a.b` (or `a.b`) a field.
+fn resolve_item<'a>(key: &str, item: &'a Value) -> Option<&'a Value> {
+	match key {
+		"$" => Some(item),
+		_ => resolve(key.strip_prefix("$.").unwrap_or(key), item),
+	}
+}
+
+/// Numbers compare numerically; anything else by its rendered string. Missing sorts first.
+fn compare_sort_keys(a: Option<&Value>, b: Option<&Value>) -> Ordering {
+	match (a, b) {
+		(Some(Value::Number(x)), Some(Value::Number(y))) => {
+			x.as_f64().partial_cmp(&y.as_f64()).unwrap_or(Ordering::Equal)
+		}
+		_ => stringify(a.unwrap_or(&Value::Null)).cmp(&stringify(b.unwrap_or(&Value::Null))),
 	}
+}
 
+/// Escaping for every value spliced into an attribute (plain and `style`). Covers both
+/// quote styles so a value cannot close the attribute it lands in, whichever quoting
+/// surrounding markup uses. Text slots keep `escape_html`.
+pub fn escape_attr_value(s: &str) -> String {
+	let mut out = String::with_capacity(s.len());
+	for ch in s.chars() {
+		match ch {
+			'&' => out.push_str("&amp;"),
+			'<' => out.push_str("&lt;"),
+			'>' => out.push_str("&gt;"),
+			'"' => out.push_str("&quot;"),
+			'\'' => out.push_str("&#39;"),
+			c => out.push(c),
+		}
+	}
 	out
 }
 
+/// Start of the first `<name` opening tag at or after `from`, stepping over other tags
+/// (closing ones included). Names compare ASCII case-insensitively.
+pub(crate) fn find_named_tag(html: &str, from: usize, name: &str) -> Option<usize> {
+	let mut at = from;
+	while let Some((rel, _)) = next_tag(&html[at..]) {
+		let start = at + rel;
+		if html[start + 1..find_tag_name_end(html, start)].eq_ignore_ascii_case(name) {
+			return Some(start);
+		}
+		at = tag_end(html, start)?;
+	}
+	None
+}
+
+/// Extend a streaming flush boundary past the tags that `@tag` selectors attach to.
+/// `None` while a selector's target has not been rendered yet.
+fn selector_targets_end(html: &str, attrs: &[AttrEntry], end: usize) -> Option<usize> {
+	attrs.iter().try_fold(end, |end, entry| {
+		let (Some(name), Some(pos)) = (&entry.target, html.find(&entry.marker)) else {
+			return Some(end);
+		};
+		let start = find_named_tag(html, pos, name)?;
+		Some(end.max(tag_end(html, start)?))
+	})
+}
+
 /// Find the byte offset where the tag name ends (first whitespace, `>`, or `/`).
 fn find_tag_name_end(html: &str, abs_start: usize) -> usize {
 	let bytes = html.as_bytes();
@@ -145,47 +529,160 @@ fn find_tag_name_end(html: &str, abs_start: usize) -> usize {
 	end
 }
 
-pub(crate) fn inject_attributes(mut html: String, attrs: &[AttrEntry]) -> String {
+/// Every entry is inserted right after its tag name, so walking them last-to-first
+/// leaves slots targeting the same element in source order, ahead of existing attributes.
+pub(crate) fn inject_attributes(mut html: String, attrs: &[AttrEntry], sort: bool) -> String {
 	for entry in attrs.iter().rev() {
 		if let Some(pos) = html.find(&entry.marker) {
 			html = format!("{}{}", &html[..pos], &html[pos + entry.marker.len()..]);
-			if let Some(tag_rel) = html[pos..].find('<') {
-				let abs_start = pos + tag_rel;
+			// A closing tag before any opening one means the slot has no target; drop it
+			let target = match &entry.target {
+				Some(name) => find_named_tag(&html, pos, name),
+				None => next_tag(&html[pos..]).and_then(|(rel, opens)| opens.then_some(pos + rel)),
+			};
+			if let Some(abs_start) = target {
 				let tag_name_end = find_tag_name_end(&html, abs_start);
 				let injection = format!(r#" {}="{}""#, entry.attr_name, entry.value);
 				html = format!("{}{}{}", &html[..tag_name_end], injection, &html[tag_name_end..]);
+				if sort {
+					sort_tag_attributes(&mut html, abs_start);
+				}
 			}
 		}
 	}
 	html
 }
 
-pub(crate) fn inject_style_attributes(mut html: String, entries: &[StyleAttrEntry]) -> String {
+/// Rewrite the opening tag at `tag_start` with its attributes in name order (stable for
+/// duplicates). Values are kept verbatim; separators collapse to one space, and any
+/// trailing `/` of a void tag is preserved.
+fn sort_tag_attributes(html: &mut String, tag_start: usize) {
+	let name_end = find_tag_name_end(html, tag_start);
+	let bytes = html.as_bytes();
+	let mut attrs: Vec<(usize, usize, usize)> = Vec::new(); // (start, name_end, end)
+	let mut i = name_end;
+	let tag_end = loop {
+		while i < bytes.len() && bytes[i].is_ascii_whitespace() {
+			i += 1;
+		}
+		if i >= bytes.len() {
+			return;
+		}
+		if bytes[i] == b'>' || (bytes[i] == b'/' && bytes.get(i + 1) == Some(&b'>')) {
+			break i;
+		}
+		let start = i;
+		while i < bytes.len()
+			&& !matches!(bytes[i], b'=' | b'>' | b'/')
+			&& !bytes[i].is_ascii_whitespace()
+		{
+			i += 1;
+		}
+		let attr_name_end = i;
+		if bytes.get(i) == Some(&b'=') {
+			i += 1;
+			match bytes.get(i) {
+				Some(&q @ (b'"' | b'\'')) => {
+					let Some(close) = html[i + 1..].find(q as char) else {
+						return;
+					};
+					i += close + 2;
+				}
+				_ => {
+					while i < bytes.len() && bytes[i] != b'>' && !bytes[i].is_ascii_whitespace() {
+						i += 1;
+					}
+				}
+			}
+		} else if i == start {
+			// Stray `/` inside the tag; skip it rather than loop
+			i += 1;
+			continue;
+		}
+		attrs.push((start, attr_name_end, i));
+	};
+	let tail_start = attrs.last().map_or(name_end, |a| a.2);
+	attrs.sort_by(|a, b| html[a.0..a.1].cmp(&html[b.0..b.1]));
+	let mut rebuilt = String::with_capacity(tag_end - name_end);
+	for (start, _, end) in &attrs {
+		rebuilt.push(' ');
+		rebuilt.push_str(&html[*start..*end]);
+	}
+	rebuilt.push_str(&html[tail_start..tag_end]);
+	html.replace_range(name_end..tag_end, &rebuilt);
+}
+
+/// Set `property` in an existing `style` value: replace its first declaration in place,
+/// or append one. A static `margin-top:4px` overridden by a slot stays a single declaration.
+fn merge_style_declaration(existing: &str, property: &str, value: &str) -> String {
+	let mut replaced = false;
+	let decls: Vec<String> = existing
+		.split(';')
+		.map(|decl| match decl.split_once(':') {
+			Some((name, _)) if !replaced && name.trim().eq_ignore_ascii_case(property) => {
+				replaced = true;
+				let indent = &decl[..decl.len() - decl.trim_start().len()];
+				format!("{indent}{property}:{value}")
+			}
+			_ => decl.to_string(),
+		})
+		.collect();
+	if replaced { decls.join(";") } else { format!("{existing};{property}:{value}") }
+}
+
+/// Byte range of the `style="..."` value within opening tag `tag`. Only attribute
+/// positions count: `data-style="..."` or `style="` inside another quoted value do not.
+fn style_value_range(tag: &str) -> Option<(usize, usize)> {
+	let bytes = tag.as_bytes();
+	let mut quote = None;
+	for i in 1..bytes.len() {
+		match quote {
+			Some(q) if bytes[i] == q => quote = None,
+			Some(_) => {}
+			None if matches!(bytes[i], b'"' | b'\'') => quote = Some(bytes[i]),
+			None if bytes[i - 1].is_ascii_whitespace() && tag[i..].starts_with("style=") => {
+				let Some(&q @ (b'"' | b'\'')) = bytes.get(i + 6) else {
+					continue;
+				};
+				let start = i + 7;
+				let end = tag[start..].find(q as char).map_or(tag.len(), |p| start + p);
+				return Some((start, end));
+			}
+			None => {}
+		}
+	}
+	None
+}
+
+pub(crate) fn inject_style_attributes(
+	mut html: String,
+	entries: &[StyleAttrEntry],
+	sort: bool,
+) -> String {
 	for entry in entries {
 		if let Some(pos) = html.find(&entry.marker) {
 			// Remove marker
 			html = format!("{}{}", &html[..pos], &html[pos + entry.marker.len()..]);
 			// Find next opening tag
-			if let Some(tag_rel) = html[pos..].find('<') {
+			if let Some((tag_rel, true)) = next_tag(&html[pos..]) {
 				let abs_start = pos + tag_rel;
-				let tag_end = html[abs_start..].find('>').map(|p| abs_start + p).unwrap_or(html.len());
-				let tag_content = &html[abs_start..tag_end];
+				let end = tag_end(&html, abs_start).unwrap_or(html.len());
 
-				if let Some(style_rel) = tag_content.find("style=\"") {
+				if let Some((value_start, value_end)) = style_value_range(&html[abs_start..end]) {
 					// Merge into existing style attribute
-					let abs_style_val_start = abs_start + style_rel + 7;
-					let style_val_end = html[abs_style_val_start..]
-						.find('"')
-						.map(|p| abs_style_val_start + p)
-						.unwrap_or(html.len());
-					let injection = format!(";{}:{}", entry.css_property, entry.value);
-					html.insert_str(style_val_end, &injection);
+					let range = abs_start + value_start..abs_start + value_end;
+					let merged =
+						merge_style_declaration(&html[range.clone()], &entry.css_property, &entry.value);
+					html.replace_range(range, &merged);
 				} else {
 					// Insert new style attribute after tag name
 					let tag_name_end = find_tag_name_end(&html, abs_start);
 					let injection = format!(r#" style="{}:{}""#, entry.css_property, entry.value);
 					html = format!("{}{}{}", &html[..tag_name_end], injection, &html[tag_name_end..]);
 				}
+				if sort {
+					sort_tag_attributes(&mut html, abs_start);
+				}
 			}
 		}
 	}
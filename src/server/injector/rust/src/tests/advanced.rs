@@ -134,6 +134,42 @@ fn style_string_value() {
 	assert_eq!(html, r#"<div style="color:blue">text</div>"#);
 }
 
+#[test]
+fn style_slot_overrides_static_declaration() {
+	let html = inject_no_script(
+		r#"<!--seam:mt:style:margin-top--><div style="color:red; Margin-Top: 4px">text</div>"#,
+		&json!({"mt": 16}),
+	);
+	assert_eq!(html, r#"<div style="color:red; margin-top:16px">text</div>"#);
+}
+
+#[test]
+fn style_slot_explicit_units() {
+	let tmpl = "<!--seam:mt:style:margin-top:rem--><!--seam:w:style:width:%--><div></div>";
+	let html = inject_no_script(tmpl, &json!({"mt": 1.5, "w": 50}));
+	assert_eq!(html, r#"<div style="margin-top:1.5rem;width:50%"></div>"#);
+}
+
+#[test]
+fn style_slot_important() {
+	let tmpl = "<!--seam:c:style:color:!important--><!--seam:m:style:margin:!important--><p></p>";
+	let html = inject_no_script(tmpl, &json!({"c": "red", "m": 4}));
+	assert_eq!(html, r#"<p style="color:red !important;margin:4px !important"></p>"#);
+}
+
+#[test]
+fn style_slot_string_with_unit_keeps_value() {
+	let html = inject_no_script("<!--seam:w:style:width--><div></div>", &json!({"w": "10vw"}));
+	assert_eq!(html, r#"<div style="width:10vw"></div>"#);
+}
+
+#[test]
+fn style_slot_escapes_value() {
+	let tmpl = "<!--seam:f:style:font-family--><p>x</p>";
+	let html = inject_no_script(tmpl, &json!({"f": "\"a\"><b>'"}));
+	assert_eq!(html, r#"<p style="font-family:&quot;a&quot;&gt;&lt;b&gt;&#39;">x</p>"#);
+}
+
 // -- Float-hoisted metadata --
 
 #[test]
@@ -169,6 +205,21 @@ fn float_dual_attrs_on_meta() {
 	assert_eq!(html, r#"<meta property="og:title" content="My Page" name="og">"#);
 }
 
+#[test]
+fn multiple_attrs_keep_source_order_before_existing() {
+	let tmpl = concat!(
+		"<!--seam:id:attr:id--><!--seam:off:attr:disabled--><!--seam:t:attr:title-->",
+		r#"<button class="btn">x</button>"#,
+	);
+	let data = json!({"id": "b1", "off": true, "t": "Go"});
+	let expected = r#"<button id="b1" disabled="" title="Go" class="btn">x</button>"#;
+	assert_eq!(inject_no_script(tmpl, &data), expected);
+
+	let mut streamed = Vec::new();
+	inject_no_script_to_writer(tmpl, &data, &mut streamed).unwrap();
+	assert_eq!(String::from_utf8(streamed).unwrap(), expected);
+}
+
 // -- HTML slot no escape --
 
 #[test]
@@ -202,6 +253,36 @@ fn match_numeric_value() {
 	assert_eq!(html, "OK");
 }
 
+#[test]
+fn match_numeric_ranges() {
+	let tmpl = concat!(
+		"<!--seam:match:code-->",
+		"<!--seam:when:200..299-->Success",
+		"<!--seam:when:>=400-->Error",
+		"<!--seam:when:_-->Other",
+		"<!--seam:endmatch-->"
+	);
+	assert_eq!(inject_no_script(tmpl, &json!({"code": 204})), "Success");
+	assert_eq!(inject_no_script(tmpl, &json!({"code": 299})), "Success");
+	assert_eq!(inject_no_script(tmpl, &json!({"code": 500})), "Error");
+	assert_eq!(inject_no_script(tmpl, &json!({"code": 302})), "Other");
+	// Ranges only apply to numbers
+	assert_eq!(inject_no_script(tmpl, &json!({"code": "204"})), "Other");
+}
+
+#[test]
+fn match_exact_arm_beats_range() {
+	let tmpl = concat!(
+		"<!--seam:match:code-->",
+		"<!--seam:when:400..499-->Client error",
+		"<!--seam:when:404-->Not Found",
+		"<!--seam:endmatch-->"
+	);
+	assert_eq!(inject_no_script(tmpl, &json!({"code": 404})), "Not Found");
+	assert_eq!(inject_no_script(tmpl, &json!({"code": 410})), "Client error");
+	assert_eq!(inject_no_script(tmpl, &json!({"code": 200})), "");
+}
+
 // -- Non-boolean attr with null value --
 
 #[test]
@@ -240,7 +321,7 @@ fn float_full_document() {
 		"</div></body></html>",
 	);
 	let data = json!({"t": "Home", "d": "Welcome page", "body": "Hello world"});
-	let html = inject(tmpl, &data, "__data");
+	let html = inject(tmpl, &data);
 
 	// <head> section has injected values
 	let head = html.split("</head>").next().unwrap();
@@ -280,3 +361,212 @@ fn malformed_template_still_renders() {
 	assert_eq!(diags.len(), 1);
 	assert_eq!(diags[0].kind, DiagnosticKind::UnmatchedBlockClose);
 }
+
+#[test]
+fn duplicate_match_arm_reported_once_per_duplicate() {
+	let tmpl = "<!--seam:match:role--><!--seam:when:admin-->A<!--seam:when:user-->U\
+		<!--seam:when:admin-->B<!--seam:when:admin-->C<!--seam:endmatch-->";
+	let (html, diags) = inject_no_script_with_diagnostics(tmpl, &json!({"role": "admin"}));
+	// First arm wins
+	assert_eq!(html, "A");
+	assert_eq!(diags.len(), 2);
+	assert!(diags.iter().all(|d| d.kind == DiagnosticKind::DuplicateMatchArm));
+	assert!(diags.iter().all(|d| d.directive == "when:admin"));
+}
+
+#[test]
+fn match_arm_after_default_reported() {
+	let tmpl =
+		"<!--seam:match:role--><!--seam:when:_-->any<!--seam:when:guest-->G<!--seam:endmatch-->";
+	let (html, diags) = inject_no_script_with_diagnostics(tmpl, &json!({"role": "guest"}));
+	assert_eq!(html, "any");
+	assert_eq!(diags.len(), 1);
+	assert_eq!(diags[0].kind, DiagnosticKind::DuplicateMatchArm);
+	assert_eq!(diags[0].directive, "when:guest");
+}
+
+fn nested_each(depth: usize) -> String {
+	format!("{}x{}", "<!--seam:each:/items-->".repeat(depth), "<!--seam:endeach-->".repeat(depth))
+}
+
+#[test]
+fn nested_each_past_depth_limit_stops_expanding() {
+	let data = json!({"items": [1]});
+	let (html, diags) = inject_no_script_with_diagnostics(&nested_each(DEFAULT_MAX_DEPTH), &data);
+	assert_eq!(html, "x");
+	assert!(diags.is_empty());
+
+	let (html, diags) = inject_no_script_with_diagnostics(&nested_each(200), &data);
+	assert_eq!(html, "");
+	assert_eq!(diags.len(), 1);
+	assert_eq!(diags[0].kind, DiagnosticKind::DepthLimitExceeded);
+	assert_eq!(diags[0].directive, "each:/items");
+}
+
+#[test]
+fn depth_limit_is_configurable() {
+	let data = json!({"items": [1, 2, 3]});
+	let compiled = compile_with_max_depth(&nested_each(3), 2);
+	assert_eq!(inject_precompiled(&compiled, &data), "");
+	assert_eq!(compiled.diagnostics().len(), 1);
+	assert_eq!(compiled.diagnostics()[0].kind, DiagnosticKind::DepthLimitExceeded);
+	let compiled = compile_with_max_depth(&nested_each(3), 3);
+	assert_eq!(inject_precompiled(&compiled, &data), "x".repeat(27));
+	assert!(compiled.diagnostics().is_empty());
+}
+
+#[test]
+fn hostile_nesting_compiles_on_a_small_stack() {
+	const LEVELS: usize = 50_000;
+	let handle = std::thread::Builder::new()
+		.stack_size(2 * 1024 * 1024)
+		.spawn(|| {
+			// The inert `endeach` inside `raw` must not close a skipped block
+			let tmpl = format!(
+				"{}<!--seam:raw--><!--seam:endeach-->x<!--seam:endraw-->{}<p>after</p>",
+				"<!--seam:if:a--><!--seam:each:/items-->".repeat(LEVELS / 2),
+				"<!--seam:endeach--><!--seam:endif:a-->".repeat(LEVELS / 2),
+			);
+			inject_no_script_with_diagnostics(&tmpl, &json!({"a": true, "items": [1]}))
+		})
+		.unwrap();
+	let (html, diags) = handle.join().unwrap();
+	assert_eq!(html, "<p>after</p>");
+	assert_eq!(diags.len(), 1);
+	assert_eq!(diags[0].kind, DiagnosticKind::DepthLimitExceeded);
+}
+
+#[test]
+fn match_default_arm_catches_unlisted_value() {
+	let tmpl =
+		"<!--seam:match:role--><!--seam:when:admin-->A<!--seam:when:_-->other<!--seam:endmatch-->";
+	let (html, diags) = inject_no_script_with_diagnostics(tmpl, &json!({"role": "guest"}));
+	assert_eq!(html, "other");
+	assert!(diags.is_empty());
+	assert_eq!(inject_no_script(tmpl, &json!({"role": "admin"})), "A");
+}
+
+#[test]
+fn match_null_arm_distinct_from_empty_string() {
+	let tmpl = "<!--seam:match:bio--><!--seam:when:null-->N<!--seam:when:-->E\
+		<!--seam:when:_-->V<!--seam:endmatch-->";
+	assert_eq!(inject_no_script(tmpl, &json!({"bio": null})), "N");
+	assert_eq!(inject_no_script(tmpl, &json!({})), "N");
+	assert_eq!(inject_no_script(tmpl, &json!({"bio": ""})), "E");
+	assert_eq!(inject_no_script(tmpl, &json!({"bio": "hi"})), "V");
+	// The string "null" is a value, not the null state
+	assert_eq!(inject_no_script(tmpl, &json!({"bio": "null"})), "V");
+}
+
+#[test]
+fn match_null_falls_back_to_empty_arm_without_null_arm() {
+	let tmpl = "<!--seam:match:bio--><!--seam:when:-->E<!--seam:when:_-->V<!--seam:endmatch-->";
+	assert_eq!(inject_no_script(tmpl, &json!({"bio": null})), "E");
+	assert_eq!(inject_no_script(tmpl, &json!({})), "E");
+	assert_eq!(inject_no_script(tmpl, &json!({"bio": "hi"})), "V");
+}
+
+// -- Locale blocks --
+
+fn render_in(tmpl: &str, locale: Option<&str>) -> String {
+	let opts = RenderOptions { locale, ..RenderOptions::default() };
+	inject_no_script_with_options(tmpl, &json!({}), &opts)
+}
+
+#[test]
+fn iflocale_exact_match_renders_body() {
+	let tmpl = "<!--seam:iflocale:fr-CA-->Bonjour<!--seam:endiflocale-->!";
+	assert_eq!(render_in(tmpl, Some("fr-CA")), "Bonjour!");
+	assert_eq!(render_in(tmpl, Some("fr-ca")), "Bonjour!");
+}
+
+#[test]
+fn iflocale_prefix_matches_region_subtag() {
+	let tmpl = "<!--seam:iflocale:fr-->Bonjour<!--seam:endiflocale-->";
+	assert_eq!(render_in(tmpl, Some("fr-CA")), "Bonjour");
+	assert_eq!(render_in(tmpl, Some("fr")), "Bonjour");
+	// A prefix stops at a subtag boundary
+	assert_eq!(render_in(tmpl, Some("fry")), "");
+}
+
+#[test]
+fn iflocale_non_match_renders_nothing() {
+	let tmpl = "<!--seam:iflocale:de,ja-->Hallo<!--seam:endiflocale-->";
+	assert_eq!(render_in(tmpl, Some("en-US")), "");
+	assert_eq!(render_in(tmpl, Some("ja")), "Hallo");
+}
+
+#[test]
+fn iflocale_without_locale_follows_option() {
+	let tmpl = "<!--seam:iflocale:en-->Hi<!--seam:endiflocale-->";
+	assert_eq!(render_in(tmpl, None), "");
+	let opts = RenderOptions { iflocale_when_unset: true, ..RenderOptions::default() };
+	assert_eq!(inject_no_script_with_options(tmpl, &json!({}), &opts), "Hi");
+}
+
+#[test]
+fn iflocale_unclosed_reports_diagnostic() {
+	let (_, diags) = inject_no_script_with_diagnostics("<!--seam:iflocale:en-->Hi", &json!({}));
+	assert_eq!(diags.len(), 1);
+	assert_eq!(diags[0].directive, "iflocale:en");
+}
+
+// -- Quote-aware splice targets --
+
+#[test]
+fn attr_lands_on_tag_with_lt_in_value() {
+	let tmpl = r#"<!--seam:href:attr:href--><a data-html="<b>x</b>">go</a><i>after</i>"#;
+	let html = inject_no_script(tmpl, &json!({"href": "/next"}));
+	assert_eq!(html, r#"<a href="/next" data-html="<b>x</b>">go</a><i>after</i>"#);
+}
+
+#[test]
+fn attr_skips_bare_lt_in_text_before_target() {
+	let tmpl = "<!--seam:t:attr:title-->1 < 2 <b>x</b>";
+	let html = inject_no_script(tmpl, &json!({"t": "T"}));
+	assert_eq!(html, r#"1 < 2 <b title="T">x</b>"#);
+}
+
+#[test]
+fn style_merges_past_gt_in_earlier_attribute_value() {
+	let tmpl = r#"<!--seam:c:style:color--><p data-tip="a>b" style="margin:0">x</p>"#;
+	let html = inject_no_script(tmpl, &json!({"c": "red"}));
+	assert_eq!(html, r#"<p data-tip="a>b" style="margin:0;color:red">x</p>"#);
+}
+
+#[test]
+fn style_ignores_look_alike_attribute_names() {
+	let tmpl = r#"<!--seam:c:style:color--><p data-style="x" title='style="y"'>x</p>"#;
+	let html = inject_no_script(tmpl, &json!({"c": "red"}));
+	assert_eq!(html, r#"<p style="color:red" data-style="x" title='style="y"'>x</p>"#);
+}
+
+#[test]
+fn style_merges_into_single_quoted_style() {
+	let tmpl = r#"<!--seam:c:style:color--><p style='margin:0'>x</p>"#;
+	let html = inject_no_script(tmpl, &json!({"c": "red"}));
+	assert_eq!(html, r#"<p style='margin:0;color:red'>x</p>"#);
+}
+
+// -- Opaque elements: pre/textarea/script/style --
+
+#[test]
+fn textarea_keeps_literal_directive_text_and_whitespace() {
+	let tmpl = "<textarea>  <!--seam:if:x-->\n\t<!--seam:code-->  </textarea>";
+	let html = inject_no_script(tmpl, &json!({"x": false, "code": "a < b"}));
+	assert_eq!(html, "<textarea>  <!--seam:if:x-->\n\ta &lt; b  </textarea>");
+}
+
+#[test]
+fn directives_resume_after_opaque_element() {
+	let tmpl = "<textarea><!--seam:each:xs--></textarea><!--seam:if:x-->yes<!--seam:endif:x-->";
+	let html = inject_no_script(tmpl, &json!({"x": true}));
+	assert_eq!(html, "<textarea><!--seam:each:xs--></textarea>yes");
+}
+
+#[test]
+fn pre_keeps_literal_directive_text_and_whitespace() {
+	let tmpl = "<pre>  <!--seam:if:x-->\n\t<!--seam:code-->  </pre>";
+	let html = inject_no_script(tmpl, &json!({"x": false, "code": "a < b"}));
+	assert_eq!(html, "<pre>  <!--seam:if:x-->\n\ta &lt; b  </pre>");
+}
@@ -54,6 +54,37 @@ fn two_text_slots_keep_boundary_comment() {
 	assert_eq!(html, "<p>Ada<!-- -->Lovelace</p>");
 }
 
+// -- Array index paths --
+
+#[test]
+fn array_index_in_slot_attr_and_if() {
+	let tmpl = concat!(
+		"<!--seam:items.0.href:attr:href--><a><!--seam:items.0.name--></a>",
+		"<!--seam:if:items.1--><b>second</b><!--seam:endif:items.1-->",
+	);
+	let data = json!({"items": [{"name": "First", "href": "/a"}]});
+	assert_eq!(inject_no_script(tmpl, &data), r#"<a href="/a">First</a>"#);
+}
+
+#[test]
+fn array_index_out_of_range_is_empty() {
+	let data = json!({"items": [{"name": "a"}]});
+	assert_eq!(inject_no_script("<p><!--seam:items.9.name--></p>", &data), "<p></p>");
+}
+
+#[test]
+fn array_index_on_non_array_is_empty() {
+	let data = json!({"items": {"name": "a"}});
+	assert_eq!(inject_no_script("<p><!--seam:items.0.name--></p>", &data), "<p></p>");
+}
+
+#[test]
+fn array_index_in_style_slot() {
+	let tmpl = "<!--seam:sizes.1:style:width--><div></div>";
+	let data = json!({"sizes": [10, 20]});
+	assert_eq!(inject_no_script(tmpl, &data), r#"<div style="width:20px"></div>"#);
+}
+
 // -- Raw HTML --
 
 #[test]
@@ -63,6 +94,31 @@ fn raw_slot() {
 	assert_eq!(html, "<div><b>bold</b></div>");
 }
 
+// -- URL encoding --
+
+#[test]
+fn urlencode_text_slot() {
+	let tmpl = "<a>/search?q=<!--seam:q:urlencode--></a>";
+	assert_eq!(
+		inject_no_script(tmpl, &json!({"q": "fish & chips"})),
+		"<a>/search?q=fish%20%26%20chips</a>"
+	);
+}
+
+#[test]
+fn urlencode_missing_path_renders_empty() {
+	assert_eq!(inject_no_script("<p><!--seam:q:urlencode--></p>", &json!({})), "<p></p>");
+}
+
+#[test]
+fn urlencode_attr_slot() {
+	let tmpl = "<!--seam:q:urlencode:attr:href--><a>search</a>";
+	assert_eq!(
+		inject_no_script(tmpl, &json!({"q": "a b&c=\"d\""})),
+		r#"<a href="a%20b%26c%3D%22d%22">search</a>"#
+	);
+}
+
 // -- Attribute slots --
 
 #[test]
@@ -77,12 +133,161 @@ fn attr_slot_escapes_value() {
 	assert_eq!(html, r#"<span title="a&quot;b">x</span>"#);
 }
 
+#[test]
+fn attr_slot_escapes_dangerous_chars() {
+	let html =
+		inject_no_script("<!--seam:v:attr:title--><span>x</span>", &json!({"v": "& < > \" '"}));
+	assert_eq!(html, r#"<span title="&amp; &lt; &gt; &quot; &#39;">x</span>"#);
+}
+
+#[test]
+fn text_slot_escaping_unchanged() {
+	let html = inject_no_script("<p><!--seam:v--></p>", &json!({"v": "'"}));
+	assert_eq!(html, "<p>&#x27;</p>");
+}
+
 #[test]
 fn attr_slot_missing_skips() {
 	let html = inject_no_script("<!--seam:missing:attr:class--><div>hi</div>", &json!({}));
 	assert_eq!(html, "<div>hi</div>");
 }
 
+#[test]
+fn attr_slot_skips_whitespace_and_comments_to_target() {
+	let tmpl = "<ul><!--seam:cls:attr:class-->\n  <!-- item --> <li>a</li></ul>";
+	let (html, diags) = inject_no_script_with_diagnostics(tmpl, &json!({"cls": "on"}));
+	assert_eq!(html, "<ul>\n  <!-- item --> <li class=\"on\">a</li></ul>");
+	assert!(diags.is_empty(), "{diags:?}");
+}
+
+#[test]
+fn dangling_attr_slot_is_dropped_and_reported() {
+	let tmpl = "<li><!--seam:cls:attr:class-->text</li><li>b</li>";
+	let (html, diags) = inject_no_script_with_diagnostics(tmpl, &json!({"cls": "on"}));
+	assert_eq!(html, "<li>text</li><li>b</li>");
+	assert_eq!(diags.len(), 1);
+	assert_eq!(diags[0].kind, DiagnosticKind::DanglingAttrSlot);
+	assert_eq!(diags[0].directive, "cls:attr:class");
+
+	// A following directive also ends the search, even if a tag comes after it
+	let (_, diags) = inject_no_script_with_diagnostics(
+		"<!--seam:w:style:width--><!--seam:name--><b></b>",
+		&json!({}),
+	);
+	assert_eq!(diags.len(), 1);
+	assert_eq!(diags[0].directive, "w:style:width");
+}
+
+#[test]
+fn attr_slot_skips_block_markers_to_target() {
+	let tmpl = "<!--seam:c:attr:class--><!--seam:if:x--><div>a</div><!--seam:endif:x-->";
+	let (html, diags) = inject_no_script_with_diagnostics(tmpl, &json!({"c": "on", "x": true}));
+	assert_eq!(html, r#"<div class="on">a</div>"#);
+	assert!(diags.is_empty(), "{diags:?}");
+
+	let tmpl = "<!--seam:each:rows--><!--seam:if:@even--><!--seam:$.c:attr:class-->\
+		<!--seam:endif:@even--><tr></tr><!--seam:endeach-->";
+	let data = json!({"rows": [{"c": "a"}, {"c": "b"}, {"c": "c"}]});
+	let (html, diags) = inject_no_script_with_diagnostics(tmpl, &data);
+	assert_eq!(html, r#"<tr class="a"></tr><tr></tr><tr class="c"></tr>"#);
+	assert!(diags.is_empty(), "{diags:?}");
+}
+
+#[test]
+fn attrif_truthy_adds_valueless_attr() {
+	let tmpl = "<!--seam:isCollapsed:attrif:hidden--><div>hi</div>";
+	let html = inject_no_script(tmpl, &json!({"isCollapsed": "yes"}));
+	assert_eq!(html, r#"<div hidden="">hi</div>"#);
+}
+
+#[test]
+fn attrif_falsy_omits_attr() {
+	let tmpl = "<!--seam:isCollapsed:attrif:hidden--><div>hi</div>";
+	for data in [json!({"isCollapsed": 0}), json!({"isCollapsed": ""}), json!({})] {
+		assert_eq!(inject_no_script(tmpl, &data), "<div>hi</div>", "{data}");
+	}
+}
+
+#[test]
+fn attrif_never_injects_the_value() {
+	let tmpl = "<!--seam:label:attrif:data-labelled--><!--seam:label:attr:title--><i></i>";
+	let html = inject_no_script(tmpl, &json!({"label": "x"}));
+	assert_eq!(html, r#"<i data-labelled="" title="x"></i>"#);
+}
+
+#[test]
+fn sort_attributes_orders_injected_element() {
+	let tmpl = concat!(
+		"<!--seam:t:attr:title--><!--seam:w:style:width--><!--seam:id:attr:data-id-->",
+		r#"<a href="/x" class='c' hidden>go</a><!--seam:t:attr:title--><br/>"#
+	);
+	let data = json!({"t": "T", "w": 4, "id": 7});
+	assert_eq!(
+		inject_no_script(tmpl, &data),
+		r#"<a title="T" data-id="7" style="width:4px" href="/x" class='c' hidden>go</a><br title="T"/>"#
+	);
+	let opts = RenderOptions { sort_attributes: true, ..RenderOptions::default() };
+	assert_eq!(
+		inject_no_script_with_options(tmpl, &data, &opts),
+		r#"<a class='c' data-id="7" hidden href="/x" style="width:4px" title="T">go</a><br title="T"/>"#
+	);
+}
+
+#[test]
+fn sort_attributes_keeps_quoted_gt_in_value() {
+	let tmpl = r#"<!--seam:a:attr:alt--><img src="a>b.png" alt0 />"#;
+	let opts = RenderOptions { sort_attributes: true, ..RenderOptions::default() };
+	assert_eq!(
+		inject_no_script_with_options(tmpl, &json!({"a": "x"}), &opts),
+		r#"<img alt="x" alt0 src="a>b.png" />"#
+	);
+}
+
+#[test]
+fn attr_tag_selector_targets_inner_input() {
+	let tmpl = r#"<!--seam:id:attr:id@input--><label>Name <span>*</span><input type="text"></label>"#;
+	assert_eq!(
+		inject_no_script(tmpl, &json!({"id": "email"})),
+		r#"<label>Name <span>*</span><input id="email" type="text"></label>"#
+	);
+	// Without the selector the wrapper gets it
+	let tmpl = "<!--seam:id:attr:id--><label>Name <input></label>";
+	assert_eq!(
+		inject_no_script(tmpl, &json!({"id": "email"})),
+		r#"<label id="email">Name <input></label>"#
+	);
+}
+
+#[test]
+fn attrif_tag_selector_and_missing_target() {
+	let tmpl = "<!--seam:req:attrif:required@INPUT--><label>x <input></label>";
+	assert_eq!(
+		inject_no_script(tmpl, &json!({"req": true})),
+		r#"<label>x <input required=""></label>"#
+	);
+	// No such tag: the attribute is dropped and reported
+	let tmpl = "<!--seam:id:attr:id@select--><label>x <input></label>";
+	let (html, diags) = inject_no_script_with_diagnostics(tmpl, &json!({"id": "a"}));
+	assert_eq!(html, "<label>x <input></label>");
+	assert_eq!(diags.len(), 1);
+	assert_eq!(diags[0].kind, DiagnosticKind::MissingSelectorTarget);
+	assert_eq!(diags[0].directive, "id:attr:id@select");
+}
+
+#[test]
+fn attr_names_with_at_sign_are_not_selectors() {
+	let tmpl = "<!--seam:h:attr:@click--><button>go</button>";
+	let (html, diags) = inject_no_script_with_diagnostics(tmpl, &json!({"h": "run()"}));
+	assert_eq!(html, r#"<button @click="run()">go</button>"#);
+	assert!(diags.is_empty(), "{diags:?}");
+	// The last `@` still selects a tag after such a name
+	let tmpl = "<!--seam:h:attr:@click@button--><div><button>go</button></div>";
+	assert_eq!(
+		inject_no_script(tmpl, &json!({"h": "run()"})),
+		r#"<div><button @click="run()">go</button></div>"#
+	);
+}
+
 // -- Conditional --
 
 #[test]
@@ -141,6 +346,20 @@ fn cond_nested_different_paths() {
 	assert_eq!(inject_no_script(tmpl, &json!({"a": false, "b": true})), "");
 }
 
+#[test]
+fn cond_whitespace_string_truthy_by_default() {
+	let tmpl = "<!--seam:if:s-->yes<!--seam:else-->no<!--seam:endif:s-->";
+	assert_eq!(inject_no_script(tmpl, &json!({"s": "   "})), "yes");
+}
+
+#[test]
+fn cond_whitespace_string_falsy_when_opted_in() {
+	let tmpl = "<!--seam:if:s-->yes<!--seam:else-->no<!--seam:endif:s-->";
+	let opts = RenderOptions { blank_strings_falsy: true, ..RenderOptions::default() };
+	assert_eq!(inject_no_script_with_options(tmpl, &json!({"s": " \t\n"}), &opts), "no");
+	assert_eq!(inject_no_script_with_options(tmpl, &json!({"s": " x "}), &opts), "yes");
+}
+
 // -- Else branch --
 
 #[test]
@@ -169,6 +388,38 @@ fn else_empty_array() {
 	assert_eq!(inject_no_script(tmpl, &json!({"items": []})), "<p>No items</p>");
 }
 
+// -- Presence (has) --
+
+#[test]
+fn has_shows_zero_that_if_hides() {
+	let data = json!({"count": 0});
+	assert_eq!(inject_no_script("<!--seam:if:count--><b>n</b><!--seam:endif:count-->", &data), "");
+	assert_eq!(
+		inject_no_script("<!--seam:has:count--><b><!--seam:count--></b><!--seam:endhas-->", &data),
+		"<b>0</b>"
+	);
+}
+
+#[test]
+fn has_keeps_false_and_empty_string() {
+	let tmpl = "<!--seam:has:a-->a<!--seam:endhas--><!--seam:has:b-->b<!--seam:endhas-->";
+	assert_eq!(inject_no_script(tmpl, &json!({"a": false, "b": ""})), "ab");
+}
+
+#[test]
+fn has_drops_missing_and_null() {
+	let tmpl = "<!--seam:has:x-->x<!--seam:endhas--><!--seam:has:y.z-->z<!--seam:endhas-->";
+	assert_eq!(inject_no_script(tmpl, &json!({"x": null, "y": {}})), "");
+}
+
+#[test]
+fn has_unclosed_reports_diagnostic() {
+	let (html, diagnostics) =
+		inject_no_script_with_diagnostics("<!--seam:has:x-->body", &json!({"x": 1}));
+	assert_eq!(html, "body");
+	assert_eq!(diagnostics[0].directive, "has:x");
+}
+
 // -- Each iteration --
 
 #[test]
@@ -190,6 +441,38 @@ fn each_missing_path() {
 	assert_eq!(inject_no_script(tmpl, &json!({})), "");
 }
 
+#[test]
+fn each_else_skipped_when_populated() {
+	let tmpl = "<ul><!--seam:each:items--><li><!--seam:$--></li><!--seam:else--><li>none</li><!--seam:endeach--></ul>";
+	let data = json!({"items": ["a", "b"]});
+	assert_eq!(inject_no_script(tmpl, &data), "<ul><li>a</li><li>b</li></ul>");
+}
+
+#[test]
+fn each_else_renders_for_empty_array() {
+	let tmpl = "<!--seam:each:items--><li><!--seam:$--></li><!--seam:else--><p>No <!--seam:label--></p><!--seam:endeach-->";
+	let data = json!({"items": [], "label": "items"});
+	assert_eq!(inject_no_script(tmpl, &data), "<p>No items</p>");
+}
+
+#[test]
+fn each_else_renders_for_missing_or_non_array() {
+	let tmpl = "<!--seam:each:items--><li>x</li><!--seam:else--><p>empty</p><!--seam:endeach-->";
+	assert_eq!(inject_no_script(tmpl, &json!({})), "<p>empty</p>");
+	assert_eq!(inject_no_script(tmpl, &json!({"items": "nope"})), "<p>empty</p>");
+}
+
+#[test]
+fn each_else_not_taken_from_nested_if() {
+	let tmpl = concat!(
+		"<!--seam:each:items-->",
+		"<!--seam:if:$.on-->on<!--seam:else-->off<!--seam:endif:$.on-->",
+		"<!--seam:endeach-->"
+	);
+	let data = json!({"items": [{"on": true}, {"on": false}]});
+	assert_eq!(inject_no_script(tmpl, &data), "onoff");
+}
+
 #[test]
 fn each_attr_inside() {
 	let tmpl = r#"<!--seam:each:links--><!--seam:$.url:attr:href--><a><!--seam:$.text--></a><!--seam:endeach-->"#;
@@ -286,6 +569,192 @@ fn each_nested_with_double_dollar() {
 	);
 }
 
+#[test]
+fn each_body_reads_root_path() {
+	let tmpl = "<!--seam:each:comments--><!--seam:each:$.replies-->\
+		<p><!--seam:/pageTitle-->:<!--seam:$.text--></p><!--seam:endeach--><!--seam:endeach-->";
+	let data = json!({
+		"pageTitle": "Post",
+		"comments": [{"pageTitle": "shadow", "replies": [{"text": "a"}, {"text": "b"}]}]
+	});
+	assert_eq!(inject_no_script(tmpl, &data), "<p>Post:a</p><p>Post:b</p>");
+}
+
+#[test]
+fn root_path_in_block_directives() {
+	let tmpl = "<!--seam:each:items--><!--seam:if:/show--><!--seam:/label:attr:title--><a>\
+		<!--seam:$--></a><!--seam:endif:/show--><!--seam:endeach-->";
+	let data = json!({"show": true, "label": "L", "items": ["x"]});
+	assert_eq!(inject_no_script(tmpl, &data), r#"<a title="L">x</a>"#);
+}
+
+// -- Each modifiers --
+
+#[test]
+fn each_reverse() {
+	let tmpl = "<!--seam:each:items:reverse--><i><!--seam:$--></i><!--seam:endeach-->";
+	let data = json!({"items": ["a", "b", "c"]});
+	assert_eq!(inject_no_script(tmpl, &data), "<i>c</i><i>b</i><i>a</i>");
+	assert_eq!(data["items"], json!(["a", "b", "c"]));
+}
+
+#[test]
+fn each_sort_ascending_string() {
+	let tmpl = "<!--seam:each:users:sort:$.name--><!--seam:$.name-->,<!--seam:endeach-->";
+	let data = json!({"users": [{"name": "Cara"}, {"name": "Alice"}, {"name": "Bob"}]});
+	assert_eq!(inject_no_script(tmpl, &data), "Alice,Bob,Cara,");
+}
+
+#[test]
+fn each_sort_is_stable() {
+	let tmpl = "<!--seam:each:xs:sort:$.k--><!--seam:$.id--><!--seam:endeach-->";
+	let data = json!({"xs": [
+		{"k": "b", "id": 1}, {"k": "a", "id": 2}, {"k": "b", "id": 3}, {"k": "a", "id": 4}
+	]});
+	assert_eq!(inject_no_script(tmpl, &data), "2413");
+}
+
+#[test]
+fn each_sort_numbers_numerically_then_reverse() {
+	let tmpl = "<!--seam:each:xs:sort:$.n:reverse--><!--seam:$.n-->,<!--seam:endeach-->";
+	let data = json!({"xs": [{"n": 9}, {"n": 10}, {"n": 2}]});
+	assert_eq!(inject_no_script(tmpl, &data), "10,9,2,");
+}
+
+#[test]
+fn each_where_filters_by_boolean_field() {
+	let tmpl =
+		"<ul><!--seam:each:users:where:$.active--><li><!--seam:$.name--></li><!--seam:endeach--></ul>";
+	let data = json!({"users": [
+		{"name": "Ada", "active": true}, {"name": "Bob", "active": false}, {"name": "Cy", "active": true}
+	]});
+	assert_eq!(inject_no_script(tmpl, &data), "<ul><li>Ada</li><li>Cy</li></ul>");
+}
+
+#[test]
+fn each_where_missing_field_filters_everything() {
+	let tmpl =
+		"<ul><!--seam:each:users:where:$.ghost--><li><!--seam:$.name--></li><!--seam:endeach--></ul>";
+	let data = json!({"users": [{"name": "Ada"}, {"name": "Bob"}]});
+	assert_eq!(inject_no_script(tmpl, &data), "<ul></ul>");
+}
+
+#[test]
+fn each_where_applies_before_sort_and_limit() {
+	let tmpl = "<!--seam:each:xs:limit:2:sort:$.n:where:$.on--><!--seam:$.n-->,<!--seam:endeach-->";
+	let data = json!({"xs": [
+		{"n": 4, "on": true}, {"n": 1, "on": false}, {"n": 3, "on": true}, {"n": 2, "on": true}
+	]});
+	assert_eq!(inject_no_script(tmpl, &data), "2,3,");
+}
+
+#[test]
+fn each_limit_beyond_length() {
+	let tmpl = "<!--seam:each:xs:limit:5--><!--seam:$--><!--seam:endeach-->";
+	assert_eq!(inject_no_script(tmpl, &json!({"xs": [1, 2, 3]})), "123");
+	let tmpl = "<!--seam:each:xs:limit:2--><!--seam:$--><!--seam:endeach-->";
+	assert_eq!(inject_no_script(tmpl, &json!({"xs": [1, 2, 3]})), "12");
+}
+
+#[test]
+fn each_offset_beyond_length_is_empty() {
+	let tmpl = "<ul><!--seam:each:xs:offset:9--><li><!--seam:$--></li><!--seam:endeach--></ul>";
+	assert_eq!(inject_no_script(tmpl, &json!({"xs": [1, 2, 3]})), "<ul></ul>");
+}
+
+#[test]
+fn each_offset_and_limit_window() {
+	let tmpl = "<!--seam:each:xs:limit:2:offset:1--><!--seam:$--><!--seam:endeach-->";
+	assert_eq!(inject_no_script(tmpl, &json!({"xs": [1, 2, 3, 4, 5]})), "23");
+	let tmpl = "<!--seam:each:xs:offset:3:limit:5--><!--seam:$--><!--seam:endeach-->";
+	assert_eq!(inject_no_script(tmpl, &json!({"xs": [1, 2, 3, 4, 5]})), "45");
+}
+
+#[test]
+fn each_range_counts_from_one() {
+	let tmpl =
+		"<nav><!--seam:each:range:pageCount--><a><!--seam:@index1--></a><!--seam:endeach--></nav>";
+	assert_eq!(
+		inject_no_script(tmpl, &json!({"pageCount": 3})),
+		"<nav><a>1</a><a>2</a><a>3</a></nav>"
+	);
+}
+
+#[test]
+fn each_range_zero_or_non_numeric_renders_nothing() {
+	let tmpl = "<nav><!--seam:each:range:pageCount--><a><!--seam:$--></a><!--seam:endeach--></nav>";
+	for data in
+		[json!({"pageCount": 0}), json!({"pageCount": "3"}), json!({"pageCount": -1}), json!({})]
+	{
+		assert_eq!(inject_no_script(tmpl, &data), "<nav></nav>", "{data}");
+	}
+}
+
+#[test]
+fn each_range_keeps_modifiers_and_root_lookup() {
+	let tmpl = "<!--seam:each:range:/pages:reverse:limit:2--><!--seam:$-->,<!--seam:endeach-->";
+	assert_eq!(inject_no_script(tmpl, &json!({"pages": 5})), "5,4,");
+}
+
+#[test]
+fn each_range_is_capped_by_max_range() {
+	let compiled = compile(
+		"<!--seam:each:xs--><!--seam:each:range:$.n--><!--seam:$--><!--seam:endeach-->;<!--seam:endeach-->",
+	);
+	let options = RenderOptions { max_range: 3, ..RenderOptions::default() };
+	let data = json!({"xs": [{"n": 1_000_000_000_000_u64}, {"n": 2}]});
+	let (html, diagnostics) = inject_precompiled_with_diagnostics(&compiled, &data, &options);
+	assert_eq!(html, "123;12;");
+	assert_eq!(
+		diagnostics,
+		vec![ParseDiagnostic {
+			kind: DiagnosticKind::RangeLimitExceeded,
+			directive: "each:range:$.n".to_string(),
+		}]
+	);
+}
+
+#[test]
+fn each_exposes_zero_and_one_based_index() {
+	let tmpl =
+		"<!--seam:each:xs--><!--seam:@index-->/<!--seam:@index1-->=<!--seam:$--> <!--seam:endeach-->";
+	assert_eq!(inject_no_script(tmpl, &json!({"xs": ["a", "b"]})), "0/1=a 1/2=b ");
+}
+
+#[test]
+fn each_even_odd_choose_row_class() {
+	let tmpl = concat!(
+		"<!--seam:each:rows-->",
+		"<!--seam:if:@even--><!--seam:/even:attr:class--><!--seam:endif:@even-->",
+		"<!--seam:if:@odd--><!--seam:/odd:attr:class--><!--seam:endif:@odd-->",
+		"<tr><!--seam:$--></tr><!--seam:endeach-->",
+	);
+	let data = json!({"rows": ["a", "b", "c", "d"], "even": "row-even", "odd": "row-odd"});
+	assert_eq!(
+		inject_no_script(tmpl, &data),
+		concat!(
+			r#"<tr class="row-even">a</tr><tr class="row-odd">b</tr>"#,
+			r#"<tr class="row-even">c</tr><tr class="row-odd">d</tr>"#,
+		)
+	);
+}
+
+#[test]
+fn each_even_odd_follow_innermost_loop() {
+	let tmpl = concat!(
+		"<!--seam:each:xs--><!--seam:each:$-->",
+		"<!--seam:if:@odd-->o<!--seam:else-->e<!--seam:endif:@odd-->",
+		"<!--seam:endeach-->|<!--seam:endeach-->",
+	);
+	assert_eq!(inject_no_script(tmpl, &json!({"xs": [[1, 2, 3], [1]]})), "eoe|e|");
+}
+
+#[test]
+fn each_array_named_range() {
+	let tmpl = "<!--seam:each:range:reverse--><!--seam:$--><!--seam:endeach-->";
+	assert_eq!(inject_no_script(tmpl, &json!({"range": [1, 2]})), "21");
+}
+
 // -- Empty array falsy --
 
 #[test]
@@ -386,16 +855,168 @@ fn match_with_nested_slots() {
 	assert_eq!(inject_no_script(tmpl, &json!({"role": "admin", "name": "Alice"})), "<b>Alice</b>");
 }
 
+// -- Raw passthrough --
+
+#[test]
+fn raw_block_renders_directives_literally() {
+	let tmpl = "<code><!--seam:raw--><!--seam:if:x--><!--seam:title--><!--seam:endif:x--><!--seam:endraw--></code>";
+	let html = inject_no_script(tmpl, &json!({"x": false, "title": "T"}));
+	assert_eq!(html, "<code><!--seam:if:x--><!--seam:title--><!--seam:endif:x--></code>");
+}
+
+#[test]
+fn raw_block_nested_raw_closes_at_first_endraw() {
+	let tmpl =
+		"<!--seam:raw-->a<!--seam:raw-->b<!--seam:endraw-->c<!--seam:endraw--><!--seam:title-->";
+	let (html, diags) = inject_no_script_with_diagnostics(tmpl, &json!({"title": "T"}));
+	assert_eq!(html, "a<!--seam:raw-->bcT");
+	assert_eq!(diags.len(), 1);
+	assert_eq!(diags[0].directive, "endraw");
+}
+
+// -- Template comments --
+
+#[test]
+fn comment_block_drops_content_and_inner_directives() {
+	let tmpl = concat!(
+		"<p>a</p><!--seam:#comment-->TODO: <b><!--seam:title--></b>",
+		"<!--seam:if:x--><!--seam:each:xs--><!--seam:endmatch--><!--seam:#endcomment--><p>b</p>",
+	);
+	let (html, diags) = inject_no_script_with_diagnostics(tmpl, &json!({"title": "T", "x": true}));
+	assert_eq!(html, "<p>a</p><p>b</p>");
+	assert!(diags.is_empty(), "{diags:?}");
+}
+
+#[test]
+fn hash_marker_is_a_one_line_comment() {
+	let tmpl = "<!--seam:#shown to editors only: title:attr:x--><h1><!--seam:title--></h1>";
+	let (html, diags) = inject_no_script_with_diagnostics(tmpl, &json!({"title": "T"}));
+	assert_eq!(html, "<h1>T</h1>");
+	assert!(diags.is_empty(), "{diags:?}");
+}
+
+#[test]
+fn comment_between_attr_slot_and_target_is_transparent() {
+	let tmpl =
+		"<!--seam:cls:attr:class--><!--seam:#comment-->x<!--seam:#endcomment--><!--seam:#n--><i>y</i>";
+	let (html, diags) = inject_no_script_with_diagnostics(tmpl, &json!({"cls": "c"}));
+	assert_eq!(html, r#"<i class="c">y</i>"#);
+	assert!(diags.is_empty(), "{diags:?}");
+}
+
+#[test]
+fn unclosed_comment_drops_only_its_marker() {
+	let (html, diags) = inject_no_script_with_diagnostics(
+		"a<!--seam:#comment-->b<!--seam:title-->",
+		&json!({"title": "T"}),
+	);
+	assert_eq!(html, "abT");
+	assert_eq!(diags.len(), 1);
+	assert_eq!(diags[0].directive, "#comment");
+}
+
+#[test]
+fn unclosed_comment_before_attr_target_only_reports_itself() {
+	let (html, diags) = inject_no_script_with_diagnostics(
+		"<!--seam:cls:attr:class--><!--seam:#comment--><i>y</i>",
+		&json!({"cls": "c"}),
+	);
+	assert_eq!(html, r#"<i class="c">y</i>"#);
+	assert_eq!(diags.len(), 1, "{diags:?}");
+	assert_eq!(diags[0].directive, "#comment");
+}
+
+#[test]
+fn comment_is_an_ordinary_data_key() {
+	let tmpl = "<p><!--seam:comment--></p><footer><!--seam:author--></footer>";
+	let (html, diags) =
+		inject_no_script_with_diagnostics(tmpl, &json!({"comment": "Nice post", "author": "A"}));
+	assert_eq!(html, "<p>Nice post</p><footer>A</footer>");
+	assert!(diags.is_empty(), "{diags:?}");
+}
+
 // -- Data script --
 
 #[test]
 fn data_script_before_body() {
-	let html = inject("<body><p>hi</p></body>", &json!({"x": 1}), "__data");
+	let html = inject("<body><p>hi</p></body>", &json!({"x": 1}));
 	assert!(html.contains(r#"<script id="__data" type="application/json">{"x":1}</script></body>"#));
 }
 
 #[test]
 fn data_script_appended_when_no_body() {
-	let html = inject("<p>hi</p>", &json!({"x": 1}), "__data");
+	let html = inject("<p>hi</p>", &json!({"x": 1}));
 	assert!(html.ends_with(r#"<script id="__data" type="application/json">{"x":1}</script>"#));
 }
+
+#[test]
+fn data_script_custom_id() {
+	let html = inject_with_id("<body><p>hi</p></body>", &json!({"x": 1}), "__app");
+	assert!(html.contains(r#"<script id="__app" type="application/json">{"x":1}</script></body>"#));
+	assert!(!html.contains("__data"));
+}
+
+#[test]
+fn data_script_custom_id_appended_when_no_body() {
+	let html = inject_with_id("<p>hi</p>", &json!({"x": 1}), "__app");
+	assert!(html.ends_with(r#"<script id="__app" type="application/json">{"x":1}</script>"#));
+}
+
+#[test]
+fn data_script_with_nonce() {
+	let opts = InjectOptions { nonce: Some("r4nd0m"), ..InjectOptions::default() };
+	let html = inject_with_options("<body></body>", &json!({"x": 1}), &opts);
+	assert_eq!(
+		html,
+		r#"<body><script id="__data" nonce="r4nd0m" type="application/json">{"x":1}</script></body>"#
+	);
+}
+
+#[test]
+fn data_script_escapes_id_and_nonce() {
+	let opts = InjectOptions { data_id: r#"a"b"#, nonce: Some("n\"><x"), integrity: false };
+	let html = inject_with_options("<body></body>", &json!({}), &opts);
+	assert!(html.contains(r#"<script id="a&quot;b" nonce="n&quot;&gt;&lt;x" type="#), "{html}");
+}
+
+#[test]
+fn data_script_integrity_matches_json_digest() {
+	use base64::Engine;
+	use sha2::{Digest, Sha256};
+
+	let opts = InjectOptions { integrity: true, ..InjectOptions::default() };
+	let html = inject_with_options("<body></body>", &json!({"x": 1}), &opts);
+	let expected = format!(
+		"sha256-{}",
+		base64::engine::general_purpose::STANDARD.encode(Sha256::digest(br#"{"x":1}"#))
+	);
+	assert_eq!(
+		html,
+		format!(
+			r#"<body><script id="__data" data-integrity="{expected}" type="application/json">{{"x":1}}</script></body>"#
+		)
+	);
+	assert!(!inject("<body></body>", &json!({"x": 1})).contains("data-integrity"));
+}
+
+#[test]
+fn data_script_without_nonce_has_no_attribute() {
+	let html = inject_with_options("<body></body>", &json!({}), &InjectOptions::default());
+	assert!(!html.contains("nonce"));
+	assert_eq!(html, inject("<body></body>", &json!({})));
+}
+
+#[test]
+fn render_fragment_splices_attrs_without_script() {
+	let tmpl = r#"<!--seam:id:attr:id--><!--seam:w:style:width--><tr class="row"><td>x</td></tr>"#;
+	let html = render_fragment(tmpl, &json!({"id": "r1", "w": 40}));
+	assert_eq!(html, r#"<tr id="r1" style="width:40px" class="row"><td>x</td></tr>"#);
+}
+
+#[test]
+fn render_fragment_loop_with_body_text() {
+	// A literal `</body>` in fragment text must not attract a data script
+	let tmpl = "<!--seam:each:items--><li><!--seam:$.name--></li><!--seam:endeach--><p>&lt;/body&gt; </body></p>";
+	let html = render_fragment(tmpl, &json!({"items": [{"name": "a"}, {"name": "b"}]}));
+	assert_eq!(html, "<li>a</li><li>b</li><p>&lt;/body&gt; </body></p>");
+}
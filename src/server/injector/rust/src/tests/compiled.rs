@@ -0,0 +1,60 @@
+/* src/server/injector/rust/src/tests/compiled.rs */
+
+use super::*;
+use serde_json::json;
+
+const TEMPLATE: &str = concat!(
+	"<html><body><h1><!--seam:title--></h1>",
+	"<!--seam:if:user--><p><!--seam:user.name--></p><!--seam:else--><p>guest</p><!--seam:endif:user-->",
+	"<ul><!--seam:each:items--><!--seam:$.id:attr:data-id--><li><!--seam:$.label--></li><!--seam:endeach--></ul>",
+	"<!--seam:w:style:width--><div>box</div>",
+	"</body></html>"
+);
+
+#[test]
+fn precompiled_matches_per_request_path() {
+	let compiled = compile(TEMPLATE);
+	let datasets = [
+		json!({"title": "A", "user": {"name": "Ada"}, "items": [{"id": 1, "label": "x"}], "w": 10}),
+		json!({"title": "B", "items": [], "w": 0}),
+		json!({}),
+	];
+	for data in datasets {
+		assert_eq!(inject_precompiled(&compiled, &data), inject_no_script(TEMPLATE, &data));
+	}
+}
+
+#[test]
+fn precompiled_writer_matches_buffered() {
+	let compiled = compile(TEMPLATE);
+	let data = json!({"title": "T", "items": [{"id": 7, "label": "seven"}], "w": 3});
+	let mut out = Vec::new();
+	inject_precompiled_to_writer(&compiled, &data, &mut out).unwrap();
+	assert_eq!(String::from_utf8(out).unwrap(), inject_precompiled(&compiled, &data));
+}
+
+#[test]
+fn compile_strips_null_bytes() {
+	let compiled = compile("<p>\0<!--seam:name-->\0</p>");
+	assert_eq!(inject_precompiled(&compiled, &json!({"name": "x"})), "<p>x</p>");
+}
+
+#[test]
+fn compile_keeps_diagnostics() {
+	let compiled = compile("<!--seam:if:x-->open");
+	assert_eq!(compiled.diagnostics().len(), 1);
+	assert_eq!(compiled.diagnostics()[0].kind, DiagnosticKind::UnclosedBlock);
+}
+
+#[test]
+fn slot_count_includes_nested_blocks() {
+	assert_eq!(compile(TEMPLATE).slot_count(), 5);
+	assert_eq!(compile("<p>static</p>").slot_count(), 0);
+}
+
+#[test]
+fn two_phase_compile_matches_compile() {
+	let compiled = parse_template(&tokenize_template(TEMPLATE));
+	let data = json!({"title": "A", "w": 1});
+	assert_eq!(inject_precompiled(&compiled, &data), inject_no_script(TEMPLATE, &data));
+}
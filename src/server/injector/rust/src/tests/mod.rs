@@ -4,3 +4,6 @@ use super::*;
 
 mod advanced;
 mod basic;
+mod compiled;
+mod positions;
+mod streaming;
@@ -0,0 +1,78 @@
+/* src/server/injector/rust/src/tests/positions.rs */
+
+use super::*;
+
+fn spans(template: &str) -> Vec<(SlotKind, String, &str)> {
+	slot_positions(template)
+		.into_iter()
+		.map(|p| (p.kind, p.path, &template[p.start..p.end]))
+		.collect()
+}
+
+#[test]
+fn positions_cover_text_if_and_each() {
+	let template = concat!(
+		"<h1><!--seam:title--></h1>",
+		"<!--seam:if:user--><b>hi</b><!--seam:endif:user-->",
+		"<ul><!--seam:each:items:reverse--><li><!--seam:$.name--></li><!--seam:endeach--></ul>",
+	);
+	let positions = slot_positions(template);
+	assert_eq!(
+		positions[0],
+		SlotPosition { kind: SlotKind::Text, path: "title".into(), start: 4, end: 21 }
+	);
+	assert_eq!(
+		spans(template),
+		vec![
+			(SlotKind::Text, "title".into(), "<!--seam:title-->"),
+			(SlotKind::If, "user".into(), "<!--seam:if:user-->"),
+			(SlotKind::EndIf, "user".into(), "<!--seam:endif:user-->"),
+			(SlotKind::Each, "items".into(), "<!--seam:each:items:reverse-->"),
+			(SlotKind::Text, "$.name".into(), "<!--seam:$.name-->"),
+			(SlotKind::EndEach, String::new(), "<!--seam:endeach-->"),
+		]
+	);
+}
+
+#[test]
+fn positions_classify_attr_style_and_html() {
+	let template = "<!--seam:url:attr:href--><!--seam:w:style:width--><a></a><!--seam:body:html-->";
+	let kinds: Vec<_> = slot_positions(template).into_iter().map(|p| (p.kind, p.path)).collect();
+	assert_eq!(
+		kinds,
+		vec![
+			(SlotKind::Attr, "url".into()),
+			(SlotKind::Style, "w".into()),
+			(SlotKind::Html, "body".into()),
+		]
+	);
+}
+
+#[test]
+fn positions_classify_urlencode_with_bare_path() {
+	let template = "<!--seam:q:urlencode--><!--seam:q:urlencode:attr:href--><a></a>";
+	let kinds: Vec<_> = slot_positions(template).into_iter().map(|p| (p.kind, p.path)).collect();
+	assert_eq!(kinds, vec![(SlotKind::UrlEncode, "q".into()), (SlotKind::Attr, "q".into())]);
+}
+
+#[test]
+fn positions_skip_literal_markers() {
+	let template = concat!(
+		"<script>\"<!--seam:if:x-->\"</script>",
+		"<!--seam:raw--><!--seam:title--><!--seam:endraw-->",
+		"<!--seam:name-->",
+	);
+	let kinds: Vec<_> = slot_positions(template).into_iter().map(|p| p.kind).collect();
+	assert_eq!(kinds, vec![SlotKind::Raw, SlotKind::EndRaw, SlotKind::Text]);
+}
+
+#[test]
+fn positions_skip_markers_inside_comments() {
+	let template =
+		"<!--seam:#note--><!--seam:#comment--><!--seam:title--><!--seam:#endcomment--><!--seam:name-->";
+	let kinds: Vec<_> = slot_positions(template).into_iter().map(|p| p.kind).collect();
+	assert_eq!(
+		kinds,
+		vec![SlotKind::Comment, SlotKind::Comment, SlotKind::EndComment, SlotKind::Text]
+	);
+}
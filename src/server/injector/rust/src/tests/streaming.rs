@@ -0,0 +1,114 @@
+/* src/server/injector/rust/src/tests/streaming.rs */
+
+use super::*;
+use serde_json::json;
+
+fn streamed(template: &str, data: &Value) -> String {
+	let mut out = Vec::new();
+	inject_no_script_to_writer(template, data, &mut out).unwrap();
+	String::from_utf8(out).unwrap()
+}
+
+/// Records each write separately to observe incremental output.
+struct ChunkRecorder(Vec<String>);
+
+impl Write for ChunkRecorder {
+	fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+		self.0.push(String::from_utf8_lossy(buf).into_owned());
+		Ok(buf.len())
+	}
+
+	fn flush(&mut self) -> io::Result<()> {
+		Ok(())
+	}
+}
+
+#[test]
+fn streamed_matches_buffered() {
+	let cases = [
+		("<p><!--seam:name--></p>", json!({"name": "Alice"})),
+		("<!--seam:cls:attr:class--><div>hi</div>", json!({"cls": "active"})),
+		(r#"<!--seam:mt:style:margin-top--><div style="color:red">x</div>"#, json!({"mt": 16})),
+		(
+			"<ul><!--seam:each:items--><!--seam:$.id:attr:data-id--><li><!--seam:$.name--></li><!--seam:endeach--></ul>",
+			json!({"items": [{"id": 1, "name": "a"}, {"id": 2, "name": "b"}]}),
+		),
+		(
+			"<!--seam:if:ok--><!--seam:href:attr:href--><a>yes</a><!--seam:else-->no<!--seam:endif:ok-->",
+			json!({"ok": true, "href": "/x"}),
+		),
+		(
+			"<!--seam:match:s--><!--seam:when:a-->A<!--seam:when:b-->B<!--seam:endmatch-->",
+			json!({"s": "b"}),
+		),
+		// Dangling marker with no following element
+		("<p>tail</p><!--seam:cls:attr:class-->", json!({"cls": "x"})),
+		("<li><!--seam:cls:attr:class-->text</li><li>b</li>", json!({"cls": "x"})),
+		("<!--seam:cls:attr:class--> <!-- c --> <i>x</i>", json!({"cls": "x"})),
+		// Quoted `<` / `>` must not be taken for tag boundaries
+		(r#"<p data-html="<b>">a</p><!--seam:cls:attr:class--><i>x</i>"#, json!({"cls": "x"})),
+		(r#"<!--seam:c:style:color--><p data-tip="a>b" style="margin:0">x</p>"#, json!({"c": "red"})),
+		("<!--seam:cls:attr:class-->1 < 2 <i>x</i>", json!({"cls": "x"})),
+		// `@tag` selectors reach past the next tag, into later nodes
+		(
+			"<!--seam:id:attr:id@input--><label><!--seam:name--> <input></label>",
+			json!({"id": "a", "name": "Name"}),
+		),
+		(
+			"<!--seam:each:xs--><!--seam:$:attr:value@input--><label><input></label><!--seam:endeach-->",
+			json!({"xs": [1, 2]}),
+		),
+	];
+	for (template, data) in cases {
+		assert_eq!(
+			streamed(template, &data),
+			inject_no_script(template, &data),
+			"template: {template}"
+		);
+	}
+}
+
+#[test]
+fn streamed_large_each_writes_incrementally() {
+	let items: Vec<_> = (0..100).map(|i| json!({"n": i})).collect();
+	let data = json!({"items": items});
+	let template = "<ul><!--seam:each:items--><li><!--seam:$.n--></li><!--seam:endeach--></ul>";
+
+	let mut recorder = ChunkRecorder(Vec::new());
+	inject_no_script_to_writer(template, &data, &mut recorder).unwrap();
+	assert!(recorder.0.len() > 100, "expected per-node writes, got {}", recorder.0.len());
+	assert_eq!(recorder.0.concat(), inject_no_script(template, &data));
+}
+
+#[test]
+fn streamed_holds_back_only_pending_element() {
+	let data = json!({"items": [{"c": "x"}, {"c": "y"}]});
+	let template = "<!--seam:each:items--><!--seam:$.c:attr:class--><li>row</li><!--seam:endeach-->";
+
+	let mut recorder = ChunkRecorder(Vec::new());
+	inject_no_script_to_writer(template, &data, &mut recorder).unwrap();
+	// No chunk ever leaks an unresolved marker
+	assert!(recorder.0.iter().all(|c| !c.contains('\0')));
+	assert_eq!(recorder.0.concat(), r#"<li class="x">row</li><li class="y">row</li>"#);
+}
+
+#[test]
+fn streamed_flush_waits_for_quoted_gt_in_target_tag() {
+	let data = json!({"items": [{"c": "red"}, {"c": "blue"}]});
+	let template = concat!(
+		"<!--seam:each:items--><!--seam:$.c:style:color-->",
+		r#"<li data-tip="a>b" style="margin:0">row</li><!--seam:endeach-->"#,
+	);
+
+	let mut recorder = ChunkRecorder(Vec::new());
+	inject_no_script_to_writer(template, &data, &mut recorder).unwrap();
+	// A flush cut at the quoted `>` would hide the existing style from the merge
+	assert!(recorder.0.iter().all(|c| !c.contains('\0')));
+	assert_eq!(
+		recorder.0.concat(),
+		concat!(
+			r#"<li data-tip="a>b" style="margin:0;color:red">row</li>"#,
+			r#"<li data-tip="a>b" style="margin:0;color:blue">row</li>"#,
+		)
+	);
+}
@@ -1,5 +1,7 @@
 /* src/server/injector/rust/src/token.rs */
 
+use std::ops::Range;
+
 #[derive(Debug)]
 pub(crate) enum Token {
 	Text(String),
@@ -9,34 +11,149 @@ pub(crate) enum Token {
 pub(crate) const MARKER_OPEN: &str = "<!--seam:";
 pub(crate) const MARKER_CLOSE: &str = "-->";
 
+/// Elements whose content the browser never parses as markup. Only plain slot markers
+/// are honored inside them; anything else seam-like is literal content and kept verbatim.
+const OPAQUE_ELEMENTS: &[&str] = &["pre", "script", "style", "textarea"];
+
+/// Directive words that could pass for a bare slot path.
+const BARE_DIRECTIVES: &[&str] =
+	&["else", "endeach", "endhas", "endiflocale", "endmatch", "endraw", "raw"];
+
 pub(crate) fn tokenize(template: &str) -> Vec<Token> {
 	let mut tokens = Vec::new();
+	scan(template, &mut |piece| match piece {
+		Piece::Text(text) => push_text(&mut tokens, text),
+		Piece::Marker(directive, _) => tokens.push(Token::Marker(directive.to_string())),
+	});
+	tokens
+}
+
+/// Every honored marker with the byte range of its full `<!--seam:...-->` text.
+pub(crate) fn marker_spans(template: &str) -> Vec<(&str, Range<usize>)> {
+	let mut markers = Vec::new();
+	scan(template, &mut |piece| {
+		if let Piece::Marker(directive, span) = piece {
+			markers.push((directive, span));
+		}
+	});
+	markers
+}
+
+enum Piece<'a> {
+	Text(&'a str),
+	/// Directive body and the marker's byte range in the whole template.
+	Marker(&'a str, Range<usize>),
+}
+
+fn scan<'a>(template: &'a str, emit: &mut impl FnMut(Piece<'a>)) {
 	let mut pos = 0;
-	let bytes = template.as_bytes();
+	while let Some((content_start, content_end)) = next_opaque_range(template, pos) {
+		scan_range(template, pos..content_start, false, emit);
+		scan_range(template, content_start..content_end, true, emit);
+		pos = content_end;
+	}
+	scan_range(template, pos..template.len(), false, emit);
+}
 
-	while pos < bytes.len() {
-		if let Some(rel) = template[pos..].find(MARKER_OPEN) {
-			let marker_start = pos + rel;
-			if marker_start > pos {
-				tokens.push(Token::Text(template[pos..marker_start].to_string()));
-			}
-			let after_open = marker_start + MARKER_OPEN.len();
-			if let Some(close_rel) = template[after_open..].find(MARKER_CLOSE) {
-				let directive = template[after_open..after_open + close_rel].to_string();
-				tokens.push(Token::Marker(directive));
-				pos = after_open + close_rel + MARKER_CLOSE.len();
-			} else {
-				// Unclosed marker -- treat rest as text
-				tokens.push(Token::Text(template[marker_start..].to_string()));
-				break;
-			}
-		} else {
-			tokens.push(Token::Text(template[pos..].to_string()));
+fn scan_range<'a>(
+	template: &'a str,
+	range: Range<usize>,
+	slots_only: bool,
+	emit: &mut impl FnMut(Piece<'a>),
+) {
+	let end = range.end;
+	let mut pos = range.start;
+	while pos < end {
+		let Some(rel) = template[pos..end].find(MARKER_OPEN) else {
+			emit(Piece::Text(&template[pos..end]));
+			break;
+		};
+		let marker_start = pos + rel;
+		emit(Piece::Text(&template[pos..marker_start]));
+		let after_open = marker_start + MARKER_OPEN.len();
+		let Some(close_rel) = template[after_open..end].find(MARKER_CLOSE) else {
+			// Unclosed marker -- treat rest as text
+			emit(Piece::Text(&template[marker_start..end]));
 			break;
+		};
+		let directive = &template[after_open..after_open + close_rel];
+		pos = after_open + close_rel + MARKER_CLOSE.len();
+		if slots_only && !is_slot_directive(directive) {
+			emit(Piece::Text(&template[marker_start..pos]));
+		} else {
+			emit(Piece::Marker(directive, marker_start..pos));
 		}
 	}
+}
 
-	tokens
+/// Append text, merging with a preceding text token so opaque ranges stay contiguous.
+fn push_text(tokens: &mut Vec<Token>, text: &str) {
+	if text.is_empty() {
+		return;
+	}
+	if let Some(Token::Text(prev)) = tokens.last_mut() {
+		prev.push_str(text);
+	} else {
+		tokens.push(Token::Text(text.to_string()));
+	}
+}
+
+/// A plain text or `:html` slot: a bare path, not a block or attribute directive.
+fn is_slot_directive(directive: &str) -> bool {
+	let path = directive.strip_suffix(":html").unwrap_or(directive);
+	!path.is_empty()
+		&& !BARE_DIRECTIVES.contains(&path)
+		&& path
+			.bytes()
+			.all(|b| b.is_ascii_alphanumeric() || matches!(b, b'_' | b'$' | b'.' | b'-' | b'/'))
+}
+
+/// Content range (after the opening tag, up to the closing tag) of the next opaque
+/// element at or after `from`. An unclosed element runs to the end of the template.
+fn next_opaque_range(html: &str, from: usize) -> Option<(usize, usize)> {
+	let bytes = html.as_bytes();
+	let mut i = from;
+	while let Some(rel) = html[i..].find('<') {
+		let lt = i + rel;
+		for name in OPAQUE_ELEMENTS {
+			let name_end = lt + 1 + name.len();
+			if name_end < bytes.len()
+				&& bytes[lt + 1..name_end].eq_ignore_ascii_case(name.as_bytes())
+				&& matches!(bytes[name_end], b'>' | b'/' | b' ' | b'\t' | b'\n' | b'\r')
+			{
+				let content_start = open_tag_end(bytes, name_end)?;
+				let content_end = find_close_tag(bytes, content_start, name).unwrap_or(bytes.len());
+				return Some((content_start, content_end));
+			}
+		}
+		i = lt + 1;
+	}
+	None
+}
+
+/// Index just past the `>` closing an opening tag, skipping quoted attribute values.
+fn open_tag_end(bytes: &[u8], from: usize) -> Option<usize> {
+	let mut quote = None;
+	for (i, &b) in bytes.iter().enumerate().skip(from) {
+		match quote {
+			Some(q) if b == q => quote = None,
+			Some(_) => {}
+			None if b == b'"' || b == b'\'' => quote = Some(b),
+			None if b == b'>' => return Some(i + 1),
+			None => {}
+		}
+	}
+	None
+}
+
+/// Start of the first `</name` at or after `from`, case-insensitive.
+fn find_close_tag(bytes: &[u8], from: usize, name: &str) -> Option<usize> {
+	let needle_len = name.len() + 2;
+	(from..bytes.len().saturating_sub(needle_len - 1)).find(|&i| {
+		bytes[i] == b'<'
+			&& bytes[i + 1] == b'/'
+			&& bytes[i + 2..i + needle_len].eq_ignore_ascii_case(name.as_bytes())
+	})
 }
 
 #[cfg(test)]
@@ -94,6 +211,29 @@ mod tests {
 		assert!(matches!(&tokens[0], Token::Text(s) if s == "<!--seam:x"));
 	}
 
+	#[test]
+	fn tokenize_script_keeps_directives_literal() {
+		let tokens = tokenize(r#"<script>var s = "<!--seam:if:x-->";</script><!--seam:if:x-->"#);
+		assert_eq!(tokens.len(), 2);
+		assert!(
+			matches!(&tokens[0], Token::Text(s) if s == r#"<script>var s = "<!--seam:if:x-->";</script>"#)
+		);
+		assert!(matches!(&tokens[1], Token::Marker(s) if s == "if:x"));
+	}
+
+	#[test]
+	fn tokenize_opaque_element_honors_slots() {
+		let tokens = tokenize("<TEXTAREA rows=\"2\">a<!--seam:body--></textarea>");
+		assert_eq!(tokens.len(), 3);
+		assert!(matches!(&tokens[1], Token::Marker(s) if s == "body"));
+	}
+
+	#[test]
+	fn tokenize_unclosed_opaque_element_runs_to_end() {
+		let tokens = tokenize("<style>a{}<!--seam:else-->");
+		assert_eq!(tokens.len(), 1);
+	}
+
 	#[test]
 	fn tokenize_empty_directive() {
 		let tokens = tokenize("<!--seam:-->");
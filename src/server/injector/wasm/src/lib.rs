@@ -1,13 +1,20 @@
 /* src/server/injector/wasm/src/lib.rs */
 #![cfg_attr(test, allow(clippy::unwrap_used))]
 
-use serde_json::Value;
+use seam_injector::DiagnosticKind;
+use serde_json::{Value, json};
 use wasm_bindgen::prelude::*;
 
 #[wasm_bindgen]
 pub fn inject(template: &str, data_json: &str) -> String {
 	let data: Value = serde_json::from_str(data_json).unwrap_or(Value::Null);
-	seam_injector::inject(template, &data, "__data")
+	seam_injector::inject(template, &data)
+}
+
+#[wasm_bindgen]
+pub fn inject_with_id(template: &str, data_json: &str, data_id: &str) -> String {
+	let data: Value = serde_json::from_str(data_json).unwrap_or(Value::Null);
+	seam_injector::inject_with_id(template, &data, data_id)
 }
 
 #[wasm_bindgen]
@@ -15,3 +22,70 @@ pub fn inject_no_script(template: &str, data_json: &str) -> String {
 	let data: Value = serde_json::from_str(data_json).unwrap_or(Value::Null);
 	seam_injector::inject_no_script(template, &data)
 }
+
+/// Render without the data script and report template problems alongside the HTML:
+/// `{"html": "...", "diagnostics": [{"kind": "unclosedBlock", "directive": "if:x"}]}`.
+#[wasm_bindgen]
+pub fn inject_with_diagnostics(template: &str, data_json: &str) -> String {
+	let data: Value = serde_json::from_str(data_json).unwrap_or(Value::Null);
+	let (html, diagnostics) = seam_injector::inject_no_script_with_diagnostics(template, &data);
+	let diagnostics: Vec<Value> = diagnostics
+		.iter()
+		.map(|d| {
+			let kind = match d.kind {
+				DiagnosticKind::UnmatchedBlockClose => "unmatchedBlockClose",
+				DiagnosticKind::UnclosedBlock => "unclosedBlock",
+				DiagnosticKind::DuplicateMatchArm => "duplicateMatchArm",
+				DiagnosticKind::DanglingAttrSlot => "danglingAttrSlot",
+				DiagnosticKind::MissingSelectorTarget => "missingSelectorTarget",
+				DiagnosticKind::DepthLimitExceeded => "depthLimitExceeded",
+				DiagnosticKind::RangeLimitExceeded => "rangeLimitExceeded",
+			};
+			json!({ "kind": kind, "directive": d.directive })
+		})
+		.collect();
+	json!({ "html": html, "diagnostics": diagnostics }).to_string()
+}
+
+/// Byte ranges of the template's markers, derived without rendering:
+/// `[{"kind": "text", "path": "title", "start": 4, "end": 21}]`.
+#[wasm_bindgen]
+pub fn slot_positions(template: &str) -> String {
+	let positions: Vec<Value> = seam_injector::slot_positions(template)
+		.iter()
+		.map(|p| json!({ "kind": p.kind.as_str(), "path": p.path, "start": p.start, "end": p.end }))
+		.collect();
+	Value::Array(positions).to_string()
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn diagnostics_serialized_for_malformed_template() {
+		let out = inject_with_diagnostics("<!--seam:if:x-->hi<!--seam:endeach-->", r#"{"x":true}"#);
+		let parsed: Value = serde_json::from_str(&out).unwrap();
+		assert_eq!(parsed["html"], "hi");
+		assert_eq!(
+			parsed["diagnostics"],
+			json!([
+				{"kind": "unmatchedBlockClose", "directive": "endeach"},
+				{"kind": "unclosedBlock", "directive": "if:x"}
+			])
+		);
+	}
+
+	#[test]
+	fn well_formed_template_has_no_diagnostics() {
+		let out = inject_with_diagnostics("<p><!--seam:a--></p>", r#"{"a":"b"}"#);
+		let parsed: Value = serde_json::from_str(&out).unwrap();
+		assert_eq!(parsed, json!({"html": "<p>b</p>", "diagnostics": []}));
+	}
+
+	#[test]
+	fn slot_positions_serialized() {
+		let parsed: Value = serde_json::from_str(&slot_positions("<p><!--seam:a--></p>")).unwrap();
+		assert_eq!(parsed, json!([{"kind": "text", "path": "a", "start": 3, "end": 16}]));
+	}
+}